@@ -0,0 +1,127 @@
+//! Diferencial de alíquotas para consumidor final não contribuinte (DIFAL)
+//!
+//! Nas vendas interestaduais a consumidor final não contribuinte, o
+//! remetente recolhe à UF de destino a diferença entre a alíquota interna
+//! do destino e a interestadual (EC 87/2015). Desde 2019 a partilha é
+//! 100% para o destino e, desde a LC 190/2022, cada UF define o método:
+//!
+//! ```text
+//! Base única:  vBCUFDest   = V
+//!              vICMSUFDest = V × (pICMSUFDest − pICMSInter)
+//!
+//! Base dupla:  vBCUFDest   = (V − V × pICMSInter) / (1 − (pICMSUFDest + pFCPUFDest))
+//!              vICMSUFDest = vBCUFDest × pICMSUFDest − V × pICMSInter
+//!
+//! Ambos:       vFCPUFDest  = vBCUFDest × pFCPUFDest
+//! ```
+//!
+//! Onde `V` é o valor da operação (produto, frete, seguro e outras despesas,
+//! com o IPI e sem o desconto).
+
+use crate::base::decimal::{arredondar_abnt, Decimal, CASAS_VALOR};
+use crate::base::item::IcmsUfDest;
+use crate::base::municipios::{buscar_uf, calcular_aliquota_interestadual};
+
+/// UFs que adotam a base dupla, conforme a legislação estadual
+const UFS_BASE_DUPLA: [&str; 14] = [
+    "AL", "BA", "GO", "MA", "MG", "PA", "PB", "PE", "PI", "PR", "RS", "SC", "SE", "TO",
+];
+
+/// Método de cálculo da base do DIFAL
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MetodoDifal {
+    /// Base única: a BC é o valor da operação
+    BaseUnica,
+    /// Base dupla: a BC é recomposta com o ICMS interno "por dentro"
+    BaseDupla,
+}
+
+impl MetodoDifal {
+    /// Método adotado pela UF de destino
+    pub fn da_uf(sigla: &str) -> Self {
+        if UFS_BASE_DUPLA.contains(&sigla) {
+            MetodoDifal::BaseDupla
+        } else {
+            MetodoDifal::BaseUnica
+        }
+    }
+}
+
+/// Dados da operação necessários ao cálculo do DIFAL
+///
+/// As alíquotas não informadas vêm de [`buscar_uf`] e de
+/// [`calcular_aliquota_interestadual`].
+#[derive(Debug, Clone, Default)]
+pub struct ParametrosDifal {
+    /// Valor da operação (produto + frete + seguro + outras + IPI − desconto)
+    pub valor_operacao: Decimal,
+    /// UF do remetente
+    pub uf_origem: String,
+    /// UF do destinatário
+    pub uf_destino: String,
+    /// Origem da mercadoria (1, 2, 3 e 8 usam a alíquota de 4%)
+    pub origem_mercadoria: u8,
+    /// Alíquota interestadual (`None`: conforme as UFs e a origem)
+    pub aliquota_interestadual: Option<Decimal>,
+    /// Alíquota interna do destino (`None`: alíquota padrão da UF)
+    pub aliquota_interna: Option<Decimal>,
+    /// Percentual do FCP do destino (`None`: FCP padrão da UF)
+    pub aliquota_fcp: Option<Decimal>,
+    /// Método de cálculo (`None`: método adotado pela UF de destino)
+    pub metodo: Option<MetodoDifal>,
+}
+
+/// Calcula o grupo `<ICMSUFDest>` do item
+pub fn calcular_difal(parametros: &ParametrosDifal) -> Result<IcmsUfDest, String> {
+    if parametros.uf_origem == parametros.uf_destino {
+        return Err(format!(
+            "DIFAL exige operação interestadual: origem e destino em {}",
+            parametros.uf_destino
+        ));
+    }
+    let destino = buscar_uf(&parametros.uf_destino)
+        .ok_or_else(|| format!("UF de destino desconhecida: {}", parametros.uf_destino))?;
+
+    let aplicar = |base: Decimal, aliquota: Decimal| {
+        arredondar_abnt(base * aliquota / Decimal::ONE_HUNDRED, CASAS_VALOR)
+    };
+
+    let aliquota_interestadual = match parametros.aliquota_interestadual {
+        Some(aliquota) => aliquota,
+        None => match (parametros.origem_mercadoria, buscar_uf(&parametros.uf_origem)) {
+            (1 | 2 | 3 | 8, Some(uf)) => uf.aliquota_importados,
+            _ => calcular_aliquota_interestadual(&parametros.uf_origem, &parametros.uf_destino),
+        },
+    };
+    let aliquota_interna = parametros.aliquota_interna.unwrap_or(destino.aliquota_icms_interna);
+    let aliquota_fcp = parametros
+        .aliquota_fcp
+        .unwrap_or(destino.aliquota_fcp.unwrap_or_default());
+    let metodo = parametros.metodo.unwrap_or(MetodoDifal::da_uf(&parametros.uf_destino));
+
+    let valor = parametros.valor_operacao;
+    let icms_origem = aplicar(valor, aliquota_interestadual);
+    let (valor_bc, valor_icms_uf_dest) = match metodo {
+        MetodoDifal::BaseUnica => (valor, aplicar(valor, aliquota_interna) - icms_origem),
+        MetodoDifal::BaseDupla => {
+            let complemento = Decimal::ONE_HUNDRED - aliquota_interna - aliquota_fcp;
+            let base = arredondar_abnt(
+                (valor - icms_origem) * Decimal::ONE_HUNDRED / complemento,
+                CASAS_VALOR,
+            );
+            (base, aplicar(base, aliquota_interna) - icms_origem)
+        }
+    };
+
+    let fcp = aliquota_fcp > Decimal::ZERO;
+    Ok(IcmsUfDest {
+        valor_bc_uf_dest: valor_bc,
+        valor_bc_fcp_uf_dest: fcp.then_some(valor_bc),
+        percentual_fcp_uf_dest: fcp.then_some(aliquota_fcp),
+        aliquota_uf_dest: aliquota_interna,
+        aliquota_interestadual,
+        valor_fcp_uf_dest: fcp.then(|| aplicar(valor_bc, aliquota_fcp)),
+        valor_icms_uf_dest: valor_icms_uf_dest.max(Decimal::ZERO),
+        ..Default::default()
+    })
+}
@@ -0,0 +1,150 @@
+//! IBS e CBS no período de transição da Reforma Tributária
+//!
+//! A LC 214/2025 (arts. 343 a 348) fixa alíquotas de teste e uma substituição
+//! gradual do ICMS/ISS pelo IBS entre 2026 e 2033:
+//!
+//! ```text
+//! Ano        CBS               IBS UF            IBS Município
+//! 2026       0,9%              0,1%              0%
+//! 2027-2028  referência − 0,1  0,05%             0,05%
+//! 2029       referência        10% da ref.       10% da ref.
+//! 2030       referência        20% da ref.       20% da ref.
+//! 2031       referência        30% da ref.       30% da ref.
+//! 2032       referência        40% da ref.       40% da ref.
+//! 2033+      referência        referência        referência
+//! ```
+//!
+//! As alíquotas de referência são fixadas pelo Senado Federal e, por isso,
+//! informadas pelo chamador.
+
+use crate::base::decimal::{arredondar, Decimal, CASAS_PERCENTUAL, CASAS_VALOR};
+use crate::base::item::{
+    Cbs, Diferimento, GrupoIbsCbs, IbsCbs, IbsMunicipal, IbsUf, ReducaoAliquota,
+};
+
+/// Alíquotas do IBS e da CBS, em percentual
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct AliquotasIbsCbs {
+    /// Alíquota da CBS
+    pub cbs: Decimal,
+    /// Alíquota do IBS estadual
+    pub ibs_uf: Decimal,
+    /// Alíquota do IBS municipal
+    pub ibs_mun: Decimal,
+}
+
+/// Dados do item necessários ao cálculo do IBS e da CBS
+#[derive(Debug, Clone, Default)]
+pub struct ParametrosIbsCbs {
+    /// CST do IBS/CBS (ex.: `"000"`)
+    pub cst: String,
+    /// Código de classificação tributária (ex.: `"000001"`)
+    pub classificacao_tributaria: String,
+    /// Base de cálculo comum ao IBS e à CBS
+    pub valor_bc: Decimal,
+    /// Ano de emissão, que define as alíquotas de transição
+    pub ano: i32,
+    /// Alíquotas de referência fixadas pelo Senado
+    pub aliquotas_referencia: AliquotasIbsCbs,
+    /// Percentual de redução de alíquota (ex.: `dec!(60)` para a cesta reduzida)
+    pub percentual_reducao: Decimal,
+    /// Percentual de diferimento
+    pub percentual_diferimento: Decimal,
+}
+
+/// Alíquotas aplicáveis no ano, conforme o cronograma de transição
+///
+/// Retorna `None` antes de 2026, quando o IBS e a CBS ainda não incidem.
+pub fn aliquotas_transicao(ano: i32, referencia: &AliquotasIbsCbs) -> Option<AliquotasIbsCbs> {
+    let fracao_ibs = |fracao: Decimal| AliquotasIbsCbs {
+        cbs: referencia.cbs,
+        ibs_uf: arredondar(referencia.ibs_uf * fracao, CASAS_PERCENTUAL),
+        ibs_mun: arredondar(referencia.ibs_mun * fracao, CASAS_PERCENTUAL),
+    };
+
+    match ano {
+        i32::MIN..=2025 => None,
+        2026 => Some(AliquotasIbsCbs {
+            cbs: Decimal::new(9, 1),
+            ibs_uf: Decimal::new(1, 1),
+            ibs_mun: Decimal::ZERO,
+        }),
+        2027 | 2028 => Some(AliquotasIbsCbs {
+            cbs: (referencia.cbs - Decimal::new(1, 1)).max(Decimal::ZERO),
+            ibs_uf: Decimal::new(5, 2),
+            ibs_mun: Decimal::new(5, 2),
+        }),
+        2029 => Some(fracao_ibs(Decimal::new(1, 1))),
+        2030 => Some(fracao_ibs(Decimal::new(2, 1))),
+        2031 => Some(fracao_ibs(Decimal::new(3, 1))),
+        2032 => Some(fracao_ibs(Decimal::new(4, 1))),
+        _ => Some(*referencia),
+    }
+}
+
+/// Calcula o grupo `<IBSCBS>` do item
+///
+/// Retorna `None` para anos anteriores à vigência do IBS/CBS.
+pub fn calcular_ibs_cbs(parametros: &ParametrosIbsCbs) -> Option<IbsCbs> {
+    let aliquotas = aliquotas_transicao(parametros.ano, &parametros.aliquotas_referencia)?;
+    let bc = parametros.valor_bc;
+    let percentual = |valor: Decimal, p: Decimal| valor * p / Decimal::ONE_HUNDRED;
+
+    // Redução de alíquota e diferimento só são informados quando houver
+    let reducao = |aliquota: Decimal| {
+        (parametros.percentual_reducao > Decimal::ZERO).then(|| ReducaoAliquota {
+            percentual: parametros.percentual_reducao,
+            aliquota_efetiva: arredondar(
+                aliquota - percentual(aliquota, parametros.percentual_reducao),
+                CASAS_PERCENTUAL,
+            ),
+        })
+    };
+    let aplicar = |aliquota: Decimal| {
+        let reducao = reducao(aliquota);
+        let efetiva = reducao.as_ref().map(|r| r.aliquota_efetiva).unwrap_or(aliquota);
+        let bruto = arredondar(percentual(bc, efetiva), CASAS_VALOR);
+        let diferimento = (parametros.percentual_diferimento > Decimal::ZERO).then(|| Diferimento {
+            percentual: parametros.percentual_diferimento,
+            valor: arredondar(percentual(bruto, parametros.percentual_diferimento), CASAS_VALOR),
+        });
+        let valor = bruto - diferimento.as_ref().map(|d| d.valor).unwrap_or_default();
+        (reducao, diferimento, valor)
+    };
+
+    let (reducao_uf, diferimento_uf, valor_uf) = aplicar(aliquotas.ibs_uf);
+    let (reducao_mun, diferimento_mun, valor_mun) = aplicar(aliquotas.ibs_mun);
+    let (reducao_cbs, diferimento_cbs, valor_cbs) = aplicar(aliquotas.cbs);
+
+    Some(IbsCbs {
+        cst: parametros.cst.clone(),
+        classificacao_tributaria: parametros.classificacao_tributaria.clone(),
+        valores: Some(GrupoIbsCbs {
+            valor_bc: bc,
+            ibs_uf: IbsUf {
+                aliquota: aliquotas.ibs_uf,
+                diferimento: diferimento_uf,
+                devolucao: None,
+                reducao: reducao_uf,
+                valor: valor_uf,
+            },
+            ibs_municipal: IbsMunicipal {
+                aliquota: aliquotas.ibs_mun,
+                diferimento: diferimento_mun,
+                devolucao: None,
+                reducao: reducao_mun,
+                valor: valor_mun,
+            },
+            valor_ibs: valor_uf + valor_mun,
+            cbs: Cbs {
+                aliquota: aliquotas.cbs,
+                diferimento: diferimento_cbs,
+                devolucao: None,
+                reducao: reducao_cbs,
+                valor: valor_cbs,
+            },
+            credito_presumido_ibs: None,
+            credito_presumido_cbs: None,
+        }),
+    })
+}
@@ -0,0 +1,421 @@
+//! ICMS próprio por CST/CSOSN
+//!
+//! Monta o grupo `<ICMS>` do item a partir do código de tributação e das
+//! alíquotas da operação.
+//!
+//! ```text
+//! vBC   = (vProd + vFrete + vSeg + vOutro − vDesc + vIPI) × (1 − pRedBC)
+//! vICMS = vBC × pICMS
+//! vFCP  = vBC × pFCP
+//! ```
+//!
+//! O IPI só integra a base quando o destinatário não é contribuinte ou a
+//! mercadoria se destina a uso e consumo (CF, art. 155, § 2º, XI). Os valores
+//! são arredondados pela ABNT NBR 5891.
+
+use super::{calcular_icms_st, ParametrosIcmsSt};
+use crate::base::decimal::{arredondar_abnt, Decimal, CASAS_VALOR};
+use crate::base::item::{
+    Icms00, Icms10, Icms20, Icms30, Icms40, Icms51, Icms60, Icms70, Icms90,
+    IcmsContainer, IcmsSn101, IcmsSn102, IcmsSn201, IcmsSn202, IcmsSn500, IcmsSn900, IpiContainer,
+};
+use crate::base::municipios::{buscar_uf, calcular_aliquota_interestadual};
+
+/// Modalidade de BC padrão: 3 - Valor da operação
+const MODALIDADE_VALOR_OPERACAO: u8 = 3;
+
+/// Dados do item necessários ao cálculo do ICMS
+///
+/// Alíquotas em percentual (ex.: `dec!(18)` para 18%).
+#[derive(Debug, Clone, Default)]
+pub struct ParametrosIcms {
+    /// Origem da mercadoria (0-8)
+    pub origem: u8,
+    /// CST (2 dígitos, regime normal) ou CSOSN (3 dígitos, Simples Nacional)
+    pub cst: String,
+    /// Modalidade de determinação da BC (`None`: 3 - valor da operação)
+    pub modalidade_bc: Option<u8>,
+    /// Valor bruto do produto (tag `<vProd>`)
+    pub valor_produto: Decimal,
+    /// Frete rateado para o item
+    pub valor_frete: Decimal,
+    /// Seguro rateado para o item
+    pub valor_seguro: Decimal,
+    /// Outras despesas acessórias
+    pub valor_outros: Decimal,
+    /// Desconto concedido no item
+    pub valor_desconto: Decimal,
+    /// IPI que integra a BC do ICMS (consumidor final ou uso e consumo)
+    pub valor_ipi: Decimal,
+    /// Alíquota do ICMS
+    pub aliquota: Decimal,
+    /// Percentual de redução da BC (CST 20, 51, 70 e 90)
+    pub percentual_reducao_bc: Decimal,
+    /// Percentual do Fundo de Combate à Pobreza
+    pub aliquota_fcp: Decimal,
+    /// Alíquota do crédito do Simples Nacional (CSOSN 101, 201 e 900)
+    pub aliquota_credito_sn: Decimal,
+    /// Percentual do diferimento (CST 51)
+    pub percentual_diferimento: Decimal,
+    /// Motivo da desoneração, quando o ICMS dispensado deve ser informado
+    pub motivo_desoneracao: Option<u8>,
+    /// Substituição tributária (CST 10, 30, 70 e 90; CSOSN 201, 202, 203 e 900)
+    pub st: Option<ParametrosIcmsSt>,
+}
+
+impl ParametrosIcms {
+    /// Usa a alíquota interna e o FCP da UF como padrão
+    ///
+    /// Só preenche as alíquotas ainda zeradas. UFs desconhecidas são ignoradas.
+    pub fn com_aliquotas_uf(mut self, sigla: &str) -> Self {
+        if let Some(uf) = buscar_uf(sigla) {
+            if self.aliquota.is_zero() {
+                self.aliquota = uf.aliquota_icms_interna;
+            }
+            if self.aliquota_fcp.is_zero() {
+                self.aliquota_fcp = uf.aliquota_fcp.unwrap_or_default();
+            }
+        }
+        self
+    }
+
+    /// Usa a alíquota interestadual entre as UFs como padrão
+    ///
+    /// Mercadorias importadas (origem 1, 2, 3 e 8) usam a alíquota de 4%
+    /// (Resolução do Senado 13/2012).
+    pub fn com_aliquota_interestadual(mut self, uf_origem: &str, uf_destino: &str) -> Self {
+        if self.aliquota.is_zero() {
+            self.aliquota = match (self.origem, buscar_uf(uf_origem)) {
+                (1 | 2 | 3 | 8, Some(uf)) => uf.aliquota_importados,
+                _ => calcular_aliquota_interestadual(uf_origem, uf_destino),
+            };
+        }
+        self
+    }
+
+    /// Informa o IPI já calculado do item
+    ///
+    /// O IPI sempre integra a BC do ICMS-ST; a do ICMS próprio, só quando
+    /// `integra_bc_icms` (consumidor final ou mercadoria para uso e consumo).
+    pub fn com_ipi(mut self, ipi: &IpiContainer, integra_bc_icms: bool) -> Self {
+        let valor = ipi.valor_ipi();
+        if integra_bc_icms {
+            self.valor_ipi = valor;
+        }
+        if let Some(st) = &mut self.st {
+            st.valor_ipi = valor;
+        }
+        self
+    }
+
+    /// Valor da mercadoria: produto e despesas acessórias, menos o desconto
+    pub fn valor_mercadoria(&self) -> Decimal {
+        self.valor_produto + self.valor_frete + self.valor_seguro + self.valor_outros
+            - self.valor_desconto
+    }
+
+    /// Valor da operação: valor da mercadoria e IPI que integra a BC
+    pub fn valor_operacao(&self) -> Decimal {
+        self.valor_mercadoria() + self.valor_ipi
+    }
+
+    /// Base de cálculo do ICMS, com a redução aplicada
+    pub fn base_calculo(&self) -> Decimal {
+        let reducao = self.valor_operacao() * self.percentual_reducao_bc / Decimal::ONE_HUNDRED;
+        arredondar_abnt(self.valor_operacao() - reducao, CASAS_VALOR)
+    }
+}
+
+/// Calcula o grupo `<ICMS>` do item para o CST/CSOSN informado
+///
+/// Os CST/CSOSN com substituição tributária (10, 30, 70, 201, 202 e 203)
+/// exigem os parâmetros do ICMS-ST; nos CST 90 e CSOSN 900 eles são opcionais.
+pub fn calcular_icms(parametros: &ParametrosIcms) -> Result<IcmsContainer, String> {
+    let aplicar = |base: Decimal, aliquota: Decimal| {
+        arredondar_abnt(base * aliquota / Decimal::ONE_HUNDRED, CASAS_VALOR)
+    };
+    let opcional = |valor: Decimal| if valor > Decimal::ZERO { Some(valor) } else { None };
+
+    let origem = parametros.origem;
+    let cst = parametros.cst.as_str();
+    let modalidade_bc = parametros.modalidade_bc.unwrap_or(MODALIDADE_VALOR_OPERACAO);
+    let valor_bc = parametros.base_calculo();
+    let valor = aplicar(valor_bc, parametros.aliquota);
+    let valor_fcp = aplicar(valor_bc, parametros.aliquota_fcp);
+    let percentual_fcp = opcional(parametros.aliquota_fcp);
+
+    // ICMS dispensado: o que seria devido sem o benefício
+    let desonerado = |devido: Decimal| {
+        parametros.motivo_desoneracao.map(|_| {
+            aplicar(parametros.valor_operacao(), parametros.aliquota) - devido
+        })
+    };
+
+    let st = parametros.st.as_ref().map(|st| calcular_icms_st(parametros, st));
+    let exigir_st = || {
+        st.clone()
+            .ok_or_else(|| format!("CST/CSOSN {} exige os parâmetros do ICMS-ST", cst))
+    };
+
+    let mut icms = IcmsContainer::default();
+    match cst {
+        "00" => {
+            icms.icms00 = Some(Icms00 {
+                origem,
+                cst: cst.to_string(),
+                modalidade_bc,
+                valor_bc,
+                aliquota: parametros.aliquota,
+                valor,
+                percentual_fcp,
+                valor_fcp: percentual_fcp.map(|_| valor_fcp),
+            })
+        }
+        "10" => {
+            let st = exigir_st()?;
+            icms.icms10 = Some(Icms10 {
+                origem,
+                cst: cst.to_string(),
+                modalidade_bc,
+                valor_bc,
+                aliquota: parametros.aliquota,
+                valor,
+                valor_bc_fcp: percentual_fcp.map(|_| valor_bc),
+                percentual_fcp,
+                valor_fcp: percentual_fcp.map(|_| valor_fcp),
+                modalidade_bc_st: st.modalidade_bc_st,
+                percentual_mva_st: st.percentual_mva_st,
+                percentual_reducao_bc_st: st.percentual_reducao_bc_st,
+                valor_bc_st: st.valor_bc_st,
+                aliquota_st: st.aliquota_st,
+                valor_st: st.valor_st,
+                valor_bc_fcp_st: st.valor_bc_fcp_st,
+                percentual_fcp_st: st.percentual_fcp_st,
+                valor_fcp_st: st.valor_fcp_st,
+            })
+        }
+        "20" => {
+            icms.icms20 = Some(Icms20 {
+                origem,
+                cst: cst.to_string(),
+                modalidade_bc,
+                percentual_reducao_bc: parametros.percentual_reducao_bc,
+                valor_bc,
+                aliquota: parametros.aliquota,
+                valor,
+                valor_bc_fcp: percentual_fcp.map(|_| valor_bc),
+                percentual_fcp,
+                valor_fcp: percentual_fcp.map(|_| valor_fcp),
+                valor_icms_desonerado: desonerado(valor),
+                motivo_desoneracao: parametros.motivo_desoneracao,
+            })
+        }
+        "30" => {
+            let st = exigir_st()?;
+            icms.icms30 = Some(Icms30 {
+                origem,
+                cst: cst.to_string(),
+                modalidade_bc_st: st.modalidade_bc_st,
+                percentual_mva_st: st.percentual_mva_st,
+                percentual_reducao_bc_st: st.percentual_reducao_bc_st,
+                valor_bc_st: st.valor_bc_st,
+                aliquota_st: st.aliquota_st,
+                valor_st: st.valor_st,
+                valor_bc_fcp_st: st.valor_bc_fcp_st,
+                percentual_fcp_st: st.percentual_fcp_st,
+                valor_fcp_st: st.valor_fcp_st,
+                valor_icms_desonerado: desonerado(Decimal::ZERO),
+                motivo_desoneracao: parametros.motivo_desoneracao,
+            })
+        }
+        // O leiaute não tem grupos ICMS41 e ICMS50: os CST 41 e 50 vão no ICMS40
+        "40" | "41" | "50" => {
+            icms.icms40 = Some(Icms40 {
+                origem,
+                cst: cst.to_string(),
+                valor_icms_desonerado: desonerado(Decimal::ZERO),
+                motivo_desoneracao: parametros.motivo_desoneracao,
+            })
+        }
+        "51" => {
+            let valor_diferido = aplicar(valor, parametros.percentual_diferimento);
+            icms.icms51 = Some(Icms51 {
+                origem,
+                cst: cst.to_string(),
+                modalidade_bc: Some(modalidade_bc),
+                percentual_reducao_bc: opcional(parametros.percentual_reducao_bc),
+                valor_bc: Some(valor_bc),
+                aliquota: Some(parametros.aliquota),
+                valor_icms_operacao: Some(valor),
+                percentual_diferimento: Some(parametros.percentual_diferimento),
+                valor_icms_diferido: Some(valor_diferido),
+                valor: Some(valor - valor_diferido),
+                valor_bc_fcp: percentual_fcp.map(|_| valor_bc),
+                percentual_fcp,
+                valor_fcp: percentual_fcp.map(|_| valor_fcp),
+            })
+        }
+        "60" => {
+            icms.icms60 = Some(Icms60 {
+                origem,
+                cst: cst.to_string(),
+                valor_bc_st_retido: None,
+                aliquota_st_consumidor: None,
+                valor_icms_substituto: None,
+                valor_st_retido: None,
+                valor_bc_fcp_st_retido: None,
+                percentual_fcp_st_retido: None,
+                valor_fcp_st_retido: None,
+                percentual_reducao_bc_efetiva: None,
+                valor_bc_efetiva: None,
+                aliquota_efetiva: None,
+                valor_efetivo: None,
+            })
+        }
+        "70" => {
+            let st = exigir_st()?;
+            icms.icms70 = Some(Icms70 {
+                origem,
+                cst: cst.to_string(),
+                modalidade_bc,
+                percentual_reducao_bc: parametros.percentual_reducao_bc,
+                valor_bc,
+                aliquota: parametros.aliquota,
+                valor,
+                valor_bc_fcp: percentual_fcp.map(|_| valor_bc),
+                percentual_fcp,
+                valor_fcp: percentual_fcp.map(|_| valor_fcp),
+                modalidade_bc_st: st.modalidade_bc_st,
+                percentual_mva_st: st.percentual_mva_st,
+                percentual_reducao_bc_st: st.percentual_reducao_bc_st,
+                valor_bc_st: st.valor_bc_st,
+                aliquota_st: st.aliquota_st,
+                valor_st: st.valor_st,
+                valor_bc_fcp_st: st.valor_bc_fcp_st,
+                percentual_fcp_st: st.percentual_fcp_st,
+                valor_fcp_st: st.valor_fcp_st,
+                valor_icms_desonerado: desonerado(valor),
+                motivo_desoneracao: parametros.motivo_desoneracao,
+            })
+        }
+        "90" => {
+            let tributado = parametros.aliquota > Decimal::ZERO;
+            icms.icms90 = Some(Icms90 {
+                origem,
+                cst: cst.to_string(),
+                modalidade_bc: tributado.then_some(modalidade_bc),
+                percentual_reducao_bc: opcional(parametros.percentual_reducao_bc),
+                valor_bc: tributado.then_some(valor_bc),
+                aliquota: tributado.then_some(parametros.aliquota),
+                valor: tributado.then_some(valor),
+                valor_bc_fcp: percentual_fcp.map(|_| valor_bc),
+                percentual_fcp,
+                valor_fcp: percentual_fcp.map(|_| valor_fcp),
+                modalidade_bc_st: st.as_ref().map(|st| st.modalidade_bc_st),
+                percentual_mva_st: st.as_ref().and_then(|st| st.percentual_mva_st),
+                percentual_reducao_bc_st: st.as_ref().and_then(|st| st.percentual_reducao_bc_st),
+                valor_bc_st: st.as_ref().map(|st| st.valor_bc_st),
+                aliquota_st: st.as_ref().map(|st| st.aliquota_st),
+                valor_st: st.as_ref().map(|st| st.valor_st),
+                valor_bc_fcp_st: st.as_ref().and_then(|st| st.valor_bc_fcp_st),
+                percentual_fcp_st: st.as_ref().and_then(|st| st.percentual_fcp_st),
+                valor_fcp_st: st.as_ref().and_then(|st| st.valor_fcp_st),
+                valor_icms_desonerado: desonerado(valor),
+                motivo_desoneracao: parametros.motivo_desoneracao,
+            })
+        }
+        "101" => {
+            icms.icms_sn101 = Some(IcmsSn101 {
+                origem,
+                csosn: cst.to_string(),
+                aliquota_credito_sn: parametros.aliquota_credito_sn,
+                valor_credito_icms_sn: aplicar(parametros.valor_operacao(), parametros.aliquota_credito_sn),
+            })
+        }
+        "102" | "103" | "300" | "400" => {
+            icms.icms_sn102 = Some(IcmsSn102 {
+                origem,
+                csosn: cst.to_string(),
+            })
+        }
+        "201" => {
+            let st = exigir_st()?;
+            icms.icms_sn201 = Some(IcmsSn201 {
+                origem,
+                csosn: cst.to_string(),
+                modalidade_bc_st: st.modalidade_bc_st,
+                percentual_mva_st: st.percentual_mva_st,
+                percentual_reducao_bc_st: st.percentual_reducao_bc_st,
+                valor_bc_st: st.valor_bc_st,
+                aliquota_st: st.aliquota_st,
+                valor_st: st.valor_st,
+                valor_bc_fcp_st: st.valor_bc_fcp_st,
+                percentual_fcp_st: st.percentual_fcp_st,
+                valor_fcp_st: st.valor_fcp_st,
+                aliquota_credito_sn: parametros.aliquota_credito_sn,
+                valor_credito_icms_sn: aplicar(parametros.valor_operacao(), parametros.aliquota_credito_sn),
+            })
+        }
+        "202" | "203" => {
+            let st = exigir_st()?;
+            icms.icms_sn202 = Some(IcmsSn202 {
+                origem,
+                csosn: cst.to_string(),
+                modalidade_bc_st: st.modalidade_bc_st,
+                percentual_mva_st: st.percentual_mva_st,
+                percentual_reducao_bc_st: st.percentual_reducao_bc_st,
+                valor_bc_st: st.valor_bc_st,
+                aliquota_st: st.aliquota_st,
+                valor_st: st.valor_st,
+                valor_bc_fcp_st: st.valor_bc_fcp_st,
+                percentual_fcp_st: st.percentual_fcp_st,
+                valor_fcp_st: st.valor_fcp_st,
+            })
+        }
+        "500" => {
+            icms.icms_sn500 = Some(IcmsSn500 {
+                origem,
+                csosn: cst.to_string(),
+                valor_bc_st_retido: None,
+                aliquota_st_consumidor: None,
+                valor_icms_substituto: None,
+                valor_st_retido: None,
+                valor_bc_fcp_st_retido: None,
+                percentual_fcp_st_retido: None,
+                valor_fcp_st_retido: None,
+                percentual_reducao_bc_efetiva: None,
+                valor_bc_efetiva: None,
+                aliquota_efetiva: None,
+                valor_efetivo: None,
+            })
+        }
+        "900" => {
+            let tributado = parametros.aliquota > Decimal::ZERO;
+            let credito = parametros.aliquota_credito_sn > Decimal::ZERO;
+            icms.icms_sn900 = Some(IcmsSn900 {
+                origem,
+                csosn: cst.to_string(),
+                modalidade_bc: tributado.then_some(modalidade_bc),
+                percentual_reducao_bc: opcional(parametros.percentual_reducao_bc),
+                valor_bc: tributado.then_some(valor_bc),
+                aliquota: tributado.then_some(parametros.aliquota),
+                valor: tributado.then_some(valor),
+                modalidade_bc_st: st.as_ref().map(|st| st.modalidade_bc_st),
+                percentual_mva_st: st.as_ref().and_then(|st| st.percentual_mva_st),
+                percentual_reducao_bc_st: st.as_ref().and_then(|st| st.percentual_reducao_bc_st),
+                valor_bc_st: st.as_ref().map(|st| st.valor_bc_st),
+                aliquota_st: st.as_ref().map(|st| st.aliquota_st),
+                valor_st: st.as_ref().map(|st| st.valor_st),
+                valor_bc_fcp_st: st.as_ref().and_then(|st| st.valor_bc_fcp_st),
+                percentual_fcp_st: st.as_ref().and_then(|st| st.percentual_fcp_st),
+                valor_fcp_st: st.as_ref().and_then(|st| st.valor_fcp_st),
+                aliquota_credito_sn: credito.then_some(parametros.aliquota_credito_sn),
+                valor_credito_icms_sn: credito
+                    .then(|| aplicar(parametros.valor_operacao(), parametros.aliquota_credito_sn)),
+            })
+        }
+        _ => return Err(format!("CST/CSOSN de ICMS inválido: {}", cst)),
+    }
+
+    Ok(icms)
+}
@@ -0,0 +1,159 @@
+//! ICMS retido por substituição tributária (ICMS-ST)
+//!
+//! O substituto recolhe antecipadamente o ICMS das operações seguintes,
+//! presumindo o preço ao consumidor final pela MVA ou por um valor de pauta.
+//!
+//! ```text
+//! vBCST   = (vProd + vFrete + vSeg + vOutro − vDesc + vIPI) × (1 + pMVAST) × (1 − pRedBCST)
+//! vBCST   = pauta ou PMC × quantidade                      (modBCST 0 ou 5)
+//! vICMSST = vBCST × pICMSST − vICMS próprio
+//! vFCPST  = vBCST × pFCPST
+//! ```
+//!
+//! Diferente do ICMS-ST, o FCP-ST não deduz o FCP da operação própria
+//! (NT 2016.002): é a BC do FCP-ST, aqui igual à do ICMS-ST, vezes `pFCPST`.
+//!
+//! Nas operações interestaduais a MVA é ajustada pela diferença entre a
+//! alíquota interestadual e a interna do destino (Convênio ICMS 142/2018):
+//!
+//! ```text
+//! MVA ajustada = [(1 + MVA original) × (1 − ALQ inter) / (1 − ALQ intra)] − 1
+//! ```
+
+use super::ParametrosIcms;
+use crate::base::decimal::{arredondar, arredondar_abnt, Decimal, CASAS_PERCENTUAL, CASAS_VALOR};
+use crate::base::municipios::buscar_uf;
+
+/// Modalidade de BC do ICMS-ST: 4 - Margem Valor Agregado (%)
+const MODALIDADE_MVA: u8 = 4;
+
+/// Modalidade de BC do ICMS-ST: 5 - Pauta (valor)
+const MODALIDADE_PAUTA: u8 = 5;
+
+/// Dados da substituição tributária do item
+///
+/// Alíquotas em percentual (ex.: `dec!(18)` para 18%).
+#[derive(Debug, Clone, Default)]
+pub struct ParametrosIcmsSt {
+    /// Modalidade de determinação da BC do ICMS-ST
+    ///
+    /// `None`: 5 - Pauta quando há base de pauta, senão 4 - MVA.
+    pub modalidade_bc_st: Option<u8>,
+    /// MVA original do protocolo ou convênio
+    pub percentual_mva: Decimal,
+    /// Alíquota interestadual da operação (4%, 7% ou 12%)
+    ///
+    /// Quando informada, a MVA original é ajustada por ela. Não se confunde
+    /// com `ParametrosIcms::aliquota`, que no CST 30 e nos CSOSN 201/202 não
+    /// é a alíquota da operação interestadual.
+    pub aliquota_interestadual: Option<Decimal>,
+    /// Percentual de redução da BC do ICMS-ST
+    pub percentual_reducao_bc_st: Decimal,
+    /// Alíquota interna da UF de destino
+    pub aliquota_interna: Decimal,
+    /// Percentual do FCP retido por ST na UF de destino
+    pub aliquota_fcp_st: Decimal,
+    /// IPI do item, que sempre integra a BC do ICMS-ST
+    pub valor_ipi: Decimal,
+    /// BC pela pauta fiscal ou pelo PMC (valor unitário × quantidade)
+    ///
+    /// Quando informada, substitui a base pela MVA.
+    pub base_pauta: Option<Decimal>,
+}
+
+/// Campos de ST do grupo de ICMS (`modBCST` a `vFCPST`)
+#[derive(Debug, Clone, PartialEq)]
+pub struct CalculoIcmsSt {
+    /// Modalidade de determinação da BC do ICMS-ST
+    pub modalidade_bc_st: u8,
+    /// MVA aplicada (já ajustada, quando for o caso)
+    pub percentual_mva_st: Option<Decimal>,
+    /// Percentual de redução da BC do ICMS-ST
+    pub percentual_reducao_bc_st: Option<Decimal>,
+    /// BC do ICMS-ST
+    pub valor_bc_st: Decimal,
+    /// Alíquota interna do destino
+    pub aliquota_st: Decimal,
+    /// ICMS-ST retido, deduzido o ICMS próprio
+    pub valor_st: Decimal,
+    /// BC do FCP-ST
+    pub valor_bc_fcp_st: Option<Decimal>,
+    /// Percentual do FCP-ST
+    pub percentual_fcp_st: Option<Decimal>,
+    /// FCP retido por ST
+    pub valor_fcp_st: Option<Decimal>,
+}
+
+impl ParametrosIcmsSt {
+    /// Usa a alíquota interna e o FCP da UF de destino como padrão
+    ///
+    /// Só preenche as alíquotas ainda zeradas. UFs desconhecidas são ignoradas.
+    pub fn com_uf_destino(mut self, sigla: &str) -> Self {
+        if let Some(uf) = buscar_uf(sigla) {
+            if self.aliquota_interna.is_zero() {
+                self.aliquota_interna = uf.aliquota_icms_interna;
+            }
+            if self.aliquota_fcp_st.is_zero() {
+                self.aliquota_fcp_st = uf.aliquota_fcp.unwrap_or_default();
+            }
+        }
+        self
+    }
+}
+
+/// MVA ajustada para operações interestaduais, com 4 casas decimais
+pub fn mva_ajustada(
+    mva_original: Decimal,
+    aliquota_interestadual: Decimal,
+    aliquota_interna: Decimal,
+) -> Decimal {
+    let cem = Decimal::ONE_HUNDRED;
+    if aliquota_interna >= cem {
+        return mva_original;
+    }
+    let fator = (cem + mva_original) * (cem - aliquota_interestadual) / (cem - aliquota_interna);
+    arredondar(fator - cem, CASAS_PERCENTUAL)
+}
+
+/// Calcula os campos de ST a partir do ICMS próprio do item
+///
+/// O ICMS próprio deduzido é o de `parametros.aliquota` sobre a BC do ICMS
+/// próprio.
+pub fn calcular_icms_st(parametros: &ParametrosIcms, st: &ParametrosIcmsSt) -> CalculoIcmsSt {
+    let aplicar = |base: Decimal, aliquota: Decimal| {
+        arredondar_abnt(base * aliquota / Decimal::ONE_HUNDRED, CASAS_VALOR)
+    };
+    let opcional = |valor: Decimal| if valor > Decimal::ZERO { Some(valor) } else { None };
+
+    let (modalidade_bc_st, percentual_mva_st, base) = match st.base_pauta {
+        Some(pauta) => (st.modalidade_bc_st.unwrap_or(MODALIDADE_PAUTA), None, pauta),
+        None => {
+            let mva = match st.aliquota_interestadual {
+                Some(interestadual) => {
+                    mva_ajustada(st.percentual_mva, interestadual, st.aliquota_interna)
+                }
+                None => st.percentual_mva,
+            };
+            let valor = parametros.valor_mercadoria() + st.valor_ipi;
+            let base = valor + valor * mva / Decimal::ONE_HUNDRED;
+            (st.modalidade_bc_st.unwrap_or(MODALIDADE_MVA), Some(mva), base)
+        }
+    };
+    let reducao = base * st.percentual_reducao_bc_st / Decimal::ONE_HUNDRED;
+    let valor_bc_st = arredondar_abnt(base - reducao, CASAS_VALOR);
+
+    let valor_proprio = aplicar(parametros.base_calculo(), parametros.aliquota);
+
+    let percentual_fcp_st = opcional(st.aliquota_fcp_st);
+    CalculoIcmsSt {
+        modalidade_bc_st,
+        percentual_mva_st,
+        percentual_reducao_bc_st: opcional(st.percentual_reducao_bc_st),
+        valor_bc_st,
+        aliquota_st: st.aliquota_interna,
+        valor_st: (aplicar(valor_bc_st, st.aliquota_interna) - valor_proprio).max(Decimal::ZERO),
+        valor_bc_fcp_st: percentual_fcp_st.map(|_| valor_bc_st),
+        percentual_fcp_st,
+        valor_fcp_st: percentual_fcp_st.map(|p| aplicar(valor_bc_st, p)),
+    }
+}
@@ -0,0 +1,151 @@
+//! Tributos na importação de mercadorias
+//!
+//! Na entrada de mercadoria importada, as bases dos tributos partem do valor
+//! aduaneiro declarado na DI (CIF: mercadoria + frete + seguro internacionais).
+//!
+//! ```text
+//! vII          = VA × pII
+//! vBC IPI      = VA + vII
+//! vBC PIS/COF  = VA                                  (Lei 12.865/2013)
+//! vBC ICMS     = (VA + II + IPI + PIS + COFINS + IOF
+//!                 + AFRMM + Siscomex + outras despesas) / (1 − (pICMS + pFCP))
+//! ```
+//!
+//! O ICMS da importação é calculado "por dentro" (LC 87/96, art. 13, V e § 1º):
+//! o próprio imposto integra a sua base, por isso a soma é dividida pelo
+//! complemento da alíquota, que precisa ser menor que 100%.
+
+use crate::base::decimal::{arredondar, Decimal, CASAS_VALOR};
+use crate::base::item::ImpostoImportacao;
+
+/// Dados da adição necessários ao cálculo dos tributos na importação
+///
+/// Alíquotas em percentual (ex.: `dec!(18)` para 18%).
+#[derive(Debug, Clone, Default)]
+pub struct ParametrosImportacao {
+    /// Valor aduaneiro do item (CIF convertido para reais)
+    pub valor_aduaneiro: Decimal,
+    /// Adicional ao Frete para Renovação da Marinha Mercante
+    pub valor_afrmm: Decimal,
+    /// Taxa de utilização do Siscomex rateada para o item
+    pub taxa_siscomex: Decimal,
+    /// Demais despesas aduaneiras que compõem a base do ICMS
+    pub outras_despesas: Decimal,
+    /// Valor do IOF sobre a operação de câmbio
+    pub valor_iof: Decimal,
+    /// Alíquota do Imposto de Importação
+    pub aliquota_ii: Decimal,
+    /// Alíquota do IPI
+    pub aliquota_ipi: Decimal,
+    /// Alíquota do PIS-Importação
+    pub aliquota_pis: Decimal,
+    /// Alíquota da COFINS-Importação
+    pub aliquota_cofins: Decimal,
+    /// Alíquota do ICMS da UF do importador
+    pub aliquota_icms: Decimal,
+    /// Percentual do Fundo de Combate à Pobreza
+    pub aliquota_fcp: Decimal,
+}
+
+/// Bases e valores dos tributos da importação
+#[derive(Debug, Clone, PartialEq)]
+pub struct CalculoImportacao {
+    /// Base de cálculo do II (valor aduaneiro)
+    pub base_ii: Decimal,
+    /// Valor do II
+    pub valor_ii: Decimal,
+    /// Despesas aduaneiras (AFRMM + Siscomex + outras)
+    pub despesas_aduaneiras: Decimal,
+    /// Valor do IOF
+    pub valor_iof: Decimal,
+    /// Base de cálculo do IPI (valor aduaneiro + II)
+    pub base_ipi: Decimal,
+    /// Valor do IPI
+    pub valor_ipi: Decimal,
+    /// Base de cálculo do PIS/COFINS-Importação (valor aduaneiro)
+    pub base_pis_cofins: Decimal,
+    /// Valor do PIS-Importação
+    pub valor_pis: Decimal,
+    /// Valor da COFINS-Importação
+    pub valor_cofins: Decimal,
+    /// Base de cálculo do ICMS, com o imposto incluído ("por dentro")
+    pub base_icms: Decimal,
+    /// Valor do ICMS
+    pub valor_icms: Decimal,
+    /// Valor do FCP
+    pub valor_fcp: Decimal,
+}
+
+impl CalculoImportacao {
+    /// Grupo `<II>` do item
+    pub fn imposto_importacao(&self) -> ImpostoImportacao {
+        ImpostoImportacao {
+            valor_bc: self.base_ii,
+            valor_despesas_aduaneiras: self.despesas_aduaneiras,
+            valor: self.valor_ii,
+            valor_iof: self.valor_iof,
+        }
+    }
+}
+
+/// Calcula II, IPI, PIS/COFINS-Importação e ICMS de um item importado
+///
+/// Retorna erro se a soma das alíquotas de ICMS e FCP for de 100% ou mais,
+/// já que a base "por dentro" não existe nesse caso.
+pub fn calcular_importacao(parametros: &ParametrosImportacao) -> Result<CalculoImportacao, String> {
+    let aliquota_total = parametros.aliquota_icms + parametros.aliquota_fcp;
+    if aliquota_total >= Decimal::ONE_HUNDRED {
+        return Err(format!(
+            "Alíquota de ICMS + FCP da importação deve ser menor que 100% (informada: {}%)",
+            aliquota_total
+        ));
+    }
+
+    let aplicar = |base: Decimal, aliquota: Decimal| {
+        arredondar(base * aliquota / Decimal::ONE_HUNDRED, CASAS_VALOR)
+    };
+
+    let valor_aduaneiro = arredondar(parametros.valor_aduaneiro, CASAS_VALOR);
+    let valor_ii = aplicar(valor_aduaneiro, parametros.aliquota_ii);
+
+    let base_ipi = valor_aduaneiro + valor_ii;
+    let valor_ipi = aplicar(base_ipi, parametros.aliquota_ipi);
+
+    let valor_pis = aplicar(valor_aduaneiro, parametros.aliquota_pis);
+    let valor_cofins = aplicar(valor_aduaneiro, parametros.aliquota_cofins);
+
+    let despesas_aduaneiras =
+        parametros.valor_afrmm + parametros.taxa_siscomex + parametros.outras_despesas;
+
+    // ICMS "por dentro": o imposto integra a própria base
+    let soma = valor_aduaneiro
+        + valor_ii
+        + valor_ipi
+        + valor_pis
+        + valor_cofins
+        + parametros.valor_iof
+        + despesas_aduaneiras;
+    let base_icms = if aliquota_total.is_zero() {
+        arredondar(soma, CASAS_VALOR)
+    } else {
+        arredondar(
+            soma / (Decimal::ONE - aliquota_total / Decimal::ONE_HUNDRED),
+            CASAS_VALOR,
+        )
+    };
+
+    Ok(CalculoImportacao {
+        base_ii: valor_aduaneiro,
+        valor_ii,
+        despesas_aduaneiras,
+        valor_iof: parametros.valor_iof,
+        base_ipi,
+        valor_ipi,
+        base_pis_cofins: valor_aduaneiro,
+        valor_pis,
+        valor_cofins,
+        base_icms,
+        valor_icms: aplicar(base_icms, parametros.aliquota_icms),
+        valor_fcp: aplicar(base_icms, parametros.aliquota_fcp),
+    })
+}
@@ -0,0 +1,71 @@
+//! IPI por CST
+//!
+//! ```text
+//! 00, 49, 50, 99          → IPITrib   vIPI = vBC × pIPI  ou  qUnid × vUnid
+//! 01 a 05, 51 a 55        → IPINT
+//! ```
+//!
+//! O IPI é calculado antes do ICMS: ele integra a BC do ICMS-ST e, na venda
+//! a consumidor final ou para uso e consumo, também a do ICMS próprio (ver
+//! [`ParametrosIcms::com_ipi`](super::ParametrosIcms::com_ipi)).
+
+use crate::base::decimal::{arredondar, Decimal, CASAS_VALOR};
+use crate::base::item::{IpiContainer, IpiNt, IpiTrib};
+
+/// Dados do item necessários ao cálculo do IPI
+#[derive(Debug, Clone, Default)]
+pub struct ParametrosIpi {
+    /// CST do IPI (2 dígitos)
+    pub cst: String,
+    /// Código de enquadramento legal (vazio: `999` - tributação normal)
+    pub codigo_enquadramento: String,
+    /// Base de cálculo (valor do produto, frete, seguro e outras despesas)
+    pub valor_bc: Decimal,
+    /// Alíquota da TIPI, em percentual
+    pub aliquota: Decimal,
+    /// Quantidade na unidade padrão (cálculo por unidade)
+    pub quantidade: Decimal,
+    /// IPI em reais por unidade (cálculo por unidade)
+    pub valor_unidade: Option<Decimal>,
+}
+
+/// Calcula o grupo `<IPI>` do item para o CST informado
+pub fn calcular_ipi(parametros: &ParametrosIpi) -> Result<IpiContainer, String> {
+    let cst = parametros.cst.as_str();
+    let mut ipi = IpiContainer::default();
+    if !parametros.codigo_enquadramento.is_empty() {
+        ipi.codigo_enquadramento = parametros.codigo_enquadramento.clone();
+    }
+
+    match cst {
+        "00" | "49" | "50" | "99" => {
+            ipi.ipi_trib = Some(match parametros.valor_unidade {
+                Some(valor_unidade) => IpiTrib {
+                    cst: cst.to_string(),
+                    valor_bc: None,
+                    aliquota: None,
+                    quantidade_unidade: Some(parametros.quantidade),
+                    valor_unidade: Some(valor_unidade),
+                    valor: arredondar(parametros.quantidade * valor_unidade, CASAS_VALOR),
+                },
+                None => IpiTrib {
+                    cst: cst.to_string(),
+                    valor_bc: Some(parametros.valor_bc),
+                    aliquota: Some(parametros.aliquota),
+                    quantidade_unidade: None,
+                    valor_unidade: None,
+                    valor: arredondar(
+                        parametros.valor_bc * parametros.aliquota / Decimal::ONE_HUNDRED,
+                        CASAS_VALOR,
+                    ),
+                },
+            })
+        }
+        "01" | "02" | "03" | "04" | "05" | "51" | "52" | "53" | "54" | "55" => {
+            ipi.ipi_nt = Some(IpiNt { cst: cst.to_string() })
+        }
+        _ => return Err(format!("CST de IPI inválido: {}", cst)),
+    }
+
+    Ok(ipi)
+}
@@ -0,0 +1,21 @@
+//! Calculadoras de impostos
+//!
+//! Funções que determinam bases de cálculo e valores dos tributos do item
+//! a partir dos dados da operação. Os resultados são arredondados para 2
+//! casas decimais, conforme exigido nos campos de valor da NF-e.
+
+mod difal;
+mod ibs_cbs;
+mod icms;
+mod icms_st;
+mod importacao;
+mod ipi;
+mod pis_cofins;
+
+pub use difal::*;
+pub use ibs_cbs::*;
+pub use icms::*;
+pub use icms_st::*;
+pub use importacao::*;
+pub use ipi::*;
+pub use pis_cofins::*;
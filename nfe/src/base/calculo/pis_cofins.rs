@@ -0,0 +1,175 @@
+//! PIS e COFINS por regime de apuração e CST
+//!
+//! | Regime | PIS | COFINS |
+//! |--------|-----|--------|
+//! | Cumulativo (Lucro Presumido) | 0,65% | 3% |
+//! | Não cumulativo (Lucro Real) | 1,65% | 7,6% |
+//! | Simples Nacional | recolhido no DAS | recolhido no DAS |
+//!
+//! O grupo gerado depende do CST:
+//!
+//! ```text
+//! 01, 02          → PISAliq / COFINSAliq    vPIS = vBC × pPIS
+//! 03              → PISQtde / COFINSQtde    vPIS = qBCProd × vAliqProd
+//! 04 a 09         → PISNT / COFINSNT
+//! 49 a 99         → PISOutr / COFINSOutr    por alíquota ou por unidade
+//! ```
+
+use crate::base::decimal::{arredondar, Decimal, CASAS_VALOR};
+use crate::base::item::{
+    CofinsAliq, CofinsContainer, CofinsNt, CofinsOutr, CofinsQtde, PisAliq, PisContainer, PisNt,
+    PisOutr, PisQtde,
+};
+
+/// Regime de apuração do PIS e da COFINS do emitente
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RegimePisCofins {
+    /// Regime cumulativo (Lei 9.718/1998)
+    Cumulativo,
+    /// Regime não cumulativo (Leis 10.637/2002 e 10.833/2003)
+    #[default]
+    NaoCumulativo,
+    /// Simples Nacional: contribuições recolhidas no DAS
+    SimplesNacional,
+}
+
+impl RegimePisCofins {
+    /// Alíquotas básicas de PIS e COFINS do regime, em percentual
+    pub fn aliquotas(&self) -> (Decimal, Decimal) {
+        match self {
+            RegimePisCofins::Cumulativo => (Decimal::new(65, 2), Decimal::new(3, 0)),
+            RegimePisCofins::NaoCumulativo => (Decimal::new(165, 2), Decimal::new(76, 1)),
+            RegimePisCofins::SimplesNacional => (Decimal::ZERO, Decimal::ZERO),
+        }
+    }
+}
+
+/// Dados do item necessários ao cálculo do PIS e da COFINS
+#[derive(Debug, Clone, Default)]
+pub struct ParametrosPisCofins {
+    /// Regime de apuração do emitente
+    pub regime: RegimePisCofins,
+    /// CST do PIS e da COFINS (2 dígitos)
+    pub cst: String,
+    /// Base de cálculo (cálculo por alíquota)
+    pub valor_bc: Decimal,
+    /// Quantidade vendida (cálculo por unidade)
+    pub quantidade: Decimal,
+    /// Alíquota do PIS (`None`: alíquota básica do regime)
+    pub aliquota_pis: Option<Decimal>,
+    /// Alíquota da COFINS (`None`: alíquota básica do regime)
+    pub aliquota_cofins: Option<Decimal>,
+    /// PIS em reais por unidade (tag `<vAliqProd>`)
+    pub aliquota_pis_unidade: Option<Decimal>,
+    /// COFINS em reais por unidade (tag `<vAliqProd>`)
+    pub aliquota_cofins_unidade: Option<Decimal>,
+}
+
+/// Grupos `<PIS>` e `<COFINS>` do item
+#[derive(Debug, Clone, PartialEq)]
+pub struct CalculoPisCofins {
+    /// Grupo do PIS
+    pub pis: PisContainer,
+    /// Grupo da COFINS
+    pub cofins: CofinsContainer,
+}
+
+/// Calcula os grupos de PIS e COFINS para o CST informado
+pub fn calcular_pis_cofins(parametros: &ParametrosPisCofins) -> Result<CalculoPisCofins, String> {
+    let (padrao_pis, padrao_cofins) = parametros.regime.aliquotas();
+    let aliquota_pis = parametros.aliquota_pis.unwrap_or(padrao_pis);
+    let aliquota_cofins = parametros.aliquota_cofins.unwrap_or(padrao_cofins);
+
+    let aplicar = |aliquota: Decimal| {
+        arredondar(parametros.valor_bc * aliquota / Decimal::ONE_HUNDRED, CASAS_VALOR)
+    };
+    let por_unidade = |valor: Decimal| arredondar(parametros.quantidade * valor, CASAS_VALOR);
+
+    let cst = parametros.cst.as_str();
+    let outras_operacoes = cst.len() == 2
+        && matches!(cst.parse::<u8>(), Ok(49..=56 | 60..=67 | 70..=75 | 98 | 99));
+    let mut pis = PisContainer::default();
+    let mut cofins = CofinsContainer::default();
+
+    match cst {
+        "01" | "02" => {
+            pis.pis_aliq = Some(PisAliq {
+                cst: cst.to_string(),
+                valor_bc: parametros.valor_bc,
+                aliquota: aliquota_pis,
+                valor: aplicar(aliquota_pis),
+            });
+            cofins.cofins_aliq = Some(CofinsAliq {
+                cst: cst.to_string(),
+                valor_bc: parametros.valor_bc,
+                aliquota: aliquota_cofins,
+                valor: aplicar(aliquota_cofins),
+            });
+        }
+        "03" => {
+            let (Some(unidade_pis), Some(unidade_cofins)) =
+                (parametros.aliquota_pis_unidade, parametros.aliquota_cofins_unidade)
+            else {
+                return Err("CST 03 de PIS/COFINS exige a alíquota por unidade".to_string());
+            };
+            pis.pis_qtde = Some(PisQtde {
+                cst: cst.to_string(),
+                quantidade_bc: parametros.quantidade,
+                aliquota_valor: unidade_pis,
+                valor: por_unidade(unidade_pis),
+            });
+            cofins.cofins_qtde = Some(CofinsQtde {
+                cst: cst.to_string(),
+                quantidade_bc: parametros.quantidade,
+                aliquota_valor: unidade_cofins,
+                valor: por_unidade(unidade_cofins),
+            });
+        }
+        "04" | "05" | "06" | "07" | "08" | "09" => {
+            pis.pis_nt = Some(PisNt { cst: cst.to_string() });
+            cofins.cofins_nt = Some(CofinsNt { cst: cst.to_string() });
+        }
+        _ if outras_operacoes => {
+            let unidade = parametros.aliquota_pis_unidade.zip(parametros.aliquota_cofins_unidade);
+            pis.pis_outr = Some(match unidade {
+                Some((unidade_pis, _)) => PisOutr {
+                    cst: cst.to_string(),
+                    valor_bc: None,
+                    aliquota: None,
+                    quantidade_bc: Some(parametros.quantidade),
+                    aliquota_valor: Some(unidade_pis),
+                    valor: Some(por_unidade(unidade_pis)),
+                },
+                None => PisOutr {
+                    cst: cst.to_string(),
+                    valor_bc: Some(parametros.valor_bc),
+                    aliquota: Some(aliquota_pis),
+                    quantidade_bc: None,
+                    aliquota_valor: None,
+                    valor: Some(aplicar(aliquota_pis)),
+                },
+            });
+            cofins.cofins_outr = Some(match unidade {
+                Some((_, unidade_cofins)) => CofinsOutr {
+                    cst: cst.to_string(),
+                    valor_bc: None,
+                    aliquota: None,
+                    quantidade_bc: Some(parametros.quantidade),
+                    aliquota_valor: Some(unidade_cofins),
+                    valor: Some(por_unidade(unidade_cofins)),
+                },
+                None => CofinsOutr {
+                    cst: cst.to_string(),
+                    valor_bc: Some(parametros.valor_bc),
+                    aliquota: Some(aliquota_cofins),
+                    quantidade_bc: None,
+                    aliquota_valor: None,
+                    valor: Some(aplicar(aliquota_cofins)),
+                },
+            });
+        }
+        _ => return Err(format!("CST de PIS/COFINS inválido: {}", cst)),
+    }
+
+    Ok(CalculoPisCofins { pis, cofins })
+}
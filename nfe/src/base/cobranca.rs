@@ -0,0 +1,211 @@
+//! Cobrança da Nota Fiscal Eletrônica (Grupo `<cobr>`)
+//!
+//! Este módulo contém as estruturas para representar a fatura e as
+//! duplicatas (parcelas) de uma venda a prazo.
+//!
+//! ## Estrutura do Grupo Cobrança
+//!
+//! ```text
+//! <cobr>
+//!   <fat>          <!-- Fatura (opcional) -->
+//!     <nFat>       <!-- Número da fatura -->
+//!     <vOrig>      <!-- Valor original -->
+//!     <vDesc>      <!-- Valor do desconto -->
+//!     <vLiq>       <!-- Valor líquido -->
+//!   </fat>
+//!   <dup>          <!-- Duplicatas (0 a 120 ocorrências) -->
+//!     <nDup>       <!-- Número da parcela: 001, 002, ... -->
+//!     <dVenc>      <!-- Vencimento (AAAA-MM-DD) -->
+//!     <vDup>       <!-- Valor da parcela -->
+//!   </dup>
+//! </cobr>
+//! ```
+//!
+//! ## Parcelamento
+//!
+//! [`Cobranca::parcelar`] divide um valor em N parcelas truncando cada
+//! uma em centavos; os centavos restantes são distribuídos entre as
+//! primeiras parcelas, de modo que a soma das duplicatas é sempre igual ao
+//! valor líquido da fatura e nenhuma parcela fica zerada.
+
+use super::decimal::{arredondar, Decimal, RoundingStrategy, CASAS_VALOR};
+use super::Error;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// Quantidade máxima de duplicatas por NF-e
+pub const MAX_DUPLICATAS: usize = 120;
+
+/// Grupo de Cobrança (tag `<cobr>`)
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[serde(rename = "cobr")]
+pub struct Cobranca {
+    /// Dados da fatura (tag `<fat>`)
+    #[serde(rename = "fat")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub fatura: Option<Fatura>,
+
+    /// Duplicatas/parcelas (tags `<dup>`)
+    #[serde(rename = "dup")]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
+    pub duplicatas: Vec<Duplicata>,
+}
+
+/// Fatura (tag `<fat>`)
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct Fatura {
+    /// Número da fatura (tag `<nFat>`)
+    #[serde(rename = "$unflatten=nFat")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub numero: Option<String>,
+
+    /// Valor original da fatura (tag `<vOrig>`)
+    #[serde(rename = "$unflatten=vOrig")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub valor_original: Option<Decimal>,
+
+    /// Valor do desconto (tag `<vDesc>`)
+    #[serde(rename = "$unflatten=vDesc")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub valor_desconto: Option<Decimal>,
+
+    /// Valor líquido da fatura (tag `<vLiq>`)
+    #[serde(rename = "$unflatten=vLiq")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub valor_liquido: Option<Decimal>,
+}
+
+/// Duplicata (tag `<dup>`)
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct Duplicata {
+    /// Número da parcela (tag `<nDup>`)
+    /// Sequencial com três dígitos: "001", "002", ...
+    #[serde(rename = "$unflatten=nDup")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub numero: Option<String>,
+
+    /// Data de vencimento (tag `<dVenc>`)
+    #[serde(rename = "$unflatten=dVenc")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub vencimento: Option<NaiveDate>,
+
+    /// Valor da parcela (tag `<vDup>`)
+    #[serde(rename = "$unflatten=vDup")]
+    #[serde(with = "crate::base::decimal::valor")]
+    pub valor: Decimal,
+}
+
+impl Cobranca {
+    /// Divide `valor` em uma parcela por data de vencimento
+    ///
+    /// Cada parcela recebe o valor truncado em centavos e os centavos que
+    /// sobram são distribuídos, um a um, a partir da primeira parcela,
+    /// garantindo que a soma das duplicatas seja igual ao valor informado.
+    /// A fatura é preenchida com `vOrig = vLiq = valor`.
+    ///
+    /// Retorna erro se não houver vencimentos, se houver mais de
+    /// [`MAX_DUPLICATAS`] ou se o valor não cobrir ao menos um centavo por
+    /// parcela (`vDup` zerado é rejeitado pelo schema).
+    ///
+    /// ## Exemplo
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use nfe_parser::Cobranca;
+    /// use rust_decimal_macros::dec;
+    ///
+    /// let vencimentos = [
+    ///     NaiveDate::from_ymd_opt(2024, 2, 15).unwrap(),
+    ///     NaiveDate::from_ymd_opt(2024, 3, 15).unwrap(),
+    ///     NaiveDate::from_ymd_opt(2024, 4, 15).unwrap(),
+    /// ];
+    ///
+    /// let cobr = Cobranca::parcelar("1234", dec!(100), &vencimentos).unwrap();
+    /// let valores: Vec<_> = cobr.duplicatas.iter().map(|d| d.valor).collect();
+    /// assert_eq!(vec![dec!(33.34), dec!(33.33), dec!(33.33)], valores);
+    /// ```
+    pub fn parcelar(
+        numero_fatura: &str,
+        valor: Decimal,
+        vencimentos: &[NaiveDate],
+    ) -> Result<Self, String> {
+        if vencimentos.is_empty() {
+            return Err("Informe ao menos um vencimento para o parcelamento".to_string());
+        }
+        if vencimentos.len() > MAX_DUPLICATAS {
+            return Err(format!(
+                "Máximo de {} duplicatas por NF-e (informados: {} vencimentos)",
+                MAX_DUPLICATAS,
+                vencimentos.len()
+            ));
+        }
+
+        let centavo = Decimal::new(1, CASAS_VALOR);
+        let quantidade = Decimal::from(vencimentos.len());
+        let valor = arredondar(valor, CASAS_VALOR);
+        let parcela = (valor / quantidade).round_dp_with_strategy(CASAS_VALOR, RoundingStrategy::ToZero);
+
+        if parcela < centavo {
+            return Err(format!(
+                "Valor {} insuficiente para {} parcelas de ao menos R$ 0,01",
+                valor,
+                vencimentos.len()
+            ));
+        }
+
+        // Centavos que sobram da divisão: um a mais em cada parcela inicial
+        let sobra = (valor - parcela * quantidade) / centavo;
+
+        let duplicatas = vencimentos
+            .iter()
+            .enumerate()
+            .map(|(i, vencimento)| Duplicata {
+                numero: Some(format!("{:03}", i + 1)),
+                vencimento: Some(*vencimento),
+                valor: if Decimal::from(i) < sobra { parcela + centavo } else { parcela },
+            })
+            .collect();
+
+        Ok(Self {
+            fatura: Some(Fatura {
+                numero: Some(numero_fatura.to_string()),
+                valor_original: Some(valor),
+                valor_desconto: Some(Decimal::ZERO),
+                valor_liquido: Some(valor),
+            }),
+            duplicatas,
+        })
+    }
+
+    /// Soma dos valores das duplicatas
+    pub fn valor_duplicatas(&self) -> Decimal {
+        self.duplicatas.iter().map(|d| d.valor).sum()
+    }
+}
+
+impl FromStr for Cobranca {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        quick_xml::de::from_str(s).map_err(|e| e.into())
+    }
+}
+
+impl fmt::Display for Cobranca {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&quick_xml::se::to_string(self).expect("Falha ao serializar a cobrança"))
+    }
+}
@@ -0,0 +1,177 @@
+//! Valores decimais de precisão fixa
+//!
+//! Todos os valores monetários, quantidades e percentuais da NF-e são
+//! representados por [`Decimal`] (ponto fixo em base 10) em vez de `f32`.
+//! Com ponto flutuante binário, somatórios acima de alguns milhares de reais
+//! acumulam diferenças de centavos e a SEFAZ rejeita os totais.
+//!
+//! ## Precisão do Layout 4.00
+//!
+//! | Tipo XSD | Uso | Casas decimais |
+//! |----------|-----|----------------|
+//! | TDec_1302 | Valores (vProd, vBC, vICMS, vNF...) | 2 |
+//! | TDec_1104v | Quantidades (qCom, qTrib, qUnid...) | 4 |
+//! | TDec_1110v | Valores unitários (vUnCom, vUnTrib) | 2 a 10 |
+//! | TDec_0302a04 | Percentuais e alíquotas (pICMS, pPIS...) | 2 a 4 |
+//! | TDec_1203 | Pesos (pesoL, pesoB) | 3 |
+//!
+//! A deserialização aceita qualquer quantidade de casas decimais. A
+//! serialização arredonda cada campo para a precisão definida no layout.
+//!
+//! ## Exemplo
+//!
+//! ```rust
+//! use nfe_parser::decimal::{formatar, Decimal, CASAS_VALOR};
+//! use std::str::FromStr;
+//!
+//! let valor = Decimal::from_str("138.305").unwrap();
+//! assert_eq!("138.31", formatar(valor, CASAS_VALOR, CASAS_VALOR));
+//! ```
+
+use serde::de::{self, Visitor};
+use serde::{Deserialize, Deserializer, Serializer};
+use std::fmt;
+use std::str::FromStr;
+
+pub use rust_decimal::{Decimal, RoundingStrategy};
+
+/// Casas decimais dos valores monetários (TDec_1302)
+pub const CASAS_VALOR: u32 = 2;
+
+/// Casas decimais das quantidades (TDec_1104v)
+pub const CASAS_QUANTIDADE: u32 = 4;
+
+/// Casas decimais máximas dos valores unitários (TDec_1110v)
+pub const CASAS_VALOR_UNITARIO: u32 = 10;
+
+/// Casas decimais máximas dos percentuais e alíquotas (TDec_0302a04)
+pub const CASAS_PERCENTUAL: u32 = 4;
+
+/// Casas decimais dos pesos em kg (TDec_1203)
+pub const CASAS_PESO: u32 = 3;
+
+/// Arredonda um valor para a quantidade de casas informada
+///
+/// Usa o arredondamento comercial (meio para cima), o mesmo aplicado
+/// pela serialização dos campos da NF-e.
+pub fn arredondar(valor: Decimal, casas: u32) -> Decimal {
+    valor.round_dp_with_strategy(casas, RoundingStrategy::MidpointAwayFromZero)
+}
+
+/// Arredonda um valor conforme a ABNT NBR 5891
+///
+/// Quando a parte descartada é exatamente 5 (seguido de zeros), o último
+/// algarismo conservado fica par: `0,125` → `0,12` e `0,135` → `0,14`.
+/// Nos demais casos o resultado é igual ao de [`arredondar`].
+pub fn arredondar_abnt(valor: Decimal, casas: u32) -> Decimal {
+    valor.round_dp_with_strategy(casas, RoundingStrategy::MidpointNearestEven)
+}
+
+/// Formata um valor com no mínimo `minimo` e no máximo `maximo` casas decimais
+///
+/// Zeros à direita além do mínimo são removidos.
+pub fn formatar(valor: Decimal, minimo: u32, maximo: u32) -> String {
+    let mut valor = arredondar(valor, maximo).normalize();
+    if valor.scale() < minimo {
+        valor.rescale(minimo);
+    }
+
+    valor.to_string()
+}
+
+/// Decimal lido do texto de um elemento XML
+///
+/// O quick-xml entrega o conteúdo dos elementos como texto, então o
+/// valor é sempre lido com `deserialize_str`.
+struct DecimalXml(Decimal);
+
+impl<'de> Deserialize<'de> for DecimalXml {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct DecimalVisitor;
+
+        impl<'de> Visitor<'de> for DecimalVisitor {
+            type Value = DecimalXml;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("um número decimal")
+            }
+
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                let v = v.trim();
+                Decimal::from_str(v)
+                    .or_else(|_| Decimal::from_scientific(v))
+                    .map(DecimalXml)
+                    .map_err(|_| E::invalid_value(de::Unexpected::Str(v), &self))
+            }
+
+            fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E> {
+                Ok(DecimalXml(Decimal::from(v)))
+            }
+
+            fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E> {
+                Ok(DecimalXml(Decimal::from(v)))
+            }
+        }
+
+        deserializer.deserialize_str(DecimalVisitor)
+    }
+}
+
+/// Gera os módulos usados em `#[serde(with = ...)]` (campo obrigatório e
+/// opcional) para uma precisão do layout
+macro_rules! formato {
+    ($nome:ident, $nome_opcional:ident, $minimo:expr, $maximo:expr) => {
+        #[allow(dead_code)]
+        pub(crate) mod $nome {
+            use super::*;
+
+            pub fn serialize<S>(valor: &Decimal, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                serializer.serialize_str(&formatar(*valor, $minimo, $maximo))
+            }
+
+            pub fn deserialize<'de, D>(deserializer: D) -> Result<Decimal, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                DecimalXml::deserialize(deserializer).map(|v| v.0)
+            }
+        }
+
+        #[allow(dead_code)]
+        pub(crate) mod $nome_opcional {
+            use super::*;
+
+            pub fn serialize<S>(valor: &Option<Decimal>, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                match valor {
+                    Some(valor) => super::$nome::serialize(valor, serializer),
+                    None => serializer.serialize_none(),
+                }
+            }
+
+            pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<Decimal>, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                Option::<DecimalXml>::deserialize(deserializer).map(|v| v.map(|v| v.0))
+            }
+        }
+    };
+}
+
+formato!(valor, valor_opcional, CASAS_VALOR, CASAS_VALOR);
+formato!(quantidade, quantidade_opcional, CASAS_QUANTIDADE, CASAS_QUANTIDADE);
+formato!(valor_unitario, valor_unitario_opcional, CASAS_VALOR, CASAS_VALOR_UNITARIO);
+formato!(percentual, percentual_opcional, CASAS_VALOR, CASAS_PERCENTUAL);
+formato!(peso, peso_opcional, CASAS_PESO, CASAS_PESO);
@@ -3,37 +3,29 @@
 //! Este módulo contém as estruturas para representar o destinatário
 //! (comprador/cliente) da NF-e.
 
+use super::documento::Documento;
 use super::endereco::Endereco;
 use super::Error;
-use serde::{Deserialize, Serialize};
+use serde::de;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use serde_repr::{Deserialize_repr, Serialize_repr};
+use std::fmt;
 use std::str::FromStr;
 
 /// Dados do destinatário da NFe
-#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
-#[serde(rename = "dest")]
+#[derive(Debug, PartialEq, Clone)]
 pub struct Destinatario {
-    /// CNPJ do destinatário (14 dígitos)
-    #[serde(rename = "CNPJ")]
-    pub cnpj: String,
+    /// CNPJ, CPF ou identificação do estrangeiro
+    /// (tags `<CNPJ>` / `<CPF>` / `<idEstrangeiro>`)
+    pub documento: Documento,
     /// Razão social ou nome do destinatário
-    #[serde(rename = "xNome")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(default)]
     pub razao_social: Option<String>,
     /// Endereço do destinatário
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "enderDest")]
-    #[serde(default)]
     pub endereco: Option<Endereco>,
-    /// Inscrição Estadual do destinatário
-    #[serde(rename = "IE")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(default)]
-    pub ie: Option<String>,
     /// Indicador da IE do destinatário
-    #[serde(rename = "indIEDest")]
     pub indicador_ie: IndicadorContribuicaoIe,
+    /// Inscrição Estadual do destinatário
+    pub ie: Option<String>,
 }
 
 /// Indicador da IE do destinatário
@@ -56,8 +48,86 @@ impl FromStr for Destinatario {
     }
 }
 
-impl ToString for Destinatario {
-    fn to_string(&self) -> String {
-        quick_xml::se::to_string(self).expect("Falha ao serializar destinatário")
+impl fmt::Display for Destinatario {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&quick_xml::se::to_string(self).expect("Falha ao serializar destinatário"))
+    }
+}
+
+impl<'de> Deserialize<'de> for Destinatario {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let dest = DestContainer::deserialize(deserializer)?;
+
+        let documento = match Documento::from_tags(dest.cnpj, dest.cpf, dest.id_estrangeiro) {
+            Some(documento) => documento,
+            None => {
+                return Err(de::Error::custom(
+                    "CNPJ, CPF ou idEstrangeiro do destinatário não informado",
+                ))
+            }
+        };
+
+        Ok(Self {
+            documento,
+            razao_social: dest.razao_social,
+            endereco: dest.endereco,
+            indicador_ie: dest.indicador_ie,
+            ie: dest.ie,
+        })
+    }
+}
+
+impl Serialize for Destinatario {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (cnpj, cpf, id_estrangeiro) = self.documento.to_tags();
+
+        let dest = DestContainer {
+            cnpj,
+            cpf,
+            id_estrangeiro,
+            razao_social: self.razao_social.clone(),
+            endereco: self.endereco.clone(),
+            indicador_ie: self.indicador_ie,
+            ie: self.ie.clone(),
+        };
+
+        dest.serialize(serializer)
     }
 }
+
+#[derive(Deserialize, Serialize)]
+#[serde(rename = "dest")]
+struct DestContainer {
+    #[serde(rename = "$unflatten=CNPJ")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub cnpj: Option<String>,
+    #[serde(rename = "$unflatten=CPF")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub cpf: Option<String>,
+    #[serde(rename = "$unflatten=idEstrangeiro")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub id_estrangeiro: Option<String>,
+    #[serde(rename = "$unflatten=xNome")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub razao_social: Option<String>,
+    #[serde(rename = "enderDest")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub endereco: Option<Endereco>,
+    #[serde(rename = "$unflatten=indIEDest")]
+    pub indicador_ie: IndicadorContribuicaoIe,
+    #[serde(rename = "$unflatten=IE")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub ie: Option<String>,
+}
@@ -0,0 +1,160 @@
+//! Documento de identificação de pessoas na NF-e
+//!
+//! O emitente e o destinatário são identificados por uma escolha
+//! (`xs:choice`) entre CNPJ, CPF ou, para o destinatário estrangeiro,
+//! o documento de identificação estrangeiro:
+//!
+//! | Tag | Grupo | Conteúdo |
+//! |-----|-------|----------|
+//! | `<CNPJ>` | emit, dest | 14 dígitos |
+//! | `<CPF>` | emit, dest | 11 dígitos |
+//! | `<idEstrangeiro>` | dest | Passaporte ou documento estrangeiro (pode ser vazio) |
+//!
+//! Os grupos que só admitem CNPJ ou CPF (emitente, transportador) usam
+//! [`DocumentoNacional`], que não representa o idEstrangeiro.
+
+/// Documento de identificação do emitente ou destinatário
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Documento {
+    /// CNPJ (tag `<CNPJ>`)
+    Cnpj(String),
+    /// CPF (tag `<CPF>`)
+    Cpf(String),
+    /// Identificação do estrangeiro (tag `<idEstrangeiro>`)
+    IdEstrangeiro(String),
+}
+
+impl Documento {
+    /// Cria um documento CNPJ removendo a máscara (pontos, barra e hífen)
+    pub fn cnpj(cnpj: &str) -> Self {
+        Self::Cnpj(remover_mascara(cnpj))
+    }
+
+    /// Cria um documento CPF removendo a máscara (pontos e hífen)
+    pub fn cpf(cpf: &str) -> Self {
+        Self::Cpf(remover_mascara(cpf))
+    }
+
+    /// Cria uma identificação de estrangeiro
+    pub fn id_estrangeiro(id: &str) -> Self {
+        Self::IdEstrangeiro(id.trim().to_string())
+    }
+
+    /// Número do documento, sem máscara
+    pub fn numero(&self) -> &str {
+        match self {
+            Self::Cnpj(n) | Self::Cpf(n) | Self::IdEstrangeiro(n) => n,
+        }
+    }
+
+    /// Nome da tag XML do documento
+    pub fn tag(&self) -> &'static str {
+        match self {
+            Self::Cnpj(_) => "CNPJ",
+            Self::Cpf(_) => "CPF",
+            Self::IdEstrangeiro(_) => "idEstrangeiro",
+        }
+    }
+
+    /// Monta o documento a partir das tags lidas do XML
+    ///
+    /// A precedência segue a ordem do `xs:choice`: CNPJ, CPF, idEstrangeiro.
+    pub(crate) fn from_tags(
+        cnpj: Option<String>,
+        cpf: Option<String>,
+        id_estrangeiro: Option<String>,
+    ) -> Option<Self> {
+        cnpj.map(Self::Cnpj)
+            .or_else(|| cpf.map(Self::Cpf))
+            .or_else(|| id_estrangeiro.map(Self::IdEstrangeiro))
+    }
+
+    /// Separa o documento nas tags `(CNPJ, CPF, idEstrangeiro)` para serialização
+    pub fn to_tags(&self) -> (Option<String>, Option<String>, Option<String>) {
+        match self {
+            Self::Cnpj(n) => (Some(n.clone()), None, None),
+            Self::Cpf(n) => (None, Some(n.clone()), None),
+            Self::IdEstrangeiro(n) => (None, None, Some(n.clone())),
+        }
+    }
+}
+
+/// Documento brasileiro de identificação: CNPJ ou CPF
+///
+/// Usado nos grupos cujo `xs:choice` não contém `<idEstrangeiro>`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum DocumentoNacional {
+    /// CNPJ (tag `<CNPJ>`)
+    Cnpj(String),
+    /// CPF (tag `<CPF>`)
+    Cpf(String),
+}
+
+impl DocumentoNacional {
+    /// Cria um documento CNPJ removendo a máscara (pontos, barra e hífen)
+    pub fn cnpj(cnpj: &str) -> Self {
+        Self::Cnpj(remover_mascara(cnpj))
+    }
+
+    /// Cria um documento CPF removendo a máscara (pontos e hífen)
+    pub fn cpf(cpf: &str) -> Self {
+        Self::Cpf(remover_mascara(cpf))
+    }
+
+    /// Número do documento, sem máscara
+    pub fn numero(&self) -> &str {
+        match self {
+            Self::Cnpj(n) | Self::Cpf(n) => n,
+        }
+    }
+
+    /// Nome da tag XML do documento
+    pub fn tag(&self) -> &'static str {
+        match self {
+            Self::Cnpj(_) => "CNPJ",
+            Self::Cpf(_) => "CPF",
+        }
+    }
+
+    /// Monta o documento a partir das tags lidas do XML (CNPJ tem precedência)
+    pub(crate) fn from_tags(cnpj: Option<String>, cpf: Option<String>) -> Option<Self> {
+        cnpj.map(Self::Cnpj).or_else(|| cpf.map(Self::Cpf))
+    }
+
+    /// Separa o documento nas tags `(CNPJ, CPF)` para serialização
+    pub fn to_tags(&self) -> (Option<String>, Option<String>) {
+        match self {
+            Self::Cnpj(n) => (Some(n.clone()), None),
+            Self::Cpf(n) => (None, Some(n.clone())),
+        }
+    }
+}
+
+impl From<DocumentoNacional> for Documento {
+    fn from(documento: DocumentoNacional) -> Self {
+        match documento {
+            DocumentoNacional::Cnpj(n) => Self::Cnpj(n),
+            DocumentoNacional::Cpf(n) => Self::Cpf(n),
+        }
+    }
+}
+
+impl TryFrom<Documento> for DocumentoNacional {
+    type Error = String;
+
+    /// Falha para o idEstrangeiro, que não tem equivalente nacional
+    fn try_from(documento: Documento) -> Result<Self, Self::Error> {
+        match documento {
+            Documento::Cnpj(n) => Ok(Self::Cnpj(n)),
+            Documento::Cpf(n) => Ok(Self::Cpf(n)),
+            Documento::IdEstrangeiro(id) => Err(format!(
+                "idEstrangeiro {} não é permitido, informe CNPJ ou CPF",
+                id
+            )),
+        }
+    }
+}
+
+fn remover_mascara(documento: &str) -> String {
+    documento.replace(&['.', '/', '-'][..], "").trim().to_string()
+}
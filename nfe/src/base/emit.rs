@@ -1,37 +1,70 @@
 //! Emitente da Nota Fiscal Eletrônica
 
+use super::documento::DocumentoNacional;
 use super::endereco::Endereco;
 use super::Error;
-use serde::{Deserialize, Serialize};
+use serde::de;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_repr::{Deserialize_repr, Serialize_repr};
+use std::fmt;
 use std::str::FromStr;
 
 /// Emitente da NFe
-/// 
+///
 /// Contém os dados do emitente (empresa que está emitindo a nota fiscal),
-/// incluindo CNPJ, razão social, nome fantasia, inscrição estadual e endereço.
-#[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
-#[serde(rename = "emit")]
+/// incluindo CNPJ/CPF, razão social, nome fantasia, inscrições, endereço
+/// e o código de regime tributário.
+#[derive(Debug, PartialEq, Clone)]
 pub struct Emitente {
-    /// CNPJ do emitente (14 dígitos)
-    #[serde(rename = "$unflatten=CNPJ")]
-    pub cnpj: Option<String>,
+    /// CNPJ ou CPF do emitente (tags `<CNPJ>` / `<CPF>`)
+    pub documento: DocumentoNacional,
     /// Razão social do emitente
-    #[serde(rename = "$unflatten=xNome")]
     pub razao_social: Option<String>,
     /// Nome fantasia do emitente
-    #[serde(rename = "$unflatten=xFant")]
-    #[serde(skip_serializing_if = "Option::is_none")]
     pub nome_fantasia: Option<String>,
+    /// Endereço do emitente
+    pub endereco: Endereco,
     /// Inscrição Estadual do emitente
-    #[serde(rename = "$unflatten=IE")]
     pub ie: Option<String>,
     /// Inscrição Estadual do Substituto Tributário
-    #[serde(rename = "$unflatten=IEST")]
-    #[serde(skip_serializing_if = "Option::is_none")]
     pub iest: Option<u32>,
-    /// Endereço do emitente
-    #[serde(rename = "enderEmit")]
-    pub endereco: Endereco,
+    /// Inscrição Municipal (obrigatória quando há serviços sujeitos ao ISSQN)
+    pub im: Option<String>,
+    /// CNAE fiscal (informado junto com a Inscrição Municipal)
+    pub cnae: Option<String>,
+    /// Código de Regime Tributário
+    pub crt: RegimeTributario,
+}
+
+/// Código de Regime Tributário (tag `<CRT>`)
+///
+/// Determina se o ICMS dos itens é informado por CST (regime normal)
+/// ou por CSOSN (Simples Nacional).
+///
+/// | Código | Descrição | ICMS |
+/// |--------|-----------|------|
+/// | 1 | Simples Nacional | CSOSN |
+/// | 2 | Simples Nacional - excesso de sublimite de receita bruta | CST |
+/// | 3 | Regime Normal | CST |
+/// | 4 | Simples Nacional - Microempreendedor Individual (MEI) | CSOSN |
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Deserialize_repr, Serialize_repr)]
+#[repr(u8)]
+pub enum RegimeTributario {
+    /// 1 - Simples Nacional
+    SimplesNacional = 1,
+    /// 2 - Simples Nacional, excesso de sublimite de receita bruta
+    SimplesNacionalExcessoSublimite = 2,
+    /// 3 - Regime Normal
+    RegimeNormal = 3,
+    /// 4 - Simples Nacional - MEI
+    SimplesNacionalMei = 4,
+}
+
+impl RegimeTributario {
+    /// Indica se o ICMS dos itens deve ser informado por CSOSN
+    pub fn usa_csosn(&self) -> bool {
+        matches!(self, Self::SimplesNacional | Self::SimplesNacionalMei)
+    }
 }
 
 impl FromStr for Emitente {
@@ -42,8 +75,95 @@ impl FromStr for Emitente {
     }
 }
 
-impl ToString for Emitente {
-    fn to_string(&self) -> String {
-        quick_xml::se::to_string(self).expect("Falha ao serializar emitente")
+impl fmt::Display for Emitente {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&quick_xml::se::to_string(self).expect("Falha ao serializar emitente"))
+    }
+}
+
+impl<'de> Deserialize<'de> for Emitente {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let emit = EmitContainer::deserialize(deserializer)?;
+
+        let documento = match DocumentoNacional::from_tags(emit.cnpj, emit.cpf) {
+            Some(documento) => documento,
+            None => return Err(de::Error::custom("CNPJ ou CPF do emitente não informado")),
+        };
+
+        Ok(Self {
+            documento,
+            razao_social: emit.razao_social,
+            nome_fantasia: emit.nome_fantasia,
+            endereco: emit.endereco,
+            ie: emit.ie,
+            iest: emit.iest,
+            im: emit.im,
+            cnae: emit.cnae,
+            crt: emit.crt,
+        })
+    }
+}
+
+impl Serialize for Emitente {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (cnpj, cpf) = self.documento.to_tags();
+
+        let emit = EmitContainer {
+            cnpj,
+            cpf,
+            razao_social: self.razao_social.clone(),
+            nome_fantasia: self.nome_fantasia.clone(),
+            endereco: self.endereco.clone(),
+            ie: self.ie.clone(),
+            iest: self.iest,
+            im: self.im.clone(),
+            cnae: self.cnae.clone(),
+            crt: self.crt,
+        };
+
+        emit.serialize(serializer)
     }
 }
+
+#[derive(Deserialize, Serialize)]
+#[serde(rename = "emit")]
+struct EmitContainer {
+    #[serde(rename = "$unflatten=CNPJ")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub cnpj: Option<String>,
+    #[serde(rename = "$unflatten=CPF")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub cpf: Option<String>,
+    #[serde(rename = "$unflatten=xNome")]
+    pub razao_social: Option<String>,
+    #[serde(rename = "$unflatten=xFant")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub nome_fantasia: Option<String>,
+    #[serde(rename = "enderEmit")]
+    pub endereco: Endereco,
+    #[serde(rename = "$unflatten=IE")]
+    pub ie: Option<String>,
+    #[serde(rename = "$unflatten=IEST")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub iest: Option<u32>,
+    #[serde(rename = "$unflatten=IM")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub im: Option<String>,
+    #[serde(rename = "$unflatten=CNAE")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub cnae: Option<String>,
+    #[serde(rename = "$unflatten=CRT")]
+    pub crt: RegimeTributario,
+}
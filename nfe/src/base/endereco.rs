@@ -5,6 +5,7 @@
 
 use super::Error;
 use serde::{Deserialize, Serialize};
+use std::fmt;
 use std::str::FromStr;
 
 /// Endereço completo
@@ -14,43 +15,43 @@ use std::str::FromStr;
 #[serde(rename = "Endereco")]
 pub struct Endereco {
     /// Logradouro (rua, avenida, etc.)
-    #[serde(rename = "xLgr")]
+    #[serde(rename = "$unflatten=xLgr")]
     pub logradouro: String,
     /// Número do endereço
-    #[serde(rename = "nro")]
+    #[serde(rename = "$unflatten=nro")]
     pub numero: String,
     /// Complemento do endereço
-    #[serde(rename = "xCpl")]
+    #[serde(rename = "$unflatten=xCpl")]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
     pub complemento: Option<String>,
     /// Bairro
-    #[serde(rename = "xBairro")]
+    #[serde(rename = "$unflatten=xBairro")]
     pub bairro: String,
     /// Código do município (IBGE)
-    #[serde(rename = "cMun")]
+    #[serde(rename = "$unflatten=cMun")]
     pub codigo_municipio: u32,
     /// Nome do município
-    #[serde(rename = "xMun")]
+    #[serde(rename = "$unflatten=xMun")]
     pub nome_municipio: String,
     /// Sigla da UF
-    #[serde(rename = "UF")]
+    #[serde(rename = "$unflatten=UF")]
     pub sigla_uf: String,
     /// CEP (8 dígitos)
-    #[serde(rename = "CEP")]
+    #[serde(rename = "$unflatten=CEP")]
     pub cep: String,
     /// Código do país (1058 para Brasil)
-    #[serde(rename = "cPais")]
+    #[serde(rename = "$unflatten=cPais")]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
     pub codigo_pais: Option<String>,
     /// Nome do país
-    #[serde(rename = "xPais")]
+    #[serde(rename = "$unflatten=xPais")]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
     pub nome_pais: Option<String>,
     /// Telefone
-    #[serde(rename = "fone")]
+    #[serde(rename = "$unflatten=fone")]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
     pub telefone: Option<String>,
@@ -64,9 +65,9 @@ impl FromStr for Endereco {
     }
 }
 
-impl ToString for Endereco {
-    fn to_string(&self) -> String {
-        quick_xml::se::to_string(self).expect("Falha ao serializar o endereço")
+impl fmt::Display for Endereco {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&quick_xml::se::to_string(self).expect("Falha ao serializar o endereço"))
     }
 }
 
@@ -7,6 +7,7 @@
 //!
 //! - **Io**: Erros de leitura/escrita de arquivo
 //! - **Serde**: Erros de deserialização XML (estrutura inválida, campos faltando, etc.)
+//! - **Esquema**: Erros na leitura dos esquemas XSD (arquivo ausente, construção não suportada)
 //!
 //! ## Exemplo de Tratamento
 //!
@@ -18,6 +19,7 @@
 //!         Ok(nfe) => println!("NF-e processada: {}", nfe.chave_acesso),
 //!         Err(Error::Io(e)) => eprintln!("Erro de arquivo: {}", e),
 //!         Err(Error::Serde(e)) => eprintln!("XML inválido: {}", e),
+//!         Err(Error::Esquema(e)) => eprintln!("Esquema inválido: {}", e),
 //!     }
 //!     Ok(())
 //! }
@@ -41,4 +43,11 @@ pub enum Error {
     /// Exemplos: tags obrigatórias faltando, tipos inválidos, estrutura incorreta
     #[display(fmt = "Erro de XML: {}", _0)]
     Serde(quick_xml::de::DeError),
+
+    /// Erro na leitura dos esquemas XSD
+    /// Ocorre quando um arquivo do pacote está ausente, mal formado ou usa
+    /// construções do XML Schema não suportadas
+    #[display(fmt = "Erro de esquema XSD: {}", _0)]
+    #[from(ignore)]
+    Esquema(#[error(ignore)] String),
 }
@@ -0,0 +1,168 @@
+//! Árvore XML com namespaces resolvidos
+//!
+//! Usada tanto na leitura dos esquemas quanto no documento validado: cada
+//! elemento guarda o namespace do nome, os atributos e os prefixos em escopo
+//! (necessários para resolver referências como `type="ds:SignatureType"`).
+
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+/// Namespace do prefixo reservado `xml`
+const NAMESPACE_XML: &str = "http://www.w3.org/XML/1998/namespace";
+
+/// Prefixos em escopo (`""` é o namespace padrão)
+pub(super) type Prefixos = Rc<HashMap<String, String>>;
+
+/// Elemento XML já lido
+#[derive(Debug)]
+pub(super) struct No {
+    /// Namespace do elemento (vazio quando não qualificado)
+    pub namespace: String,
+    /// Nome local do elemento
+    pub nome: String,
+    /// Atributos pelo nome local, sem as declarações `xmlns`
+    pub atributos: Vec<(String, String)>,
+    /// Prefixos em escopo no elemento
+    pub prefixos: Prefixos,
+    pub filhos: Vec<No>,
+    /// Texto e CDATA do elemento, concatenados e sem escapes
+    pub texto: String,
+}
+
+impl No {
+    pub fn atributo(&self, nome: &str) -> Option<&str> {
+        self.atributos
+            .iter()
+            .find(|(chave, _)| chave == nome)
+            .map(|(_, valor)| valor.as_str())
+    }
+
+    /// Resolve um nome qualificado (`prefixo:nome`) pelos prefixos em escopo
+    pub fn resolver(&self, qualificado: &str) -> Result<(String, String), String> {
+        let (prefixo, local) = qualificado.split_once(':').unwrap_or(("", qualificado));
+
+        match self.prefixos.get(prefixo) {
+            Some(namespace) => Ok((namespace.clone(), local.to_string())),
+            None if prefixo.is_empty() => Ok((String::new(), local.to_string())),
+            None => Err(format!("prefixo '{}' não declarado em '{}'", prefixo, qualificado)),
+        }
+    }
+}
+
+/// Lê o documento e retorna o elemento raiz
+pub(super) fn ler(xml: &str) -> Result<No, String> {
+    let xml = xml.trim_start_matches('\u{feff}');
+
+    let mut reader = Reader::from_str(xml);
+    reader.check_end_names(true);
+
+    let mut pilha: Vec<No> = Vec::new();
+    let mut raiz = None;
+
+    loop {
+        let evento = reader
+            .read_event_unbuffered()
+            .map_err(|e| format!("XML mal formado (posição {}): {}", reader.buffer_position(), e))?;
+
+        match evento {
+            Event::Eof => break,
+            Event::Start(e) => {
+                let escopo = pilha.last().map(|pai| &pai.prefixos);
+                pilha.push(abrir(&e, escopo)?);
+            }
+            Event::Empty(e) => {
+                let escopo = pilha.last().map(|pai| &pai.prefixos);
+                let no = abrir(&e, escopo)?;
+                fechar(no, &mut pilha, &mut raiz)?;
+            }
+            Event::End(_) => {
+                let no = pilha.pop().expect("fechamento verificado pelo leitor");
+                fechar(no, &mut pilha, &mut raiz)?;
+            }
+            Event::Text(e) => {
+                let texto = e.unescaped().map_err(|e| format!("XML mal formado: {}", e))?;
+                texto_do_elemento(&mut pilha, &texto)?;
+            }
+            Event::CData(e) => texto_do_elemento(&mut pilha, &e.into_inner())?,
+            _ => {}
+        }
+    }
+
+    if !pilha.is_empty() {
+        return Err("XML mal formado: elementos não fechados".to_string());
+    }
+
+    raiz.ok_or_else(|| "XML mal formado: documento sem elemento raiz".to_string())
+}
+
+fn abrir(e: &BytesStart, escopo: Option<&Prefixos>) -> Result<No, String> {
+    let mut declarados = Vec::new();
+    let mut atributos = Vec::new();
+
+    for atributo in e.attributes() {
+        let atributo = atributo.map_err(|e| format!("XML mal formado: {}", e))?;
+        let valor = atributo
+            .unescaped_value()
+            .map_err(|e| format!("XML mal formado: {}", e))?;
+        let valor = String::from_utf8_lossy(&valor).into_owned();
+        let chave = String::from_utf8_lossy(atributo.key).into_owned();
+
+        match chave.split_once(':') {
+            _ if chave == "xmlns" => declarados.push((String::new(), valor)),
+            Some(("xmlns", prefixo)) => declarados.push((prefixo.to_string(), valor)),
+            // Atributos qualificados (xsi:, xml:) não fazem parte dos modelos do leiaute
+            Some(_) => {}
+            None => atributos.push((chave, valor)),
+        }
+    }
+
+    let prefixos = match escopo {
+        Some(escopo) if declarados.is_empty() => escopo.clone(),
+        _ => {
+            let mut prefixos = escopo.map(|p| p.as_ref().clone()).unwrap_or_else(|| {
+                HashMap::from([("xml".to_string(), NAMESPACE_XML.to_string())])
+            });
+            prefixos.extend(declarados);
+            Rc::new(prefixos)
+        }
+    };
+
+    let nome = String::from_utf8_lossy(e.name()).into_owned();
+    let (prefixo, local) = nome.split_once(':').unwrap_or(("", nome.as_str()));
+    let namespace = match prefixos.get(prefixo) {
+        Some(namespace) => namespace.clone(),
+        None if prefixo.is_empty() => String::new(),
+        None => return Err(format!("XML mal formado: prefixo '{}' não declarado", prefixo)),
+    };
+
+    Ok(No {
+        namespace,
+        nome: local.to_string(),
+        atributos,
+        prefixos,
+        filhos: Vec::new(),
+        texto: String::new(),
+    })
+}
+
+fn fechar(no: No, pilha: &mut [No], raiz: &mut Option<No>) -> Result<(), String> {
+    match pilha.last_mut() {
+        Some(pai) => pai.filhos.push(no),
+        None if raiz.is_none() => *raiz = Some(no),
+        None => return Err("XML mal formado: mais de um elemento raiz".to_string()),
+    }
+    Ok(())
+}
+
+fn texto_do_elemento(pilha: &mut [No], texto: &[u8]) -> Result<(), String> {
+    let texto = String::from_utf8_lossy(texto);
+
+    match pilha.last_mut() {
+        Some(no) => no.texto.push_str(&texto),
+        None if texto.trim().is_empty() => {}
+        None => return Err("XML mal formado: texto fora do elemento raiz".to_string()),
+    }
+    Ok(())
+}
@@ -0,0 +1,586 @@
+//! Validação do documento contra as declarações dos esquemas
+
+use super::arvore::No;
+use super::modelo::{
+    Conteudo, Curinga, DeclAtributo, Definicoes, Espacos, Facetas, Nome, Particula, Processamento,
+    RefTipo, Termo, Tipo, TipoComplexo, TipoSimples, NAMESPACE_XSD,
+};
+use super::ViolacaoEsquema;
+use crate::base::decimal::Decimal;
+use std::str::FromStr;
+
+/// Tipo referenciado, já resolvido nas definições
+enum Resolvido<'a> {
+    /// Tipo embutido do XML Schema (`xs:string`, `xs:decimal`...)
+    Primitivo(&'a str),
+    /// `xs:anyType`: qualquer conteúdo
+    QualquerTipo,
+    Simples(&'a TipoSimples),
+    Complexo(&'a TipoComplexo),
+}
+
+/// Destino de um filho casado com o modelo de conteúdo
+enum Alvo<'a> {
+    Tipo(&'a RefTipo),
+    Curinga(&'a Curinga),
+}
+
+/// Posição em que o modelo de conteúdo deixou de ser atendido
+struct Falha {
+    posicao: usize,
+    esperados: Vec<String>,
+}
+
+pub(super) struct Validador<'a> {
+    definicoes: &'a Definicoes,
+    /// Elementos obrigatórios no esquema cuja ausência é aceita
+    dispensados: Vec<Nome>,
+    violacoes: Vec<ViolacaoEsquema>,
+}
+
+impl<'a> Validador<'a> {
+    pub fn new(definicoes: &'a Definicoes) -> Self {
+        Self { definicoes, dispensados: Vec::new(), violacoes: Vec::new() }
+    }
+
+    /// Aceita a ausência do elemento, ainda que obrigatório no esquema
+    pub fn dispensar(mut self, nome: Nome) -> Self {
+        self.dispensados.push(nome);
+        self
+    }
+
+    pub fn validar(mut self, raiz: &No) -> Vec<ViolacaoEsquema> {
+        let caminho = format!("/{}", raiz.nome);
+
+        match self.definicoes.elementos.get(&nome_do(raiz)) {
+            Some(elemento) => self.validar_elemento(raiz, &elemento.tipo, &caminho),
+            None => self.violar(&caminho, format!("elemento raiz {} não declarado no esquema", descrever(raiz))),
+        }
+
+        self.violacoes
+    }
+
+    fn violar(&mut self, caminho: &str, mensagem: String) {
+        self.violacoes.push(ViolacaoEsquema { caminho: caminho.to_string(), mensagem });
+    }
+
+    fn resolver(&self, referencia: &'a RefTipo) -> Resolvido<'a> {
+        let tipo = match referencia {
+            RefTipo::Anonimo(tipo) => tipo.as_ref(),
+            RefTipo::Nomeado(nome) if nome.0 == NAMESPACE_XSD => {
+                return match nome.1.as_str() {
+                    "anyType" => Resolvido::QualquerTipo,
+                    primitivo => Resolvido::Primitivo(primitivo),
+                }
+            }
+            RefTipo::Nomeado(nome) => match self.definicoes.tipos.get(nome) {
+                Some(tipo) => tipo.as_ref(),
+                // As referências são conferidas na leitura dos esquemas
+                None => return Resolvido::QualquerTipo,
+            },
+        };
+
+        match tipo {
+            Tipo::Simples(simples) => Resolvido::Simples(simples),
+            Tipo::Complexo(complexo) => Resolvido::Complexo(complexo),
+        }
+    }
+
+    /// Tipo embutido de onde a restrição deriva
+    fn primitivo(&self, tipo: &'a RefTipo) -> Option<&'a str> {
+        match self.resolver(tipo) {
+            Resolvido::Primitivo(primitivo) => Some(primitivo),
+            Resolvido::Simples(TipoSimples::Restricao { base, .. }) => self.primitivo(base),
+            _ => None,
+        }
+    }
+
+    fn complexo(&self, nome: &Nome) -> Option<&'a TipoComplexo> {
+        match self.definicoes.tipos.get(nome).map(|tipo| tipo.as_ref()) {
+            Some(Tipo::Complexo(complexo)) => Some(complexo),
+            _ => None,
+        }
+    }
+
+    fn validar_elemento(&mut self, no: &No, tipo: &'a RefTipo, caminho: &str) {
+        match self.resolver(tipo) {
+            Resolvido::QualquerTipo => {}
+            Resolvido::Primitivo(_) | Resolvido::Simples(_) => {
+                self.validar_atributos(no, &[], false, caminho);
+                self.sem_filhos(no, caminho);
+                if let Err(mensagem) = self.validar_valor(&no.texto, tipo) {
+                    self.violar(caminho, mensagem);
+                }
+            }
+            Resolvido::Complexo(complexo) => self.validar_complexo(no, complexo, caminho),
+        }
+    }
+
+    fn validar_complexo(&mut self, no: &No, complexo: &'a TipoComplexo, caminho: &str) {
+        // Cadeia de derivação: atributos de todos os tipos base e, nas
+        // extensões de conteúdo complexo, os filhos do tipo base primeiro
+        let mut cadeia = vec![complexo];
+        let mut atual = complexo;
+        while let Some(base) = atual.base.as_ref().and_then(|base| self.complexo(base)) {
+            if cadeia.iter().any(|tipo| std::ptr::eq(*tipo, base)) {
+                break;
+            }
+            cadeia.push(base);
+            atual = base;
+        }
+
+        let atributos: Vec<&DeclAtributo> = cadeia.iter().flat_map(|tipo| &tipo.atributos).collect();
+        let qualquer_atributo = cadeia.iter().any(|tipo| tipo.qualquer_atributo);
+        self.validar_atributos(no, &atributos, qualquer_atributo, caminho);
+
+        let mut particulas = Vec::new();
+        for tipo in &cadeia {
+            if let Conteudo::Elementos(particula) = &tipo.conteudo {
+                particulas.push(particula);
+            }
+            if !tipo.herda_conteudo {
+                break;
+            }
+        }
+        particulas.reverse();
+
+        if let Conteudo::Texto(texto) = &complexo.conteudo {
+            self.sem_filhos(no, caminho);
+            if let Err(mensagem) = self.validar_valor(&no.texto, texto) {
+                self.violar(caminho, mensagem);
+            }
+            return;
+        }
+
+        if !complexo.misto && !no.texto.trim().is_empty() {
+            self.violar(caminho, format!("texto não permitido: '{}'", no.texto.trim()));
+        }
+        self.validar_filhos(no, &particulas, caminho);
+    }
+
+    fn sem_filhos(&mut self, no: &No, caminho: &str) {
+        if let Some(filho) = no.filhos.first() {
+            self.violar(
+                caminho,
+                format!("elemento {} não permitido: o conteúdo deve ser texto", descrever(filho)),
+            );
+        }
+    }
+
+    fn validar_atributos(
+        &mut self,
+        no: &No,
+        declarados: &[&'a DeclAtributo],
+        qualquer_atributo: bool,
+        caminho: &str,
+    ) {
+        for (nome, valor) in &no.atributos {
+            match declarados.iter().find(|a| &a.nome == nome) {
+                Some(atributo) => {
+                    let resultado = match (&atributo.fixo, &atributo.tipo) {
+                        (Some(fixo), _) if fixo != valor => {
+                            Err(format!("valor '{}' difere do fixo '{}'", valor, fixo))
+                        }
+                        (_, Some(tipo)) => self.validar_valor(valor, tipo),
+                        _ => Ok(()),
+                    };
+                    if let Err(mensagem) = resultado {
+                        self.violar(caminho, format!("atributo '{}': {}", nome, mensagem));
+                    }
+                }
+                None if qualquer_atributo => {}
+                None => self.violar(caminho, format!("atributo '{}' não declarado", nome)),
+            }
+        }
+
+        for atributo in declarados.iter().filter(|a| a.obrigatorio) {
+            if no.atributo(&atributo.nome).is_none() {
+                self.violar(caminho, format!("atributo obrigatório ausente: '{}'", atributo.nome));
+            }
+        }
+    }
+
+    fn validar_filhos(&mut self, no: &No, particulas: &[&'a Particula], caminho: &str) {
+        let filhos: Vec<Nome> = no.filhos.iter().map(nome_do).collect();
+        let mut posicao = 0;
+        let mut casados = Vec::new();
+        let mut resultado = Ok(());
+
+        for particula in particulas {
+            resultado = self.casar(particula, &filhos, &mut posicao, &mut casados);
+            if resultado.is_err() {
+                break;
+            }
+        }
+
+        match resultado {
+            Err(Falha { posicao, esperados }) if posicao < filhos.len() => {
+                let filho = &no.filhos[posicao];
+                self.violar(
+                    &caminho_filho(no, posicao, caminho),
+                    format!("elemento {} inesperado; esperado: {}", descrever(filho), esperados.join(", ")),
+                );
+            }
+            Err(Falha { esperados, .. }) => self.violar(
+                caminho,
+                format!("elemento obrigatório ausente; esperado: {}", esperados.join(", ")),
+            ),
+            Ok(()) if posicao < filhos.len() => {
+                let filho = &no.filhos[posicao];
+                self.violar(
+                    &caminho_filho(no, posicao, caminho),
+                    format!("elemento {} não permitido nesta posição", descrever(filho)),
+                );
+            }
+            Ok(()) => {}
+        }
+
+        for (indice, alvo) in casados {
+            let filho = &no.filhos[indice];
+            let caminho = caminho_filho(no, indice, caminho);
+
+            match alvo {
+                Alvo::Tipo(tipo) => self.validar_elemento(filho, tipo, &caminho),
+                Alvo::Curinga(curinga) => {
+                    let global = self.definicoes.elementos.get(&nome_do(filho));
+                    match (curinga.processamento, global) {
+                        (Processamento::Ignorar, _) => {}
+                        (_, Some(elemento)) => self.validar_elemento(filho, &elemento.tipo, &caminho),
+                        (Processamento::Flexivel, None) => {}
+                        (Processamento::Estrito, None) => self.violar(
+                            &caminho,
+                            format!("elemento {} não declarado no esquema", descrever(filho)),
+                        ),
+                    }
+                }
+            }
+        }
+    }
+
+    /// Casa a partícula, com suas ocorrências, a partir da posição atual
+    fn casar(
+        &self,
+        particula: &'a Particula,
+        filhos: &[Nome],
+        posicao: &mut usize,
+        casados: &mut Vec<(usize, Alvo<'a>)>,
+    ) -> Result<(), Falha> {
+        let mut ocorrencias = 0;
+
+        while particula.max.map_or(true, |max| ocorrencias < max) {
+            let inicia = filhos
+                .get(*posicao)
+                .is_some_and(|filho| self.inicia(&particula.termo, filho));
+
+            if !inicia {
+                if ocorrencias < particula.min && !self.termo_anulavel(&particula.termo) {
+                    return Err(Falha { posicao: *posicao, esperados: self.primeiros(&particula.termo) });
+                }
+                break;
+            }
+
+            let antes = *posicao;
+            self.casar_termo(&particula.termo, filhos, posicao, casados)?;
+            ocorrencias += 1;
+
+            if *posicao == antes {
+                break;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn casar_termo(
+        &self,
+        termo: &'a Termo,
+        filhos: &[Nome],
+        posicao: &mut usize,
+        casados: &mut Vec<(usize, Alvo<'a>)>,
+    ) -> Result<(), Falha> {
+        match termo {
+            Termo::Elemento(elemento) => {
+                casados.push((*posicao, Alvo::Tipo(&elemento.tipo)));
+                *posicao += 1;
+            }
+            Termo::Referencia(nome) => {
+                let elemento = &self.definicoes.elementos[nome];
+                casados.push((*posicao, Alvo::Tipo(&elemento.tipo)));
+                *posicao += 1;
+            }
+            Termo::Qualquer(curinga) => {
+                casados.push((*posicao, Alvo::Curinga(curinga)));
+                *posicao += 1;
+            }
+            Termo::Sequencia(itens) => {
+                for item in itens {
+                    self.casar(item, filhos, posicao, casados)?;
+                }
+            }
+            Termo::Escolha(itens) => {
+                let proximo = filhos.get(*posicao);
+                let alternativa = itens
+                    .iter()
+                    .find(|item| proximo.is_some_and(|filho| self.inicia(&item.termo, filho)));
+
+                match alternativa {
+                    Some(item) => self.casar(item, filhos, posicao, casados)?,
+                    None if itens.iter().any(|item| self.anulavel(item)) => {}
+                    None => return Err(Falha { posicao: *posicao, esperados: self.primeiros(termo) }),
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Indica se o termo pode começar pelo elemento informado
+    fn inicia(&self, termo: &Termo, filho: &Nome) -> bool {
+        match termo {
+            Termo::Elemento(elemento) => &elemento.nome == filho,
+            Termo::Referencia(nome) => nome == filho,
+            Termo::Qualquer(curinga) => curinga.aceita(&filho.0),
+            Termo::Sequencia(itens) => {
+                for item in itens {
+                    if self.inicia(&item.termo, filho) {
+                        return true;
+                    }
+                    if !self.anulavel(item) {
+                        return false;
+                    }
+                }
+                false
+            }
+            Termo::Escolha(itens) => itens.iter().any(|item| self.inicia(&item.termo, filho)),
+        }
+    }
+
+    fn anulavel(&self, particula: &Particula) -> bool {
+        particula.min == 0 || self.termo_anulavel(&particula.termo)
+    }
+
+    fn termo_anulavel(&self, termo: &Termo) -> bool {
+        match termo {
+            Termo::Elemento(elemento) => self.dispensados.contains(&elemento.nome),
+            Termo::Referencia(nome) => self.dispensados.contains(nome),
+            Termo::Qualquer(_) => false,
+            Termo::Sequencia(itens) => itens.iter().all(|item| self.anulavel(item)),
+            Termo::Escolha(itens) => itens.iter().any(|item| self.anulavel(item)),
+        }
+    }
+
+    /// Elementos que podem iniciar o termo, para as mensagens de erro
+    fn primeiros(&self, termo: &Termo) -> Vec<String> {
+        match termo {
+            Termo::Elemento(elemento) => vec![format!("'{}'", elemento.nome.1)],
+            Termo::Referencia(nome) => vec![format!("'{}'", nome.1)],
+            Termo::Qualquer(_) => vec!["qualquer elemento".to_string()],
+            Termo::Sequencia(itens) => {
+                let mut primeiros = Vec::new();
+                for item in itens {
+                    primeiros.extend(self.primeiros(&item.termo));
+                    if !self.anulavel(item) {
+                        break;
+                    }
+                }
+                primeiros
+            }
+            Termo::Escolha(itens) => itens.iter().flat_map(|item| self.primeiros(&item.termo)).collect(),
+        }
+    }
+
+    /// Valida um valor textual (conteúdo de elemento ou atributo)
+    fn validar_valor(&self, valor: &str, tipo: &'a RefTipo) -> Result<(), String> {
+        let valor = normalizar(valor, self.espacos(tipo));
+
+        match self.resolver(tipo) {
+            Resolvido::Primitivo(primitivo) => validar_primitivo(&valor, primitivo),
+            Resolvido::Simples(TipoSimples::Restricao { base, facetas }) => {
+                self.validar_valor(&valor, base)?;
+                verificar_facetas(&valor, facetas, self.primitivo(base))
+            }
+            Resolvido::Simples(TipoSimples::Lista(item)) => valor
+                .split_whitespace()
+                .try_for_each(|parte| self.validar_valor(parte, item)),
+            Resolvido::Simples(TipoSimples::Uniao(membros)) => {
+                if membros.iter().any(|membro| self.validar_valor(&valor, membro).is_ok()) {
+                    Ok(())
+                } else {
+                    Err(format!("valor '{}' não corresponde a nenhum dos tipos permitidos", valor))
+                }
+            }
+            Resolvido::Complexo(TipoComplexo { conteudo: Conteudo::Texto(texto), .. }) => {
+                self.validar_valor(&valor, texto)
+            }
+            Resolvido::Complexo(_) | Resolvido::QualquerTipo => Ok(()),
+        }
+    }
+
+    /// Tratamento de espaços (`whiteSpace`) efetivo do tipo
+    fn espacos(&self, tipo: &'a RefTipo) -> Espacos {
+        match self.resolver(tipo) {
+            Resolvido::Primitivo("string" | "anySimpleType") => Espacos::Preservar,
+            Resolvido::Primitivo("normalizedString") => Espacos::Substituir,
+            Resolvido::Simples(TipoSimples::Restricao { base, facetas }) => {
+                facetas.espacos.unwrap_or_else(|| self.espacos(base))
+            }
+            Resolvido::Complexo(TipoComplexo { conteudo: Conteudo::Texto(texto), .. }) => self.espacos(texto),
+            Resolvido::Complexo(_) | Resolvido::QualquerTipo => Espacos::Preservar,
+            _ => Espacos::Colapsar,
+        }
+    }
+}
+
+fn nome_do(no: &No) -> Nome {
+    (no.namespace.clone(), no.nome.clone())
+}
+
+fn descrever(no: &No) -> String {
+    format!("'{}'", no.nome)
+}
+
+/// Caminho do filho, com o índice quando há irmãos de mesmo nome (`det[2]`)
+fn caminho_filho(pai: &No, indice: usize, caminho: &str) -> String {
+    let filho = &pai.filhos[indice];
+    let mesmo_nome = |f: &&No| f.nome == filho.nome && f.namespace == filho.namespace;
+
+    if pai.filhos.iter().filter(mesmo_nome).count() > 1 {
+        let posicao = pai.filhos[..indice].iter().filter(mesmo_nome).count() + 1;
+        format!("{}/{}[{}]", caminho, filho.nome, posicao)
+    } else {
+        format!("{}/{}", caminho, filho.nome)
+    }
+}
+
+fn normalizar(valor: &str, espacos: Espacos) -> String {
+    match espacos {
+        Espacos::Preservar => valor.to_string(),
+        Espacos::Substituir => valor.replace(['\t', '\n', '\r'], " "),
+        Espacos::Colapsar => valor.split_whitespace().collect::<Vec<_>>().join(" "),
+    }
+}
+
+fn verificar_facetas(valor: &str, facetas: &Facetas, primitivo: Option<&str>) -> Result<(), String> {
+    if !facetas.enumeracao.is_empty() && !facetas.enumeracao.iter().any(|e| e == valor) {
+        return Err(format!(
+            "valor '{}' não está entre os permitidos: {}",
+            valor,
+            facetas.enumeracao.join(", ")
+        ));
+    }
+    if !facetas.padroes.is_empty() && !facetas.padroes.iter().any(|(_, padrao)| padrao.is_match(valor)) {
+        let padroes: Vec<&str> = facetas.padroes.iter().map(|(texto, _)| texto.as_str()).collect();
+        return Err(format!("valor '{}' não atende ao padrão {}", valor, padroes.join(" | ")));
+    }
+
+    let tamanho = medir(valor, primitivo);
+    let unidade = match primitivo {
+        Some("base64Binary" | "hexBinary") => "octetos",
+        _ => "caracteres",
+    };
+    if let Some(comprimento) = facetas.comprimento.filter(|c| tamanho != *c) {
+        return Err(format!("valor '{}' deve ter {} {}", valor, comprimento, unidade));
+    }
+    if let Some(minimo) = facetas.min_comprimento.filter(|m| tamanho < *m) {
+        return Err(format!("valor '{}' deve ter ao menos {} {}", valor, minimo, unidade));
+    }
+    if let Some(maximo) = facetas.max_comprimento.filter(|m| tamanho > *m) {
+        return Err(format!("valor '{}' deve ter no máximo {} {}", valor, maximo, unidade));
+    }
+
+    if facetas.total_digitos.is_some()
+        || facetas.digitos_fracao.is_some()
+        || facetas.min_inclusivo.is_some()
+        || facetas.max_inclusivo.is_some()
+        || facetas.min_exclusivo.is_some()
+        || facetas.max_exclusivo.is_some()
+    {
+        verificar_numero(valor, facetas)?;
+    }
+
+    Ok(())
+}
+
+/// Comprimento para as facetas `length`, `minLength` e `maxLength`
+///
+/// Nos tipos binários conta os octetos decodificados, não os caracteres.
+fn medir(valor: &str, primitivo: Option<&str>) -> usize {
+    match primitivo {
+        Some("base64Binary") => {
+            let conteudo: String = valor.chars().filter(|c| !c.is_whitespace()).collect();
+            let preenchimento = conteudo.len() - conteudo.trim_end_matches('=').len();
+            (conteudo.len() / 4 * 3).saturating_sub(preenchimento)
+        }
+        Some("hexBinary") => valor.len() / 2,
+        _ => valor.chars().count(),
+    }
+}
+
+fn verificar_numero(valor: &str, facetas: &Facetas) -> Result<(), String> {
+    let numero = Decimal::from_str(valor).map_err(|_| format!("valor '{}' não é numérico", valor))?;
+
+    let sem_sinal = valor.trim_start_matches(['+', '-']);
+    let (inteira, fracao) = sem_sinal.split_once('.').unwrap_or((sem_sinal, ""));
+    let inteira = inteira.trim_start_matches('0');
+    let fracao = fracao.trim_end_matches('0');
+
+    if let Some(total) = facetas.total_digitos.filter(|t| inteira.len() + fracao.len() > *t) {
+        return Err(format!("valor '{}' excede {} dígitos", valor, total));
+    }
+    if let Some(casas) = facetas.digitos_fracao.filter(|c| fracao.len() > *c) {
+        return Err(format!("valor '{}' excede {} casas decimais", valor, casas));
+    }
+    if let Some(minimo) = facetas.min_inclusivo.filter(|m| numero < *m) {
+        return Err(format!("valor '{}' menor que o mínimo {}", valor, minimo));
+    }
+    if let Some(maximo) = facetas.max_inclusivo.filter(|m| numero > *m) {
+        return Err(format!("valor '{}' maior que o máximo {}", valor, maximo));
+    }
+    if let Some(minimo) = facetas.min_exclusivo.filter(|m| numero <= *m) {
+        return Err(format!("valor '{}' deve ser maior que {}", valor, minimo));
+    }
+    if let Some(maximo) = facetas.max_exclusivo.filter(|m| numero >= *m) {
+        return Err(format!("valor '{}' deve ser menor que {}", valor, maximo));
+    }
+
+    Ok(())
+}
+
+/// Verificação léxica dos tipos embutidos usados nos leiautes
+fn validar_primitivo(valor: &str, primitivo: &str) -> Result<(), String> {
+    let digitos = |texto: &str| !texto.is_empty() && texto.bytes().all(|b| b.is_ascii_digit());
+    let sem_sinal = valor.strip_prefix(['+', '-']).unwrap_or(valor);
+
+    let valido = match primitivo {
+        "decimal" => {
+            let (inteira, fracao) = sem_sinal.split_once('.').unwrap_or((sem_sinal, ""));
+            (digitos(inteira) || digitos(fracao))
+                && (inteira.is_empty() || digitos(inteira))
+                && (fracao.is_empty() || digitos(fracao))
+        }
+        "integer" | "long" | "int" | "short" | "byte" => digitos(sem_sinal),
+        "nonNegativeInteger" | "unsignedLong" | "unsignedInt" | "unsignedShort" | "unsignedByte" => {
+            digitos(sem_sinal) && (!valor.starts_with('-') || sem_sinal.bytes().all(|b| b == b'0'))
+        }
+        "positiveInteger" => {
+            digitos(sem_sinal) && !valor.starts_with('-') && sem_sinal.bytes().any(|b| b != b'0')
+        }
+        "boolean" => matches!(valor, "true" | "false" | "1" | "0"),
+        "base64Binary" => {
+            let conteudo: String = valor.chars().filter(|c| !c.is_whitespace()).collect();
+            conteudo.len() % 4 == 0
+                && conteudo
+                    .trim_end_matches('=')
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '+' || c == '/')
+        }
+        "hexBinary" => valor.len() % 2 == 0 && valor.chars().all(|c| c.is_ascii_hexdigit()),
+        _ => true,
+    };
+
+    if valido {
+        Ok(())
+    } else {
+        Err(format!("valor '{}' inválido para xs:{}", valor, primitivo))
+    }
+}
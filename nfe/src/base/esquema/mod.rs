@@ -0,0 +1,193 @@
+//! Validação offline contra os esquemas XSD da SEFAZ
+//!
+//! Antes de assinar e transmitir, o XML precisa estar conforme o pacote de
+//! esquemas do leiaute (PL_009 para a NF-e 4.00). Este módulo lê os arquivos
+//! `.xsd` do pacote e valida o documento localmente, sem consulta à SEFAZ.
+//!
+//! ## Pacote PL_009
+//!
+//! Os arquivos de [`ARQUIVOS_PL_009`], como distribuídos no Portal da NF-e,
+//! vêm embutidos na biblioteca ([`EsquemaXsd::pl_009_embutido`]). Para usar
+//! outra versão do pacote, [`EsquemaXsd::pl_009`] lê os mesmos arquivos de um
+//! diretório. `include` e `import` são resolvidos pelo nome do arquivo,
+//! dentro do mesmo conjunto.
+//!
+//! ## Exemplo
+//!
+//! ```rust,ignore
+//! use nfe_parser::EsquemaXsd;
+//!
+//! let esquema = EsquemaXsd::pl_009_embutido()?;
+//!
+//! // Nota gerada pela biblioteca
+//! if let Err(violacoes) = nfe.validar_esquema(&esquema) {
+//!     for violacao in violacoes {
+//!         // "/NFe/infNFe/det[2]/prod/NCM: valor '6109' não atende ao padrão [0-9]{2}|[0-9]{8}"
+//!         println!("{}", violacao);
+//!     }
+//! }
+//!
+//! // XML assinado recebido de terceiros
+//! let valido = esquema.validar(&xml_recebido).is_ok();
+//! ```
+
+mod arvore;
+mod instancia;
+mod modelo;
+
+use super::Error;
+use std::collections::HashMap;
+use std::fmt;
+use std::path::Path;
+
+/// Arquivos do pacote de esquemas da NF-e 4.00 (PL_009)
+///
+/// O primeiro é o esquema principal, que declara o elemento `<NFe>`.
+pub const ARQUIVOS_PL_009: &[&str] = &[
+    "nfe_v4.00.xsd",
+    "leiauteNFe_v4.00.xsd",
+    "tiposBasico_v4.00.xsd",
+    "xmldsig-core-schema_v1.01.xsd",
+    "DFeTiposBasicos_v1.00.xsd",
+];
+
+/// Conteúdo dos arquivos de [`ARQUIVOS_PL_009`] embutidos na biblioteca
+const PL_009_EMBUTIDO: &[(&str, &str)] = &[
+    ("nfe_v4.00.xsd", include_str!("../../../schemas/PL_009/nfe_v4.00.xsd")),
+    ("leiauteNFe_v4.00.xsd", include_str!("../../../schemas/PL_009/leiauteNFe_v4.00.xsd")),
+    ("tiposBasico_v4.00.xsd", include_str!("../../../schemas/PL_009/tiposBasico_v4.00.xsd")),
+    (
+        "xmldsig-core-schema_v1.01.xsd",
+        include_str!("../../../schemas/PL_009/xmldsig-core-schema_v1.01.xsd"),
+    ),
+    ("DFeTiposBasicos_v1.00.xsd", include_str!("../../../schemas/PL_009/DFeTiposBasicos_v1.00.xsd")),
+];
+
+/// Namespace da assinatura digital (`<Signature>`)
+const NAMESPACE_XMLDSIG: &str = "http://www.w3.org/2000/09/xmldsig#";
+
+/// Violação do esquema, com o caminho do elemento no documento
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ViolacaoEsquema {
+    /// Caminho do elemento (ex: `/NFe/infNFe/det[2]/prod/NCM`)
+    pub caminho: String,
+    pub mensagem: String,
+}
+
+impl fmt::Display for ViolacaoEsquema {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.caminho, self.mensagem)
+    }
+}
+
+/// Conjunto de esquemas XSD carregado e pronto para validação
+#[derive(Debug)]
+pub struct EsquemaXsd {
+    definicoes: modelo::Definicoes,
+}
+
+impl EsquemaXsd {
+    /// Carrega os esquemas a partir do conteúdo dos arquivos (nome, conteúdo)
+    ///
+    /// A leitura começa pelo arquivo `principal` e segue os `include` e
+    /// `import`, que devem estar entre os arquivos informados.
+    pub fn carregar<'a, I>(arquivos: I, principal: &str) -> Result<Self, Error>
+    where
+        I: IntoIterator<Item = (&'a str, &'a str)>,
+    {
+        let arquivos: HashMap<String, String> = arquivos
+            .into_iter()
+            .map(|(nome, conteudo)| (nome.to_string(), conteudo.to_string()))
+            .collect();
+
+        Self::compilar(&arquivos, principal)
+    }
+
+    /// Carrega os arquivos `.xsd` de um diretório
+    pub fn carregar_diretorio(diretorio: impl AsRef<Path>, principal: &str) -> Result<Self, Error> {
+        let mut arquivos = HashMap::new();
+
+        for entrada in std::fs::read_dir(diretorio)? {
+            let caminho = entrada?.path();
+            let xsd = caminho
+                .extension()
+                .is_some_and(|extensao| extensao.eq_ignore_ascii_case("xsd"));
+
+            if let (true, Some(nome)) = (xsd, caminho.file_name().and_then(|nome| nome.to_str())) {
+                let conteudo = super::xml::decodificar(&std::fs::read(&caminho)?);
+                arquivos.insert(nome.to_string(), conteudo);
+            }
+        }
+
+        Self::compilar(&arquivos, principal)
+    }
+
+    /// Carrega o pacote de esquemas da NF-e 4.00 (PL_009) embutido na biblioteca
+    pub fn pl_009_embutido() -> Result<Self, Error> {
+        Self::carregar(PL_009_EMBUTIDO.iter().copied(), ARQUIVOS_PL_009[0])
+    }
+
+    /// Carrega o pacote de esquemas da NF-e 4.00 (PL_009) de um diretório
+    ///
+    /// Substitui o pacote embutido (ver [`EsquemaXsd::pl_009_embutido`]) quando
+    /// a SEFAZ publica uma revisão dos esquemas antes de uma nova versão da
+    /// biblioteca.
+    pub fn pl_009(diretorio: impl AsRef<Path>) -> Result<Self, Error> {
+        let diretorio = diretorio.as_ref();
+
+        for arquivo in ARQUIVOS_PL_009 {
+            if !diretorio.join(arquivo).is_file() {
+                return Err(Error::Esquema(format!(
+                    "arquivo do pacote PL_009 não encontrado: {}",
+                    diretorio.join(arquivo).display()
+                )));
+            }
+        }
+
+        Self::carregar_diretorio(diretorio, ARQUIVOS_PL_009[0])
+    }
+
+    fn compilar(arquivos: &HashMap<String, String>, principal: &str) -> Result<Self, Error> {
+        let definicoes = modelo::compilar(arquivos, principal).map_err(Error::Esquema)?;
+        Ok(Self { definicoes })
+    }
+
+    /// Valida o documento XML, retornando todas as violações encontradas
+    ///
+    /// Um XML mal formado resulta em uma única violação no caminho `/`.
+    pub fn validar(&self, xml: &str) -> Result<(), Vec<ViolacaoEsquema>> {
+        self.validar_dispensando(xml, None)
+    }
+
+    /// Valida o documento antes da assinatura digital
+    ///
+    /// Igual a [`EsquemaXsd::validar`], mas aceita a ausência do `<Signature>`,
+    /// obrigatório no leiaute: a assinatura é o último passo antes da
+    /// transmissão. Uma assinatura presente continua sendo validada.
+    pub fn validar_sem_assinatura(&self, xml: &str) -> Result<(), Vec<ViolacaoEsquema>> {
+        let assinatura = (NAMESPACE_XMLDSIG.to_string(), "Signature".to_string());
+        self.validar_dispensando(xml, Some(assinatura))
+    }
+
+    fn validar_dispensando(
+        &self,
+        xml: &str,
+        dispensado: Option<modelo::Nome>,
+    ) -> Result<(), Vec<ViolacaoEsquema>> {
+        let raiz = arvore::ler(xml).map_err(|mensagem| {
+            vec![ViolacaoEsquema { caminho: "/".to_string(), mensagem }]
+        })?;
+
+        let mut validador = instancia::Validador::new(&self.definicoes);
+        if let Some(nome) = dispensado {
+            validador = validador.dispensar(nome);
+        }
+        let violacoes = validador.validar(&raiz);
+
+        if violacoes.is_empty() {
+            Ok(())
+        } else {
+            Err(violacoes)
+        }
+    }
+}
@@ -0,0 +1,684 @@
+//! Modelo dos esquemas XSD e leitura das declarações
+//!
+//! Cobre o subconjunto do XML Schema 1.0 usado pelos pacotes da SEFAZ:
+//! `element`, `complexType` (`sequence`, `choice`, `any`, `simpleContent`,
+//! `complexContent`), `simpleType` (`restriction`, `list`, `union`), atributos,
+//! `include` (inclusive de esquemas sem `targetNamespace`) e `import`.
+//! Restrições de identidade (`key`, `unique`) não são verificadas; `group`,
+//! `attributeGroup` e `all` são recusados na leitura.
+
+use super::arvore::{self, No};
+use crate::base::decimal::Decimal;
+use regex::Regex;
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// Namespace do XML Schema
+pub(super) const NAMESPACE_XSD: &str = "http://www.w3.org/2001/XMLSchema";
+
+/// Nome qualificado: namespace e nome local
+pub(super) type Nome = (String, String);
+
+/// Declarações globais do conjunto de esquemas
+#[derive(Debug, Default)]
+pub(super) struct Definicoes {
+    pub elementos: HashMap<Nome, Arc<DeclElemento>>,
+    pub tipos: HashMap<Nome, Arc<Tipo>>,
+}
+
+#[derive(Debug)]
+pub(super) struct DeclElemento {
+    pub nome: Nome,
+    pub tipo: RefTipo,
+}
+
+#[derive(Debug, Clone)]
+pub(super) enum RefTipo {
+    Nomeado(Nome),
+    Anonimo(Arc<Tipo>),
+}
+
+#[derive(Debug)]
+pub(super) enum Tipo {
+    Simples(TipoSimples),
+    Complexo(TipoComplexo),
+}
+
+#[derive(Debug)]
+pub(super) enum TipoSimples {
+    Restricao { base: RefTipo, facetas: Box<Facetas> },
+    Lista(RefTipo),
+    Uniao(Vec<RefTipo>),
+}
+
+#[derive(Debug)]
+pub(super) struct TipoComplexo {
+    /// Tipo base, de quem os atributos são herdados
+    pub base: Option<Nome>,
+    /// Extensão de conteúdo complexo: os filhos do tipo base vêm primeiro
+    pub herda_conteudo: bool,
+    pub conteudo: Conteudo,
+    pub atributos: Vec<DeclAtributo>,
+    pub qualquer_atributo: bool,
+    pub misto: bool,
+}
+
+#[derive(Debug)]
+pub(super) enum Conteudo {
+    Vazio,
+    Elementos(Particula),
+    /// Conteúdo simples (`simpleContent`), validado pelo tipo informado
+    Texto(RefTipo),
+}
+
+#[derive(Debug)]
+pub(super) struct DeclAtributo {
+    pub nome: String,
+    /// `None` aceita qualquer valor
+    pub tipo: Option<RefTipo>,
+    pub obrigatorio: bool,
+    pub fixo: Option<String>,
+}
+
+#[derive(Debug)]
+pub(super) struct Particula {
+    pub termo: Termo,
+    pub min: u32,
+    /// `None` para `maxOccurs="unbounded"`
+    pub max: Option<u32>,
+}
+
+#[derive(Debug)]
+pub(super) enum Termo {
+    Elemento(Arc<DeclElemento>),
+    /// Referência a um elemento global (`ref="ds:Signature"`)
+    Referencia(Nome),
+    Sequencia(Vec<Particula>),
+    Escolha(Vec<Particula>),
+    Qualquer(Curinga),
+}
+
+/// Elemento `xs:any`
+#[derive(Debug)]
+pub(super) struct Curinga {
+    pub namespaces: NamespacesCuringa,
+    pub processamento: Processamento,
+}
+
+#[derive(Debug)]
+pub(super) enum NamespacesCuringa {
+    Qualquer,
+    /// `##other`: qualquer namespace diferente do informado (e não vazio)
+    Outro(String),
+    Lista(Vec<String>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Processamento {
+    Estrito,
+    Flexivel,
+    Ignorar,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Espacos {
+    Preservar,
+    Substituir,
+    Colapsar,
+}
+
+#[derive(Debug, Default)]
+pub(super) struct Facetas {
+    pub enumeracao: Vec<String>,
+    /// Padrões da mesma derivação: basta atender a um deles
+    pub padroes: Vec<(String, Regex)>,
+    pub comprimento: Option<usize>,
+    pub min_comprimento: Option<usize>,
+    pub max_comprimento: Option<usize>,
+    pub espacos: Option<Espacos>,
+    pub total_digitos: Option<usize>,
+    pub digitos_fracao: Option<usize>,
+    pub min_inclusivo: Option<Decimal>,
+    pub max_inclusivo: Option<Decimal>,
+    pub min_exclusivo: Option<Decimal>,
+    pub max_exclusivo: Option<Decimal>,
+}
+
+impl Curinga {
+    pub fn aceita(&self, namespace: &str) -> bool {
+        match &self.namespaces {
+            NamespacesCuringa::Qualquer => true,
+            NamespacesCuringa::Outro(alvo) => !namespace.is_empty() && namespace != alvo,
+            NamespacesCuringa::Lista(lista) => lista.iter().any(|n| n == namespace),
+        }
+    }
+}
+
+/// Documento de esquema em leitura
+struct Documento {
+    namespace_alvo: String,
+    /// `elementFormDefault="qualified"`
+    qualificado: bool,
+    /// Esquema sem `targetNamespace` incluído em outro: adota o namespace de
+    /// quem o inclui, inclusive nas referências sem namespace
+    camaleao: bool,
+}
+
+impl Documento {
+    /// Resolve uma referência a tipo ou elemento declarada em `no`
+    fn resolver(&self, no: &No, qualificado: &str) -> Result<Nome, String> {
+        let (namespace, local) = no.resolver(qualificado)?;
+        if self.camaleao && namespace.is_empty() {
+            Ok((self.namespace_alvo.clone(), local))
+        } else {
+            Ok((namespace, local))
+        }
+    }
+}
+
+/// Lê o esquema principal e os que ele inclui ou importa
+pub(super) fn compilar(arquivos: &HashMap<String, String>, principal: &str) -> Result<Definicoes, String> {
+    let mut definicoes = Definicoes::default();
+    let mut pendentes = vec![(principal.to_string(), None)];
+    let mut lidos = HashSet::new();
+
+    while let Some((arquivo, incluido_em)) = pendentes.pop() {
+        if !lidos.insert((arquivo.clone(), incluido_em.clone())) {
+            continue;
+        }
+
+        let conteudo = arquivos
+            .get(&arquivo)
+            .ok_or_else(|| format!("esquema '{}' não encontrado", arquivo))?;
+        let raiz = arvore::ler(conteudo).map_err(|e| format!("{}: {}", arquivo, e))?;
+
+        compilar_documento(&raiz, incluido_em, &mut definicoes, &mut pendentes)
+            .map_err(|e| format!("{}: {}", arquivo, e))?;
+    }
+
+    verificar_referencias(&definicoes)?;
+    Ok(definicoes)
+}
+
+/// `incluido_em` é o namespace alvo do esquema que fez o `include`
+fn compilar_documento(
+    raiz: &No,
+    incluido_em: Option<String>,
+    definicoes: &mut Definicoes,
+    pendentes: &mut Vec<(String, Option<String>)>,
+) -> Result<(), String> {
+    if raiz.namespace != NAMESPACE_XSD || raiz.nome != "schema" {
+        return Err("o elemento raiz deve ser xs:schema".to_string());
+    }
+
+    let (namespace_alvo, camaleao) = match (raiz.atributo("targetNamespace"), incluido_em) {
+        (Some(namespace), _) => (namespace.to_string(), false),
+        (None, Some(namespace)) => (namespace, true),
+        (None, None) => (String::new(), false),
+    };
+    let documento = Documento {
+        namespace_alvo,
+        qualificado: raiz.atributo("elementFormDefault") == Some("qualified"),
+        camaleao,
+    };
+
+    for no in filhos_xsd(raiz) {
+        match no.nome.as_str() {
+            "include" | "import" => {
+                if let Some(local) = no.atributo("schemaLocation") {
+                    // Os arquivos do pacote são referenciados pelo nome, no mesmo diretório
+                    let nome = local.rsplit(['/', '\\']).next().unwrap_or(local);
+                    let incluido_em = (no.nome == "include").then(|| documento.namespace_alvo.clone());
+                    pendentes.push((nome.to_string(), incluido_em));
+                }
+            }
+            "element" => {
+                let elemento = compilar_elemento(no, &documento, true)?;
+                let nome = elemento.nome.clone();
+                if definicoes.elementos.insert(nome.clone(), Arc::new(elemento)).is_some() {
+                    return Err(format!("elemento '{}' declarado mais de uma vez", nome.1));
+                }
+            }
+            "complexType" | "simpleType" => {
+                let nome = (documento.namespace_alvo.clone(), obrigatorio(no, "name")?.to_string());
+                let tipo = compilar_tipo(no, &documento)?;
+                if definicoes.tipos.insert(nome.clone(), Arc::new(tipo)).is_some() {
+                    return Err(format!("tipo '{}' declarado mais de uma vez", nome.1));
+                }
+            }
+            "annotation" | "notation" => {}
+            outro => return Err(nao_suportado(outro)),
+        }
+    }
+
+    Ok(())
+}
+
+/// Filhos do namespace XSD, sem `annotation`; outros elementos são documentação
+fn filhos_xsd(no: &No) -> impl Iterator<Item = &No> {
+    no.filhos
+        .iter()
+        .filter(|f| f.namespace == NAMESPACE_XSD && f.nome != "annotation")
+}
+
+fn obrigatorio<'a>(no: &'a No, atributo: &str) -> Result<&'a str, String> {
+    no.atributo(atributo)
+        .ok_or_else(|| format!("xs:{} sem o atributo '{}'", no.nome, atributo))
+}
+
+fn nao_suportado(nome: &str) -> String {
+    format!("construção XSD não suportada: xs:{}", nome)
+}
+
+fn compilar_elemento(no: &No, documento: &Documento, global: bool) -> Result<DeclElemento, String> {
+    let qualificado = match no.atributo("form") {
+        Some(form) => form == "qualified",
+        None => global || documento.qualificado,
+    };
+    let namespace = if qualificado { documento.namespace_alvo.clone() } else { String::new() };
+
+    Ok(DeclElemento {
+        nome: (namespace, obrigatorio(no, "name")?.to_string()),
+        tipo: tipo_declarado(no, documento)?
+            .unwrap_or_else(|| RefTipo::Nomeado((NAMESPACE_XSD.to_string(), "anyType".to_string()))),
+    })
+}
+
+/// Tipo do atributo `type` ou da definição anônima filha
+fn tipo_declarado(no: &No, documento: &Documento) -> Result<Option<RefTipo>, String> {
+    if let Some(tipo) = no.atributo("type") {
+        return Ok(Some(RefTipo::Nomeado(documento.resolver(no, tipo)?)));
+    }
+
+    for filho in filhos_xsd(no) {
+        if filho.nome == "complexType" || filho.nome == "simpleType" {
+            return Ok(Some(RefTipo::Anonimo(Arc::new(compilar_tipo(filho, documento)?))));
+        }
+    }
+
+    Ok(None)
+}
+
+fn compilar_tipo(no: &No, documento: &Documento) -> Result<Tipo, String> {
+    if no.nome == "simpleType" {
+        compilar_tipo_simples(no, documento).map(Tipo::Simples)
+    } else {
+        compilar_tipo_complexo(no, documento).map(Tipo::Complexo)
+    }
+}
+
+fn compilar_tipo_simples(no: &No, documento: &Documento) -> Result<TipoSimples, String> {
+    let Some(derivacao) = filhos_xsd(no).next() else {
+        return Err("xs:simpleType sem derivação".to_string());
+    };
+    let anonimos = || -> Result<Vec<RefTipo>, String> {
+        filhos_xsd(derivacao)
+            .filter(|f| f.nome == "simpleType")
+            .map(|f| Ok(RefTipo::Anonimo(Arc::new(Tipo::Simples(compilar_tipo_simples(f, documento)?)))))
+            .collect()
+    };
+
+    match derivacao.nome.as_str() {
+        "restriction" => {
+            let base = match derivacao.atributo("base") {
+                Some(base) => RefTipo::Nomeado(documento.resolver(derivacao, base)?),
+                None => anonimos()?
+                    .pop()
+                    .ok_or_else(|| "xs:restriction sem tipo base".to_string())?,
+            };
+            Ok(TipoSimples::Restricao { base, facetas: Box::new(compilar_facetas(derivacao)?) })
+        }
+        "list" => {
+            let item = match derivacao.atributo("itemType") {
+                Some(item) => RefTipo::Nomeado(documento.resolver(derivacao, item)?),
+                None => anonimos()?
+                    .pop()
+                    .ok_or_else(|| "xs:list sem tipo dos itens".to_string())?,
+            };
+            Ok(TipoSimples::Lista(item))
+        }
+        "union" => {
+            let mut membros = Vec::new();
+            for membro in derivacao.atributo("memberTypes").unwrap_or_default().split_whitespace() {
+                membros.push(RefTipo::Nomeado(documento.resolver(derivacao, membro)?));
+            }
+            membros.extend(anonimos()?);
+            Ok(TipoSimples::Uniao(membros))
+        }
+        outro => Err(nao_suportado(outro)),
+    }
+}
+
+fn compilar_facetas(restricao: &No) -> Result<Facetas, String> {
+    let mut facetas = Facetas::default();
+
+    for faceta in filhos_xsd(restricao) {
+        let valor = || obrigatorio(faceta, "value");
+        let tamanho = || valor()?.parse::<usize>().map_err(|_| invalida(faceta));
+        let limite = || Decimal::from_str(valor()?).map_err(|_| invalida(faceta));
+
+        match faceta.nome.as_str() {
+            "simpleType" => {}
+            "enumeration" => facetas.enumeracao.push(valor()?.to_string()),
+            "pattern" => {
+                let padrao = valor()?;
+                facetas.padroes.push((padrao.to_string(), compilar_padrao(padrao)?));
+            }
+            "length" => facetas.comprimento = Some(tamanho()?),
+            "minLength" => facetas.min_comprimento = Some(tamanho()?),
+            "maxLength" => facetas.max_comprimento = Some(tamanho()?),
+            "totalDigits" => facetas.total_digitos = Some(tamanho()?),
+            "fractionDigits" => facetas.digitos_fracao = Some(tamanho()?),
+            "minInclusive" => facetas.min_inclusivo = Some(limite()?),
+            "maxInclusive" => facetas.max_inclusivo = Some(limite()?),
+            "minExclusive" => facetas.min_exclusivo = Some(limite()?),
+            "maxExclusive" => facetas.max_exclusivo = Some(limite()?),
+            "whiteSpace" => {
+                facetas.espacos = Some(match valor()? {
+                    "preserve" => Espacos::Preservar,
+                    "replace" => Espacos::Substituir,
+                    "collapse" => Espacos::Colapsar,
+                    _ => return Err(invalida(faceta)),
+                })
+            }
+            outro => return Err(nao_suportado(outro)),
+        }
+    }
+
+    Ok(facetas)
+}
+
+fn invalida(faceta: &No) -> String {
+    format!(
+        "valor inválido na faceta xs:{}: '{}'",
+        faceta.nome,
+        faceta.atributo("value").unwrap_or_default()
+    )
+}
+
+/// Converte um padrão do XML Schema para a sintaxe do `regex`
+///
+/// Os padrões do XSD valem para o valor inteiro, e `^`/`$` são literais
+/// fora das classes de caracteres. As classes abreviadas seguem o XML Schema
+/// (Parte 2, F.1.1), e não as classes Unicode do `regex`:
+///
+/// | XSD | Conteúdo |
+/// |-----|----------|
+/// | `.` | tudo, exceto `\n` e `\r` |
+/// | `\s` | espaço, `\t`, `\n` e `\r` |
+/// | `\d` | dígitos decimais Unicode (`\p{Nd}`) |
+/// | `\w` | tudo, exceto pontuação, separadores e "outros" (`\p{P}`, `\p{Z}`, `\p{C}`) |
+///
+/// As maiúsculas (`\S`, `\D`, `\W`) são os complementos. As classes são
+/// emitidas entre colchetes, o que o `regex` também aceita dentro de outra
+/// classe (`[\d-]` → `[[\p{Nd}]-]`).
+fn compilar_padrao(padrao: &str) -> Result<Regex, String> {
+    let mut convertido = String::with_capacity(padrao.len() + 8);
+    let mut classe = 0;
+    let mut caracteres = padrao.chars();
+
+    while let Some(c) = caracteres.next() {
+        match c {
+            '\\' => match caracteres.next() {
+                Some('i') => convertido.push_str("[_:A-Za-z]"),
+                Some('I') => convertido.push_str("[^_:A-Za-z]"),
+                Some('c') => convertido.push_str("[-._:A-Za-z0-9]"),
+                Some('C') => convertido.push_str("[^-._:A-Za-z0-9]"),
+                Some('s') => convertido.push_str(r"[ \t\n\r]"),
+                Some('S') => convertido.push_str(r"[^ \t\n\r]"),
+                Some('d') => convertido.push_str(r"[\p{Nd}]"),
+                Some('D') => convertido.push_str(r"[^\p{Nd}]"),
+                Some('w') => convertido.push_str(r"[^\p{P}\p{Z}\p{C}]"),
+                Some('W') => convertido.push_str(r"[\p{P}\p{Z}\p{C}]"),
+                Some(escapado) => {
+                    convertido.push('\\');
+                    convertido.push(escapado);
+                }
+                None => convertido.push_str("\\\\"),
+            },
+            '[' => {
+                classe += 1;
+                convertido.push(c);
+            }
+            ']' if classe > 0 => {
+                classe -= 1;
+                convertido.push(c);
+            }
+            '.' if classe == 0 => convertido.push_str(r"[^\n\r]"),
+            '^' | '$' if classe == 0 => {
+                convertido.push('\\');
+                convertido.push(c);
+            }
+            _ => convertido.push(c),
+        }
+    }
+
+    Regex::new(&format!(r"\A(?:{})\z", convertido))
+        .map_err(|e| format!("padrão inválido '{}': {}", padrao, e))
+}
+
+fn compilar_tipo_complexo(no: &No, documento: &Documento) -> Result<TipoComplexo, String> {
+    let mut tipo = TipoComplexo {
+        base: None,
+        herda_conteudo: false,
+        conteudo: Conteudo::Vazio,
+        atributos: Vec::new(),
+        qualquer_atributo: false,
+        misto: no.atributo("mixed") == Some("true"),
+    };
+
+    for filho in filhos_xsd(no) {
+        match filho.nome.as_str() {
+            "simpleContent" | "complexContent" => {
+                let Some(derivacao) = filhos_xsd(filho).next() else {
+                    return Err(format!("xs:{} sem derivação", filho.nome));
+                };
+                let base = documento.resolver(derivacao, obrigatorio(derivacao, "base")?)?;
+                let extensao = derivacao.nome == "extension";
+
+                if filho.nome == "simpleContent" {
+                    tipo.conteudo = Conteudo::Texto(if extensao {
+                        RefTipo::Nomeado(base.clone())
+                    } else {
+                        RefTipo::Anonimo(Arc::new(Tipo::Simples(TipoSimples::Restricao {
+                            base: RefTipo::Nomeado(base.clone()),
+                            facetas: Box::new(compilar_facetas(derivacao)?),
+                        })))
+                    });
+                } else {
+                    tipo.herda_conteudo = extensao;
+                    tipo.misto |= filho.atributo("mixed") == Some("true");
+                }
+
+                tipo.base = Some(base);
+                compilar_membros(derivacao, documento, &mut tipo)?;
+            }
+            _ => compilar_membros_item(filho, documento, &mut tipo)?,
+        }
+    }
+
+    Ok(tipo)
+}
+
+/// Modelo de conteúdo e atributos de uma derivação (`extension`/`restriction`)
+fn compilar_membros(no: &No, documento: &Documento, tipo: &mut TipoComplexo) -> Result<(), String> {
+    for filho in filhos_xsd(no) {
+        compilar_membros_item(filho, documento, tipo)?;
+    }
+    Ok(())
+}
+
+fn compilar_membros_item(no: &No, documento: &Documento, tipo: &mut TipoComplexo) -> Result<(), String> {
+    match no.nome.as_str() {
+        "sequence" | "choice" => tipo.conteudo = Conteudo::Elementos(compilar_particula(no, documento)?),
+        "attribute" => {
+            if no.atributo("use") != Some("prohibited") {
+                tipo.atributos.push(compilar_atributo(no, documento)?);
+            }
+        }
+        "anyAttribute" => tipo.qualquer_atributo = true,
+        // Facetas de `simpleContent/restriction`, já lidas
+        "enumeration" | "pattern" | "length" | "minLength" | "maxLength" | "whiteSpace"
+        | "totalDigits" | "fractionDigits" | "minInclusive" | "maxInclusive" | "minExclusive"
+        | "maxExclusive" | "simpleType" => {}
+        outro => return Err(nao_suportado(outro)),
+    }
+    Ok(())
+}
+
+fn compilar_atributo(no: &No, documento: &Documento) -> Result<DeclAtributo, String> {
+    let nome = match no.atributo("ref") {
+        Some(referencia) => documento.resolver(no, referencia)?.1,
+        None => obrigatorio(no, "name")?.to_string(),
+    };
+    let tipo = match no.atributo("ref") {
+        Some(_) => None,
+        None => tipo_declarado(no, documento)?,
+    };
+
+    Ok(DeclAtributo {
+        nome,
+        tipo,
+        obrigatorio: no.atributo("use") == Some("required"),
+        fixo: no.atributo("fixed").map(str::to_string),
+    })
+}
+
+fn compilar_particula(no: &No, documento: &Documento) -> Result<Particula, String> {
+    let min = match no.atributo("minOccurs") {
+        Some(min) => min.parse().map_err(|_| format!("minOccurs inválido: '{}'", min))?,
+        None => 1,
+    };
+    let max = match no.atributo("maxOccurs") {
+        Some("unbounded") => None,
+        Some(max) => Some(max.parse().map_err(|_| format!("maxOccurs inválido: '{}'", max))?),
+        None => Some(1),
+    };
+
+    let termo = match no.nome.as_str() {
+        "element" => match no.atributo("ref") {
+            Some(referencia) => Termo::Referencia(documento.resolver(no, referencia)?),
+            None => Termo::Elemento(Arc::new(compilar_elemento(no, documento, false)?)),
+        },
+        "sequence" | "choice" => {
+            let itens = filhos_xsd(no)
+                .map(|filho| compilar_particula(filho, documento))
+                .collect::<Result<_, _>>()?;
+
+            if no.nome == "sequence" {
+                Termo::Sequencia(itens)
+            } else {
+                Termo::Escolha(itens)
+            }
+        }
+        "any" => Termo::Qualquer(compilar_curinga(no, documento)),
+        outro => return Err(nao_suportado(outro)),
+    };
+
+    Ok(Particula { termo, min, max })
+}
+
+fn compilar_curinga(no: &No, documento: &Documento) -> Curinga {
+    let alvo = &documento.namespace_alvo;
+    let namespaces = match no.atributo("namespace").unwrap_or("##any") {
+        "##any" => NamespacesCuringa::Qualquer,
+        "##other" => NamespacesCuringa::Outro(alvo.clone()),
+        lista => NamespacesCuringa::Lista(
+            lista
+                .split_whitespace()
+                .map(|namespace| match namespace {
+                    "##local" => String::new(),
+                    "##targetNamespace" => alvo.clone(),
+                    namespace => namespace.to_string(),
+                })
+                .collect(),
+        ),
+    };
+    let processamento = match no.atributo("processContents") {
+        Some("lax") => Processamento::Flexivel,
+        Some("skip") => Processamento::Ignorar,
+        _ => Processamento::Estrito,
+    };
+
+    Curinga { namespaces, processamento }
+}
+
+/// Garante que todos os tipos e elementos referenciados foram declarados
+fn verificar_referencias(definicoes: &Definicoes) -> Result<(), String> {
+    let tipo_existe = |nome: &Nome| nome.0 == NAMESPACE_XSD || definicoes.tipos.contains_key(nome);
+    let mut erros = Vec::new();
+    let verificar_ref = |referencia: &RefTipo, erros: &mut Vec<String>| {
+        if let RefTipo::Nomeado(nome) = referencia {
+            if !tipo_existe(nome) {
+                erros.push(format!("tipo '{}' não declarado", nome.1));
+            }
+        }
+    };
+
+    let mut pendentes_tipos: Vec<&Tipo> = definicoes.tipos.values().map(Arc::as_ref).collect();
+    let mut pendentes_particulas: Vec<&Particula> = Vec::new();
+
+    for elemento in definicoes.elementos.values() {
+        verificar_ref(&elemento.tipo, &mut erros);
+        if let RefTipo::Anonimo(tipo) = &elemento.tipo {
+            pendentes_tipos.push(tipo);
+        }
+    }
+
+    loop {
+        if let Some(tipo) = pendentes_tipos.pop() {
+            let mut referencias: Vec<&RefTipo> = Vec::new();
+
+            match tipo {
+                Tipo::Simples(TipoSimples::Restricao { base, .. }) => referencias.push(base),
+                Tipo::Simples(TipoSimples::Lista(item)) => referencias.push(item),
+                Tipo::Simples(TipoSimples::Uniao(membros)) => referencias.extend(membros),
+                Tipo::Complexo(complexo) => {
+                    if let Some(base) = &complexo.base {
+                        if !tipo_existe(base) {
+                            erros.push(format!("tipo '{}' não declarado", base.1));
+                        }
+                    }
+                    match &complexo.conteudo {
+                        Conteudo::Elementos(particula) => pendentes_particulas.push(particula),
+                        Conteudo::Texto(texto) => referencias.push(texto),
+                        Conteudo::Vazio => {}
+                    }
+                    referencias.extend(complexo.atributos.iter().filter_map(|a| a.tipo.as_ref()));
+                }
+            }
+
+            for referencia in referencias {
+                verificar_ref(referencia, &mut erros);
+                if let RefTipo::Anonimo(tipo) = referencia {
+                    pendentes_tipos.push(tipo);
+                }
+            }
+        } else if let Some(particula) = pendentes_particulas.pop() {
+            match &particula.termo {
+                Termo::Elemento(elemento) => {
+                    verificar_ref(&elemento.tipo, &mut erros);
+                    if let RefTipo::Anonimo(tipo) = &elemento.tipo {
+                        pendentes_tipos.push(tipo);
+                    }
+                }
+                Termo::Referencia(nome) if !definicoes.elementos.contains_key(nome) => {
+                    erros.push(format!("elemento '{}' não declarado", nome.1));
+                }
+                Termo::Sequencia(itens) | Termo::Escolha(itens) => pendentes_particulas.extend(itens),
+                _ => {}
+            }
+        } else {
+            break;
+        }
+    }
+
+    erros.sort();
+    erros.dedup();
+    if erros.is_empty() {
+        Ok(())
+    } else {
+        Err(erros.join("; "))
+    }
+}
@@ -0,0 +1,112 @@
+//! Exportação e informações de compra (Grupos `<exporta>` e `<compra>`)
+//!
+//! ## Exportação
+//!
+//! Informado nas operações de saída para o exterior (CFOP 7.xxx), indica
+//! o local de embarque ou de transposição de fronteira:
+//!
+//! ```text
+//! <exporta>
+//!   <UFSaidaPais>   <!-- UF de embarque ou de saída do país -->
+//!   <xLocExporta>   <!-- Local de embarque ou de transposição de fronteira -->
+//!   <xLocDespacho>  <!-- Recinto alfandegado do despacho (opcional) -->
+//! </exporta>
+//! ```
+//!
+//! ## Compra
+//!
+//! Usado principalmente nas vendas a órgãos públicos (nota de empenho):
+//!
+//! ```text
+//! <compra>
+//!   <xNEmp>   <!-- Nota de empenho (opcional) -->
+//!   <xPed>    <!-- Pedido (opcional) -->
+//!   <xCont>   <!-- Contrato (opcional) -->
+//! </compra>
+//! ```
+
+use super::Error;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// Informações de exportação (tag `<exporta>`)
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename = "exporta")]
+pub struct Exportacao {
+    /// Sigla da UF de embarque ou de transposição de fronteira (tag `<UFSaidaPais>`)
+    #[serde(rename = "$unflatten=UFSaidaPais")]
+    pub uf_saida: String,
+
+    /// Local de embarque ou de transposição de fronteira (tag `<xLocExporta>`)
+    #[serde(rename = "$unflatten=xLocExporta")]
+    pub local_exportacao: String,
+
+    /// Recinto alfandegado do despacho (tag `<xLocDespacho>`)
+    #[serde(rename = "$unflatten=xLocDespacho")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub local_despacho: Option<String>,
+}
+
+/// Informações de compra (tag `<compra>`)
+#[derive(Debug, PartialEq, Eq, Clone, Default, Serialize, Deserialize)]
+#[serde(rename = "compra")]
+pub struct Compra {
+    /// Nota de empenho, nas compras públicas (tag `<xNEmp>`)
+    #[serde(rename = "$unflatten=xNEmp")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub nota_empenho: Option<String>,
+
+    /// Pedido (tag `<xPed>`)
+    #[serde(rename = "$unflatten=xPed")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub pedido: Option<String>,
+
+    /// Contrato (tag `<xCont>`)
+    #[serde(rename = "$unflatten=xCont")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub contrato: Option<String>,
+}
+
+impl Exportacao {
+    /// Cria o grupo de exportação com UF e local de embarque
+    pub fn new(uf_saida: &str, local_exportacao: &str) -> Self {
+        Self {
+            uf_saida: uf_saida.to_uppercase(),
+            local_exportacao: local_exportacao.to_string(),
+            local_despacho: None,
+        }
+    }
+}
+
+impl FromStr for Exportacao {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        quick_xml::de::from_str(s).map_err(|e| e.into())
+    }
+}
+
+impl fmt::Display for Exportacao {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&quick_xml::se::to_string(self).expect("Falha ao serializar o grupo de exportação"))
+    }
+}
+
+impl FromStr for Compra {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        quick_xml::de::from_str(s).map_err(|e| e.into())
+    }
+}
+
+impl fmt::Display for Compra {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&quick_xml::se::to_string(self).expect("Falha ao serializar o grupo de compra"))
+    }
+}
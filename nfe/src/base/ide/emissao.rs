@@ -6,7 +6,8 @@ use serde_repr::{Deserialize_repr, Serialize_repr};
 /// Dados referentes a emissão da nota
 #[derive(Debug, PartialEq, Clone)]
 pub struct Emissao {
-    pub horario: DateTime<Utc>,
+    /// Data e hora de emissão, no fuso horário informado (tag `<dhEmi>`)
+    pub horario: DateTime<FixedOffset>,
     pub tipo: TipoEmissao,
     pub finalidade: FinalidadeEmissao,
     pub processo: TipoProcessoEmissao,
@@ -12,6 +12,7 @@
 //! - Tipo de operação (entrada/saída)
 //! - Destino da operação (interna, interestadual, exterior)
 //! - Tipo de ambiente (produção/homologação)
+//! - Documentos fiscais referenciados (devolução, complemento, ajuste)
 //!
 //! ## Referência SEFAZ
 //!
@@ -23,13 +24,16 @@ use super::Error;
 use chrono::prelude::*;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use serde_repr::{Deserialize_repr, Serialize_repr};
+use std::fmt;
 use std::str::FromStr;
 
 mod emissao;
 mod operacao;
+mod referencia;
 
 pub use emissao::*;
 pub use operacao::*;
+pub use referencia::*;
 
 /// Identificação da NF-e (Grupo IDE - tag `<ide>`)
 ///
@@ -49,6 +53,7 @@ pub use operacao::*;
 /// | codigo_municipio | cMunFG | Código IBGE do município de ocorrência | Sim |
 /// | formato_danfe | tpImp | Formato de impressão do DANFE | Sim |
 /// | ambiente | tpAmb | 1=Produção, 2=Homologação | Sim |
+/// | referencias | NFref | Documentos fiscais referenciados | Não |
 #[derive(Debug, PartialEq, Clone)]
 pub struct Identificacao {
     /// Código IBGE da UF do emitente (2 dígitos)
@@ -87,6 +92,10 @@ pub struct Identificacao {
     /// Tipo de ambiente: Produção ou Homologação
     /// Em homologação, a NF-e não tem validade fiscal
     pub ambiente: TipoAmbiente,
+
+    /// Documentos fiscais referenciados (tags `<NFref>`, até 999)
+    /// Obrigatórios na NF-e complementar e na de devolução
+    pub referencias: Vec<DocumentoReferenciado>,
 }
 
 /// Modelo do documento fiscal eletrônico (tag `<mod>`)
@@ -188,9 +197,9 @@ impl FromStr for Identificacao {
     }
 }
 
-impl ToString for Identificacao {
-    fn to_string(&self) -> String {
-        quick_xml::se::to_string(self).expect("Falha ao serializar a identificação")
+impl fmt::Display for Identificacao {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&quick_xml::se::to_string(self).expect("Falha ao serializar a identificação"))
     }
 }
 
@@ -231,6 +240,7 @@ impl<'de> Deserialize<'de> for Identificacao {
                 processo: ide.e_processo,
                 versao_processo: ide.e_versao_processo,
             },
+            referencias: ide.referencias,
         })
     }
 }
@@ -242,24 +252,25 @@ impl Serialize for Identificacao {
     {
         let ide = IdeContainer {
             codigo_uf: self.codigo_uf,
-            numero: self.numero,
-            serie: self.serie,
-            modelo: self.modelo,
-            codigo_municipio: self.codigo_municipio,
-            formato_danfe: self.formato_danfe,
-            ambiente: self.ambiente,
             c_codigo: self.chave.codigo.clone(),
-            c_digito_verificador: self.chave.digito_verificador,
+            o_natureza: self.operacao.natureza.clone(),
+            modelo: self.modelo,
+            serie: self.serie,
+            numero: self.numero,
+            e_horario: self.emissao.horario,
             o_horario: self.operacao.horario,
             o_tipo: self.operacao.tipo,
             o_destino: self.operacao.destino,
-            o_natureza: self.operacao.natureza.clone(),
+            codigo_municipio: self.codigo_municipio,
+            referencias: self.referencias.clone(),
+            formato_danfe: self.formato_danfe,
+            e_tipo: self.emissao.tipo,
+            c_digito_verificador: self.chave.digito_verificador,
+            ambiente: self.ambiente,
+            e_finalidade: self.emissao.finalidade,
             o_consumidor: self.operacao.consumidor,
             o_presenca: self.operacao.presenca,
             o_intermediador: self.operacao.intermediador,
-            e_horario: self.emissao.horario,
-            e_tipo: self.emissao.tipo,
-            e_finalidade: self.emissao.finalidade,
             e_processo: self.emissao.processo,
             e_versao_processo: self.emissao.versao_processo.clone(),
         };
@@ -268,52 +279,50 @@ impl Serialize for Identificacao {
     }
 }
 
+/// Espelho do grupo `<ide>` com os campos na ordem do leiaute
+///
+/// A serialização segue a ordem dos campos, que precisa ser a do
+/// `xs:sequence` de `TNFe/infNFe/ide` do schema.
 #[derive(Deserialize, Serialize)]
 #[serde(rename = "ide")]
 struct IdeContainer {
     #[serde(rename = "$unflatten=cUF")]
     pub codigo_uf: u8,
-    #[serde(rename = "$unflatten=nNF")]
-    pub numero: u32,
-    #[serde(rename = "$unflatten=serie")]
-    pub serie: u16,
-    #[serde(rename = "$unflatten=mod")]
-    pub modelo: ModeloDocumentoFiscal,
-    #[serde(rename = "$unflatten=cMunFG")]
-    pub codigo_municipio: u32,
-    #[serde(rename = "$unflatten=tpImp")]
-    pub formato_danfe: FormatoImpressaoDanfe,
-    #[serde(rename = "$unflatten=tpAmb")]
-    pub ambiente: TipoAmbiente,
-
     #[serde(rename = "$unflatten=cNF")]
     pub c_codigo: String,
-    #[serde(rename = "$unflatten=cDV")]
-    pub c_digito_verificador: u8,
-
+    #[serde(rename = "$unflatten=natOp")]
+    pub o_natureza: String,
+    #[serde(rename = "$unflatten=mod")]
+    pub modelo: ModeloDocumentoFiscal,
+    #[serde(rename = "$unflatten=serie")]
+    pub serie: u16,
+    #[serde(rename = "$unflatten=nNF")]
+    pub numero: u32,
     #[serde(rename = "$unflatten=dhEmi")]
     #[serde(serialize_with = "serialize_horario")]
-    pub e_horario: DateTime<Utc>,
-    #[serde(rename = "$unflatten=tpEmis")]
-    pub e_tipo: TipoEmissao,
-    #[serde(rename = "$unflatten=finNFe")]
-    pub e_finalidade: FinalidadeEmissao,
-    #[serde(rename = "$unflatten=procEmi")]
-    pub e_processo: TipoProcessoEmissao,
-    #[serde(rename = "$unflatten=verProc")]
-    pub e_versao_processo: String,
-
+    pub e_horario: DateTime<FixedOffset>,
     #[serde(rename = "$unflatten=dhSaiEnt")]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(serialize_with = "serialize_horario_op")]
     #[serde(default)]
-    pub o_horario: Option<DateTime<Utc>>,
+    pub o_horario: Option<DateTime<FixedOffset>>,
     #[serde(rename = "$unflatten=tpNF")]
     pub o_tipo: TipoOperacao,
     #[serde(rename = "$unflatten=idDest")]
     pub o_destino: DestinoOperacao,
-    #[serde(rename = "$unflatten=natOp")]
-    pub o_natureza: String,
+    #[serde(rename = "$unflatten=cMunFG")]
+    pub codigo_municipio: u32,
+
+    #[serde(rename = "$unflatten=tpImp")]
+    pub formato_danfe: FormatoImpressaoDanfe,
+    #[serde(rename = "$unflatten=tpEmis")]
+    pub e_tipo: TipoEmissao,
+    #[serde(rename = "$unflatten=cDV")]
+    pub c_digito_verificador: u8,
+    #[serde(rename = "$unflatten=tpAmb")]
+    pub ambiente: TipoAmbiente,
+    #[serde(rename = "$unflatten=finNFe")]
+    pub e_finalidade: FinalidadeEmissao,
     #[serde(rename = "$unflatten=indFinal")]
     pub o_consumidor: TipoConsumidor,
     #[serde(rename = "$unflatten=indPres")]
@@ -322,16 +331,26 @@ struct IdeContainer {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
     pub o_intermediador: Option<TipoIntermediador>,
+    #[serde(rename = "$unflatten=procEmi")]
+    pub e_processo: TipoProcessoEmissao,
+    #[serde(rename = "$unflatten=verProc")]
+    pub e_versao_processo: String,
+
+    #[serde(rename = "NFref")]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
+    pub referencias: Vec<DocumentoReferenciado>,
 }
 
-fn serialize_horario<S>(date: &DateTime<Utc>, serializer: S) -> Result<S::Ok, S::Error>
+/// Formato `AAAA-MM-DDThh:mm:ssTZD` do leiaute (sem fração de segundos)
+fn serialize_horario<S>(date: &DateTime<FixedOffset>, serializer: S) -> Result<S::Ok, S::Error>
 where
     S: Serializer,
 {
-    serializer.serialize_str(&date.to_rfc3339())
+    serializer.serialize_str(&date.format("%Y-%m-%dT%H:%M:%S%:z").to_string())
 }
 
-fn serialize_horario_op<S>(date: &Option<DateTime<Utc>>, serializer: S) -> Result<S::Ok, S::Error>
+fn serialize_horario_op<S>(date: &Option<DateTime<FixedOffset>>, serializer: S) -> Result<S::Ok, S::Error>
 where
     S: Serializer,
 {
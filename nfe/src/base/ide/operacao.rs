@@ -6,7 +6,8 @@ use serde_repr::{Deserialize_repr, Serialize_repr};
 /// Dados referentes a operação da nota
 #[derive(Debug, PartialEq, Clone)]
 pub struct Operacao {
-    pub horario: Option<DateTime<Utc>>,
+    /// Data e hora de saída ou entrada, no fuso horário informado (tag `<dhSaiEnt>`)
+    pub horario: Option<DateTime<FixedOffset>>,
     pub tipo: TipoOperacao,
     pub destino: DestinoOperacao,
     pub natureza: String,
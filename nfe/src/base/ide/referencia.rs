@@ -0,0 +1,216 @@
+//! Documentos fiscais referenciados (Grupo `<NFref>`)
+//!
+//! NF-e de devolução, complementar ou de ajuste devem referenciar os
+//! documentos originais. Cada `<NFref>` contém exatamente uma das formas:
+//!
+//! | Tag | Documento |
+//! |-----|-----------|
+//! | `<refNFe>` | NF-e ou NFC-e (chave de acesso) |
+//! | `<refNF>` | Nota Fiscal modelo 1/1A ou 02 |
+//! | `<refNFP>` | Nota Fiscal de produtor rural (modelo 04 ou 01) |
+//! | `<refCTe>` | CT-e (chave de acesso) |
+//! | `<refECF>` | Cupom fiscal emitido por ECF |
+//!
+//! Uma NF-e pode referenciar até 999 documentos.
+
+use crate::base::documento::Documento;
+use serde::de;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Documento fiscal referenciado (tag `<NFref>`)
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum DocumentoReferenciado {
+    /// Chave de acesso da NF-e/NFC-e referenciada (tag `<refNFe>`)
+    Nfe(String),
+    /// Nota Fiscal modelo 1/1A ou 02 (tag `<refNF>`)
+    Nf(NotaFiscalReferenciada),
+    /// Nota Fiscal de produtor rural (tag `<refNFP>`)
+    NfProdutor(NotaProdutorReferenciada),
+    /// Chave de acesso do CT-e referenciado (tag `<refCTe>`)
+    Cte(String),
+    /// Cupom fiscal emitido por ECF (tag `<refECF>`)
+    Ecf(CupomFiscalReferenciado),
+}
+
+/// Nota Fiscal modelo 1/1A ou 02 referenciada (tag `<refNF>`)
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub struct NotaFiscalReferenciada {
+    /// Código IBGE da UF do emitente (tag `<cUF>`)
+    #[serde(rename = "$unflatten=cUF")]
+    pub codigo_uf: u8,
+    /// Ano e mês da emissão no formato AAMM (tag `<AAMM>`)
+    #[serde(rename = "$unflatten=AAMM")]
+    pub ano_mes: String,
+    /// CNPJ do emitente (tag `<CNPJ>`)
+    #[serde(rename = "$unflatten=CNPJ")]
+    pub cnpj: String,
+    /// Modelo do documento: `01` (modelo 1/1A) ou `02` (modelo 02) (tag `<mod>`)
+    #[serde(rename = "$unflatten=mod")]
+    pub modelo: String,
+    /// Série do documento, 0 se inexistente (tag `<serie>`)
+    #[serde(rename = "$unflatten=serie")]
+    pub serie: u16,
+    /// Número do documento (tag `<nNF>`)
+    #[serde(rename = "$unflatten=nNF")]
+    pub numero: u32,
+}
+
+/// Nota Fiscal de produtor rural referenciada (tag `<refNFP>`)
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct NotaProdutorReferenciada {
+    /// Código IBGE da UF do emitente
+    pub codigo_uf: u8,
+    /// Ano e mês da emissão no formato AAMM
+    pub ano_mes: String,
+    /// CNPJ ou CPF do emitente (tags `<CNPJ>` / `<CPF>`)
+    pub documento: Documento,
+    /// Inscrição Estadual do emitente ou `ISENTO`
+    pub ie: String,
+    /// Modelo do documento: `04` (NF de produtor) ou `01` (NF modelo 1/1A)
+    pub modelo: String,
+    /// Série do documento, 0 se inexistente
+    pub serie: u16,
+    /// Número do documento
+    pub numero: u32,
+}
+
+/// Cupom fiscal referenciado (tag `<refECF>`)
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub struct CupomFiscalReferenciado {
+    /// Modelo: `2B` (máquina registradora), `2C` (PDV) ou `2D` (ECF) (tag `<mod>`)
+    #[serde(rename = "$unflatten=mod")]
+    pub modelo: String,
+    /// Número de ordem sequencial do ECF (tag `<nECF>`)
+    #[serde(rename = "$unflatten=nECF")]
+    pub numero_ecf: String,
+    /// Número do Contador de Ordem de Operação (tag `<nCOO>`)
+    #[serde(rename = "$unflatten=nCOO")]
+    pub numero_coo: String,
+}
+
+impl DocumentoReferenciado {
+    /// Chave de acesso do documento referenciado, se for NF-e ou CT-e
+    pub fn chave_acesso(&self) -> Option<&str> {
+        match self {
+            Self::Nfe(chave) | Self::Cte(chave) => Some(chave),
+            _ => None,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for DocumentoReferenciado {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let nf_ref = NfRefContainer::deserialize(deserializer)?;
+
+        if let Some(chave) = nf_ref.nfe {
+            Ok(Self::Nfe(chave))
+        } else if let Some(nf) = nf_ref.nf {
+            Ok(Self::Nf(nf))
+        } else if let Some(nfp) = nf_ref.nf_produtor {
+            let documento = match Documento::from_tags(nfp.cnpj, nfp.cpf, None) {
+                Some(documento) => documento,
+                None => return Err(de::Error::custom("CNPJ ou CPF do produtor não informado")),
+            };
+
+            Ok(Self::NfProdutor(NotaProdutorReferenciada {
+                codigo_uf: nfp.codigo_uf,
+                ano_mes: nfp.ano_mes,
+                documento,
+                ie: nfp.ie,
+                modelo: nfp.modelo,
+                serie: nfp.serie,
+                numero: nfp.numero,
+            }))
+        } else if let Some(chave) = nf_ref.cte {
+            Ok(Self::Cte(chave))
+        } else if let Some(ecf) = nf_ref.ecf {
+            Ok(Self::Ecf(ecf))
+        } else {
+            Err(de::Error::custom("Grupo NFref sem documento referenciado"))
+        }
+    }
+}
+
+impl Serialize for DocumentoReferenciado {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut nf_ref = NfRefContainer::default();
+
+        match self {
+            Self::Nfe(chave) => nf_ref.nfe = Some(chave.clone()),
+            Self::Nf(nf) => nf_ref.nf = Some(nf.clone()),
+            Self::NfProdutor(nfp) => {
+                let (cnpj, cpf, _) = nfp.documento.to_tags();
+
+                nf_ref.nf_produtor = Some(RefNfpContainer {
+                    codigo_uf: nfp.codigo_uf,
+                    ano_mes: nfp.ano_mes.clone(),
+                    cnpj,
+                    cpf,
+                    ie: nfp.ie.clone(),
+                    modelo: nfp.modelo.clone(),
+                    serie: nfp.serie,
+                    numero: nfp.numero,
+                });
+            }
+            Self::Cte(chave) => nf_ref.cte = Some(chave.clone()),
+            Self::Ecf(ecf) => nf_ref.ecf = Some(ecf.clone()),
+        }
+
+        nf_ref.serialize(serializer)
+    }
+}
+
+#[derive(Default, Deserialize, Serialize)]
+#[serde(rename = "NFref")]
+struct NfRefContainer {
+    #[serde(rename = "$unflatten=refNFe")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub nfe: Option<String>,
+    #[serde(rename = "refNF")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub nf: Option<NotaFiscalReferenciada>,
+    #[serde(rename = "refNFP")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub nf_produtor: Option<RefNfpContainer>,
+    #[serde(rename = "$unflatten=refCTe")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub cte: Option<String>,
+    #[serde(rename = "refECF")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub ecf: Option<CupomFiscalReferenciado>,
+}
+
+#[derive(Deserialize, Serialize)]
+struct RefNfpContainer {
+    #[serde(rename = "$unflatten=cUF")]
+    pub codigo_uf: u8,
+    #[serde(rename = "$unflatten=AAMM")]
+    pub ano_mes: String,
+    #[serde(rename = "$unflatten=CNPJ")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub cnpj: Option<String>,
+    #[serde(rename = "$unflatten=CPF")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub cpf: Option<String>,
+    #[serde(rename = "$unflatten=IE")]
+    pub ie: String,
+    #[serde(rename = "$unflatten=mod")]
+    pub modelo: String,
+    #[serde(rename = "$unflatten=serie")]
+    pub serie: u16,
+    #[serde(rename = "$unflatten=nNF")]
+    pub numero: u32,
+}
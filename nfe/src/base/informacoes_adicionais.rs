@@ -0,0 +1,151 @@
+//! Informações adicionais da NF-e (Grupo `<infAdic>`)
+//!
+//! ## Estrutura do Grupo
+//!
+//! ```text
+//! <infAdic>
+//!   <infAdFisco>  <!-- Informações de interesse do Fisco (até 2000 caracteres) -->
+//!   <infCpl>      <!-- Informações complementares do contribuinte (até 5000 caracteres) -->
+//!   <obsCont>     <!-- Observações do contribuinte, campo/valor (até 10) -->
+//!   <obsFisco>    <!-- Observações do Fisco, campo/valor (até 10) -->
+//!   <procRef>     <!-- Processos judiciais ou administrativos referenciados (até 100) -->
+//! </infAdic>
+//! ```
+//!
+//! Mensagens exigidas pela legislação, como a do crédito de ICMS do Simples
+//! Nacional (LC 123/2006, art. 23), são impressas em `<infCpl>`.
+
+use super::decimal::{formatar, Decimal};
+use super::Error;
+use serde::{Deserialize, Serialize};
+use serde_repr::{Deserialize_repr, Serialize_repr};
+use std::fmt;
+use std::str::FromStr;
+
+/// Informações adicionais (tag `<infAdic>`)
+#[derive(Debug, PartialEq, Eq, Clone, Default, Serialize, Deserialize)]
+#[serde(rename = "infAdic")]
+pub struct InformacoesAdicionais {
+    /// Informações adicionais de interesse do Fisco (tag `<infAdFisco>`)
+    #[serde(rename = "$unflatten=infAdFisco")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub fisco: Option<String>,
+
+    /// Informações complementares de interesse do contribuinte (tag `<infCpl>`)
+    #[serde(rename = "$unflatten=infCpl")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub complementar: Option<String>,
+
+    /// Observações de uso livre do contribuinte (tags `<obsCont>`)
+    #[serde(rename = "obsCont")]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
+    pub observacoes_contribuinte: Vec<Observacao>,
+
+    /// Observações de uso livre do Fisco (tags `<obsFisco>`)
+    #[serde(rename = "obsFisco")]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
+    pub observacoes_fisco: Vec<Observacao>,
+
+    /// Processos referenciados (tags `<procRef>`)
+    #[serde(rename = "procRef")]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
+    pub processos: Vec<ProcessoReferenciado>,
+}
+
+/// Observação no formato campo/valor (tags `<obsCont>` e `<obsFisco>`)
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub struct Observacao {
+    /// Identificação do campo (atributo `xCampo`)
+    #[serde(rename = "xCampo")]
+    pub campo: String,
+
+    /// Conteúdo do campo (tag `<xTexto>`)
+    #[serde(rename = "$unflatten=xTexto")]
+    pub texto: String,
+}
+
+/// Processo judicial ou administrativo referenciado (tag `<procRef>`)
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub struct ProcessoReferenciado {
+    /// Identificador do processo ou ato concessório (tag `<nProc>`)
+    #[serde(rename = "$unflatten=nProc")]
+    pub numero: String,
+
+    /// Origem do processo (tag `<indProc>`)
+    #[serde(rename = "$unflatten=indProc")]
+    pub origem: OrigemProcesso,
+
+    /// Tipo do ato concessório, quando a origem é a SEFAZ (tag `<tpAto>`)
+    ///
+    /// `08` - Termo de Acordo, `10` - Regime Especial, `12` - Autorização específica,
+    /// `14` - Ajuste SINIEF, `15` - Convênio ICMS
+    #[serde(rename = "$unflatten=tpAto")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub tipo_ato: Option<String>,
+}
+
+/// Origem do processo referenciado (tag `<indProc>`)
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Deserialize_repr, Serialize_repr)]
+#[repr(u8)]
+pub enum OrigemProcesso {
+    /// 0 - SEFAZ
+    Sefaz = 0,
+    /// 1 - Justiça Federal
+    JusticaFederal = 1,
+    /// 2 - Justiça Estadual
+    JusticaEstadual = 2,
+    /// 3 - Secex/RFB
+    SecexRfb = 3,
+    /// 4 - CONFAZ
+    Confaz = 4,
+    /// 9 - Outros
+    Outros = 9,
+}
+
+impl InformacoesAdicionais {
+    /// Acrescenta um texto às informações complementares, separado por `"; "`
+    pub fn acrescentar_complementar(&mut self, texto: &str) {
+        self.complementar = Some(match self.complementar.take() {
+            Some(atual) if !atual.is_empty() => format!("{}; {}", atual, texto),
+            _ => texto.to_string(),
+        });
+    }
+
+    /// Indica se nenhum campo do grupo foi preenchido
+    pub fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+}
+
+/// Mensagem de permissão de crédito de ICMS do Simples Nacional
+///
+/// Exigida quando o optante vende a contribuinte do ICMS com direito a
+/// crédito (CSOSN 101/201/900), conforme LC 123/2006, art. 23, §§ 1º e 2º.
+pub fn mensagem_credito_simples_nacional(valor: Decimal, aliquota: Decimal) -> String {
+    format!(
+        "PERMITE O APROVEITAMENTO DO CRÉDITO DE ICMS NO VALOR DE R$ {}; CORRESPONDENTE À \
+         ALÍQUOTA DE {}%, NOS TERMOS DO ART. 23 DA LEI COMPLEMENTAR Nº 123, DE 2006",
+        formatar(valor, 2, 2).replace('.', ","),
+        formatar(aliquota, 2, 2).replace('.', ",")
+    )
+}
+
+impl FromStr for InformacoesAdicionais {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        quick_xml::de::from_str(s).map_err(|e| e.into())
+    }
+}
+
+impl fmt::Display for InformacoesAdicionais {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&quick_xml::se::to_string(self).expect("Falha ao serializar as informações adicionais"))
+    }
+}
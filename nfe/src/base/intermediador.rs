@@ -0,0 +1,99 @@
+//! Intermediador da transação (Grupo `<infIntermed>`)
+//!
+//! Vendas realizadas em plataformas de terceiros (marketplaces) informam
+//! `indIntermed = 1` na identificação da NF-e e, nesse caso, devem
+//! identificar o intermediador:
+//!
+//! ```text
+//! <infIntermed>
+//!   <CNPJ>          <!-- CNPJ do intermediador -->
+//!   <idCadIntTran>  <!-- Identificador do vendedor no cadastro do intermediador -->
+//! </infIntermed>
+//! ```
+//!
+//! O grupo só pode ser informado quando `indIntermed = 1` (NT 2020.006).
+
+use super::ide::TipoIntermediador;
+use super::Error;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// Intermediador da transação (tag `<infIntermed>`)
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename = "infIntermed")]
+pub struct Intermediador {
+    /// CNPJ do intermediador da transação (tag `<CNPJ>`)
+    #[serde(rename = "$unflatten=CNPJ")]
+    pub cnpj: String,
+
+    /// Nome do usuário ou identificação do perfil do vendedor no site do
+    /// intermediador (tag `<idCadIntTran>`)
+    #[serde(rename = "$unflatten=idCadIntTran")]
+    pub identificador: String,
+}
+
+impl Intermediador {
+    /// Cria o grupo a partir do CNPJ (com ou sem máscara) e do identificador
+    pub fn new(cnpj: &str, identificador: &str) -> Self {
+        Self {
+            cnpj: cnpj.replace(&['.', '/', '-'][..], ""),
+            identificador: identificador.to_string(),
+        }
+    }
+}
+
+/// Valida a coerência entre o indicador `indIntermed` e o grupo `<infIntermed>`
+///
+/// - `indIntermed = 1` exige o grupo do intermediador
+/// - Sem intermediador (ou indicador ausente) o grupo não pode ser informado
+/// - CNPJ com 14 dígitos e identificador com 2 a 60 caracteres
+pub fn validar_intermediador(
+    indicador: Option<TipoIntermediador>,
+    intermediador: Option<&Intermediador>,
+) -> Result<(), String> {
+    match (indicador, intermediador) {
+        (Some(TipoIntermediador::EmSiteDeTerceiros), None) => Err(
+            "Operação com intermediador (indIntermed = 1) exige o grupo infIntermed".to_string(),
+        ),
+        (Some(TipoIntermediador::EmSiteDeTerceiros), Some(intermediador)) => {
+            if intermediador.cnpj.len() != 14
+                || !intermediador.cnpj.chars().all(|c| c.is_ascii_digit())
+            {
+                return Err(format!(
+                    "CNPJ do intermediador deve ter 14 dígitos: {}",
+                    intermediador.cnpj
+                ));
+            }
+
+            let tamanho = intermediador.identificador.chars().count();
+            if !(2..=60).contains(&tamanho) {
+                return Err(
+                    "Identificador do vendedor no intermediador deve ter de 2 a 60 caracteres"
+                        .to_string(),
+                );
+            }
+
+            Ok(())
+        }
+        (_, Some(_)) => Err(
+            "Grupo infIntermed informado em operação sem intermediador (indIntermed diferente de 1)"
+                .to_string(),
+        ),
+        (_, None) => Ok(()),
+    }
+}
+
+impl FromStr for Intermediador {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        quick_xml::de::from_str(s).map_err(|e| e.into())
+    }
+}
+
+impl fmt::Display for Intermediador {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&quick_xml::se::to_string(self).expect("Falha ao serializar o intermediador"))
+    }
+}
@@ -0,0 +1,40 @@
+//! Imposto devolvido (Grupo `<impostoDevol>`)
+//!
+//! Informado na NF-e de devolução (finNFe = 4) para indicar o percentual
+//! da mercadoria devolvida e o IPI destacado na nota de origem que está
+//! sendo devolvido.
+//!
+//! ```text
+//! <impostoDevol>
+//!   <pDevol>         <!-- Percentual da mercadoria devolvida -->
+//!   <IPI>
+//!     <vIPIDevol>    <!-- Valor do IPI devolvido -->
+//!   </IPI>
+//! </impostoDevol>
+//! ```
+
+use crate::base::decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Imposto devolvido do item (tag `<impostoDevol>`)
+#[derive(Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
+#[serde(rename = "impostoDevol")]
+pub struct ImpostoDevolvido {
+    /// Percentual da mercadoria devolvida (tag `<pDevol>`)
+    #[serde(rename = "$unflatten=pDevol")]
+    #[serde(with = "crate::base::decimal::percentual")]
+    pub percentual_devolucao: Decimal,
+
+    /// IPI devolvido (tag `<IPI>`)
+    #[serde(rename = "IPI")]
+    pub ipi: IpiDevolvido,
+}
+
+/// IPI devolvido (tag `<IPI>` dentro de `<impostoDevol>`)
+#[derive(Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
+pub struct IpiDevolvido {
+    /// Valor do IPI devolvido (tag `<vIPIDevol>`)
+    #[serde(rename = "$unflatten=vIPIDevol")]
+    #[serde(with = "crate::base::decimal::valor")]
+    pub valor: Decimal,
+}
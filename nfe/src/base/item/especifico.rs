@@ -0,0 +1,357 @@
+//! Grupos específicos de produto e rastreabilidade
+//!
+//! Alguns produtos exigem informações próprias no grupo `<prod>`. O layout
+//! admite apenas uma das formas abaixo por item:
+//!
+//! | Tag | Produto |
+//! |-----|---------|
+//! | `<veicProd>` | Veículo novo |
+//! | `<med>` | Medicamento ou matéria-prima farmacêutica |
+//! | `<arma>` | Armamento (até 500 ocorrências) |
+//! | `<comb>` | Combustível |
+//! | `<nRECOPI>` | Papel imune (número do RECOPI) |
+//!
+//! Independentemente da escolha, o item pode informar até 500 grupos
+//! `<rastro>` com os lotes de produtos sujeitos à rastreabilidade
+//! (medicamentos, bebidas, defensivos agrícolas etc.).
+
+use crate::base::decimal::Decimal;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+/// Grupo específico do produto (escolha exclusiva)
+#[derive(Debug, PartialEq, Clone)]
+#[allow(clippy::large_enum_variant)]
+pub enum ProdutoEspecifico {
+    /// Veículo novo (tag `<veicProd>`)
+    Veiculo(VeiculoNovo),
+    /// Medicamento (tag `<med>`)
+    Medicamento(Medicamento),
+    /// Armamentos (tags `<arma>`)
+    Armamentos(Vec<Arma>),
+    /// Combustível (tag `<comb>`)
+    Combustivel(Combustivel),
+    /// Número do RECOPI (tag `<nRECOPI>`)
+    Recopi(String),
+}
+
+/// Rastreabilidade do produto (tag `<rastro>`)
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct Rastreabilidade {
+    /// Número do lote (tag `<nLote>`)
+    #[serde(rename = "$unflatten=nLote")]
+    pub numero_lote: String,
+
+    /// Quantidade de produto no lote (tag `<qLote>`)
+    #[serde(rename = "$unflatten=qLote")]
+    #[serde(with = "crate::base::decimal::peso")]
+    pub quantidade_lote: Decimal,
+
+    /// Data de fabricação ou produção (tag `<dFab>`)
+    #[serde(rename = "$unflatten=dFab")]
+    pub data_fabricacao: NaiveDate,
+
+    /// Data de validade (tag `<dVal>`)
+    #[serde(rename = "$unflatten=dVal")]
+    pub data_validade: NaiveDate,
+
+    /// Código de agregação (tag `<cAgreg>`)
+    #[serde(rename = "$unflatten=cAgreg")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub codigo_agregacao: Option<String>,
+}
+
+/// Medicamento e matéria-prima farmacêutica (tag `<med>`)
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct Medicamento {
+    /// Código de registro do produto na ANVISA ou `ISENTO` (tag `<cProdANVISA>`)
+    #[serde(rename = "$unflatten=cProdANVISA")]
+    pub codigo_anvisa: String,
+
+    /// Motivo da isenção do registro na ANVISA (tag `<xMotivoIsencao>`)
+    #[serde(rename = "$unflatten=xMotivoIsencao")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub motivo_isencao: Option<String>,
+
+    /// Preço máximo ao consumidor (tag `<vPMC>`)
+    #[serde(rename = "$unflatten=vPMC")]
+    #[serde(with = "crate::base::decimal::valor")]
+    pub preco_maximo_consumidor: Decimal,
+}
+
+/// Veículo novo (tag `<veicProd>`)
+///
+/// Os códigos seguem as tabelas do RENAVAM/DENATRAN e são mantidos como
+/// texto para preservar zeros à esquerda.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct VeiculoNovo {
+    /// Tipo da operação: 0 - Outros, 1 - Venda concessionária,
+    /// 2 - Faturamento direto ao consumidor final, 3 - Venda direta (tag `<tpOp>`)
+    #[serde(rename = "$unflatten=tpOp")]
+    pub tipo_operacao: u8,
+
+    /// Chassi do veículo (tag `<chassi>`)
+    #[serde(rename = "$unflatten=chassi")]
+    pub chassi: String,
+
+    /// Código da cor da montadora (tag `<cCor>`)
+    #[serde(rename = "$unflatten=cCor")]
+    pub codigo_cor: String,
+
+    /// Descrição da cor (tag `<xCor>`)
+    #[serde(rename = "$unflatten=xCor")]
+    pub descricao_cor: String,
+
+    /// Potência máxima do motor em cavalo-vapor (tag `<pot>`)
+    #[serde(rename = "$unflatten=pot")]
+    pub potencia: String,
+
+    /// Cilindradas (tag `<cilin>`)
+    #[serde(rename = "$unflatten=cilin")]
+    pub cilindradas: String,
+
+    /// Peso líquido em toneladas (tag `<pesoL>`)
+    #[serde(rename = "$unflatten=pesoL")]
+    pub peso_liquido: String,
+
+    /// Peso bruto em toneladas (tag `<pesoB>`)
+    #[serde(rename = "$unflatten=pesoB")]
+    pub peso_bruto: String,
+
+    /// Número de série (tag `<nSerie>`)
+    #[serde(rename = "$unflatten=nSerie")]
+    pub numero_serie: String,
+
+    /// Tipo de combustível, tabela RENAVAM (tag `<tpComb>`)
+    #[serde(rename = "$unflatten=tpComb")]
+    pub tipo_combustivel: String,
+
+    /// Número do motor (tag `<nMotor>`)
+    #[serde(rename = "$unflatten=nMotor")]
+    pub numero_motor: String,
+
+    /// Capacidade máxima de tração em toneladas (tag `<CMT>`)
+    #[serde(rename = "$unflatten=CMT")]
+    pub capacidade_tracao: String,
+
+    /// Distância entre eixos (tag `<dist>`)
+    #[serde(rename = "$unflatten=dist")]
+    pub distancia_eixos: String,
+
+    /// Ano do modelo de fabricação (tag `<anoMod>`)
+    #[serde(rename = "$unflatten=anoMod")]
+    pub ano_modelo: u16,
+
+    /// Ano de fabricação (tag `<anoFab>`)
+    #[serde(rename = "$unflatten=anoFab")]
+    pub ano_fabricacao: u16,
+
+    /// Tipo de pintura (tag `<tpPint>`)
+    #[serde(rename = "$unflatten=tpPint")]
+    pub tipo_pintura: String,
+
+    /// Tipo de veículo, tabela RENAVAM (tag `<tpVeic>`)
+    #[serde(rename = "$unflatten=tpVeic")]
+    pub tipo_veiculo: String,
+
+    /// Espécie de veículo, tabela RENAVAM (tag `<espVeic>`)
+    #[serde(rename = "$unflatten=espVeic")]
+    pub especie_veiculo: String,
+
+    /// Condição do VIN: `R` - Remarcado, `N` - Normal (tag `<VIN>`)
+    #[serde(rename = "$unflatten=VIN")]
+    pub vin: String,
+
+    /// Condição do veículo: 1 - Acabado, 2 - Inacabado, 3 - Semiacabado (tag `<condVeic>`)
+    #[serde(rename = "$unflatten=condVeic")]
+    pub condicao: u8,
+
+    /// Código marca/modelo, tabela RENAVAM (tag `<cMod>`)
+    #[serde(rename = "$unflatten=cMod")]
+    pub codigo_modelo: String,
+
+    /// Código da cor, tabela DENATRAN (tag `<cCorDENATRAN>`)
+    #[serde(rename = "$unflatten=cCorDENATRAN")]
+    pub codigo_cor_denatran: String,
+
+    /// Capacidade máxima de lotação, incluindo o motorista (tag `<lota>`)
+    #[serde(rename = "$unflatten=lota")]
+    pub lotacao: u16,
+
+    /// Restrição: 0 - Não há, 1 - Alienação fiduciária, 2 - Arrendamento mercantil,
+    /// 3 - Reserva de domínio, 4 - Penhor, 9 - Outras (tag `<tpRest>`)
+    #[serde(rename = "$unflatten=tpRest")]
+    pub restricao: u8,
+}
+
+/// Armamento (tag `<arma>`)
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub struct Arma {
+    /// Indicador do tipo de arma de fogo (tag `<tpArma>`)
+    #[serde(rename = "$unflatten=tpArma")]
+    pub tipo: TipoArma,
+
+    /// Número de série da arma (tag `<nSerie>`)
+    #[serde(rename = "$unflatten=nSerie")]
+    pub numero_serie: String,
+
+    /// Número de série do cano (tag `<nCano>`)
+    #[serde(rename = "$unflatten=nCano")]
+    pub numero_cano: String,
+
+    /// Descrição completa da arma: calibre, marca, capacidade etc. (tag `<descr>`)
+    #[serde(rename = "$unflatten=descr")]
+    pub descricao: String,
+}
+
+/// Tipo de arma de fogo (tag `<tpArma>`)
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Deserialize_repr, Serialize_repr)]
+#[repr(u8)]
+pub enum TipoArma {
+    /// 0 - Uso permitido
+    UsoPermitido = 0,
+    /// 1 - Uso restrito
+    UsoRestrito = 1,
+}
+
+/// Combustível (tag `<comb>`)
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct Combustivel {
+    /// Código do produto na ANP (tag `<cProdANP>`)
+    #[serde(rename = "$unflatten=cProdANP")]
+    pub codigo_anp: String,
+
+    /// Descrição do produto conforme a ANP (tag `<descANP>`)
+    #[serde(rename = "$unflatten=descANP")]
+    pub descricao_anp: String,
+
+    /// Percentual do GLP derivado do petróleo no GLP (tag `<pGLP>`)
+    #[serde(rename = "$unflatten=pGLP")]
+    #[serde(with = "crate::base::decimal::percentual_opcional")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub percentual_glp: Option<Decimal>,
+
+    /// Percentual de gás natural nacional (tag `<pGNn>`)
+    #[serde(rename = "$unflatten=pGNn")]
+    #[serde(with = "crate::base::decimal::percentual_opcional")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub percentual_gas_nacional: Option<Decimal>,
+
+    /// Percentual de gás natural importado (tag `<pGNi>`)
+    #[serde(rename = "$unflatten=pGNi")]
+    #[serde(with = "crate::base::decimal::percentual_opcional")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub percentual_gas_importado: Option<Decimal>,
+
+    /// Valor de partida, apenas para GLP (tag `<vPart>`)
+    #[serde(rename = "$unflatten=vPart")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub valor_partida: Option<Decimal>,
+
+    /// Código de autorização do CODIF (tag `<CODIF>`)
+    #[serde(rename = "$unflatten=CODIF")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub codif: Option<String>,
+
+    /// Quantidade faturada à temperatura ambiente (tag `<qTemp>`)
+    #[serde(rename = "$unflatten=qTemp")]
+    #[serde(with = "crate::base::decimal::quantidade_opcional")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub quantidade_temperatura: Option<Decimal>,
+
+    /// Sigla da UF de consumo (tag `<UFCons>`)
+    #[serde(rename = "$unflatten=UFCons")]
+    pub uf_consumo: String,
+
+    /// CIDE-Combustíveis (tag `<CIDE>`)
+    #[serde(rename = "CIDE")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub cide: Option<Cide>,
+
+    /// Encerrante da bomba no abastecimento (tag `<encerrante>`)
+    #[serde(rename = "encerrante")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub encerrante: Option<Encerrante>,
+}
+
+/// CIDE-Combustíveis (tag `<CIDE>`)
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct Cide {
+    /// Base de cálculo da CIDE em quantidade (tag `<qBCProd>`)
+    #[serde(rename = "$unflatten=qBCProd")]
+    #[serde(with = "crate::base::decimal::quantidade")]
+    pub quantidade_bc: Decimal,
+
+    /// Valor da alíquota da CIDE por unidade (tag `<vAliqProd>`)
+    #[serde(rename = "$unflatten=vAliqProd")]
+    #[serde(with = "crate::base::decimal::quantidade")]
+    pub aliquota: Decimal,
+
+    /// Valor da CIDE (tag `<vCIDE>`)
+    #[serde(rename = "$unflatten=vCIDE")]
+    #[serde(with = "crate::base::decimal::valor")]
+    pub valor: Decimal,
+}
+
+/// Encerrante da bomba de combustível (tag `<encerrante>`)
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct Encerrante {
+    /// Número do bico (tag `<nBico>`)
+    #[serde(rename = "$unflatten=nBico")]
+    pub bico: u16,
+
+    /// Número da bomba (tag `<nBomba>`)
+    #[serde(rename = "$unflatten=nBomba")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub bomba: Option<u16>,
+
+    /// Número do tanque (tag `<nTanque>`)
+    #[serde(rename = "$unflatten=nTanque")]
+    pub tanque: u16,
+
+    /// Valor do encerrante no início do abastecimento (tag `<vEncIni>`)
+    #[serde(rename = "$unflatten=vEncIni")]
+    #[serde(with = "crate::base::decimal::peso")]
+    pub valor_inicial: Decimal,
+
+    /// Valor do encerrante no final do abastecimento (tag `<vEncFin>`)
+    #[serde(rename = "$unflatten=vEncFin")]
+    #[serde(with = "crate::base::decimal::peso")]
+    pub valor_final: Decimal,
+}
+
+impl ProdutoEspecifico {
+    /// Monta a escolha a partir das tags lidas, na ordem do layout
+    pub(crate) fn from_tags(
+        veiculo: Option<VeiculoNovo>,
+        medicamento: Option<Medicamento>,
+        armas: Vec<Arma>,
+        combustivel: Option<Combustivel>,
+        recopi: Option<String>,
+    ) -> Option<Self> {
+        if let Some(veiculo) = veiculo {
+            Some(Self::Veiculo(veiculo))
+        } else if let Some(med) = medicamento {
+            Some(Self::Medicamento(med))
+        } else if !armas.is_empty() {
+            Some(Self::Armamentos(armas))
+        } else if let Some(comb) = combustivel {
+            Some(Self::Combustivel(comb))
+        } else {
+            recopi.map(Self::Recopi)
+        }
+    }
+}
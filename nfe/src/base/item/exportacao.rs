@@ -0,0 +1,53 @@
+//! Detalhe da exportação do item (Grupo `<detExport>`)
+//!
+//! Cada item pode ter até 500 grupos, informando o ato concessório de
+//! drawback e, na exportação indireta, o registro de exportação e a NF-e
+//! recebida com fim específico de exportação.
+//!
+//! ```text
+//! <detExport>
+//!   <nDraw>        <!-- Ato concessório de drawback (opcional) -->
+//!   <exportInd>    <!-- Exportação indireta (opcional) -->
+//!     <nRE>        <!-- Registro de exportação -->
+//!     <chNFe>      <!-- Chave da NF-e recebida para exportação -->
+//!     <qExport>    <!-- Quantidade efetivamente exportada -->
+//!   </exportInd>
+//! </detExport>
+//! ```
+
+use crate::base::decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Detalhe da exportação do item (tag `<detExport>`)
+#[derive(Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
+#[serde(rename = "detExport")]
+pub struct DetalheExportacao {
+    /// Número do ato concessório de drawback (tag `<nDraw>`)
+    #[serde(rename = "$unflatten=nDraw")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub drawback: Option<String>,
+
+    /// Exportação indireta (tag `<exportInd>`)
+    #[serde(rename = "exportInd")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub exportacao_indireta: Option<ExportacaoIndireta>,
+}
+
+/// Exportação indireta (tag `<exportInd>`)
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct ExportacaoIndireta {
+    /// Número do registro de exportação (tag `<nRE>`)
+    #[serde(rename = "$unflatten=nRE")]
+    pub registro_exportacao: String,
+
+    /// Chave de acesso da NF-e recebida para exportação (tag `<chNFe>`)
+    #[serde(rename = "$unflatten=chNFe")]
+    pub chave_acesso: String,
+
+    /// Quantidade do item efetivamente exportada (tag `<qExport>`)
+    #[serde(rename = "$unflatten=qExport")]
+    #[serde(with = "crate::base::decimal::quantidade")]
+    pub quantidade: Decimal,
+}
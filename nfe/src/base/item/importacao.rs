@@ -0,0 +1,231 @@
+//! Declaração de importação do item (Grupos `<DI>` e `<adi>`)
+//!
+//! Na entrada de mercadoria importada, cada item informa as Declarações de
+//! Importação (até 100) e, em cada uma, as adições (até 999).
+//!
+//! ```text
+//! <DI>
+//!   <nDI>           <!-- Número da DI, DSI, DA ou DUIMP -->
+//!   <dDI>           <!-- Data de registro -->
+//!   <xLocDesemb>    <!-- Local do desembaraço aduaneiro -->
+//!   <UFDesemb>      <!-- UF do desembaraço -->
+//!   <dDesemb>       <!-- Data do desembaraço -->
+//!   <tpViaTransp>   <!-- Via de transporte internacional -->
+//!   <vAFRMM>        <!-- AFRMM (via marítima) -->
+//!   <tpIntermedio>  <!-- Forma de importação quanto à intermediação -->
+//!   <CNPJ>/<CPF>    <!-- Adquirente ou encomendante (conta e ordem/encomenda) -->
+//!   <UFTerceiro>    <!-- UF do adquirente ou encomendante -->
+//!   <cExportador>   <!-- Código do exportador -->
+//!   <adi>           <!-- Adições (1 a 999) -->
+//!     <nAdicao>     <!-- Número da adição -->
+//!     <nSeqAdic>    <!-- Sequencial do item na adição -->
+//!     <cFabricante> <!-- Código do fabricante estrangeiro -->
+//!     <vDescDI>     <!-- Desconto do item na DI -->
+//!     <nDraw>       <!-- Ato concessório de drawback -->
+//!   </adi>
+//! </DI>
+//! ```
+
+use crate::base::decimal::Decimal;
+use crate::base::documento::Documento;
+use chrono::NaiveDate;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+/// Declaração de Importação (tag `<DI>`)
+#[derive(Debug, PartialEq, Clone)]
+pub struct DeclaracaoImportacao {
+    /// Número da DI, DSI, DA ou DUIMP
+    pub numero: String,
+    /// Data de registro do documento
+    pub data_registro: NaiveDate,
+    /// Local do desembaraço aduaneiro
+    pub local_desembaraco: String,
+    /// Sigla da UF do desembaraço aduaneiro
+    pub uf_desembaraco: String,
+    /// Data do desembaraço aduaneiro
+    pub data_desembaraco: NaiveDate,
+    /// Via de transporte internacional
+    pub via_transporte: ViaTransporte,
+    /// Adicional ao Frete para Renovação da Marinha Mercante (via marítima)
+    pub valor_afrmm: Option<Decimal>,
+    /// Forma de importação quanto à intermediação
+    pub intermediacao: FormaImportacao,
+    /// Adquirente ou encomendante, na importação por conta e ordem ou por encomenda
+    pub adquirente: Option<Documento>,
+    /// Sigla da UF do adquirente ou encomendante
+    pub uf_terceiro: Option<String>,
+    /// Código do exportador
+    pub codigo_exportador: String,
+    /// Adições da declaração
+    pub adicoes: Vec<Adicao>,
+}
+
+/// Adição da Declaração de Importação (tag `<adi>`)
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct Adicao {
+    /// Número da adição (tag `<nAdicao>`)
+    #[serde(rename = "$unflatten=nAdicao")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub numero: Option<u16>,
+
+    /// Número sequencial do item dentro da adição (tag `<nSeqAdic>`)
+    #[serde(rename = "$unflatten=nSeqAdic")]
+    pub sequencial: u16,
+
+    /// Código do fabricante estrangeiro (tag `<cFabricante>`)
+    #[serde(rename = "$unflatten=cFabricante")]
+    pub codigo_fabricante: String,
+
+    /// Valor do desconto do item da DI (tag `<vDescDI>`)
+    #[serde(rename = "$unflatten=vDescDI")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub valor_desconto: Option<Decimal>,
+
+    /// Número do ato concessório de drawback (tag `<nDraw>`)
+    #[serde(rename = "$unflatten=nDraw")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub drawback: Option<String>,
+}
+
+/// Via de transporte internacional informada na DI (tag `<tpViaTransp>`)
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Deserialize_repr, Serialize_repr)]
+#[repr(u8)]
+pub enum ViaTransporte {
+    /// 1 - Marítima (sujeita ao AFRMM)
+    Maritima = 1,
+    /// 2 - Fluvial
+    Fluvial = 2,
+    /// 3 - Lacustre
+    Lacustre = 3,
+    /// 4 - Aérea
+    Aerea = 4,
+    /// 5 - Postal
+    Postal = 5,
+    /// 6 - Ferroviária
+    Ferroviaria = 6,
+    /// 7 - Rodoviária
+    Rodoviaria = 7,
+    /// 8 - Conduto / Rede de transmissão
+    Conduto = 8,
+    /// 9 - Meios próprios
+    MeiosProprios = 9,
+    /// 10 - Entrada / Saída ficta
+    Ficta = 10,
+    /// 11 - Courier
+    Courier = 11,
+    /// 12 - Em mãos
+    EmMaos = 12,
+    /// 13 - Por reboque
+    Reboque = 13,
+}
+
+/// Forma de importação quanto à intermediação (tag `<tpIntermedio>`)
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Deserialize_repr, Serialize_repr)]
+#[repr(u8)]
+pub enum FormaImportacao {
+    /// 1 - Importação por conta própria
+    ContaPropria = 1,
+    /// 2 - Importação por conta e ordem
+    ContaEOrdem = 2,
+    /// 3 - Importação por encomenda
+    Encomenda = 3,
+}
+
+impl<'de> Deserialize<'de> for DeclaracaoImportacao {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let di = DiContainer::deserialize(deserializer)?;
+
+        Ok(Self {
+            numero: di.numero,
+            data_registro: di.data_registro,
+            local_desembaraco: di.local_desembaraco,
+            uf_desembaraco: di.uf_desembaraco,
+            data_desembaraco: di.data_desembaraco,
+            via_transporte: di.via_transporte,
+            valor_afrmm: di.valor_afrmm,
+            intermediacao: di.intermediacao,
+            adquirente: Documento::from_tags(di.cnpj, di.cpf, None),
+            uf_terceiro: di.uf_terceiro,
+            codigo_exportador: di.codigo_exportador,
+            adicoes: di.adicoes,
+        })
+    }
+}
+
+impl Serialize for DeclaracaoImportacao {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (cnpj, cpf, _) = match &self.adquirente {
+            Some(documento) => documento.to_tags(),
+            None => (None, None, None),
+        };
+
+        let di = DiContainer {
+            numero: self.numero.clone(),
+            data_registro: self.data_registro,
+            local_desembaraco: self.local_desembaraco.clone(),
+            uf_desembaraco: self.uf_desembaraco.clone(),
+            data_desembaraco: self.data_desembaraco,
+            via_transporte: self.via_transporte,
+            valor_afrmm: self.valor_afrmm,
+            intermediacao: self.intermediacao,
+            cnpj,
+            cpf,
+            uf_terceiro: self.uf_terceiro.clone(),
+            codigo_exportador: self.codigo_exportador.clone(),
+            adicoes: self.adicoes.clone(),
+        };
+
+        di.serialize(serializer)
+    }
+}
+
+#[derive(Deserialize, Serialize)]
+#[serde(rename = "DI")]
+struct DiContainer {
+    #[serde(rename = "$unflatten=nDI")]
+    pub numero: String,
+    #[serde(rename = "$unflatten=dDI")]
+    pub data_registro: NaiveDate,
+    #[serde(rename = "$unflatten=xLocDesemb")]
+    pub local_desembaraco: String,
+    #[serde(rename = "$unflatten=UFDesemb")]
+    pub uf_desembaraco: String,
+    #[serde(rename = "$unflatten=dDesemb")]
+    pub data_desembaraco: NaiveDate,
+    #[serde(rename = "$unflatten=tpViaTransp")]
+    pub via_transporte: ViaTransporte,
+    #[serde(rename = "$unflatten=vAFRMM")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub valor_afrmm: Option<Decimal>,
+    #[serde(rename = "$unflatten=tpIntermedio")]
+    pub intermediacao: FormaImportacao,
+    #[serde(rename = "$unflatten=CNPJ")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub cnpj: Option<String>,
+    #[serde(rename = "$unflatten=CPF")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub cpf: Option<String>,
+    #[serde(rename = "$unflatten=UFTerceiro")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub uf_terceiro: Option<String>,
+    #[serde(rename = "$unflatten=cExportador")]
+    pub codigo_exportador: String,
+    #[serde(rename = "adi")]
+    pub adicoes: Vec<Adicao>,
+}
@@ -2,13 +2,20 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::base::decimal::Decimal;
+
 /// Container para os grupos de COFINS
-#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Default)]
 pub struct CofinsContainer {
     /// COFINS Alíquota - CST 01 e 02
     #[serde(rename = "COFINSAliq")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cofins_aliq: Option<CofinsAliq>,
+    /// COFINS Quantidade - CST 03
+    #[serde(rename = "COFINSQtde")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub cofins_qtde: Option<CofinsQtde>,
     /// COFINS Não Tributado - CST 04, 05, 06, 07, 08 e 09
     #[serde(rename = "COFINSNT")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -19,6 +26,16 @@ pub struct CofinsContainer {
     pub cofins_outr: Option<CofinsOutr>,
 }
 
+impl CofinsContainer {
+    /// Valor da COFINS do grupo informado
+    pub fn valor(&self) -> Decimal {
+        self.cofins_aliq.as_ref().map(|g| g.valor)
+            .or(self.cofins_qtde.as_ref().map(|g| g.valor))
+            .or(self.cofins_outr.as_ref().and_then(|g| g.valor))
+            .unwrap_or_default()
+    }
+}
+
 /// COFINS Alíquota - Tributação por alíquota
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct CofinsAliq {
@@ -27,13 +44,36 @@ pub struct CofinsAliq {
     pub cst: String,
     /// Valor da Base de Cálculo da COFINS
     #[serde(rename = "$unflatten=vBC")]
-    pub valor_bc: f32,
+    #[serde(with = "crate::base::decimal::valor")]
+    pub valor_bc: Decimal,
     /// Alíquota da COFINS (em percentual)
     #[serde(rename = "$unflatten=pCOFINS")]
-    pub aliquota: f32,
+    #[serde(with = "crate::base::decimal::percentual")]
+    pub aliquota: Decimal,
     /// Valor da COFINS
     #[serde(rename = "$unflatten=vCOFINS")]
-    pub valor: f32,
+    #[serde(with = "crate::base::decimal::valor")]
+    pub valor: Decimal,
+}
+
+/// COFINS Quantidade - Tributação por unidade de produto
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct CofinsQtde {
+    /// Código de Situação Tributária da COFINS
+    #[serde(rename = "$unflatten=CST")]
+    pub cst: String,
+    /// Quantidade vendida
+    #[serde(rename = "$unflatten=qBCProd")]
+    #[serde(with = "crate::base::decimal::quantidade")]
+    pub quantidade_bc: Decimal,
+    /// Alíquota da COFINS (em reais por unidade)
+    #[serde(rename = "$unflatten=vAliqProd")]
+    #[serde(with = "crate::base::decimal::quantidade")]
+    pub aliquota_valor: Decimal,
+    /// Valor da COFINS
+    #[serde(rename = "$unflatten=vCOFINS")]
+    #[serde(with = "crate::base::decimal::valor")]
+    pub valor: Decimal,
 }
 
 /// COFINS Não Tributado
@@ -52,14 +92,32 @@ pub struct CofinsOutr {
     pub cst: String,
     /// Valor da Base de Cálculo da COFINS
     #[serde(rename = "$unflatten=vBC")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub valor_bc: Option<f32>,
+    #[serde(default)]
+    pub valor_bc: Option<Decimal>,
     /// Alíquota da COFINS (em percentual)
     #[serde(rename = "$unflatten=pCOFINS")]
+    #[serde(with = "crate::base::decimal::percentual_opcional")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub aliquota: Option<Decimal>,
+    /// Quantidade vendida (tributação por unidade)
+    #[serde(rename = "$unflatten=qBCProd")]
+    #[serde(with = "crate::base::decimal::quantidade_opcional")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub quantidade_bc: Option<Decimal>,
+    /// Alíquota da COFINS em reais por unidade
+    #[serde(rename = "$unflatten=vAliqProd")]
+    #[serde(with = "crate::base::decimal::quantidade_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub aliquota: Option<f32>,
+    #[serde(default)]
+    pub aliquota_valor: Option<Decimal>,
     /// Valor da COFINS
     #[serde(rename = "$unflatten=vCOFINS")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub valor: Option<f32>,
+    #[serde(default)]
+    pub valor: Option<Decimal>,
 }
\ No newline at end of file
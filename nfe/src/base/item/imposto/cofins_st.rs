@@ -10,34 +10,45 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::base::decimal::Decimal;
+
 /// COFINS Substituição Tributária (tag `<COFINSST>`)
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct CofinsSt {
     /// Valor da Base de Cálculo da COFINS ST (tag `<vBC>`)
     #[serde(rename = "$unflatten=vBC")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub valor_bc: Option<f32>,
+    #[serde(default)]
+    pub valor_bc: Option<Decimal>,
 
     /// Alíquota da COFINS ST em percentual (tag `<pCOFINS>`)
     #[serde(rename = "$unflatten=pCOFINS")]
+    #[serde(with = "crate::base::decimal::percentual_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub aliquota: Option<f32>,
+    #[serde(default)]
+    pub aliquota: Option<Decimal>,
 
     /// Quantidade vendida (tag `<qBCProd>`)
     /// Para cálculo por quantidade
     #[serde(rename = "$unflatten=qBCProd")]
+    #[serde(with = "crate::base::decimal::quantidade_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub quantidade_bc_produto: Option<f32>,
+    #[serde(default)]
+    pub quantidade_bc_produto: Option<Decimal>,
 
     /// Alíquota da COFINS ST em reais (tag `<vAliqProd>`)
     /// Para cálculo por quantidade
     #[serde(rename = "$unflatten=vAliqProd")]
+    #[serde(with = "crate::base::decimal::quantidade_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub aliquota_produto: Option<f32>,
+    #[serde(default)]
+    pub aliquota_produto: Option<Decimal>,
 
     /// Valor da COFINS ST (tag `<vCOFINS>`)
     #[serde(rename = "$unflatten=vCOFINS")]
-    pub valor: f32,
+    #[serde(with = "crate::base::decimal::valor")]
+    pub valor: Decimal,
 }
 
 impl Default for CofinsSt {
@@ -47,7 +58,7 @@ impl Default for CofinsSt {
             aliquota: None,
             quantidade_bc_produto: None,
             aliquota_produto: None,
-            valor: 0.0,
+            valor: Decimal::ZERO,
         }
     }
 }
@@ -0,0 +1,267 @@
+//! IBS e CBS - Reforma Tributária do Consumo (NT 2025.002-RTC)
+//!
+//! Este módulo implementa o grupo `<IBSCBS>` do item, que informa o Imposto
+//! sobre Bens e Serviços (IBS, partilhado entre UF e município) e a
+//! Contribuição sobre Bens e Serviços (CBS, federal), instituídos pela
+//! EC 132/2023 e regulamentados pela LC 214/2025.
+//!
+//! ## Estrutura do Grupo
+//!
+//! ```text
+//! <IBSCBS>
+//!   <CST>            <!-- CST do IBS/CBS (3 dígitos) -->
+//!   <cClassTrib>     <!-- Código de classificação tributária (6 dígitos) -->
+//!   <gIBSCBS>
+//!     <vBC>          <!-- Base de cálculo comum ao IBS e à CBS -->
+//!     <gIBSUF>       <!-- IBS estadual: pIBSUF, gDif, gDevTrib, gRed, vIBSUF -->
+//!     <gIBSMun>      <!-- IBS municipal: pIBSMun, gDif, gDevTrib, gRed, vIBSMun -->
+//!     <vIBS>         <!-- IBS total (UF + município) -->
+//!     <gCBS>         <!-- CBS: pCBS, gDif, gDevTrib, gRed, vCBS -->
+//!     <gIBSCredPres> <!-- Crédito presumido do IBS -->
+//!     <gCBSCredPres> <!-- Crédito presumido da CBS -->
+//!   </gIBSCBS>
+//! </IBSCBS>
+//! ```
+//!
+//! ## Cálculo
+//!
+//! ```text
+//! pAliqEfet = alíquota × (1 - pRedAliq / 100)
+//! valor     = vBC × pAliqEfet / 100
+//! vDif      = valor × pDif / 100          (parcela diferida)
+//! vIBSUF    = valor - vDif
+//! ```
+//!
+//! As alíquotas do período de transição (2026 a 2033) são obtidas em
+//! [`crate::base::calculo::aliquotas_transicao`].
+
+use serde::{Deserialize, Serialize};
+
+use crate::base::decimal::Decimal;
+
+/// IBS e CBS do item (tag `<IBSCBS>`)
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct IbsCbs {
+    /// Código de Situação Tributária do IBS/CBS (tag `<CST>`)
+    #[serde(rename = "$unflatten=CST")]
+    pub cst: String,
+
+    /// Código de classificação tributária do IBS/CBS (tag `<cClassTrib>`)
+    #[serde(rename = "$unflatten=cClassTrib")]
+    pub classificacao_tributaria: String,
+
+    /// Valores do IBS e da CBS (tag `<gIBSCBS>`)
+    ///
+    /// Ausente nas situações sem incidência (ex.: imunidade, não incidência).
+    #[serde(rename = "gIBSCBS")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub valores: Option<GrupoIbsCbs>,
+}
+
+/// Valores do IBS e da CBS (tag `<gIBSCBS>`)
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct GrupoIbsCbs {
+    /// Base de cálculo do IBS e da CBS (tag `<vBC>`)
+    #[serde(rename = "$unflatten=vBC")]
+    #[serde(with = "crate::base::decimal::valor")]
+    pub valor_bc: Decimal,
+
+    /// IBS de competência da UF (tag `<gIBSUF>`)
+    #[serde(rename = "gIBSUF")]
+    pub ibs_uf: IbsUf,
+
+    /// IBS de competência do município (tag `<gIBSMun>`)
+    #[serde(rename = "gIBSMun")]
+    pub ibs_municipal: IbsMunicipal,
+
+    /// Valor total do IBS: UF + município (tag `<vIBS>`)
+    #[serde(rename = "$unflatten=vIBS")]
+    #[serde(with = "crate::base::decimal::valor")]
+    pub valor_ibs: Decimal,
+
+    /// CBS (tag `<gCBS>`)
+    #[serde(rename = "gCBS")]
+    pub cbs: Cbs,
+
+    /// Crédito presumido do IBS (tag `<gIBSCredPres>`)
+    #[serde(rename = "gIBSCredPres")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub credito_presumido_ibs: Option<CreditoPresumido>,
+
+    /// Crédito presumido da CBS (tag `<gCBSCredPres>`)
+    #[serde(rename = "gCBSCredPres")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub credito_presumido_cbs: Option<CreditoPresumido>,
+}
+
+/// IBS de competência da UF (tag `<gIBSUF>`)
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct IbsUf {
+    /// Alíquota do IBS estadual (tag `<pIBSUF>`)
+    #[serde(rename = "$unflatten=pIBSUF")]
+    #[serde(with = "crate::base::decimal::percentual")]
+    pub aliquota: Decimal,
+
+    /// Diferimento (tag `<gDif>`)
+    #[serde(rename = "gDif")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub diferimento: Option<Diferimento>,
+
+    /// Devolução de tributo (tag `<gDevTrib>`)
+    #[serde(rename = "gDevTrib")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub devolucao: Option<DevolucaoTributo>,
+
+    /// Redução de alíquota (tag `<gRed>`)
+    #[serde(rename = "gRed")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub reducao: Option<ReducaoAliquota>,
+
+    /// Valor do IBS estadual (tag `<vIBSUF>`)
+    #[serde(rename = "$unflatten=vIBSUF")]
+    #[serde(with = "crate::base::decimal::valor")]
+    pub valor: Decimal,
+}
+
+/// IBS de competência do município (tag `<gIBSMun>`)
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct IbsMunicipal {
+    /// Alíquota do IBS municipal (tag `<pIBSMun>`)
+    #[serde(rename = "$unflatten=pIBSMun")]
+    #[serde(with = "crate::base::decimal::percentual")]
+    pub aliquota: Decimal,
+
+    /// Diferimento (tag `<gDif>`)
+    #[serde(rename = "gDif")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub diferimento: Option<Diferimento>,
+
+    /// Devolução de tributo (tag `<gDevTrib>`)
+    #[serde(rename = "gDevTrib")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub devolucao: Option<DevolucaoTributo>,
+
+    /// Redução de alíquota (tag `<gRed>`)
+    #[serde(rename = "gRed")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub reducao: Option<ReducaoAliquota>,
+
+    /// Valor do IBS municipal (tag `<vIBSMun>`)
+    #[serde(rename = "$unflatten=vIBSMun")]
+    #[serde(with = "crate::base::decimal::valor")]
+    pub valor: Decimal,
+}
+
+/// CBS (tag `<gCBS>`)
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct Cbs {
+    /// Alíquota da CBS (tag `<pCBS>`)
+    #[serde(rename = "$unflatten=pCBS")]
+    #[serde(with = "crate::base::decimal::percentual")]
+    pub aliquota: Decimal,
+
+    /// Diferimento (tag `<gDif>`)
+    #[serde(rename = "gDif")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub diferimento: Option<Diferimento>,
+
+    /// Devolução de tributo (tag `<gDevTrib>`)
+    #[serde(rename = "gDevTrib")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub devolucao: Option<DevolucaoTributo>,
+
+    /// Redução de alíquota (tag `<gRed>`)
+    #[serde(rename = "gRed")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub reducao: Option<ReducaoAliquota>,
+
+    /// Valor da CBS (tag `<vCBS>`)
+    #[serde(rename = "$unflatten=vCBS")]
+    #[serde(with = "crate::base::decimal::valor")]
+    pub valor: Decimal,
+}
+
+/// Diferimento do IBS/CBS (tag `<gDif>`)
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct Diferimento {
+    /// Percentual do diferimento (tag `<pDif>`)
+    #[serde(rename = "$unflatten=pDif")]
+    #[serde(with = "crate::base::decimal::percentual")]
+    pub percentual: Decimal,
+
+    /// Valor diferido (tag `<vDif>`)
+    #[serde(rename = "$unflatten=vDif")]
+    #[serde(with = "crate::base::decimal::valor")]
+    pub valor: Decimal,
+}
+
+/// Devolução de tributo ao consumidor (tag `<gDevTrib>`)
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct DevolucaoTributo {
+    /// Valor do tributo devolvido (tag `<vDevTrib>`)
+    #[serde(rename = "$unflatten=vDevTrib")]
+    #[serde(with = "crate::base::decimal::valor")]
+    pub valor: Decimal,
+}
+
+/// Redução de alíquota (tag `<gRed>`)
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct ReducaoAliquota {
+    /// Percentual de redução da alíquota (tag `<pRedAliq>`)
+    #[serde(rename = "$unflatten=pRedAliq")]
+    #[serde(with = "crate::base::decimal::percentual")]
+    pub percentual: Decimal,
+
+    /// Alíquota efetiva após a redução (tag `<pAliqEfet>`)
+    #[serde(rename = "$unflatten=pAliqEfet")]
+    #[serde(with = "crate::base::decimal::percentual")]
+    pub aliquota_efetiva: Decimal,
+}
+
+/// Crédito presumido do IBS ou da CBS (tags `<gIBSCredPres>` e `<gCBSCredPres>`)
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct CreditoPresumido {
+    /// Código de classificação do crédito presumido (tag `<cCredPres>`)
+    #[serde(rename = "$unflatten=cCredPres")]
+    pub codigo: String,
+
+    /// Percentual do crédito presumido (tag `<pCredPres>`)
+    #[serde(rename = "$unflatten=pCredPres")]
+    #[serde(with = "crate::base::decimal::percentual")]
+    pub percentual: Decimal,
+
+    /// Valor do crédito presumido (tag `<vCredPres>`)
+    #[serde(rename = "$unflatten=vCredPres")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub valor: Option<Decimal>,
+
+    /// Valor do crédito presumido em condição suspensiva (tag `<vCredPresCondSus>`)
+    #[serde(rename = "$unflatten=vCredPresCondSus")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub valor_condicao_suspensiva: Option<Decimal>,
+}
+
+impl GrupoIbsCbs {
+    /// Valor diferido do IBS (UF + município)
+    pub fn valor_diferido_ibs(&self) -> Decimal {
+        let uf = self.ibs_uf.diferimento.as_ref().map(|d| d.valor);
+        let mun = self.ibs_municipal.diferimento.as_ref().map(|d| d.valor);
+        uf.unwrap_or_default() + mun.unwrap_or_default()
+    }
+}
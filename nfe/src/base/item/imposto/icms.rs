@@ -40,6 +40,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::base::decimal::Decimal;
+
 /// Container para os grupos de ICMS (tag `<ICMS>`)
 ///
 /// O ICMS é informado através de grupos exclusivos, onde apenas UM grupo
@@ -132,6 +134,144 @@ pub struct IcmsContainer {
     pub icms_sn900: Option<IcmsSn900>,
 }
 
+impl IcmsContainer {
+    /// Origem da mercadoria do grupo informado
+    pub fn origem(&self) -> Option<u8> {
+        self.icms00.as_ref().map(|i| i.origem)
+            .or(self.icms10.as_ref().map(|i| i.origem))
+            .or(self.icms20.as_ref().map(|i| i.origem))
+            .or(self.icms30.as_ref().map(|i| i.origem))
+            .or(self.icms40.as_ref().map(|i| i.origem))
+            .or(self.icms41.as_ref().map(|i| i.origem))
+            .or(self.icms50.as_ref().map(|i| i.origem))
+            .or(self.icms51.as_ref().map(|i| i.origem))
+            .or(self.icms60.as_ref().map(|i| i.origem))
+            .or(self.icms70.as_ref().map(|i| i.origem))
+            .or(self.icms90.as_ref().map(|i| i.origem))
+            .or(self.icms_sn101.as_ref().map(|i| i.origem))
+            .or(self.icms_sn102.as_ref().map(|i| i.origem))
+            .or(self.icms_sn201.as_ref().map(|i| i.origem))
+            .or(self.icms_sn202.as_ref().map(|i| i.origem))
+            .or(self.icms_sn500.as_ref().map(|i| i.origem))
+            .or(self.icms_sn900.as_ref().map(|i| i.origem))
+    }
+
+    /// Base de cálculo do ICMS próprio do grupo informado
+    pub fn valor_bc(&self) -> Decimal {
+        self.icms00.as_ref().map(|i| i.valor_bc)
+            .or(self.icms10.as_ref().map(|i| i.valor_bc))
+            .or(self.icms20.as_ref().map(|i| i.valor_bc))
+            .or(self.icms51.as_ref().and_then(|i| i.valor_bc))
+            .or(self.icms70.as_ref().map(|i| i.valor_bc))
+            .or(self.icms90.as_ref().and_then(|i| i.valor_bc))
+            .or(self.icms_sn900.as_ref().and_then(|i| i.valor_bc))
+            .unwrap_or_default()
+    }
+
+    /// Valor do ICMS próprio do grupo informado
+    pub fn valor_icms(&self) -> Decimal {
+        self.icms00.as_ref().map(|i| i.valor)
+            .or(self.icms10.as_ref().map(|i| i.valor))
+            .or(self.icms20.as_ref().map(|i| i.valor))
+            .or(self.icms51.as_ref().and_then(|i| i.valor))
+            .or(self.icms70.as_ref().map(|i| i.valor))
+            .or(self.icms90.as_ref().and_then(|i| i.valor))
+            .or(self.icms_sn900.as_ref().and_then(|i| i.valor))
+            .unwrap_or_default()
+    }
+
+    /// Valor do FCP do grupo informado
+    pub fn valor_fcp(&self) -> Decimal {
+        self.icms00.as_ref().and_then(|i| i.valor_fcp)
+            .or(self.icms10.as_ref().and_then(|i| i.valor_fcp))
+            .or(self.icms20.as_ref().and_then(|i| i.valor_fcp))
+            .or(self.icms51.as_ref().and_then(|i| i.valor_fcp))
+            .or(self.icms70.as_ref().and_then(|i| i.valor_fcp))
+            .or(self.icms90.as_ref().and_then(|i| i.valor_fcp))
+            .unwrap_or_default()
+    }
+
+    /// Valor do ICMS desonerado do grupo informado
+    pub fn valor_icms_desonerado(&self) -> Decimal {
+        self.icms20.as_ref().and_then(|i| i.valor_icms_desonerado)
+            .or(self.icms30.as_ref().and_then(|i| i.valor_icms_desonerado))
+            .or(self.icms40.as_ref().and_then(|i| i.valor_icms_desonerado))
+            .or(self.icms41.as_ref().and_then(|i| i.valor_icms_desonerado))
+            .or(self.icms50.as_ref().and_then(|i| i.valor_icms_desonerado))
+            .or(self.icms70.as_ref().and_then(|i| i.valor_icms_desonerado))
+            .or(self.icms90.as_ref().and_then(|i| i.valor_icms_desonerado))
+            .unwrap_or_default()
+    }
+
+    /// Base de cálculo do ICMS-ST do grupo informado
+    pub fn valor_bc_st(&self) -> Decimal {
+        self.icms10.as_ref().map(|i| i.valor_bc_st)
+            .or(self.icms30.as_ref().map(|i| i.valor_bc_st))
+            .or(self.icms70.as_ref().map(|i| i.valor_bc_st))
+            .or(self.icms_sn201.as_ref().map(|i| i.valor_bc_st))
+            .or(self.icms_sn202.as_ref().map(|i| i.valor_bc_st))
+            .or(self.icms90.as_ref().and_then(|i| i.valor_bc_st))
+            .or(self.icms_sn900.as_ref().and_then(|i| i.valor_bc_st))
+            .unwrap_or_default()
+    }
+
+    /// Valor do ICMS-ST do grupo informado
+    pub fn valor_st(&self) -> Decimal {
+        self.icms10.as_ref().map(|i| i.valor_st)
+            .or(self.icms30.as_ref().map(|i| i.valor_st))
+            .or(self.icms70.as_ref().map(|i| i.valor_st))
+            .or(self.icms_sn201.as_ref().map(|i| i.valor_st))
+            .or(self.icms_sn202.as_ref().map(|i| i.valor_st))
+            .or(self.icms90.as_ref().and_then(|i| i.valor_st))
+            .or(self.icms_sn900.as_ref().and_then(|i| i.valor_st))
+            .unwrap_or_default()
+    }
+
+    /// Valor do FCP retido por ST do grupo informado
+    pub fn valor_fcp_st(&self) -> Decimal {
+        self.icms10.as_ref().and_then(|i| i.valor_fcp_st)
+            .or(self.icms30.as_ref().and_then(|i| i.valor_fcp_st))
+            .or(self.icms70.as_ref().and_then(|i| i.valor_fcp_st))
+            .or(self.icms90.as_ref().and_then(|i| i.valor_fcp_st))
+            .or(self.icms_sn201.as_ref().and_then(|i| i.valor_fcp_st))
+            .or(self.icms_sn202.as_ref().and_then(|i| i.valor_fcp_st))
+            .or(self.icms_sn900.as_ref().and_then(|i| i.valor_fcp_st))
+            .unwrap_or_default()
+    }
+
+    /// Indica se foi informado um grupo de CST (regime normal)
+    pub fn usa_cst(&self) -> bool {
+        self.icms00.is_some()
+            || self.icms10.is_some()
+            || self.icms20.is_some()
+            || self.icms30.is_some()
+            || self.icms40.is_some()
+            || self.icms41.is_some()
+            || self.icms50.is_some()
+            || self.icms51.is_some()
+            || self.icms60.is_some()
+            || self.icms70.is_some()
+            || self.icms90.is_some()
+    }
+
+    /// Indica se foi informado um grupo de CSOSN (Simples Nacional)
+    pub fn usa_csosn(&self) -> bool {
+        self.icms_sn101.is_some()
+            || self.icms_sn102.is_some()
+            || self.icms_sn201.is_some()
+            || self.icms_sn202.is_some()
+            || self.icms_sn500.is_some()
+            || self.icms_sn900.is_some()
+    }
+
+    /// Valor do FCP retido anteriormente por ST do grupo informado
+    pub fn valor_fcp_st_retido(&self) -> Decimal {
+        self.icms60.as_ref().and_then(|i| i.valor_fcp_st_retido)
+            .or(self.icms_sn500.as_ref().and_then(|i| i.valor_fcp_st_retido))
+            .unwrap_or_default()
+    }
+}
+
 /// ICMS CST 00 - Tributação Integral (tag `<ICMS00>`)
 ///
 /// Usado quando o produto é tributado integralmente pelo ICMS,
@@ -152,25 +292,32 @@ pub struct Icms00 {
 
     /// Valor da Base de Cálculo do ICMS
     #[serde(rename = "$unflatten=vBC")]
-    pub valor_bc: f32,
+    #[serde(with = "crate::base::decimal::valor")]
+    pub valor_bc: Decimal,
 
     /// Alíquota do ICMS em percentual
     #[serde(rename = "$unflatten=pICMS")]
-    pub aliquota: f32,
+    #[serde(with = "crate::base::decimal::percentual")]
+    pub aliquota: Decimal,
 
     /// Valor do ICMS
     #[serde(rename = "$unflatten=vICMS")]
-    pub valor: f32,
+    #[serde(with = "crate::base::decimal::valor")]
+    pub valor: Decimal,
 
     /// Percentual do FCP (Fundo de Combate à Pobreza)
     #[serde(rename = "$unflatten=pFCP")]
+    #[serde(with = "crate::base::decimal::percentual_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub percentual_fcp: Option<f32>,
+    #[serde(default)]
+    pub percentual_fcp: Option<Decimal>,
 
     /// Valor do FCP
     #[serde(rename = "$unflatten=vFCP")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub valor_fcp: Option<f32>,
+    #[serde(default)]
+    pub valor_fcp: Option<Decimal>,
 }
 
 /// ICMS CST 10 - Tributação com ICMS por Substituição Tributária (tag `<ICMS10>`)
@@ -193,25 +340,39 @@ pub struct Icms10 {
 
     /// Valor da Base de Cálculo do ICMS próprio
     #[serde(rename = "$unflatten=vBC")]
-    pub valor_bc: f32,
+    #[serde(with = "crate::base::decimal::valor")]
+    pub valor_bc: Decimal,
 
     /// Alíquota do ICMS próprio em percentual
     #[serde(rename = "$unflatten=pICMS")]
-    pub aliquota: f32,
+    #[serde(with = "crate::base::decimal::percentual")]
+    pub aliquota: Decimal,
 
     /// Valor do ICMS próprio
     #[serde(rename = "$unflatten=vICMS")]
-    pub valor: f32,
+    #[serde(with = "crate::base::decimal::valor")]
+    pub valor: Decimal,
+
+    /// Valor da BC do FCP
+    #[serde(rename = "$unflatten=vBCFCP")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub valor_bc_fcp: Option<Decimal>,
 
     /// Percentual do FCP
     #[serde(rename = "$unflatten=pFCP")]
+    #[serde(with = "crate::base::decimal::percentual_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub percentual_fcp: Option<f32>,
+    #[serde(default)]
+    pub percentual_fcp: Option<Decimal>,
 
     /// Valor do FCP
     #[serde(rename = "$unflatten=vFCP")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub valor_fcp: Option<f32>,
+    #[serde(default)]
+    pub valor_fcp: Option<Decimal>,
 
     /// Modalidade de determinação da BC do ICMS ST (0-5)
     #[serde(rename = "$unflatten=modBCST")]
@@ -219,35 +380,53 @@ pub struct Icms10 {
 
     /// Percentual da margem de valor Adicionado do ICMS ST
     #[serde(rename = "$unflatten=pMVAST")]
+    #[serde(with = "crate::base::decimal::percentual_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub percentual_mva_st: Option<f32>,
+    #[serde(default)]
+    pub percentual_mva_st: Option<Decimal>,
 
     /// Percentual de redução da BC do ICMS ST
     #[serde(rename = "$unflatten=pRedBCST")]
+    #[serde(with = "crate::base::decimal::percentual_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub percentual_reducao_bc_st: Option<f32>,
+    #[serde(default)]
+    pub percentual_reducao_bc_st: Option<Decimal>,
 
     /// Valor da Base de Cálculo do ICMS ST
     #[serde(rename = "$unflatten=vBCST")]
-    pub valor_bc_st: f32,
+    #[serde(with = "crate::base::decimal::valor")]
+    pub valor_bc_st: Decimal,
 
     /// Alíquota do ICMS ST em percentual
     #[serde(rename = "$unflatten=pICMSST")]
-    pub aliquota_st: f32,
+    #[serde(with = "crate::base::decimal::percentual")]
+    pub aliquota_st: Decimal,
 
     /// Valor do ICMS ST
     #[serde(rename = "$unflatten=vICMSST")]
-    pub valor_st: f32,
+    #[serde(with = "crate::base::decimal::valor")]
+    pub valor_st: Decimal,
+
+    /// Valor da BC do FCP retido por ST
+    #[serde(rename = "$unflatten=vBCFCPST")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub valor_bc_fcp_st: Option<Decimal>,
 
     /// Percentual do FCP retido por ST
     #[serde(rename = "$unflatten=pFCPST")]
+    #[serde(with = "crate::base::decimal::percentual_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub percentual_fcp_st: Option<f32>,
+    #[serde(default)]
+    pub percentual_fcp_st: Option<Decimal>,
 
     /// Valor do FCP retido por ST
     #[serde(rename = "$unflatten=vFCPST")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub valor_fcp_st: Option<f32>,
+    #[serde(default)]
+    pub valor_fcp_st: Option<Decimal>,
 }
 
 /// ICMS CST 20 - Tributação com Redução de Base de Cálculo (tag `<ICMS20>`)
@@ -267,34 +446,51 @@ pub struct Icms20 {
 
     /// Percentual de redução da BC
     #[serde(rename = "$unflatten=pRedBC")]
-    pub percentual_reducao_bc: f32,
+    #[serde(with = "crate::base::decimal::percentual")]
+    pub percentual_reducao_bc: Decimal,
 
     /// Valor da BC do ICMS JÁ REDUZIDA
     #[serde(rename = "$unflatten=vBC")]
-    pub valor_bc: f32,
+    #[serde(with = "crate::base::decimal::valor")]
+    pub valor_bc: Decimal,
 
     /// Alíquota do ICMS em percentual
     #[serde(rename = "$unflatten=pICMS")]
-    pub aliquota: f32,
+    #[serde(with = "crate::base::decimal::percentual")]
+    pub aliquota: Decimal,
 
     /// Valor do ICMS
     #[serde(rename = "$unflatten=vICMS")]
-    pub valor: f32,
+    #[serde(with = "crate::base::decimal::valor")]
+    pub valor: Decimal,
+
+    /// Valor da BC do FCP
+    #[serde(rename = "$unflatten=vBCFCP")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub valor_bc_fcp: Option<Decimal>,
 
     /// Percentual do FCP
     #[serde(rename = "$unflatten=pFCP")]
+    #[serde(with = "crate::base::decimal::percentual_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub percentual_fcp: Option<f32>,
+    #[serde(default)]
+    pub percentual_fcp: Option<Decimal>,
 
     /// Valor do FCP
     #[serde(rename = "$unflatten=vFCP")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub valor_fcp: Option<f32>,
+    #[serde(default)]
+    pub valor_fcp: Option<Decimal>,
 
     /// Valor do ICMS desonerado
     #[serde(rename = "$unflatten=vICMSDeson")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub valor_icms_desonerado: Option<f32>,
+    #[serde(default)]
+    pub valor_icms_desonerado: Option<Decimal>,
 
     /// Motivo da desoneração (3-12)
     #[serde(rename = "$unflatten=motDesICMS")]
@@ -319,40 +515,60 @@ pub struct Icms30 {
 
     /// Percentual da margem de valor Adicionado do ICMS ST
     #[serde(rename = "$unflatten=pMVAST")]
+    #[serde(with = "crate::base::decimal::percentual_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub percentual_mva_st: Option<f32>,
+    #[serde(default)]
+    pub percentual_mva_st: Option<Decimal>,
 
     /// Percentual de redução da BC do ICMS ST
     #[serde(rename = "$unflatten=pRedBCST")]
+    #[serde(with = "crate::base::decimal::percentual_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub percentual_reducao_bc_st: Option<f32>,
+    #[serde(default)]
+    pub percentual_reducao_bc_st: Option<Decimal>,
 
     /// Valor da Base de Cálculo do ICMS ST
     #[serde(rename = "$unflatten=vBCST")]
-    pub valor_bc_st: f32,
+    #[serde(with = "crate::base::decimal::valor")]
+    pub valor_bc_st: Decimal,
 
     /// Alíquota do ICMS ST
     #[serde(rename = "$unflatten=pICMSST")]
-    pub aliquota_st: f32,
+    #[serde(with = "crate::base::decimal::percentual")]
+    pub aliquota_st: Decimal,
 
     /// Valor do ICMS ST
     #[serde(rename = "$unflatten=vICMSST")]
-    pub valor_st: f32,
+    #[serde(with = "crate::base::decimal::valor")]
+    pub valor_st: Decimal,
+
+    /// Valor da BC do FCP retido por ST
+    #[serde(rename = "$unflatten=vBCFCPST")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub valor_bc_fcp_st: Option<Decimal>,
 
     /// Percentual do FCP retido por ST
     #[serde(rename = "$unflatten=pFCPST")]
+    #[serde(with = "crate::base::decimal::percentual_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub percentual_fcp_st: Option<f32>,
+    #[serde(default)]
+    pub percentual_fcp_st: Option<Decimal>,
 
     /// Valor do FCP retido por ST
     #[serde(rename = "$unflatten=vFCPST")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub valor_fcp_st: Option<f32>,
+    #[serde(default)]
+    pub valor_fcp_st: Option<Decimal>,
 
     /// Valor do ICMS desonerado
     #[serde(rename = "$unflatten=vICMSDeson")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub valor_icms_desonerado: Option<f32>,
+    #[serde(default)]
+    pub valor_icms_desonerado: Option<Decimal>,
 
     /// Motivo da desoneração
     #[serde(rename = "$unflatten=motDesICMS")]
@@ -360,21 +576,23 @@ pub struct Icms30 {
     pub motivo_desoneracao: Option<u8>,
 }
 
-/// ICMS CST 40 - Isenta (tag `<ICMS40>`)
+/// ICMS CST 40, 41 e 50 - Isenta, não tributada ou suspensão (tag `<ICMS40>`)
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct Icms40 {
     /// Origem da mercadoria
     #[serde(rename = "$unflatten=orig")]
     pub origem: u8,
 
-    /// Código de Situação Tributária - sempre "40"
+    /// Código de Situação Tributária - "40", "41" ou "50"
     #[serde(rename = "$unflatten=CST")]
     pub cst: String,
 
     /// Valor do ICMS desonerado
     #[serde(rename = "$unflatten=vICMSDeson")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub valor_icms_desonerado: Option<f32>,
+    #[serde(default)]
+    pub valor_icms_desonerado: Option<Decimal>,
 
     /// Motivo da desoneração (1=Táxi, 3=Produtor Agropecuário, etc.)
     #[serde(rename = "$unflatten=motDesICMS")]
@@ -395,8 +613,10 @@ pub struct Icms41 {
 
     /// Valor do ICMS desonerado
     #[serde(rename = "$unflatten=vICMSDeson")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub valor_icms_desonerado: Option<f32>,
+    #[serde(default)]
+    pub valor_icms_desonerado: Option<Decimal>,
 
     /// Motivo da desoneração
     #[serde(rename = "$unflatten=motDesICMS")]
@@ -417,8 +637,10 @@ pub struct Icms50 {
 
     /// Valor do ICMS desonerado
     #[serde(rename = "$unflatten=vICMSDeson")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub valor_icms_desonerado: Option<f32>,
+    #[serde(default)]
+    pub valor_icms_desonerado: Option<Decimal>,
 
     /// Motivo da desoneração
     #[serde(rename = "$unflatten=motDesICMS")]
@@ -446,48 +668,73 @@ pub struct Icms51 {
 
     /// Percentual de redução da BC
     #[serde(rename = "$unflatten=pRedBC")]
+    #[serde(with = "crate::base::decimal::percentual_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub percentual_reducao_bc: Option<f32>,
+    #[serde(default)]
+    pub percentual_reducao_bc: Option<Decimal>,
 
     /// Valor da BC do ICMS
     #[serde(rename = "$unflatten=vBC")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub valor_bc: Option<f32>,
+    #[serde(default)]
+    pub valor_bc: Option<Decimal>,
 
     /// Alíquota do ICMS
     #[serde(rename = "$unflatten=pICMS")]
+    #[serde(with = "crate::base::decimal::percentual_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub aliquota: Option<f32>,
+    #[serde(default)]
+    pub aliquota: Option<Decimal>,
 
     /// Valor do ICMS da operação
     #[serde(rename = "$unflatten=vICMSOp")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub valor_icms_operacao: Option<f32>,
+    #[serde(default)]
+    pub valor_icms_operacao: Option<Decimal>,
 
     /// Percentual do diferimento
     #[serde(rename = "$unflatten=pDif")]
+    #[serde(with = "crate::base::decimal::percentual_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub percentual_diferimento: Option<f32>,
+    #[serde(default)]
+    pub percentual_diferimento: Option<Decimal>,
 
     /// Valor do ICMS diferido
     #[serde(rename = "$unflatten=vICMSDif")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub valor_icms_diferido: Option<f32>,
+    #[serde(default)]
+    pub valor_icms_diferido: Option<Decimal>,
 
     /// Valor do ICMS realmente devido
     #[serde(rename = "$unflatten=vICMS")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub valor: Option<f32>,
+    #[serde(default)]
+    pub valor: Option<Decimal>,
+
+    /// Valor da BC do FCP
+    #[serde(rename = "$unflatten=vBCFCP")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub valor_bc_fcp: Option<Decimal>,
 
     /// Percentual do FCP
     #[serde(rename = "$unflatten=pFCP")]
+    #[serde(with = "crate::base::decimal::percentual_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub percentual_fcp: Option<f32>,
+    #[serde(default)]
+    pub percentual_fcp: Option<Decimal>,
 
     /// Valor do FCP
     #[serde(rename = "$unflatten=vFCP")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub valor_fcp: Option<f32>,
+    #[serde(default)]
+    pub valor_fcp: Option<Decimal>,
 }
 
 /// ICMS CST 60 - ICMS cobrado anteriormente por ST (tag `<ICMS60>`)
@@ -505,58 +752,80 @@ pub struct Icms60 {
 
     /// Valor da BC do ICMS ST retido anteriormente
     #[serde(rename = "$unflatten=vBCSTRet")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub valor_bc_st_retido: Option<f32>,
+    #[serde(default)]
+    pub valor_bc_st_retido: Option<Decimal>,
 
     /// Alíquota suportada pelo consumidor final
     #[serde(rename = "$unflatten=pST")]
+    #[serde(with = "crate::base::decimal::percentual_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub aliquota_st_consumidor: Option<f32>,
+    #[serde(default)]
+    pub aliquota_st_consumidor: Option<Decimal>,
 
     /// Valor do ICMS próprio do substituto
     #[serde(rename = "$unflatten=vICMSSubstituto")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub valor_icms_substituto: Option<f32>,
+    #[serde(default)]
+    pub valor_icms_substituto: Option<Decimal>,
 
     /// Valor do ICMS ST retido anteriormente
     #[serde(rename = "$unflatten=vICMSSTRet")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub valor_st_retido: Option<f32>,
+    #[serde(default)]
+    pub valor_st_retido: Option<Decimal>,
 
     /// Valor da BC do FCP retido anteriormente por ST
     #[serde(rename = "$unflatten=vBCFCPSTRet")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub valor_bc_fcp_st_retido: Option<f32>,
+    #[serde(default)]
+    pub valor_bc_fcp_st_retido: Option<Decimal>,
 
     /// Percentual do FCP retido anteriormente por ST
     #[serde(rename = "$unflatten=pFCPSTRet")]
+    #[serde(with = "crate::base::decimal::percentual_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub percentual_fcp_st_retido: Option<f32>,
+    #[serde(default)]
+    pub percentual_fcp_st_retido: Option<Decimal>,
 
     /// Valor do FCP retido por ST
     #[serde(rename = "$unflatten=vFCPSTRet")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub valor_fcp_st_retido: Option<f32>,
+    #[serde(default)]
+    pub valor_fcp_st_retido: Option<Decimal>,
 
     /// Percentual de redução da BC efetiva
     #[serde(rename = "$unflatten=pRedBCEfet")]
+    #[serde(with = "crate::base::decimal::percentual_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub percentual_reducao_bc_efetiva: Option<f32>,
+    #[serde(default)]
+    pub percentual_reducao_bc_efetiva: Option<Decimal>,
 
     /// Valor da BC efetiva
     #[serde(rename = "$unflatten=vBCEfet")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub valor_bc_efetiva: Option<f32>,
+    #[serde(default)]
+    pub valor_bc_efetiva: Option<Decimal>,
 
     /// Alíquota do ICMS efetiva
     #[serde(rename = "$unflatten=pICMSEfet")]
+    #[serde(with = "crate::base::decimal::percentual_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub aliquota_efetiva: Option<f32>,
+    #[serde(default)]
+    pub aliquota_efetiva: Option<Decimal>,
 
     /// Valor do ICMS efetivo
     #[serde(rename = "$unflatten=vICMSEfet")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub valor_efetivo: Option<f32>,
+    #[serde(default)]
+    pub valor_efetivo: Option<Decimal>,
 }
 
 /// ICMS CST 70 - Com redução de BC e cobrança do ICMS por ST (tag `<ICMS70>`)
@@ -576,29 +845,44 @@ pub struct Icms70 {
 
     /// Percentual de redução da BC
     #[serde(rename = "$unflatten=pRedBC")]
-    pub percentual_reducao_bc: f32,
+    #[serde(with = "crate::base::decimal::percentual")]
+    pub percentual_reducao_bc: Decimal,
 
     /// Valor da BC do ICMS
     #[serde(rename = "$unflatten=vBC")]
-    pub valor_bc: f32,
+    #[serde(with = "crate::base::decimal::valor")]
+    pub valor_bc: Decimal,
 
     /// Alíquota do ICMS
     #[serde(rename = "$unflatten=pICMS")]
-    pub aliquota: f32,
+    #[serde(with = "crate::base::decimal::percentual")]
+    pub aliquota: Decimal,
 
     /// Valor do ICMS
     #[serde(rename = "$unflatten=vICMS")]
-    pub valor: f32,
+    #[serde(with = "crate::base::decimal::valor")]
+    pub valor: Decimal,
+
+    /// Valor da BC do FCP
+    #[serde(rename = "$unflatten=vBCFCP")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub valor_bc_fcp: Option<Decimal>,
 
     /// Percentual do FCP
     #[serde(rename = "$unflatten=pFCP")]
+    #[serde(with = "crate::base::decimal::percentual_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub percentual_fcp: Option<f32>,
+    #[serde(default)]
+    pub percentual_fcp: Option<Decimal>,
 
     /// Valor do FCP
     #[serde(rename = "$unflatten=vFCP")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub valor_fcp: Option<f32>,
+    #[serde(default)]
+    pub valor_fcp: Option<Decimal>,
 
     /// Modalidade de determinação da BC do ICMS ST
     #[serde(rename = "$unflatten=modBCST")]
@@ -606,40 +890,60 @@ pub struct Icms70 {
 
     /// Percentual da MVA do ICMS ST
     #[serde(rename = "$unflatten=pMVAST")]
+    #[serde(with = "crate::base::decimal::percentual_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub percentual_mva_st: Option<f32>,
+    #[serde(default)]
+    pub percentual_mva_st: Option<Decimal>,
 
     /// Percentual de redução da BC do ICMS ST
     #[serde(rename = "$unflatten=pRedBCST")]
+    #[serde(with = "crate::base::decimal::percentual_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub percentual_reducao_bc_st: Option<f32>,
+    #[serde(default)]
+    pub percentual_reducao_bc_st: Option<Decimal>,
 
     /// Valor da BC do ICMS ST
     #[serde(rename = "$unflatten=vBCST")]
-    pub valor_bc_st: f32,
+    #[serde(with = "crate::base::decimal::valor")]
+    pub valor_bc_st: Decimal,
 
     /// Alíquota do ICMS ST
     #[serde(rename = "$unflatten=pICMSST")]
-    pub aliquota_st: f32,
+    #[serde(with = "crate::base::decimal::percentual")]
+    pub aliquota_st: Decimal,
 
     /// Valor do ICMS ST
     #[serde(rename = "$unflatten=vICMSST")]
-    pub valor_st: f32,
+    #[serde(with = "crate::base::decimal::valor")]
+    pub valor_st: Decimal,
+
+    /// Valor da BC do FCP retido por ST
+    #[serde(rename = "$unflatten=vBCFCPST")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub valor_bc_fcp_st: Option<Decimal>,
 
     /// Percentual do FCP retido por ST
     #[serde(rename = "$unflatten=pFCPST")]
+    #[serde(with = "crate::base::decimal::percentual_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub percentual_fcp_st: Option<f32>,
+    #[serde(default)]
+    pub percentual_fcp_st: Option<Decimal>,
 
     /// Valor do FCP retido por ST
     #[serde(rename = "$unflatten=vFCPST")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub valor_fcp_st: Option<f32>,
+    #[serde(default)]
+    pub valor_fcp_st: Option<Decimal>,
 
     /// Valor do ICMS desonerado
     #[serde(rename = "$unflatten=vICMSDeson")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub valor_icms_desonerado: Option<f32>,
+    #[serde(default)]
+    pub valor_icms_desonerado: Option<Decimal>,
 
     /// Motivo da desoneração
     #[serde(rename = "$unflatten=motDesICMS")]
@@ -665,35 +969,54 @@ pub struct Icms90 {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub modalidade_bc: Option<u8>,
 
-    /// Percentual de redução da BC
-    #[serde(rename = "$unflatten=pRedBC")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub percentual_reducao_bc: Option<f32>,
-
     /// Valor da BC do ICMS
     #[serde(rename = "$unflatten=vBC")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub valor_bc: Option<f32>,
+    #[serde(default)]
+    pub valor_bc: Option<Decimal>,
+
+    /// Percentual de redução da BC
+    #[serde(rename = "$unflatten=pRedBC")]
+    #[serde(with = "crate::base::decimal::percentual_opcional")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub percentual_reducao_bc: Option<Decimal>,
 
     /// Alíquota do ICMS
     #[serde(rename = "$unflatten=pICMS")]
+    #[serde(with = "crate::base::decimal::percentual_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub aliquota: Option<f32>,
+    #[serde(default)]
+    pub aliquota: Option<Decimal>,
 
     /// Valor do ICMS
     #[serde(rename = "$unflatten=vICMS")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub valor: Option<f32>,
+    #[serde(default)]
+    pub valor: Option<Decimal>,
+
+    /// Valor da BC do FCP
+    #[serde(rename = "$unflatten=vBCFCP")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub valor_bc_fcp: Option<Decimal>,
 
     /// Percentual do FCP
     #[serde(rename = "$unflatten=pFCP")]
+    #[serde(with = "crate::base::decimal::percentual_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub percentual_fcp: Option<f32>,
+    #[serde(default)]
+    pub percentual_fcp: Option<Decimal>,
 
     /// Valor do FCP
     #[serde(rename = "$unflatten=vFCP")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub valor_fcp: Option<f32>,
+    #[serde(default)]
+    pub valor_fcp: Option<Decimal>,
 
     /// Modalidade de determinação da BC do ICMS ST
     #[serde(rename = "$unflatten=modBCST")]
@@ -702,43 +1025,66 @@ pub struct Icms90 {
 
     /// Percentual da MVA do ICMS ST
     #[serde(rename = "$unflatten=pMVAST")]
+    #[serde(with = "crate::base::decimal::percentual_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub percentual_mva_st: Option<f32>,
+    #[serde(default)]
+    pub percentual_mva_st: Option<Decimal>,
 
     /// Percentual de redução da BC do ICMS ST
     #[serde(rename = "$unflatten=pRedBCST")]
+    #[serde(with = "crate::base::decimal::percentual_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub percentual_reducao_bc_st: Option<f32>,
+    #[serde(default)]
+    pub percentual_reducao_bc_st: Option<Decimal>,
 
     /// Valor da BC do ICMS ST
     #[serde(rename = "$unflatten=vBCST")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub valor_bc_st: Option<f32>,
+    #[serde(default)]
+    pub valor_bc_st: Option<Decimal>,
 
     /// Alíquota do ICMS ST
     #[serde(rename = "$unflatten=pICMSST")]
+    #[serde(with = "crate::base::decimal::percentual_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub aliquota_st: Option<f32>,
+    #[serde(default)]
+    pub aliquota_st: Option<Decimal>,
 
     /// Valor do ICMS ST
     #[serde(rename = "$unflatten=vICMSST")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub valor_st: Option<f32>,
+    #[serde(default)]
+    pub valor_st: Option<Decimal>,
+
+    /// Valor da BC do FCP retido por ST
+    #[serde(rename = "$unflatten=vBCFCPST")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub valor_bc_fcp_st: Option<Decimal>,
 
     /// Percentual do FCP retido por ST
     #[serde(rename = "$unflatten=pFCPST")]
+    #[serde(with = "crate::base::decimal::percentual_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub percentual_fcp_st: Option<f32>,
+    #[serde(default)]
+    pub percentual_fcp_st: Option<Decimal>,
 
     /// Valor do FCP retido por ST
     #[serde(rename = "$unflatten=vFCPST")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub valor_fcp_st: Option<f32>,
+    #[serde(default)]
+    pub valor_fcp_st: Option<Decimal>,
 
     /// Valor do ICMS desonerado
     #[serde(rename = "$unflatten=vICMSDeson")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub valor_icms_desonerado: Option<f32>,
+    #[serde(default)]
+    pub valor_icms_desonerado: Option<Decimal>,
 
     /// Motivo da desoneração
     #[serde(rename = "$unflatten=motDesICMS")]
@@ -761,11 +1107,13 @@ pub struct IcmsSn101 {
 
     /// Alíquota aplicável de cálculo do crédito
     #[serde(rename = "$unflatten=pCredSN")]
-    pub aliquota_credito_sn: f32,
+    #[serde(with = "crate::base::decimal::percentual")]
+    pub aliquota_credito_sn: Decimal,
 
     /// Valor do crédito do ICMS permitido
     #[serde(rename = "$unflatten=vCredICMSSN")]
-    pub valor_credito_icms_sn: f32,
+    #[serde(with = "crate::base::decimal::valor")]
+    pub valor_credito_icms_sn: Decimal,
 }
 
 /// ICMS Simples Nacional CSOSN 102, 103, 300, 400 (tag `<ICMSSN102>`)
@@ -799,43 +1147,63 @@ pub struct IcmsSn201 {
 
     /// Percentual da MVA do ICMS ST
     #[serde(rename = "$unflatten=pMVAST")]
+    #[serde(with = "crate::base::decimal::percentual_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub percentual_mva_st: Option<f32>,
+    #[serde(default)]
+    pub percentual_mva_st: Option<Decimal>,
 
     /// Percentual de redução da BC do ICMS ST
     #[serde(rename = "$unflatten=pRedBCST")]
+    #[serde(with = "crate::base::decimal::percentual_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub percentual_reducao_bc_st: Option<f32>,
+    #[serde(default)]
+    pub percentual_reducao_bc_st: Option<Decimal>,
 
     /// Valor da BC do ICMS ST
     #[serde(rename = "$unflatten=vBCST")]
-    pub valor_bc_st: f32,
+    #[serde(with = "crate::base::decimal::valor")]
+    pub valor_bc_st: Decimal,
 
     /// Alíquota do ICMS ST
     #[serde(rename = "$unflatten=pICMSST")]
-    pub aliquota_st: f32,
+    #[serde(with = "crate::base::decimal::percentual")]
+    pub aliquota_st: Decimal,
 
     /// Valor do ICMS ST
     #[serde(rename = "$unflatten=vICMSST")]
-    pub valor_st: f32,
+    #[serde(with = "crate::base::decimal::valor")]
+    pub valor_st: Decimal,
+
+    /// Valor da BC do FCP retido por ST
+    #[serde(rename = "$unflatten=vBCFCPST")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub valor_bc_fcp_st: Option<Decimal>,
 
     /// Percentual do FCP retido por ST
     #[serde(rename = "$unflatten=pFCPST")]
+    #[serde(with = "crate::base::decimal::percentual_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub percentual_fcp_st: Option<f32>,
+    #[serde(default)]
+    pub percentual_fcp_st: Option<Decimal>,
 
     /// Valor do FCP retido por ST
     #[serde(rename = "$unflatten=vFCPST")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub valor_fcp_st: Option<f32>,
+    #[serde(default)]
+    pub valor_fcp_st: Option<Decimal>,
 
     /// Alíquota aplicável de cálculo do crédito
     #[serde(rename = "$unflatten=pCredSN")]
-    pub aliquota_credito_sn: f32,
+    #[serde(with = "crate::base::decimal::percentual")]
+    pub aliquota_credito_sn: Decimal,
 
     /// Valor do crédito do ICMS
     #[serde(rename = "$unflatten=vCredICMSSN")]
-    pub valor_credito_icms_sn: f32,
+    #[serde(with = "crate::base::decimal::valor")]
+    pub valor_credito_icms_sn: Decimal,
 }
 
 /// ICMS Simples Nacional CSOSN 202/203 (tag `<ICMSSN202>`)
@@ -857,35 +1225,53 @@ pub struct IcmsSn202 {
 
     /// Percentual da MVA do ICMS ST
     #[serde(rename = "$unflatten=pMVAST")]
+    #[serde(with = "crate::base::decimal::percentual_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub percentual_mva_st: Option<f32>,
+    #[serde(default)]
+    pub percentual_mva_st: Option<Decimal>,
 
     /// Percentual de redução da BC do ICMS ST
     #[serde(rename = "$unflatten=pRedBCST")]
+    #[serde(with = "crate::base::decimal::percentual_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub percentual_reducao_bc_st: Option<f32>,
+    #[serde(default)]
+    pub percentual_reducao_bc_st: Option<Decimal>,
 
     /// Valor da BC do ICMS ST
     #[serde(rename = "$unflatten=vBCST")]
-    pub valor_bc_st: f32,
+    #[serde(with = "crate::base::decimal::valor")]
+    pub valor_bc_st: Decimal,
 
     /// Alíquota do ICMS ST
     #[serde(rename = "$unflatten=pICMSST")]
-    pub aliquota_st: f32,
+    #[serde(with = "crate::base::decimal::percentual")]
+    pub aliquota_st: Decimal,
 
     /// Valor do ICMS ST
     #[serde(rename = "$unflatten=vICMSST")]
-    pub valor_st: f32,
+    #[serde(with = "crate::base::decimal::valor")]
+    pub valor_st: Decimal,
+
+    /// Valor da BC do FCP retido por ST
+    #[serde(rename = "$unflatten=vBCFCPST")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub valor_bc_fcp_st: Option<Decimal>,
 
     /// Percentual do FCP retido por ST
     #[serde(rename = "$unflatten=pFCPST")]
+    #[serde(with = "crate::base::decimal::percentual_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub percentual_fcp_st: Option<f32>,
+    #[serde(default)]
+    pub percentual_fcp_st: Option<Decimal>,
 
     /// Valor do FCP retido por ST
     #[serde(rename = "$unflatten=vFCPST")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub valor_fcp_st: Option<f32>,
+    #[serde(default)]
+    pub valor_fcp_st: Option<Decimal>,
 }
 
 /// ICMS Simples Nacional CSOSN 500 (tag `<ICMSSN500>`)
@@ -903,58 +1289,80 @@ pub struct IcmsSn500 {
 
     /// Valor da BC do ICMS ST retido anteriormente
     #[serde(rename = "$unflatten=vBCSTRet")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub valor_bc_st_retido: Option<f32>,
+    #[serde(default)]
+    pub valor_bc_st_retido: Option<Decimal>,
 
     /// Alíquota suportada pelo consumidor final
     #[serde(rename = "$unflatten=pST")]
+    #[serde(with = "crate::base::decimal::percentual_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub aliquota_st_consumidor: Option<f32>,
+    #[serde(default)]
+    pub aliquota_st_consumidor: Option<Decimal>,
 
     /// Valor do ICMS próprio do substituto
     #[serde(rename = "$unflatten=vICMSSubstituto")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub valor_icms_substituto: Option<f32>,
+    #[serde(default)]
+    pub valor_icms_substituto: Option<Decimal>,
 
     /// Valor do ICMS ST retido
     #[serde(rename = "$unflatten=vICMSSTRet")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub valor_st_retido: Option<f32>,
+    #[serde(default)]
+    pub valor_st_retido: Option<Decimal>,
 
     /// Valor da BC do FCP retido anteriormente
     #[serde(rename = "$unflatten=vBCFCPSTRet")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub valor_bc_fcp_st_retido: Option<f32>,
+    #[serde(default)]
+    pub valor_bc_fcp_st_retido: Option<Decimal>,
 
     /// Percentual do FCP retido anteriormente
     #[serde(rename = "$unflatten=pFCPSTRet")]
+    #[serde(with = "crate::base::decimal::percentual_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub percentual_fcp_st_retido: Option<f32>,
+    #[serde(default)]
+    pub percentual_fcp_st_retido: Option<Decimal>,
 
     /// Valor do FCP retido
     #[serde(rename = "$unflatten=vFCPSTRet")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub valor_fcp_st_retido: Option<f32>,
+    #[serde(default)]
+    pub valor_fcp_st_retido: Option<Decimal>,
 
     /// Percentual de redução da BC efetiva
     #[serde(rename = "$unflatten=pRedBCEfet")]
+    #[serde(with = "crate::base::decimal::percentual_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub percentual_reducao_bc_efetiva: Option<f32>,
+    #[serde(default)]
+    pub percentual_reducao_bc_efetiva: Option<Decimal>,
 
     /// Valor da BC efetiva
     #[serde(rename = "$unflatten=vBCEfet")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub valor_bc_efetiva: Option<f32>,
+    #[serde(default)]
+    pub valor_bc_efetiva: Option<Decimal>,
 
     /// Alíquota do ICMS efetiva
     #[serde(rename = "$unflatten=pICMSEfet")]
+    #[serde(with = "crate::base::decimal::percentual_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub aliquota_efetiva: Option<f32>,
+    #[serde(default)]
+    pub aliquota_efetiva: Option<Decimal>,
 
     /// Valor do ICMS efetivo
     #[serde(rename = "$unflatten=vICMSEfet")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub valor_efetivo: Option<f32>,
+    #[serde(default)]
+    pub valor_efetivo: Option<Decimal>,
 }
 
 /// ICMS Simples Nacional CSOSN 900 (tag `<ICMSSN900>`)
@@ -975,25 +1383,33 @@ pub struct IcmsSn900 {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub modalidade_bc: Option<u8>,
 
-    /// Percentual de redução da BC
-    #[serde(rename = "$unflatten=pRedBC")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub percentual_reducao_bc: Option<f32>,
-
     /// Valor da BC do ICMS
     #[serde(rename = "$unflatten=vBC")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub valor_bc: Option<Decimal>,
+
+    /// Percentual de redução da BC
+    #[serde(rename = "$unflatten=pRedBC")]
+    #[serde(with = "crate::base::decimal::percentual_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub valor_bc: Option<f32>,
+    #[serde(default)]
+    pub percentual_reducao_bc: Option<Decimal>,
 
     /// Alíquota do ICMS
     #[serde(rename = "$unflatten=pICMS")]
+    #[serde(with = "crate::base::decimal::percentual_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub aliquota: Option<f32>,
+    #[serde(default)]
+    pub aliquota: Option<Decimal>,
 
     /// Valor do ICMS
     #[serde(rename = "$unflatten=vICMS")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub valor: Option<f32>,
+    #[serde(default)]
+    pub valor: Option<Decimal>,
 
     /// Modalidade de determinação da BC do ICMS ST
     #[serde(rename = "$unflatten=modBCST")]
@@ -1002,46 +1418,71 @@ pub struct IcmsSn900 {
 
     /// Percentual da MVA do ICMS ST
     #[serde(rename = "$unflatten=pMVAST")]
+    #[serde(with = "crate::base::decimal::percentual_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub percentual_mva_st: Option<f32>,
+    #[serde(default)]
+    pub percentual_mva_st: Option<Decimal>,
 
     /// Percentual de redução da BC do ICMS ST
     #[serde(rename = "$unflatten=pRedBCST")]
+    #[serde(with = "crate::base::decimal::percentual_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub percentual_reducao_bc_st: Option<f32>,
+    #[serde(default)]
+    pub percentual_reducao_bc_st: Option<Decimal>,
 
     /// Valor da BC do ICMS ST
     #[serde(rename = "$unflatten=vBCST")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub valor_bc_st: Option<f32>,
+    #[serde(default)]
+    pub valor_bc_st: Option<Decimal>,
 
     /// Alíquota do ICMS ST
     #[serde(rename = "$unflatten=pICMSST")]
+    #[serde(with = "crate::base::decimal::percentual_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub aliquota_st: Option<f32>,
+    #[serde(default)]
+    pub aliquota_st: Option<Decimal>,
 
     /// Valor do ICMS ST
     #[serde(rename = "$unflatten=vICMSST")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub valor_st: Option<Decimal>,
+
+    /// Valor da BC do FCP retido por ST
+    #[serde(rename = "$unflatten=vBCFCPST")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub valor_st: Option<f32>,
+    #[serde(default)]
+    pub valor_bc_fcp_st: Option<Decimal>,
 
     /// Percentual do FCP retido por ST
     #[serde(rename = "$unflatten=pFCPST")]
+    #[serde(with = "crate::base::decimal::percentual_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub percentual_fcp_st: Option<f32>,
+    #[serde(default)]
+    pub percentual_fcp_st: Option<Decimal>,
 
     /// Valor do FCP retido por ST
     #[serde(rename = "$unflatten=vFCPST")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub valor_fcp_st: Option<f32>,
+    #[serde(default)]
+    pub valor_fcp_st: Option<Decimal>,
 
     /// Alíquota aplicável de cálculo do crédito
     #[serde(rename = "$unflatten=pCredSN")]
+    #[serde(with = "crate::base::decimal::percentual_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub aliquota_credito_sn: Option<f32>,
+    #[serde(default)]
+    pub aliquota_credito_sn: Option<Decimal>,
 
     /// Valor do crédito do ICMS
     #[serde(rename = "$unflatten=vCredICMSSN")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub valor_credito_icms_sn: Option<f32>,
+    #[serde(default)]
+    pub valor_credito_icms_sn: Option<Decimal>,
 }
@@ -23,9 +23,14 @@
 //! vICMSUFDest = Diferença de Alíquota (100% para UF destino desde 2019)
 //! vICMSUFRemet = 0 (0% para UF remetente desde 2019)
 //! ```
+//!
+//! O cálculo por base única ou base dupla (LC 190/2022) está em
+//! [`crate::base::calculo::calcular_difal`].
 
 use serde::{Deserialize, Serialize};
 
+use crate::base::decimal::Decimal;
+
 /// ICMS para UF de Destino - DIFAL (tag `<ICMSUFDest>`)
 ///
 /// Grupo de informações do ICMS Interestadual nas operações
@@ -34,60 +39,72 @@ use serde::{Deserialize, Serialize};
 pub struct IcmsUfDest {
     /// Valor da Base de Cálculo do ICMS na UF de destino (tag `<vBCUFDest>`)
     #[serde(rename = "$unflatten=vBCUFDest")]
-    pub valor_bc_uf_dest: f32,
+    #[serde(with = "crate::base::decimal::valor")]
+    pub valor_bc_uf_dest: Decimal,
 
     /// Valor da Base de Cálculo do FCP na UF de destino (tag `<vBCFCPUFDest>`)
     #[serde(rename = "$unflatten=vBCFCPUFDest")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub valor_bc_fcp_uf_dest: Option<f32>,
+    #[serde(default)]
+    pub valor_bc_fcp_uf_dest: Option<Decimal>,
 
     /// Percentual do ICMS relativo ao Fundo de Combate à Pobreza na UF de destino (tag `<pFCPUFDest>`)
     #[serde(rename = "$unflatten=pFCPUFDest")]
+    #[serde(with = "crate::base::decimal::percentual_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub percentual_fcp_uf_dest: Option<f32>,
+    #[serde(default)]
+    pub percentual_fcp_uf_dest: Option<Decimal>,
 
     /// Alíquota interna da UF de destino (tag `<pICMSUFDest>`)
     #[serde(rename = "$unflatten=pICMSUFDest")]
-    pub aliquota_uf_dest: f32,
+    #[serde(with = "crate::base::decimal::percentual")]
+    pub aliquota_uf_dest: Decimal,
 
     /// Alíquota interestadual das UFs envolvidas (tag `<pICMSInter>`)
     /// 4% para produtos importados (Resolução 13/2012)
     /// 7% ou 12% conforme UFs de origem e destino
     #[serde(rename = "$unflatten=pICMSInter")]
-    pub aliquota_interestadual: f32,
+    #[serde(with = "crate::base::decimal::percentual")]
+    pub aliquota_interestadual: Decimal,
 
     /// Percentual provisório de partilha do ICMS Interestadual (tag `<pICMSInterPart>`)
     /// Desde 2019: 100% para UF destino
     #[serde(rename = "$unflatten=pICMSInterPart")]
-    pub percentual_partilha: f32,
+    #[serde(with = "crate::base::decimal::percentual")]
+    pub percentual_partilha: Decimal,
 
     /// Valor do ICMS relativo ao FCP para a UF de destino (tag `<vFCPUFDest>`)
     #[serde(rename = "$unflatten=vFCPUFDest")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub valor_fcp_uf_dest: Option<f32>,
+    #[serde(default)]
+    pub valor_fcp_uf_dest: Option<Decimal>,
 
     /// Valor do ICMS Interestadual para a UF de destino (tag `<vICMSUFDest>`)
     #[serde(rename = "$unflatten=vICMSUFDest")]
-    pub valor_icms_uf_dest: f32,
+    #[serde(with = "crate::base::decimal::valor")]
+    pub valor_icms_uf_dest: Decimal,
 
     /// Valor do ICMS Interestadual para a UF do remetente (tag `<vICMSUFRemet>`)
     /// Desde 2019: sempre 0 (zero)
     #[serde(rename = "$unflatten=vICMSUFRemet")]
-    pub valor_icms_uf_remet: f32,
+    #[serde(with = "crate::base::decimal::valor")]
+    pub valor_icms_uf_remet: Decimal,
 }
 
 impl Default for IcmsUfDest {
     fn default() -> Self {
         Self {
-            valor_bc_uf_dest: 0.0,
+            valor_bc_uf_dest: Decimal::ZERO,
             valor_bc_fcp_uf_dest: None,
             percentual_fcp_uf_dest: None,
-            aliquota_uf_dest: 0.0,
-            aliquota_interestadual: 0.0,
-            percentual_partilha: 100.0, // 100% para UF destino desde 2019
+            aliquota_uf_dest: Decimal::ZERO,
+            aliquota_interestadual: Decimal::ZERO,
+            percentual_partilha: Decimal::ONE_HUNDRED, // 100% para UF destino desde 2019
             valor_fcp_uf_dest: None,
-            valor_icms_uf_dest: 0.0,
-            valor_icms_uf_remet: 0.0,
+            valor_icms_uf_dest: Decimal::ZERO,
+            valor_icms_uf_remet: Decimal::ZERO,
         }
     }
 }
@@ -19,6 +19,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::base::decimal::Decimal;
+
 /// Imposto de Importação (tag `<II>`)
 ///
 /// Obrigatório para operações de importação.
@@ -27,28 +29,32 @@ pub struct ImpostoImportacao {
     /// Valor da Base de Cálculo do II (tag `<vBC>`)
     /// Normalmente: valor aduaneiro + despesas aduaneiras
     #[serde(rename = "$unflatten=vBC")]
-    pub valor_bc: f32,
+    #[serde(with = "crate::base::decimal::valor")]
+    pub valor_bc: Decimal,
 
     /// Valor das despesas aduaneiras (tag `<vDespAdu>`)
     #[serde(rename = "$unflatten=vDespAdu")]
-    pub valor_despesas_aduaneiras: f32,
+    #[serde(with = "crate::base::decimal::valor")]
+    pub valor_despesas_aduaneiras: Decimal,
 
     /// Valor do Imposto de Importação (tag `<vII>`)
     #[serde(rename = "$unflatten=vII")]
-    pub valor: f32,
+    #[serde(with = "crate::base::decimal::valor")]
+    pub valor: Decimal,
 
     /// Valor do Imposto sobre Operações Financeiras (tag `<vIOF>`)
     #[serde(rename = "$unflatten=vIOF")]
-    pub valor_iof: f32,
+    #[serde(with = "crate::base::decimal::valor")]
+    pub valor_iof: Decimal,
 }
 
 impl Default for ImpostoImportacao {
     fn default() -> Self {
         Self {
-            valor_bc: 0.0,
-            valor_despesas_aduaneiras: 0.0,
-            valor: 0.0,
-            valor_iof: 0.0,
+            valor_bc: Decimal::ZERO,
+            valor_despesas_aduaneiras: Decimal::ZERO,
+            valor: Decimal::ZERO,
+            valor_iof: Decimal::ZERO,
         }
     }
 }
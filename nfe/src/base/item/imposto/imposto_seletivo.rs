@@ -0,0 +1,62 @@
+//! IS - Imposto Seletivo (NT 2025.002-RTC)
+//!
+//! Tributo federal sobre bens e serviços prejudiciais à saúde ou ao meio
+//! ambiente (LC 214/2025), cobrado a partir de 2027.
+//!
+//! ## Cálculo
+//!
+//! ```text
+//! vIS = vBCIS × pIS / 100                 (alíquota ad valorem)
+//! vIS = qTrib × pISEspec                  (alíquota específica)
+//! ```
+
+use serde::{Deserialize, Serialize};
+
+use crate::base::decimal::Decimal;
+
+/// Imposto Seletivo (tag `<IS>`)
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct ImpostoSeletivo {
+    /// Código de Situação Tributária do IS (tag `<CSTIS>`)
+    #[serde(rename = "$unflatten=CSTIS")]
+    pub cst: String,
+
+    /// Código de classificação tributária do IS (tag `<cClassTribIS>`)
+    #[serde(rename = "$unflatten=cClassTribIS")]
+    pub classificacao_tributaria: String,
+
+    /// Base de cálculo do IS (tag `<vBCIS>`)
+    #[serde(rename = "$unflatten=vBCIS")]
+    #[serde(with = "crate::base::decimal::valor")]
+    pub valor_bc: Decimal,
+
+    /// Alíquota ad valorem do IS (tag `<pIS>`)
+    #[serde(rename = "$unflatten=pIS")]
+    #[serde(with = "crate::base::decimal::percentual")]
+    pub aliquota: Decimal,
+
+    /// Alíquota específica por unidade (tag `<pISEspec>`)
+    #[serde(rename = "$unflatten=pISEspec")]
+    #[serde(with = "crate::base::decimal::quantidade_opcional")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub aliquota_especifica: Option<Decimal>,
+
+    /// Unidade de medida para a alíquota específica (tag `<uTrib>`)
+    #[serde(rename = "$unflatten=uTrib")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub unidade: Option<String>,
+
+    /// Quantidade na unidade da alíquota específica (tag `<qTrib>`)
+    #[serde(rename = "$unflatten=qTrib")]
+    #[serde(with = "crate::base::decimal::quantidade_opcional")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub quantidade: Option<Decimal>,
+
+    /// Valor do IS (tag `<vIS>`)
+    #[serde(rename = "$unflatten=vIS")]
+    #[serde(with = "crate::base::decimal::valor")]
+    pub valor: Decimal,
+}
@@ -24,6 +24,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::base::decimal::Decimal;
+
 /// Container para os grupos de IPI (tag `<IPI>`)
 ///
 /// O IPI é informado através de grupos exclusivos, onde apenas UM grupo
@@ -88,27 +90,36 @@ pub struct IpiTrib {
 
     /// Valor da Base de Cálculo do IPI (cálculo por alíquota)
     #[serde(rename = "$unflatten=vBC")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub valor_bc: Option<f32>,
+    #[serde(default)]
+    pub valor_bc: Option<Decimal>,
 
     /// Alíquota do IPI em percentual (cálculo por alíquota)
     #[serde(rename = "$unflatten=pIPI")]
+    #[serde(with = "crate::base::decimal::percentual_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub aliquota: Option<f32>,
+    #[serde(default)]
+    pub aliquota: Option<Decimal>,
 
     /// Quantidade total na unidade padrão para tributação (cálculo por quantidade)
     #[serde(rename = "$unflatten=qUnid")]
+    #[serde(with = "crate::base::decimal::quantidade_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub quantidade_unidade: Option<f32>,
+    #[serde(default)]
+    pub quantidade_unidade: Option<Decimal>,
 
     /// Valor por unidade tributável (cálculo por quantidade)
     #[serde(rename = "$unflatten=vUnid")]
+    #[serde(with = "crate::base::decimal::quantidade_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub valor_unidade: Option<f32>,
+    #[serde(default)]
+    pub valor_unidade: Option<Decimal>,
 
     /// Valor do IPI
     #[serde(rename = "$unflatten=vIPI")]
-    pub valor: f32,
+    #[serde(with = "crate::base::decimal::valor")]
+    pub valor: Decimal,
 }
 
 /// IPI Não Tributado (tag `<IPINT>`)
@@ -134,3 +145,10 @@ impl Default for IpiContainer {
         }
     }
 }
+
+impl IpiContainer {
+    /// Valor do IPI (zero quando não tributado)
+    pub fn valor_ipi(&self) -> Decimal {
+        self.ipi_trib.as_ref().map(|t| t.valor).unwrap_or_default()
+    }
+}
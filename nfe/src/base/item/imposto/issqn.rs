@@ -20,6 +20,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::base::decimal::Decimal;
+
 /// ISSQN - Imposto sobre Serviços (tag `<ISSQN>`)
 ///
 /// Informar apenas para itens que são serviços sujeitos ao ISS.
@@ -27,15 +29,18 @@ use serde::{Deserialize, Serialize};
 pub struct Issqn {
     /// Valor da Base de Cálculo do ISSQN (tag `<vBC>`)
     #[serde(rename = "$unflatten=vBC")]
-    pub valor_bc: f32,
+    #[serde(with = "crate::base::decimal::valor")]
+    pub valor_bc: Decimal,
 
     /// Alíquota do ISSQN em percentual (tag `<vAliq>`)
     #[serde(rename = "$unflatten=vAliq")]
-    pub aliquota: f32,
+    #[serde(with = "crate::base::decimal::percentual")]
+    pub aliquota: Decimal,
 
     /// Valor do ISSQN (tag `<vISSQN>`)
     #[serde(rename = "$unflatten=vISSQN")]
-    pub valor: f32,
+    #[serde(with = "crate::base::decimal::valor")]
+    pub valor: Decimal,
 
     /// Código do município de ocorrência do fato gerador (tag `<cMunFG>`)
     /// Código IBGE do município
@@ -49,28 +54,38 @@ pub struct Issqn {
 
     /// Valor dedução para redução da Base de Cálculo (tag `<vDeducao>`)
     #[serde(rename = "$unflatten=vDeducao")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub valor_deducao: Option<f32>,
+    #[serde(default)]
+    pub valor_deducao: Option<Decimal>,
 
     /// Valor outras retenções (tag `<vOutro>`)
     #[serde(rename = "$unflatten=vOutro")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub valor_outras_retencoes: Option<f32>,
+    #[serde(default)]
+    pub valor_outras_retencoes: Option<Decimal>,
 
     /// Valor desconto incondicionado (tag `<vDescIncond>`)
     #[serde(rename = "$unflatten=vDescIncond")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub valor_desconto_incondicionado: Option<f32>,
+    #[serde(default)]
+    pub valor_desconto_incondicionado: Option<Decimal>,
 
     /// Valor desconto condicionado (tag `<vDescCond>`)
     #[serde(rename = "$unflatten=vDescCond")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub valor_desconto_condicionado: Option<f32>,
+    #[serde(default)]
+    pub valor_desconto_condicionado: Option<Decimal>,
 
     /// Valor retenção ISS (tag `<vISSRet>`)
     #[serde(rename = "$unflatten=vISSRet")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub valor_retencao_iss: Option<f32>,
+    #[serde(default)]
+    pub valor_retencao_iss: Option<Decimal>,
 
     /// Indicador da exigibilidade do ISS (tag `<indISS>`)
     /// 1=Exigível, 2=Não incidência, 3=Isenção, 4=Exportação,
@@ -109,9 +124,9 @@ pub struct Issqn {
 impl Default for Issqn {
     fn default() -> Self {
         Self {
-            valor_bc: 0.0,
-            aliquota: 0.0,
-            valor: 0.0,
+            valor_bc: Decimal::ZERO,
+            aliquota: Decimal::ZERO,
+            valor: Decimal::ZERO,
             codigo_municipio_fato_gerador: String::new(),
             codigo_lista_servico: String::new(),
             valor_deducao: None,
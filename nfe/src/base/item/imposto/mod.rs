@@ -16,12 +16,16 @@
 //! | II | Imposto de Importação |
 //! | ISSQN | Imposto sobre Serviços de Qualquer Natureza |
 //! | ICMSUFDest | ICMS para UF de Destino (DIFAL - EC 87/2015) |
+//! | IS | Imposto Seletivo (LC 214/2025) |
+//! | IBSCBS | IBS e CBS da Reforma Tributária (LC 214/2025) |
 
 mod cofins;
 mod cofins_st;
+mod ibs_cbs;
 mod icms;
 mod icms_uf_dest;
 mod ii;
+mod imposto_seletivo;
 mod ipi;
 mod issqn;
 mod pis;
@@ -29,16 +33,20 @@ mod pis_st;
 
 pub use cofins::*;
 pub use cofins_st::*;
+pub use ibs_cbs::*;
 pub use icms::*;
 pub use icms_uf_dest::*;
 pub use ii::*;
+pub use imposto_seletivo::*;
 pub use ipi::*;
 pub use issqn::*;
 pub use pis::*;
 pub use pis_st::*;
 
 use super::Error;
+use crate::base::decimal::Decimal;
 use serde::{Deserialize, Serialize};
+use std::fmt;
 use std::str::FromStr;
 
 /// Impostos do item da nota fiscal
@@ -49,7 +57,7 @@ use std::str::FromStr;
 /// ## Exemplo de Uso
 ///
 /// ```rust
-/// use nfe_parser::Imposto;
+/// use nfe_parser::{CofinsContainer, IcmsContainer, Imposto, PisContainer};
 ///
 /// let imposto = Imposto {
 ///     icms: Some(IcmsContainer::default()),
@@ -58,13 +66,15 @@ use std::str::FromStr;
 ///     ..Default::default()
 /// };
 /// ```
-#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
 #[serde(rename = "imposto")]
 pub struct Imposto {
     /// Valor aproximado total de tributos (Lei da Transparência - Lei 12.741/2012)
     #[serde(rename = "$unflatten=vTotTrib")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub valor_aproximado_tributos: Option<f32>,
+    #[serde(default)]
+    pub valor_aproximado_tributos: Option<Decimal>,
 
     /// ICMS - Imposto sobre Circulação de Mercadorias e Serviços
     #[serde(rename = "ICMS")]
@@ -81,6 +91,11 @@ pub struct Imposto {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub ii: Option<ImpostoImportacao>,
 
+    /// ISSQN - Imposto sobre Serviços (substitui o ICMS para serviços)
+    #[serde(rename = "ISSQN")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub issqn: Option<Issqn>,
+
     /// PIS - Programa de Integração Social
     #[serde(rename = "PIS")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -101,15 +116,22 @@ pub struct Imposto {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cofins_st: Option<CofinsSt>,
 
-    /// ISSQN - Imposto sobre Serviços (substitui o ICMS para serviços)
-    #[serde(rename = "ISSQN")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub issqn: Option<Issqn>,
-
     /// ICMS para UF de Destino - DIFAL (operações interestaduais para consumidor final)
     #[serde(rename = "ICMSUFDest")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub icms_uf_dest: Option<IcmsUfDest>,
+
+    /// IS - Imposto Seletivo (a partir de 2027)
+    #[serde(rename = "IS")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub imposto_seletivo: Option<ImpostoSeletivo>,
+
+    /// IBS e CBS - Reforma Tributária do Consumo (a partir de 2026)
+    #[serde(rename = "IBSCBS")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub ibs_cbs: Option<IbsCbs>,
 }
 
 impl FromStr for Imposto {
@@ -120,25 +142,8 @@ impl FromStr for Imposto {
     }
 }
 
-impl ToString for Imposto {
-    fn to_string(&self) -> String {
-        quick_xml::se::to_string(self).expect("Falha ao serializar o imposto")
-    }
-}
-
-impl Default for Imposto {
-    fn default() -> Self {
-        Self {
-            valor_aproximado_tributos: None,
-            icms: None,
-            ipi: None,
-            ii: None,
-            pis: None,
-            pis_st: None,
-            cofins: None,
-            cofins_st: None,
-            issqn: None,
-            icms_uf_dest: None,
-        }
+impl fmt::Display for Imposto {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&quick_xml::se::to_string(self).expect("Falha ao serializar o imposto"))
     }
 }
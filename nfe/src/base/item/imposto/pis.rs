@@ -2,13 +2,20 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::base::decimal::Decimal;
+
 /// Container para os grupos de PIS
-#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Default)]
 pub struct PisContainer {
     /// PIS Alíquota - CST 01 e 02
     #[serde(rename = "PISAliq")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub pis_aliq: Option<PisAliq>,
+    /// PIS Quantidade - CST 03
+    #[serde(rename = "PISQtde")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub pis_qtde: Option<PisQtde>,
     /// PIS Não Tributado - CST 04, 05, 06, 07, 08 e 09
     #[serde(rename = "PISNT")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -19,6 +26,16 @@ pub struct PisContainer {
     pub pis_outr: Option<PisOutr>,
 }
 
+impl PisContainer {
+    /// Valor do PIS do grupo informado
+    pub fn valor(&self) -> Decimal {
+        self.pis_aliq.as_ref().map(|g| g.valor)
+            .or(self.pis_qtde.as_ref().map(|g| g.valor))
+            .or(self.pis_outr.as_ref().and_then(|g| g.valor))
+            .unwrap_or_default()
+    }
+}
+
 /// PIS Alíquota - Tributação por alíquota
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct PisAliq {
@@ -27,13 +44,36 @@ pub struct PisAliq {
     pub cst: String,
     /// Valor da Base de Cálculo do PIS
     #[serde(rename = "$unflatten=vBC")]
-    pub valor_bc: f32,
+    #[serde(with = "crate::base::decimal::valor")]
+    pub valor_bc: Decimal,
     /// Alíquota do PIS (em percentual)
     #[serde(rename = "$unflatten=pPIS")]
-    pub aliquota: f32,
+    #[serde(with = "crate::base::decimal::percentual")]
+    pub aliquota: Decimal,
     /// Valor do PIS
     #[serde(rename = "$unflatten=vPIS")]
-    pub valor: f32,
+    #[serde(with = "crate::base::decimal::valor")]
+    pub valor: Decimal,
+}
+
+/// PIS Quantidade - Tributação por unidade de produto
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct PisQtde {
+    /// Código de Situação Tributária do PIS
+    #[serde(rename = "$unflatten=CST")]
+    pub cst: String,
+    /// Quantidade vendida
+    #[serde(rename = "$unflatten=qBCProd")]
+    #[serde(with = "crate::base::decimal::quantidade")]
+    pub quantidade_bc: Decimal,
+    /// Alíquota do PIS (em reais por unidade)
+    #[serde(rename = "$unflatten=vAliqProd")]
+    #[serde(with = "crate::base::decimal::quantidade")]
+    pub aliquota_valor: Decimal,
+    /// Valor do PIS
+    #[serde(rename = "$unflatten=vPIS")]
+    #[serde(with = "crate::base::decimal::valor")]
+    pub valor: Decimal,
 }
 
 /// PIS Não Tributado
@@ -52,14 +92,32 @@ pub struct PisOutr {
     pub cst: String,
     /// Valor da Base de Cálculo do PIS
     #[serde(rename = "$unflatten=vBC")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub valor_bc: Option<f32>,
+    #[serde(default)]
+    pub valor_bc: Option<Decimal>,
     /// Alíquota do PIS (em percentual)
     #[serde(rename = "$unflatten=pPIS")]
+    #[serde(with = "crate::base::decimal::percentual_opcional")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub aliquota: Option<Decimal>,
+    /// Quantidade vendida (tributação por unidade)
+    #[serde(rename = "$unflatten=qBCProd")]
+    #[serde(with = "crate::base::decimal::quantidade_opcional")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub quantidade_bc: Option<Decimal>,
+    /// Alíquota do PIS em reais por unidade
+    #[serde(rename = "$unflatten=vAliqProd")]
+    #[serde(with = "crate::base::decimal::quantidade_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub aliquota: Option<f32>,
+    #[serde(default)]
+    pub aliquota_valor: Option<Decimal>,
     /// Valor do PIS
     #[serde(rename = "$unflatten=vPIS")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub valor: Option<f32>,
+    #[serde(default)]
+    pub valor: Option<Decimal>,
 }
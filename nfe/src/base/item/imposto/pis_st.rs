@@ -10,34 +10,45 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::base::decimal::Decimal;
+
 /// PIS Substituição Tributária (tag `<PISST>`)
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct PisSt {
     /// Valor da Base de Cálculo do PIS ST (tag `<vBC>`)
     #[serde(rename = "$unflatten=vBC")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub valor_bc: Option<f32>,
+    #[serde(default)]
+    pub valor_bc: Option<Decimal>,
 
     /// Alíquota do PIS ST em percentual (tag `<pPIS>`)
     #[serde(rename = "$unflatten=pPIS")]
+    #[serde(with = "crate::base::decimal::percentual_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub aliquota: Option<f32>,
+    #[serde(default)]
+    pub aliquota: Option<Decimal>,
 
     /// Quantidade vendida (tag `<qBCProd>`)
     /// Para cálculo por quantidade
     #[serde(rename = "$unflatten=qBCProd")]
+    #[serde(with = "crate::base::decimal::quantidade_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub quantidade_bc_produto: Option<f32>,
+    #[serde(default)]
+    pub quantidade_bc_produto: Option<Decimal>,
 
     /// Alíquota do PIS ST em reais (tag `<vAliqProd>`)
     /// Para cálculo por quantidade
     #[serde(rename = "$unflatten=vAliqProd")]
+    #[serde(with = "crate::base::decimal::quantidade_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub aliquota_produto: Option<f32>,
+    #[serde(default)]
+    pub aliquota_produto: Option<Decimal>,
 
     /// Valor do PIS ST (tag `<vPIS>`)
     #[serde(rename = "$unflatten=vPIS")]
-    pub valor: f32,
+    #[serde(with = "crate::base::decimal::valor")]
+    pub valor: Decimal,
 }
 
 impl Default for PisSt {
@@ -47,7 +58,7 @@ impl Default for PisSt {
             aliquota: None,
             quantidade_bc_produto: None,
             aliquota_produto: None,
-            valor: 0.0,
+            valor: Decimal::ZERO,
         }
     }
 }
@@ -5,11 +5,20 @@
 
 use super::Error;
 use serde::{Deserialize, Serialize};
+use std::fmt;
 use std::str::FromStr;
 
+mod devolucao;
+mod especifico;
+mod exportacao;
+mod importacao;
 pub mod imposto;
 mod produto;
 
+pub use devolucao::*;
+pub use especifico::*;
+pub use exportacao::*;
+pub use importacao::*;
 pub use imposto::*;
 pub use produto::*;
 
@@ -22,13 +31,18 @@ pub use produto::*;
 pub struct Item {
     /// Número sequencial do item na NF-e
     #[serde(rename = "nItem")]
-    pub numero: u8,
+    pub numero: u16,
     /// Dados do produto ou serviço
     #[serde(rename = "prod")]
     pub produto: Produto,
     /// Impostos incidentes sobre o produto
     #[serde(rename = "imposto")]
     pub imposto: Imposto,
+    /// Imposto devolvido (NF-e de devolução)
+    #[serde(rename = "impostoDevol")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub imposto_devolvido: Option<ImpostoDevolvido>,
 }
 
 impl FromStr for Item {
@@ -39,8 +53,8 @@ impl FromStr for Item {
     }
 }
 
-impl ToString for Item {
-    fn to_string(&self) -> String {
-        quick_xml::se::to_string(self).expect("Falha ao serializar o item")
+impl fmt::Display for Item {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&quick_xml::se::to_string(self).expect("Falha ao serializar o item"))
     }
 }
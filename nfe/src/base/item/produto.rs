@@ -1,8 +1,13 @@
 //! Produto da Nota Fiscal Eletrônica
 
-use super::Error;
+use super::{
+    Arma, Combustivel, DeclaracaoImportacao, DetalheExportacao, Error, Medicamento,
+    ProdutoEspecifico, Rastreabilidade, VeiculoNovo,
+};
+use crate::base::decimal::Decimal;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use serde_repr::{Deserialize_repr, Serialize_repr};
+use std::fmt;
 use std::str::FromStr;
 
 /// Produto da NFe
@@ -25,21 +30,29 @@ pub struct Produto {
     /// Unidade comercial
     pub unidade: String,
     /// Quantidade comercial
-    pub quantidade: f32,
+    pub quantidade: Decimal,
     /// Valor unitário comercial
-    pub valor_unitario: f32,
+    pub valor_unitario: Decimal,
     /// Valor bruto do produto
-    pub valor_bruto: f32,
+    pub valor_bruto: Decimal,
     /// Valor do frete
-    pub valor_frete: Option<f32>,
+    pub valor_frete: Option<Decimal>,
     /// Valor do seguro
-    pub valor_seguro: Option<f32>,
+    pub valor_seguro: Option<Decimal>,
     /// Valor do desconto
-    pub valor_desconto: Option<f32>,
+    pub valor_desconto: Option<Decimal>,
     /// Outras despesas acessórias
-    pub valor_outros: Option<f32>,
+    pub valor_outros: Option<Decimal>,
     /// Indica se o valor do produto compõe o total da NF-e
     pub valor_compoe_total_nota: bool,
+    /// Declarações de importação do item (tags `<DI>`)
+    pub declaracoes_importacao: Vec<DeclaracaoImportacao>,
+    /// Detalhes da exportação: drawback e exportação indireta (tags `<detExport>`)
+    pub exportacoes: Vec<DetalheExportacao>,
+    /// Lotes de produtos sujeitos à rastreabilidade (tags `<rastro>`)
+    pub rastreabilidade: Vec<Rastreabilidade>,
+    /// Grupo específico: veículo, medicamento, armamento, combustível ou RECOPI
+    pub especifico: Option<ProdutoEspecifico>,
 }
 
 /// Dados referentes a tributação do produto
@@ -60,9 +73,9 @@ pub struct ProdutoTributacao {
     /// Unidade tributável
     pub unidade: String,
     /// Quantidade tributável
-    pub quantidade: f32,
+    pub quantidade: Decimal,
     /// Valor unitário tributável
-    pub valor_unitario: f32,
+    pub valor_unitario: Decimal,
 }
 
 /// Indicador de Escala Relevante
@@ -83,9 +96,9 @@ impl FromStr for Produto {
     }
 }
 
-impl ToString for Produto {
-    fn to_string(&self) -> String {
-        quick_xml::se::to_string(self).expect("Falha ao serializar o produto")
+impl fmt::Display for Produto {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&quick_xml::se::to_string(self).expect("Falha ao serializar o produto"))
     }
 }
 
@@ -123,6 +136,16 @@ impl<'de> Deserialize<'de> for Produto {
             valor_desconto: prod.valor_desconto,
             valor_outros: prod.valor_outros,
             valor_compoe_total_nota: prod.valor_compoe_total_nota == 1,
+            declaracoes_importacao: prod.declaracoes_importacao,
+            exportacoes: prod.exportacoes,
+            rastreabilidade: prod.rastreabilidade,
+            especifico: ProdutoEspecifico::from_tags(
+                prod.veiculo,
+                prod.medicamento,
+                prod.armas,
+                prod.combustivel,
+                prod.recopi,
+            ),
             tributacao: ProdutoTributacao {
                 cest: prod.t_cest,
                 escala_relevante: prod.t_escala_relevante,
@@ -143,7 +166,7 @@ impl Serialize for Produto {
     where
         S: Serializer,
     {
-        let prod = ProdContainer {
+        let mut prod = ProdContainer {
             codigo: self.codigo.clone(),
             gtin: match &self.gtin {
                 Some(gt) => gt.clone(),
@@ -153,14 +176,22 @@ impl Serialize for Produto {
             ncm: self.ncm.clone(),
             fabricante_cnpj: self.fabricante_cnpj.clone(),
             unidade: self.unidade.clone(),
-            quantidade: self.quantidade.clone(),
-            valor_unitario: self.valor_unitario.clone(),
+            quantidade: self.quantidade,
+            valor_unitario: self.valor_unitario,
             valor_bruto: self.valor_bruto,
             valor_frete: self.valor_frete,
             valor_seguro: self.valor_seguro,
             valor_desconto: self.valor_desconto,
             valor_outros: self.valor_outros,
             valor_compoe_total_nota: if self.valor_compoe_total_nota { 1 } else { 0 },
+            declaracoes_importacao: self.declaracoes_importacao.clone(),
+            exportacoes: self.exportacoes.clone(),
+            rastreabilidade: self.rastreabilidade.clone(),
+            veiculo: None,
+            medicamento: None,
+            armas: Vec::new(),
+            combustivel: None,
+            recopi: None,
             t_cest: self.tributacao.cest.clone(),
             t_escala_relevante: self.tributacao.escala_relevante,
             t_codigo_beneficio_fiscal: self.tributacao.codigo_beneficio_fiscal.clone(),
@@ -175,6 +206,15 @@ impl Serialize for Produto {
             t_valor_unitario: self.tributacao.valor_unitario,
         };
 
+        match &self.especifico {
+            Some(ProdutoEspecifico::Veiculo(veiculo)) => prod.veiculo = Some(veiculo.clone()),
+            Some(ProdutoEspecifico::Medicamento(med)) => prod.medicamento = Some(med.clone()),
+            Some(ProdutoEspecifico::Armamentos(armas)) => prod.armas = armas.clone(),
+            Some(ProdutoEspecifico::Combustivel(comb)) => prod.combustivel = Some(comb.clone()),
+            Some(ProdutoEspecifico::Recopi(numero)) => prod.recopi = Some(numero.clone()),
+            None => {}
+        }
+
         prod.serialize(serializer)
     }
 }
@@ -182,71 +222,111 @@ impl Serialize for Produto {
 #[derive(Deserialize, Serialize)]
 #[serde(rename = "prod")]
 struct ProdContainer {
-    #[serde(rename = "cProd")]
+    #[serde(rename = "$unflatten=cProd")]
     pub codigo: String,
-    #[serde(rename = "cEAN")]
+    #[serde(rename = "$unflatten=cEAN")]
     pub gtin: String,
-    #[serde(rename = "xProd")]
+    #[serde(rename = "$unflatten=xProd")]
     pub descricao: String,
-    #[serde(rename = "NCM")]
+    #[serde(rename = "$unflatten=NCM")]
     pub ncm: String,
-    #[serde(rename = "CNPJFab")]
+    #[serde(rename = "$unflatten=CEST")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub t_cest: Option<String>,
+    #[serde(rename = "$unflatten=indEscala")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub t_escala_relevante: Option<EscalaRelevante>,
+    #[serde(rename = "$unflatten=CNPJFab")]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
     pub fabricante_cnpj: Option<String>,
-    #[serde(rename = "uCom")]
+    #[serde(rename = "$unflatten=cBenef")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub t_codigo_beneficio_fiscal: Option<String>,
+    #[serde(rename = "$unflatten=EXTIPI")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub t_codigo_excecao_ipi: Option<String>,
+    #[serde(rename = "$unflatten=CFOP")]
+    pub t_cfop: String,
+    #[serde(rename = "$unflatten=uCom")]
     pub unidade: String,
-    #[serde(rename = "qCom")]
-    pub quantidade: f32,
-    #[serde(rename = "vUnCom")]
-    pub valor_unitario: f32,
-    #[serde(rename = "vProd")]
-    pub valor_bruto: f32,
-    #[serde(rename = "vFrete")]
+    #[serde(rename = "$unflatten=qCom")]
+    #[serde(with = "crate::base::decimal::quantidade")]
+    pub quantidade: Decimal,
+    #[serde(rename = "$unflatten=vUnCom")]
+    #[serde(with = "crate::base::decimal::valor_unitario")]
+    pub valor_unitario: Decimal,
+    #[serde(rename = "$unflatten=vProd")]
+    #[serde(with = "crate::base::decimal::valor")]
+    pub valor_bruto: Decimal,
+    #[serde(rename = "$unflatten=cEANTrib")]
+    pub t_gtin: String,
+    #[serde(rename = "$unflatten=uTrib")]
+    pub t_unidade: String,
+    #[serde(rename = "$unflatten=qTrib")]
+    #[serde(with = "crate::base::decimal::quantidade")]
+    pub t_quantidade: Decimal,
+    #[serde(rename = "$unflatten=vUnTrib")]
+    #[serde(with = "crate::base::decimal::valor_unitario")]
+    pub t_valor_unitario: Decimal,
+    #[serde(rename = "$unflatten=vFrete")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
-    pub valor_frete: Option<f32>,
-    #[serde(rename = "vSeg")]
+    pub valor_frete: Option<Decimal>,
+    #[serde(rename = "$unflatten=vSeg")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
-    pub valor_seguro: Option<f32>,
-    #[serde(rename = "vDesc")]
+    pub valor_seguro: Option<Decimal>,
+    #[serde(rename = "$unflatten=vDesc")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
-    pub valor_desconto: Option<f32>,
-    #[serde(rename = "vOutro")]
+    pub valor_desconto: Option<Decimal>,
+    #[serde(rename = "$unflatten=vOutro")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
-    pub valor_outros: Option<f32>,
-    #[serde(rename = "indTot")]
+    pub valor_outros: Option<Decimal>,
+    #[serde(rename = "$unflatten=indTot")]
     pub valor_compoe_total_nota: u8,
-
-    #[serde(rename = "CEST")]
+    #[serde(rename = "DI")]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
+    pub declaracoes_importacao: Vec<DeclaracaoImportacao>,
+    #[serde(rename = "detExport")]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
+    pub exportacoes: Vec<DetalheExportacao>,
+    #[serde(rename = "rastro")]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
+    pub rastreabilidade: Vec<Rastreabilidade>,
+    #[serde(rename = "veicProd")]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
-    pub t_cest: Option<String>,
-    #[serde(rename = "indEscala")]
+    pub veiculo: Option<VeiculoNovo>,
+    #[serde(rename = "med")]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
-    pub t_escala_relevante: Option<EscalaRelevante>,
-    #[serde(rename = "cBenef")]
+    pub medicamento: Option<Medicamento>,
+    #[serde(rename = "arma")]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
+    pub armas: Vec<Arma>,
+    #[serde(rename = "comb")]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
-    pub t_codigo_beneficio_fiscal: Option<String>,
-    #[serde(rename = "EXTIPI")]
+    pub combustivel: Option<Combustivel>,
+    #[serde(rename = "$unflatten=nRECOPI")]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
-    pub t_codigo_excecao_ipi: Option<String>,
-    #[serde(rename = "CFOP")]
-    pub t_cfop: String,
-    #[serde(rename = "cEANTrib")]
-    pub t_gtin: String,
-    #[serde(rename = "uTrib")]
-    pub t_unidade: String,
-    #[serde(rename = "qTrib")]
-    pub t_quantidade: f32,
-    #[serde(rename = "vUnTrib")]
-    pub t_valor_unitario: f32,
+    pub recopi: Option<String>,
 }
 
 impl Produto {
@@ -262,15 +342,16 @@ impl Produto {
     /// * `quantidade` - Quantidade comercial
     /// * `valor_unitario` - Valor unitário
     /// * `valor_bruto` - Valor bruto total
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         codigo: String,
         descricao: String,
         ncm: String,
         cfop: String,
         unidade: String,
-        quantidade: f32,
-        valor_unitario: f32,
-        valor_bruto: f32,
+        quantidade: Decimal,
+        valor_unitario: Decimal,
+        valor_bruto: Decimal,
     ) -> Self {
         Produto {
             codigo,
@@ -298,6 +379,10 @@ impl Produto {
             valor_desconto: None,
             valor_outros: None,
             valor_compoe_total_nota: true,
+            declaracoes_importacao: Vec::new(),
+            exportacoes: Vec::new(),
+            rastreabilidade: Vec::new(),
+            especifico: None,
         }
     }
 }
@@ -16,7 +16,13 @@
 //!     <det>      <!-- Detalhamento de produtos/serviços (1 a 990 itens) -->
 //!     <total>    <!-- Totais da NF-e -->
 //!     <transp>   <!-- Transporte -->
+//!     <cobr>     <!-- Cobrança -->
+//!     <pag>      <!-- Pagamento -->
+//!     <infIntermed> <!-- Intermediador da transação -->
 //!     <infAdic>  <!-- Informações adicionais -->
+//!     <exporta>  <!-- Exportação -->
+//!     <compra>   <!-- Informações de compra -->
+//!     <infRespTec> <!-- Responsável técnico -->
 //!   </infNFe>
 //! </NFe>
 //! ```
@@ -30,28 +36,50 @@ use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::convert::TryFrom;
 use std::fs::File;
 use std::io::Read;
+use std::fmt;
 use std::str::FromStr;
 
 // Submódulos que compõem a estrutura da NF-e
+pub mod calculo;    // Calculadoras de impostos
+pub mod cobranca;   // Cobrança (fatura e duplicatas)
+pub mod decimal;    // Valores decimais de precisão fixa
 pub mod dest;       // Destinatário (comprador/cliente)
+pub mod documento;  // Documento de identificação (CNPJ, CPF, idEstrangeiro)
 pub mod emit;       // Emitente (vendedor/empresa)
 pub mod endereco;   // Endereço (usado por emit e dest)
+pub mod esquema;    // Validação offline contra os esquemas XSD (PL_009)
+pub mod exportacao; // Exportação e informações de compra
 mod error;          // Tipos de erro da biblioteca
 pub mod ide;        // Identificação da nota fiscal
+pub mod informacoes_adicionais; // Informações adicionais (infCpl, obsCont, procRef)
+pub mod intermediador; // Intermediador da transação (marketplace)
 pub mod item;       // Itens/produtos da nota
 pub mod municipios; // Tabela de municípios IBGE e configurações fiscais
 pub mod nfce;       // NFC-e - Nota Fiscal de Consumidor Eletrônica
 pub mod nfse;       // NFS-e - Nota Fiscal de Serviços Eletrônica
+pub mod pagamento;  // Formas de pagamento
+pub mod protocolo;  // NF-e processada e protocolo de autorização
+pub mod responsavel_tecnico; // Responsável técnico e hash do CSRT
 pub mod totais;     // Totalização de valores
 pub mod transporte; // Dados de transporte/frete
+pub mod validacao;  // Regras de negócio e códigos de rejeição da SEFAZ
+pub mod xml;        // Leitura de XML (namespaces, BOM, codificação)
 
+use cobranca::Cobranca;
 use dest::Destinatario;
 use emit::Emitente;
+use exportacao::{Compra, Exportacao};
 pub use error::Error;
 use ide::Identificacao;
+use informacoes_adicionais::InformacoesAdicionais;
+use intermediador::Intermediador;
 use item::Item;
-use totais::Totalizacao;
+use pagamento::Pagamento;
+use protocolo::NfeProc;
+use responsavel_tecnico::ResponsavelTecnico;
+use totais::{DivergenciaTotal, Totalizacao};
 use transporte::Transporte;
+use xml::ConteudoPreservado;
 
 /// Estrutura principal da Nota Fiscal Eletrônica (NF-e)
 ///
@@ -74,6 +102,13 @@ use transporte::Transporte;
 /// | itens | \<det\> | Lista de produtos (1 a 990) |
 /// | totais | \<total\> | Valores totalizados |
 /// | transporte | \<transp\> | Informações de frete |
+/// | cobranca | \<cobr\> | Fatura e duplicatas |
+/// | pagamento | \<pag\> | Formas de pagamento |
+/// | intermediador | \<infIntermed\> | Intermediador (marketplace) |
+/// | informacoes_adicionais | \<infAdic\> | Informações complementares e processos |
+/// | exportacao | \<exporta\> | Local de embarque na exportação |
+/// | compra | \<compra\> | Empenho, pedido e contrato |
+/// | responsavel_tecnico | \<infRespTec\> | Software house e hash do CSRT |
 ///
 /// ## Exemplo de Uso
 ///
@@ -120,9 +155,37 @@ pub struct Nfe {
     /// Contém: modalidade do frete (CIF/FOB), transportador, volumes, etc.
     pub transporte: Transporte,
 
-    /// Informações complementares de interesse do contribuinte (tag <infCpl>)
-    /// Campo de texto livre para observações adicionais
-    pub informacao_complementar: Option<String>,
+    /// Dados de cobrança (tag <cobr>)
+    /// Fatura e duplicatas das vendas a prazo
+    pub cobranca: Option<Cobranca>,
+
+    /// Informações de pagamento (tag <pag>)
+    /// Obrigatório no layout 4.00; opcional aqui para leitura de notas antigas
+    pub pagamento: Option<Pagamento>,
+
+    /// Intermediador da transação (tag <infIntermed>)
+    /// Obrigatório quando indIntermed = 1 (venda em plataforma de terceiros)
+    pub intermediador: Option<Intermediador>,
+
+    /// Informações adicionais (tag <infAdic>)
+    /// Texto complementar, observações campo/valor e processos referenciados
+    pub informacoes_adicionais: Option<InformacoesAdicionais>,
+
+    /// Informações de exportação (tag <exporta>)
+    /// Obrigatório nas saídas para o exterior (idDest = 3)
+    pub exportacao: Option<Exportacao>,
+
+    /// Informações de compra (tag <compra>)
+    /// Nota de empenho, pedido e contrato
+    pub compra: Option<Compra>,
+
+    /// Responsável técnico pelo sistema emissor (tag <infRespTec>)
+    /// Exigido por algumas UFs, com identificador e hash do CSRT
+    pub responsavel_tecnico: Option<ResponsavelTecnico>,
+
+    /// Assinatura e grupos não modelados do XML original
+    /// Preenchido apenas na leitura sem perdas ([`Nfe::parse_sem_perdas`])
+    pub preservado: Option<ConteudoPreservado>,
 }
 
 /// Versão do layout XML da NF-e conforme definido pela SEFAZ
@@ -150,11 +213,88 @@ pub enum VersaoLayout {
     V4_00 = 4,
 }
 
+impl Nfe {
+    /// Lê a NF-e a partir do conteúdo bruto de um arquivo
+    ///
+    /// Aceita arquivos com BOM e codificação ISO-8859-1 (ver [`xml`]).
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        xml::decodificar(bytes).parse()
+    }
+
+    /// Lê a NF-e no modo sem perdas
+    ///
+    /// Além dos grupos modelados, guarda o XML original de todo elemento, em
+    /// qualquer nível, que o modelo não representa (inclusive a assinatura e
+    /// o `<infNFeSupl>`), reemitindo-os em [`ToString::to_string`]. Enquanto
+    /// a nota não for alterada, o `<infNFe>` original é reemitido sem
+    /// alteração e a assinatura continua válida; após uma alteração, a
+    /// assinatura é descartada (ver [`xml`]).
+    /// Aceita a NF-e avulsa ou o XML de distribuição (`<nfeProc>`).
+    pub fn parse_sem_perdas(xml: &str) -> Result<Self, Error> {
+        let mut nfe: Nfe = xml.parse()?;
+        let modelo = quick_xml::se::to_string(&nfe)?;
+        nfe.preservado = Some(xml::preservar_nfe(xml, &modelo)?);
+
+        Ok(nfe)
+    }
+
+    /// Lê a NF-e no modo sem perdas a partir do conteúdo bruto de um arquivo
+    pub fn from_bytes_sem_perdas(bytes: &[u8]) -> Result<Self, Error> {
+        Self::parse_sem_perdas(&xml::decodificar(bytes))
+    }
+
+    /// Recalcula todos os totais (`<ICMSTot>`, `<ISSQNtot>`, `<ISTot>` e `<IBSCBSTot>`) a partir dos itens
+    ///
+    /// Ver [`Totalizacao::somar`]. A competência dos serviços é mantida ou,
+    /// se ausente, assume a data de emissão.
+    pub fn recalcular_totais(&mut self) {
+        let competencia = self.totais.servicos.as_ref().and_then(|s| s.data_competencia);
+
+        self.totais = Totalizacao::somar(&self.itens);
+        if let Some(servicos) = &mut self.totais.servicos {
+            servicos.data_competencia =
+                competencia.or_else(|| Some(self.ide.emissao.horario.date_naive()));
+        }
+    }
+
+    /// Confere os totais informados com o somatório dos itens
+    ///
+    /// Útil para validar XMLs recebidos de fornecedores: cada divergência
+    /// traz o código e o texto da rejeição correspondente da SEFAZ.
+    pub fn verificar_totais(&self) -> Vec<DivergenciaTotal> {
+        self.totais.divergencias(&self.itens)
+    }
+
+    /// Aplica todas as regras de negócio da SEFAZ (ver [`validacao`])
+    ///
+    /// Para escolher os conjuntos de regras ou a data de referência, use
+    /// [`validacao::ValidadorNfe`].
+    pub fn validar(&self) -> Result<(), Vec<validacao::Rejeicao>> {
+        validacao::ValidadorNfe::new().validar(self)
+    }
+
+    /// Valida o XML serializado (ver [`Nfe::to_string`]) contra os esquemas XSD
+    ///
+    /// A nota ainda não assinada é aceita (ver [`esquema::EsquemaXsd::validar_sem_assinatura`]).
+    pub fn validar_esquema(&self, esquema: &esquema::EsquemaXsd) -> Result<(), Vec<esquema::ViolacaoEsquema>> {
+        esquema.validar_sem_assinatura(&self.to_string())
+    }
+}
+
+/// Aceita tanto a NF-e avulsa (`<NFe>`) quanto o XML de distribuição
+/// (`<nfeProc>`), com namespace padrão ou prefixado
 impl FromStr for Nfe {
     type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        quick_xml::de::from_str(s).map_err(|e| e.into())
+        let documento = xml::normalizar(s)?;
+
+        if documento.raiz == "nfeProc" {
+            let proc: NfeProc = quick_xml::de::from_str(&documento.xml)?;
+            return Ok(proc.nfe);
+        }
+
+        quick_xml::de::from_str(&documento.xml).map_err(|e| e.into())
     }
 }
 
@@ -162,16 +302,24 @@ impl TryFrom<File> for Nfe {
     type Error = Error;
 
     fn try_from(mut f: File) -> Result<Self, Self::Error> {
-        let mut xml = String::new();
-        f.read_to_string(&mut xml).map_err(|e| Error::Io(e))?;
+        let mut bytes = Vec::new();
+        f.read_to_end(&mut bytes).map_err(Error::Io)?;
 
-        xml.parse::<Nfe>()
+        Nfe::from_bytes(&bytes)
     }
 }
 
-impl ToString for Nfe {
-    fn to_string(&self) -> String {
-        quick_xml::se::to_string(self).expect("Falha ao serializar a nota")
+impl fmt::Display for Nfe {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let xml = quick_xml::se::to_string(self).expect("Falha ao serializar a nota");
+
+        match &self.preservado {
+            Some(preservado) => {
+                let inalterada = preservado.inalterado(&xml);
+                f.write_str(&xml::reinserir(&xml, preservado, "NFe", inalterada))
+            }
+            None => f.write_str(&xml),
+        }
     }
 }
 
@@ -191,10 +339,14 @@ impl<'de> Deserialize<'de> for Nfe {
             itens: nfe.inf.itens,
             totais: nfe.inf.totais,
             transporte: nfe.inf.transporte,
-            informacao_complementar: match nfe.inf.add {
-                Some(add) => add.informacao_complementar,
-                None => None,
-            },
+            cobranca: nfe.inf.cobranca,
+            pagamento: nfe.inf.pagamento,
+            intermediador: nfe.inf.intermediador,
+            informacoes_adicionais: nfe.inf.informacoes_adicionais,
+            exportacao: nfe.inf.exportacao,
+            compra: nfe.inf.compra,
+            responsavel_tecnico: nfe.inf.responsavel_tecnico,
+            preservado: None,
         })
     }
 }
@@ -213,15 +365,19 @@ impl Serialize for Nfe {
             itens: self.itens.clone(),
             totais: self.totais.clone(),
             transporte: self.transporte.clone(),
-            add: match self.informacao_complementar.clone() {
-                Some(ic) => Some(InfAddContainer {
-                    informacao_complementar: Some(ic),
-                }),
-                None => None,
-            },
+            cobranca: self.cobranca.clone(),
+            pagamento: self.pagamento.clone(),
+            intermediador: self.intermediador.clone(),
+            informacoes_adicionais: self.informacoes_adicionais.clone(),
+            exportacao: self.exportacao.clone(),
+            compra: self.compra.clone(),
+            responsavel_tecnico: self.responsavel_tecnico.clone(),
         };
 
-        let root = NfeRootContainer { inf };
+        let root = NfeRootContainer {
+            xmlns: xml::NAMESPACE_NFE.to_string(),
+            inf,
+        };
 
         root.serialize(serializer)
     }
@@ -246,23 +402,16 @@ impl Serialize for VersaoLayout {
 #[derive(Deserialize, Serialize)]
 #[serde(rename = "NFe")]
 struct NfeRootContainer {
+    /// Namespace do Portal da NF-e (atributo @xmlns)
+    #[serde(rename = "xmlns")]
+    #[serde(default)]
+    pub xmlns: String,
+
     /// Elemento <infNFe> que contém todas as informações da nota
     #[serde(rename = "infNFe")]
     pub inf: NfeInfContainer,
 }
 
-/// Container para informações adicionais (tag <infAdic>)
-///
-/// Grupo opcional que pode conter informações complementares
-/// de interesse do contribuinte e do Fisco.
-#[derive(Deserialize, Serialize)]
-struct InfAddContainer {
-    /// Informações complementares de interesse do contribuinte
-    /// Tag: <infCpl> - Máximo 5000 caracteres
-    #[serde(rename = "$unflatten=infCpl")]
-    pub informacao_complementar: Option<String>,
-}
-
 /// Container para o elemento <infNFe> - Informações da NF-e
 ///
 /// Este container mapeia diretamente os atributos e elementos filhos
@@ -280,7 +429,13 @@ struct InfAddContainer {
 /// | \<det\> | itens | Itens/produtos (vetor) |
 /// | \<total\> | totais | Totalização |
 /// | \<transp\> | transporte | Transporte |
-/// | \<infAdic\> | add | Informações adicionais |
+/// | \<cobr\> | cobranca | Cobrança |
+/// | \<pag\> | pagamento | Pagamento |
+/// | \<infIntermed\> | intermediador | Intermediador da transação |
+/// | \<infAdic\> | informacoes_adicionais | Informações adicionais |
+/// | \<exporta\> | exportacao | Exportação |
+/// | \<compra\> | compra | Informações de compra |
+/// | \<infRespTec\> | responsavel_tecnico | Responsável técnico |
 #[derive(Deserialize, Serialize)]
 struct NfeInfContainer {
     /// Versão do layout (atributo @versao)
@@ -317,7 +472,45 @@ struct NfeInfContainer {
     #[serde(rename = "transp")]
     pub transporte: Transporte,
 
+    /// Grupo de cobrança (opcional)
+    #[serde(rename = "cobr")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub cobranca: Option<Cobranca>,
+
+    /// Grupo de informações de pagamento
+    #[serde(rename = "pag")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub pagamento: Option<Pagamento>,
+
+    /// Grupo do intermediador da transação (opcional)
+    #[serde(rename = "infIntermed")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub intermediador: Option<Intermediador>,
+
     /// Grupo de informações adicionais (opcional)
     #[serde(rename = "infAdic")]
-    pub add: Option<InfAddContainer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub informacoes_adicionais: Option<InformacoesAdicionais>,
+
+    /// Grupo de exportação (opcional)
+    #[serde(rename = "exporta")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub exportacao: Option<Exportacao>,
+
+    /// Grupo de informações de compra (opcional)
+    #[serde(rename = "compra")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub compra: Option<Compra>,
+
+    /// Grupo do responsável técnico (opcional)
+    #[serde(rename = "infRespTec")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub responsavel_tecnico: Option<ResponsavelTecnico>,
 }
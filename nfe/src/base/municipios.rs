@@ -6,6 +6,8 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+use super::decimal::Decimal;
+
 /// Informações de um município
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Municipio {
@@ -18,11 +20,11 @@ pub struct Municipio {
     /// Código da UF (2 dígitos)
     pub codigo_uf: u8,
     /// Alíquota padrão de ISS (%)
-    pub aliquota_iss_padrao: f32,
+    pub aliquota_iss_padrao: Decimal,
     /// Alíquota mínima de ISS (%)
-    pub aliquota_iss_minima: f32,
+    pub aliquota_iss_minima: Decimal,
     /// Alíquota máxima de ISS (%)
-    pub aliquota_iss_maxima: f32,
+    pub aliquota_iss_maxima: Decimal,
     /// Sistema de NFS-e utilizado
     pub sistema_nfse: Option<SistemaNfse>,
     /// URL do WebService de NFS-e (se disponível)
@@ -62,15 +64,15 @@ pub struct ConfiguracaoUf {
     /// Código da UF
     pub codigo: u8,
     /// Alíquota interna padrão de ICMS (%)
-    pub aliquota_icms_interna: f32,
+    pub aliquota_icms_interna: Decimal,
     /// Alíquota do FCP (Fundo de Combate à Pobreza) - se aplicável
-    pub aliquota_fcp: Option<f32>,
+    pub aliquota_fcp: Option<Decimal>,
     /// Alíquota interestadual para Sul/Sudeste (exceto ES)
-    pub aliquota_interestadual_sul_sudeste: f32,
+    pub aliquota_interestadual_sul_sudeste: Decimal,
     /// Alíquota interestadual para demais estados
-    pub aliquota_interestadual_demais: f32,
+    pub aliquota_interestadual_demais: Decimal,
     /// Alíquota para produtos importados (Resolução 13/2012)
-    pub aliquota_importados: f32,
+    pub aliquota_importados: Decimal,
 }
 
 /// Tabela de alíquotas de ISS por código de serviço (LC 116/2003)
@@ -81,7 +83,7 @@ pub struct AliquotaIss {
     /// Descrição do serviço
     pub descricao: String,
     /// Alíquota de ISS (%)
-    pub aliquota: f32,
+    pub aliquota: Decimal,
     /// ISS retido na fonte?
     pub retencao: bool,
 }
@@ -93,9 +95,9 @@ pub fn matao() -> Municipio {
         nome: "Matão".to_string(),
         uf: "SP".to_string(),
         codigo_uf: 35,
-        aliquota_iss_padrao: 5.0,
-        aliquota_iss_minima: 2.0,
-        aliquota_iss_maxima: 5.0,
+        aliquota_iss_padrao: Decimal::new(50, 1),
+        aliquota_iss_minima: Decimal::new(20, 1),
+        aliquota_iss_maxima: Decimal::new(50, 1),
         sistema_nfse: Some(SistemaNfse::GissOnline),
         url_nfse: Some("https://matao.gissdigital.com.br".to_string()),
     }
@@ -108,9 +110,9 @@ pub fn araraquara() -> Municipio {
         nome: "Araraquara".to_string(),
         uf: "SP".to_string(),
         codigo_uf: 35,
-        aliquota_iss_padrao: 5.0,
-        aliquota_iss_minima: 2.0,
-        aliquota_iss_maxima: 5.0,
+        aliquota_iss_padrao: Decimal::new(50, 1),
+        aliquota_iss_minima: Decimal::new(20, 1),
+        aliquota_iss_maxima: Decimal::new(50, 1),
         sistema_nfse: Some(SistemaNfse::GissOnline),
         url_nfse: Some("https://araraquara.gissdigital.com.br".to_string()),
     }
@@ -121,11 +123,11 @@ pub fn sao_paulo_uf() -> ConfiguracaoUf {
     ConfiguracaoUf {
         uf: "SP".to_string(),
         codigo: 35,
-        aliquota_icms_interna: 18.0,
+        aliquota_icms_interna: Decimal::new(180, 1),
         aliquota_fcp: None, // SP não tem FCP
-        aliquota_interestadual_sul_sudeste: 12.0,
-        aliquota_interestadual_demais: 7.0,
-        aliquota_importados: 4.0,
+        aliquota_interestadual_sul_sudeste: Decimal::new(120, 1),
+        aliquota_interestadual_demais: Decimal::new(70, 1),
+        aliquota_importados: Decimal::new(40, 1),
     }
 }
 
@@ -136,115 +138,115 @@ pub fn aliquotas_iss_matao() -> Vec<AliquotaIss> {
         AliquotaIss {
             codigo_servico: "7.02".to_string(),
             descricao: "Execução de obras de construção civil".to_string(),
-            aliquota: 3.0,
+            aliquota: Decimal::new(30, 1),
             retencao: true,
         },
         AliquotaIss {
             codigo_servico: "7.04".to_string(),
             descricao: "Demolição".to_string(),
-            aliquota: 3.0,
+            aliquota: Decimal::new(30, 1),
             retencao: true,
         },
         AliquotaIss {
             codigo_servico: "7.05".to_string(),
             descricao: "Reparação, conservação e reforma de edifícios".to_string(),
-            aliquota: 3.0,
+            aliquota: Decimal::new(30, 1),
             retencao: true,
         },
         AliquotaIss {
             codigo_servico: "7.19".to_string(),
             descricao: "Acompanhamento e fiscalização de obras".to_string(),
-            aliquota: 3.0,
+            aliquota: Decimal::new(30, 1),
             retencao: false,
         },
         // Informática
         AliquotaIss {
             codigo_servico: "1.01".to_string(),
             descricao: "Análise e desenvolvimento de sistemas".to_string(),
-            aliquota: 2.0,
+            aliquota: Decimal::new(20, 1),
             retencao: false,
         },
         AliquotaIss {
             codigo_servico: "1.02".to_string(),
             descricao: "Programação".to_string(),
-            aliquota: 2.0,
+            aliquota: Decimal::new(20, 1),
             retencao: false,
         },
         AliquotaIss {
             codigo_servico: "1.03".to_string(),
             descricao: "Processamento de dados".to_string(),
-            aliquota: 2.0,
+            aliquota: Decimal::new(20, 1),
             retencao: false,
         },
         AliquotaIss {
             codigo_servico: "1.04".to_string(),
             descricao: "Elaboração de programas de computadores".to_string(),
-            aliquota: 2.0,
+            aliquota: Decimal::new(20, 1),
             retencao: false,
         },
         AliquotaIss {
             codigo_servico: "1.05".to_string(),
             descricao: "Licenciamento de software".to_string(),
-            aliquota: 2.0,
+            aliquota: Decimal::new(20, 1),
             retencao: false,
         },
         // Saúde
         AliquotaIss {
             codigo_servico: "4.01".to_string(),
             descricao: "Medicina e biomedicina".to_string(),
-            aliquota: 3.0,
+            aliquota: Decimal::new(30, 1),
             retencao: false,
         },
         AliquotaIss {
             codigo_servico: "4.02".to_string(),
             descricao: "Análises clínicas".to_string(),
-            aliquota: 3.0,
+            aliquota: Decimal::new(30, 1),
             retencao: false,
         },
         AliquotaIss {
             codigo_servico: "4.03".to_string(),
             descricao: "Hospitais, clínicas e similares".to_string(),
-            aliquota: 3.0,
+            aliquota: Decimal::new(30, 1),
             retencao: false,
         },
         // Educação
         AliquotaIss {
             codigo_servico: "8.01".to_string(),
             descricao: "Ensino regular pré-escolar, fundamental, médio e superior".to_string(),
-            aliquota: 2.0,
+            aliquota: Decimal::new(20, 1),
             retencao: false,
         },
         AliquotaIss {
             codigo_servico: "8.02".to_string(),
             descricao: "Instrução, treinamento, cursos".to_string(),
-            aliquota: 3.0,
+            aliquota: Decimal::new(30, 1),
             retencao: false,
         },
         // Transporte
         AliquotaIss {
             codigo_servico: "16.01".to_string(),
             descricao: "Transporte de natureza municipal".to_string(),
-            aliquota: 5.0,
+            aliquota: Decimal::new(50, 1),
             retencao: false,
         },
         // Serviços gerais
         AliquotaIss {
             codigo_servico: "17.01".to_string(),
             descricao: "Assessoria ou consultoria".to_string(),
-            aliquota: 5.0,
+            aliquota: Decimal::new(50, 1),
             retencao: false,
         },
         AliquotaIss {
             codigo_servico: "17.02".to_string(),
             descricao: "Datilografia, digitação, estenografia".to_string(),
-            aliquota: 5.0,
+            aliquota: Decimal::new(50, 1),
             retencao: false,
         },
         // Exploração de rodovia
         AliquotaIss {
             codigo_servico: "22.01".to_string(),
             descricao: "Exploração de rodovia mediante cobrança de pedágio".to_string(),
-            aliquota: 3.0,
+            aliquota: Decimal::new(30, 1),
             retencao: false,
         },
     ]
@@ -257,102 +259,102 @@ pub fn aliquotas_iss_araraquara() -> Vec<AliquotaIss> {
         AliquotaIss {
             codigo_servico: "7.02".to_string(),
             descricao: "Execução de obras de construção civil".to_string(),
-            aliquota: 2.0, // Alíquota reduzida conforme LC 793
+            aliquota: Decimal::new(20, 1), // Alíquota reduzida conforme LC 793
             retencao: true,
         },
         AliquotaIss {
             codigo_servico: "7.04".to_string(),
             descricao: "Demolição".to_string(),
-            aliquota: 3.0,
+            aliquota: Decimal::new(30, 1),
             retencao: true,
         },
         AliquotaIss {
             codigo_servico: "7.05".to_string(),
             descricao: "Reparação, conservação e reforma de edifícios".to_string(),
-            aliquota: 3.0,
+            aliquota: Decimal::new(30, 1),
             retencao: true,
         },
         AliquotaIss {
             codigo_servico: "7.19".to_string(),
             descricao: "Acompanhamento e fiscalização de obras".to_string(),
-            aliquota: 2.0, // Alíquota reduzida conforme LC 793
+            aliquota: Decimal::new(20, 1), // Alíquota reduzida conforme LC 793
             retencao: false,
         },
         // Informática
         AliquotaIss {
             codigo_servico: "1.01".to_string(),
             descricao: "Análise e desenvolvimento de sistemas".to_string(),
-            aliquota: 2.0,
+            aliquota: Decimal::new(20, 1),
             retencao: false,
         },
         AliquotaIss {
             codigo_servico: "1.02".to_string(),
             descricao: "Programação".to_string(),
-            aliquota: 2.0,
+            aliquota: Decimal::new(20, 1),
             retencao: false,
         },
         AliquotaIss {
             codigo_servico: "1.03".to_string(),
             descricao: "Processamento de dados".to_string(),
-            aliquota: 2.0,
+            aliquota: Decimal::new(20, 1),
             retencao: false,
         },
         AliquotaIss {
             codigo_servico: "1.04".to_string(),
             descricao: "Elaboração de programas de computadores".to_string(),
-            aliquota: 2.0,
+            aliquota: Decimal::new(20, 1),
             retencao: false,
         },
         AliquotaIss {
             codigo_servico: "1.05".to_string(),
             descricao: "Licenciamento de software".to_string(),
-            aliquota: 2.0,
+            aliquota: Decimal::new(20, 1),
             retencao: false,
         },
         // Saúde
         AliquotaIss {
             codigo_servico: "4.01".to_string(),
             descricao: "Medicina e biomedicina".to_string(),
-            aliquota: 3.0,
+            aliquota: Decimal::new(30, 1),
             retencao: false,
         },
         AliquotaIss {
             codigo_servico: "4.02".to_string(),
             descricao: "Análises clínicas".to_string(),
-            aliquota: 3.0,
+            aliquota: Decimal::new(30, 1),
             retencao: false,
         },
         AliquotaIss {
             codigo_servico: "4.03".to_string(),
             descricao: "Hospitais, clínicas e similares".to_string(),
-            aliquota: 3.0,
+            aliquota: Decimal::new(30, 1),
             retencao: false,
         },
         // Educação
         AliquotaIss {
             codigo_servico: "8.01".to_string(),
             descricao: "Ensino regular".to_string(),
-            aliquota: 2.0,
+            aliquota: Decimal::new(20, 1),
             retencao: false,
         },
         AliquotaIss {
             codigo_servico: "8.02".to_string(),
             descricao: "Instrução, treinamento, cursos".to_string(),
-            aliquota: 3.0,
+            aliquota: Decimal::new(30, 1),
             retencao: false,
         },
         // Transporte
         AliquotaIss {
             codigo_servico: "16.01".to_string(),
             descricao: "Transporte de natureza municipal".to_string(),
-            aliquota: 5.0,
+            aliquota: Decimal::new(50, 1),
             retencao: false,
         },
         // Serviços gerais
         AliquotaIss {
             codigo_servico: "17.01".to_string(),
             descricao: "Assessoria ou consultoria".to_string(),
-            aliquota: 5.0,
+            aliquota: Decimal::new(50, 1),
             retencao: false,
         },
     ]
@@ -364,143 +366,143 @@ pub fn aliquotas_icms_por_uf() -> HashMap<String, ConfiguracaoUf> {
 
     // Região Norte
     mapa.insert("AC".to_string(), ConfiguracaoUf {
-        uf: "AC".to_string(), codigo: 12, aliquota_icms_interna: 19.0,
-        aliquota_fcp: Some(2.0), aliquota_interestadual_sul_sudeste: 12.0,
-        aliquota_interestadual_demais: 12.0, aliquota_importados: 4.0,
+        uf: "AC".to_string(), codigo: 12, aliquota_icms_interna: Decimal::new(190, 1),
+        aliquota_fcp: Some(Decimal::new(20, 1)), aliquota_interestadual_sul_sudeste: Decimal::new(120, 1),
+        aliquota_interestadual_demais: Decimal::new(120, 1), aliquota_importados: Decimal::new(40, 1),
     });
     mapa.insert("AM".to_string(), ConfiguracaoUf {
-        uf: "AM".to_string(), codigo: 13, aliquota_icms_interna: 20.0,
-        aliquota_fcp: Some(2.0), aliquota_interestadual_sul_sudeste: 12.0,
-        aliquota_interestadual_demais: 12.0, aliquota_importados: 4.0,
+        uf: "AM".to_string(), codigo: 13, aliquota_icms_interna: Decimal::new(200, 1),
+        aliquota_fcp: Some(Decimal::new(20, 1)), aliquota_interestadual_sul_sudeste: Decimal::new(120, 1),
+        aliquota_interestadual_demais: Decimal::new(120, 1), aliquota_importados: Decimal::new(40, 1),
     });
     mapa.insert("AP".to_string(), ConfiguracaoUf {
-        uf: "AP".to_string(), codigo: 16, aliquota_icms_interna: 18.0,
-        aliquota_fcp: None, aliquota_interestadual_sul_sudeste: 12.0,
-        aliquota_interestadual_demais: 12.0, aliquota_importados: 4.0,
+        uf: "AP".to_string(), codigo: 16, aliquota_icms_interna: Decimal::new(180, 1),
+        aliquota_fcp: None, aliquota_interestadual_sul_sudeste: Decimal::new(120, 1),
+        aliquota_interestadual_demais: Decimal::new(120, 1), aliquota_importados: Decimal::new(40, 1),
     });
     mapa.insert("PA".to_string(), ConfiguracaoUf {
-        uf: "PA".to_string(), codigo: 15, aliquota_icms_interna: 19.0,
-        aliquota_fcp: Some(2.0), aliquota_interestadual_sul_sudeste: 12.0,
-        aliquota_interestadual_demais: 12.0, aliquota_importados: 4.0,
+        uf: "PA".to_string(), codigo: 15, aliquota_icms_interna: Decimal::new(190, 1),
+        aliquota_fcp: Some(Decimal::new(20, 1)), aliquota_interestadual_sul_sudeste: Decimal::new(120, 1),
+        aliquota_interestadual_demais: Decimal::new(120, 1), aliquota_importados: Decimal::new(40, 1),
     });
     mapa.insert("RO".to_string(), ConfiguracaoUf {
-        uf: "RO".to_string(), codigo: 11, aliquota_icms_interna: 19.5,
-        aliquota_fcp: Some(2.0), aliquota_interestadual_sul_sudeste: 12.0,
-        aliquota_interestadual_demais: 12.0, aliquota_importados: 4.0,
+        uf: "RO".to_string(), codigo: 11, aliquota_icms_interna: Decimal::new(195, 1),
+        aliquota_fcp: Some(Decimal::new(20, 1)), aliquota_interestadual_sul_sudeste: Decimal::new(120, 1),
+        aliquota_interestadual_demais: Decimal::new(120, 1), aliquota_importados: Decimal::new(40, 1),
     });
     mapa.insert("RR".to_string(), ConfiguracaoUf {
-        uf: "RR".to_string(), codigo: 14, aliquota_icms_interna: 20.0,
-        aliquota_fcp: Some(2.0), aliquota_interestadual_sul_sudeste: 12.0,
-        aliquota_interestadual_demais: 12.0, aliquota_importados: 4.0,
+        uf: "RR".to_string(), codigo: 14, aliquota_icms_interna: Decimal::new(200, 1),
+        aliquota_fcp: Some(Decimal::new(20, 1)), aliquota_interestadual_sul_sudeste: Decimal::new(120, 1),
+        aliquota_interestadual_demais: Decimal::new(120, 1), aliquota_importados: Decimal::new(40, 1),
     });
     mapa.insert("TO".to_string(), ConfiguracaoUf {
-        uf: "TO".to_string(), codigo: 17, aliquota_icms_interna: 20.0,
-        aliquota_fcp: Some(2.0), aliquota_interestadual_sul_sudeste: 12.0,
-        aliquota_interestadual_demais: 12.0, aliquota_importados: 4.0,
+        uf: "TO".to_string(), codigo: 17, aliquota_icms_interna: Decimal::new(200, 1),
+        aliquota_fcp: Some(Decimal::new(20, 1)), aliquota_interestadual_sul_sudeste: Decimal::new(120, 1),
+        aliquota_interestadual_demais: Decimal::new(120, 1), aliquota_importados: Decimal::new(40, 1),
     });
 
     // Região Nordeste
     mapa.insert("AL".to_string(), ConfiguracaoUf {
-        uf: "AL".to_string(), codigo: 27, aliquota_icms_interna: 19.0,
-        aliquota_fcp: Some(2.0), aliquota_interestadual_sul_sudeste: 12.0,
-        aliquota_interestadual_demais: 12.0, aliquota_importados: 4.0,
+        uf: "AL".to_string(), codigo: 27, aliquota_icms_interna: Decimal::new(190, 1),
+        aliquota_fcp: Some(Decimal::new(20, 1)), aliquota_interestadual_sul_sudeste: Decimal::new(120, 1),
+        aliquota_interestadual_demais: Decimal::new(120, 1), aliquota_importados: Decimal::new(40, 1),
     });
     mapa.insert("BA".to_string(), ConfiguracaoUf {
-        uf: "BA".to_string(), codigo: 29, aliquota_icms_interna: 20.5,
-        aliquota_fcp: Some(2.0), aliquota_interestadual_sul_sudeste: 12.0,
-        aliquota_interestadual_demais: 12.0, aliquota_importados: 4.0,
+        uf: "BA".to_string(), codigo: 29, aliquota_icms_interna: Decimal::new(205, 1),
+        aliquota_fcp: Some(Decimal::new(20, 1)), aliquota_interestadual_sul_sudeste: Decimal::new(120, 1),
+        aliquota_interestadual_demais: Decimal::new(120, 1), aliquota_importados: Decimal::new(40, 1),
     });
     mapa.insert("CE".to_string(), ConfiguracaoUf {
-        uf: "CE".to_string(), codigo: 23, aliquota_icms_interna: 20.0,
-        aliquota_fcp: Some(2.0), aliquota_interestadual_sul_sudeste: 12.0,
-        aliquota_interestadual_demais: 12.0, aliquota_importados: 4.0,
+        uf: "CE".to_string(), codigo: 23, aliquota_icms_interna: Decimal::new(200, 1),
+        aliquota_fcp: Some(Decimal::new(20, 1)), aliquota_interestadual_sul_sudeste: Decimal::new(120, 1),
+        aliquota_interestadual_demais: Decimal::new(120, 1), aliquota_importados: Decimal::new(40, 1),
     });
     mapa.insert("MA".to_string(), ConfiguracaoUf {
-        uf: "MA".to_string(), codigo: 21, aliquota_icms_interna: 22.0,
-        aliquota_fcp: Some(2.0), aliquota_interestadual_sul_sudeste: 12.0,
-        aliquota_interestadual_demais: 12.0, aliquota_importados: 4.0,
+        uf: "MA".to_string(), codigo: 21, aliquota_icms_interna: Decimal::new(220, 1),
+        aliquota_fcp: Some(Decimal::new(20, 1)), aliquota_interestadual_sul_sudeste: Decimal::new(120, 1),
+        aliquota_interestadual_demais: Decimal::new(120, 1), aliquota_importados: Decimal::new(40, 1),
     });
     mapa.insert("PB".to_string(), ConfiguracaoUf {
-        uf: "PB".to_string(), codigo: 25, aliquota_icms_interna: 20.0,
-        aliquota_fcp: Some(2.0), aliquota_interestadual_sul_sudeste: 12.0,
-        aliquota_interestadual_demais: 12.0, aliquota_importados: 4.0,
+        uf: "PB".to_string(), codigo: 25, aliquota_icms_interna: Decimal::new(200, 1),
+        aliquota_fcp: Some(Decimal::new(20, 1)), aliquota_interestadual_sul_sudeste: Decimal::new(120, 1),
+        aliquota_interestadual_demais: Decimal::new(120, 1), aliquota_importados: Decimal::new(40, 1),
     });
     mapa.insert("PE".to_string(), ConfiguracaoUf {
-        uf: "PE".to_string(), codigo: 26, aliquota_icms_interna: 20.5,
-        aliquota_fcp: Some(2.0), aliquota_interestadual_sul_sudeste: 12.0,
-        aliquota_interestadual_demais: 12.0, aliquota_importados: 4.0,
+        uf: "PE".to_string(), codigo: 26, aliquota_icms_interna: Decimal::new(205, 1),
+        aliquota_fcp: Some(Decimal::new(20, 1)), aliquota_interestadual_sul_sudeste: Decimal::new(120, 1),
+        aliquota_interestadual_demais: Decimal::new(120, 1), aliquota_importados: Decimal::new(40, 1),
     });
     mapa.insert("PI".to_string(), ConfiguracaoUf {
-        uf: "PI".to_string(), codigo: 22, aliquota_icms_interna: 21.0,
-        aliquota_fcp: Some(2.0), aliquota_interestadual_sul_sudeste: 12.0,
-        aliquota_interestadual_demais: 12.0, aliquota_importados: 4.0,
+        uf: "PI".to_string(), codigo: 22, aliquota_icms_interna: Decimal::new(210, 1),
+        aliquota_fcp: Some(Decimal::new(20, 1)), aliquota_interestadual_sul_sudeste: Decimal::new(120, 1),
+        aliquota_interestadual_demais: Decimal::new(120, 1), aliquota_importados: Decimal::new(40, 1),
     });
     mapa.insert("RN".to_string(), ConfiguracaoUf {
-        uf: "RN".to_string(), codigo: 24, aliquota_icms_interna: 20.0,
-        aliquota_fcp: Some(2.0), aliquota_interestadual_sul_sudeste: 12.0,
-        aliquota_interestadual_demais: 12.0, aliquota_importados: 4.0,
+        uf: "RN".to_string(), codigo: 24, aliquota_icms_interna: Decimal::new(200, 1),
+        aliquota_fcp: Some(Decimal::new(20, 1)), aliquota_interestadual_sul_sudeste: Decimal::new(120, 1),
+        aliquota_interestadual_demais: Decimal::new(120, 1), aliquota_importados: Decimal::new(40, 1),
     });
     mapa.insert("SE".to_string(), ConfiguracaoUf {
-        uf: "SE".to_string(), codigo: 28, aliquota_icms_interna: 19.0,
-        aliquota_fcp: Some(2.0), aliquota_interestadual_sul_sudeste: 12.0,
-        aliquota_interestadual_demais: 12.0, aliquota_importados: 4.0,
+        uf: "SE".to_string(), codigo: 28, aliquota_icms_interna: Decimal::new(190, 1),
+        aliquota_fcp: Some(Decimal::new(20, 1)), aliquota_interestadual_sul_sudeste: Decimal::new(120, 1),
+        aliquota_interestadual_demais: Decimal::new(120, 1), aliquota_importados: Decimal::new(40, 1),
     });
 
     // Região Centro-Oeste
     mapa.insert("DF".to_string(), ConfiguracaoUf {
-        uf: "DF".to_string(), codigo: 53, aliquota_icms_interna: 20.0,
-        aliquota_fcp: Some(2.0), aliquota_interestadual_sul_sudeste: 12.0,
-        aliquota_interestadual_demais: 12.0, aliquota_importados: 4.0,
+        uf: "DF".to_string(), codigo: 53, aliquota_icms_interna: Decimal::new(200, 1),
+        aliquota_fcp: Some(Decimal::new(20, 1)), aliquota_interestadual_sul_sudeste: Decimal::new(120, 1),
+        aliquota_interestadual_demais: Decimal::new(120, 1), aliquota_importados: Decimal::new(40, 1),
     });
     mapa.insert("GO".to_string(), ConfiguracaoUf {
-        uf: "GO".to_string(), codigo: 52, aliquota_icms_interna: 19.0,
-        aliquota_fcp: Some(2.0), aliquota_interestadual_sul_sudeste: 12.0,
-        aliquota_interestadual_demais: 12.0, aliquota_importados: 4.0,
+        uf: "GO".to_string(), codigo: 52, aliquota_icms_interna: Decimal::new(190, 1),
+        aliquota_fcp: Some(Decimal::new(20, 1)), aliquota_interestadual_sul_sudeste: Decimal::new(120, 1),
+        aliquota_interestadual_demais: Decimal::new(120, 1), aliquota_importados: Decimal::new(40, 1),
     });
     mapa.insert("MS".to_string(), ConfiguracaoUf {
-        uf: "MS".to_string(), codigo: 50, aliquota_icms_interna: 17.0,
-        aliquota_fcp: Some(2.0), aliquota_interestadual_sul_sudeste: 12.0,
-        aliquota_interestadual_demais: 12.0, aliquota_importados: 4.0,
+        uf: "MS".to_string(), codigo: 50, aliquota_icms_interna: Decimal::new(170, 1),
+        aliquota_fcp: Some(Decimal::new(20, 1)), aliquota_interestadual_sul_sudeste: Decimal::new(120, 1),
+        aliquota_interestadual_demais: Decimal::new(120, 1), aliquota_importados: Decimal::new(40, 1),
     });
     mapa.insert("MT".to_string(), ConfiguracaoUf {
-        uf: "MT".to_string(), codigo: 51, aliquota_icms_interna: 17.0,
-        aliquota_fcp: Some(2.0), aliquota_interestadual_sul_sudeste: 12.0,
-        aliquota_interestadual_demais: 12.0, aliquota_importados: 4.0,
+        uf: "MT".to_string(), codigo: 51, aliquota_icms_interna: Decimal::new(170, 1),
+        aliquota_fcp: Some(Decimal::new(20, 1)), aliquota_interestadual_sul_sudeste: Decimal::new(120, 1),
+        aliquota_interestadual_demais: Decimal::new(120, 1), aliquota_importados: Decimal::new(40, 1),
     });
 
     // Região Sudeste
     mapa.insert("ES".to_string(), ConfiguracaoUf {
-        uf: "ES".to_string(), codigo: 32, aliquota_icms_interna: 17.0,
-        aliquota_fcp: Some(2.0), aliquota_interestadual_sul_sudeste: 12.0,
-        aliquota_interestadual_demais: 12.0, aliquota_importados: 4.0,
+        uf: "ES".to_string(), codigo: 32, aliquota_icms_interna: Decimal::new(170, 1),
+        aliquota_fcp: Some(Decimal::new(20, 1)), aliquota_interestadual_sul_sudeste: Decimal::new(120, 1),
+        aliquota_interestadual_demais: Decimal::new(120, 1), aliquota_importados: Decimal::new(40, 1),
     });
     mapa.insert("MG".to_string(), ConfiguracaoUf {
-        uf: "MG".to_string(), codigo: 31, aliquota_icms_interna: 18.0,
-        aliquota_fcp: Some(2.0), aliquota_interestadual_sul_sudeste: 12.0,
-        aliquota_interestadual_demais: 7.0, aliquota_importados: 4.0,
+        uf: "MG".to_string(), codigo: 31, aliquota_icms_interna: Decimal::new(180, 1),
+        aliquota_fcp: Some(Decimal::new(20, 1)), aliquota_interestadual_sul_sudeste: Decimal::new(120, 1),
+        aliquota_interestadual_demais: Decimal::new(70, 1), aliquota_importados: Decimal::new(40, 1),
     });
     mapa.insert("RJ".to_string(), ConfiguracaoUf {
-        uf: "RJ".to_string(), codigo: 33, aliquota_icms_interna: 22.0,
-        aliquota_fcp: Some(2.0), aliquota_interestadual_sul_sudeste: 12.0,
-        aliquota_interestadual_demais: 7.0, aliquota_importados: 4.0,
+        uf: "RJ".to_string(), codigo: 33, aliquota_icms_interna: Decimal::new(220, 1),
+        aliquota_fcp: Some(Decimal::new(20, 1)), aliquota_interestadual_sul_sudeste: Decimal::new(120, 1),
+        aliquota_interestadual_demais: Decimal::new(70, 1), aliquota_importados: Decimal::new(40, 1),
     });
     mapa.insert("SP".to_string(), sao_paulo_uf());
 
     // Região Sul
     mapa.insert("PR".to_string(), ConfiguracaoUf {
-        uf: "PR".to_string(), codigo: 41, aliquota_icms_interna: 19.5,
-        aliquota_fcp: Some(2.0), aliquota_interestadual_sul_sudeste: 12.0,
-        aliquota_interestadual_demais: 7.0, aliquota_importados: 4.0,
+        uf: "PR".to_string(), codigo: 41, aliquota_icms_interna: Decimal::new(195, 1),
+        aliquota_fcp: Some(Decimal::new(20, 1)), aliquota_interestadual_sul_sudeste: Decimal::new(120, 1),
+        aliquota_interestadual_demais: Decimal::new(70, 1), aliquota_importados: Decimal::new(40, 1),
     });
     mapa.insert("RS".to_string(), ConfiguracaoUf {
-        uf: "RS".to_string(), codigo: 43, aliquota_icms_interna: 17.0,
-        aliquota_fcp: Some(2.0), aliquota_interestadual_sul_sudeste: 12.0,
-        aliquota_interestadual_demais: 7.0, aliquota_importados: 4.0,
+        uf: "RS".to_string(), codigo: 43, aliquota_icms_interna: Decimal::new(170, 1),
+        aliquota_fcp: Some(Decimal::new(20, 1)), aliquota_interestadual_sul_sudeste: Decimal::new(120, 1),
+        aliquota_interestadual_demais: Decimal::new(70, 1), aliquota_importados: Decimal::new(40, 1),
     });
     mapa.insert("SC".to_string(), ConfiguracaoUf {
-        uf: "SC".to_string(), codigo: 42, aliquota_icms_interna: 17.0,
-        aliquota_fcp: None, aliquota_interestadual_sul_sudeste: 12.0,
-        aliquota_interestadual_demais: 7.0, aliquota_importados: 4.0,
+        uf: "SC".to_string(), codigo: 42, aliquota_icms_interna: Decimal::new(170, 1),
+        aliquota_fcp: None, aliquota_interestadual_sul_sudeste: Decimal::new(120, 1),
+        aliquota_interestadual_demais: Decimal::new(70, 1), aliquota_importados: Decimal::new(40, 1),
     });
 
     mapa
@@ -522,7 +524,7 @@ pub fn buscar_uf(sigla: &str) -> Option<ConfiguracaoUf> {
 }
 
 /// Calcula a alíquota interestadual entre duas UFs
-pub fn calcular_aliquota_interestadual(uf_origem: &str, uf_destino: &str) -> f32 {
+pub fn calcular_aliquota_interestadual(uf_origem: &str, uf_destino: &str) -> Decimal {
     // Sul e Sudeste (exceto ES) para demais estados: 7%
     // Demais combinações: 12%
     // Importados: 4%
@@ -532,9 +534,9 @@ pub fn calcular_aliquota_interestadual(uf_origem: &str, uf_destino: &str) -> f32
     let destino_sul_sudeste = sul_sudeste.contains(&uf_destino);
 
     if origem_sul_sudeste && !destino_sul_sudeste {
-        7.0
+        Decimal::new(7, 0)
     } else {
-        12.0
+        Decimal::new(12, 0)
     }
 }
 
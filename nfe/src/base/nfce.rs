@@ -3,9 +3,13 @@
 //! Este módulo contém estruturas e funções específicas para NFC-e,
 //! incluindo geração de QR Code e validações específicas.
 
-use serde::{Deserialize, Serialize};
+use serde::de;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use sha1::{Sha1, Digest};
 
+use super::decimal::{formatar, Decimal, CASAS_VALOR};
+use super::validacao::{validar_cfop_nfce, validar_dados_nfce, validar_quantidade_itens};
+
 /// Dados para geração do QR Code da NFC-e
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct QrCodeNfce {
@@ -78,12 +82,12 @@ impl QrCodeNfce {
     /// Gera o conteúdo do QR Code para NFC-e em contingência offline
     ///
     /// Em contingência, inclui informações adicionais do digest do XML
-    pub fn gerar_url_contingencia(&self, digest_value: &str, data_emissao: &str, valor_total: f32) -> String {
+    pub fn gerar_url_contingencia(&self, digest_value: &str, data_emissao: &str, valor_total: Decimal) -> String {
         let versao_qrcode = "2";
 
         // Em contingência: chave|versao|ambiente|dia_emissao|valor|digest|csc
         let dia = &data_emissao[8..10];
-        let valor_str = format!("{:.2}", valor_total);
+        let valor_str = formatar(valor_total, CASAS_VALOR, CASAS_VALOR);
 
         let dados_hash = format!(
             "{}|{}|{}|{}|{}|{}|{}",
@@ -128,44 +132,27 @@ pub struct ValidadorNfce;
 
 impl ValidadorNfce {
     /// Valida se a NFC-e atende aos requisitos do modelo 65
+    ///
+    /// Aplica as mesmas regras do conjunto [`ConjuntoRegras::Nfce`] do
+    /// [`ValidadorNfe`](crate::base::validacao::ValidadorNfe), mais o limite
+    /// de itens; cada erro é o texto da [`Rejeicao`](crate::base::validacao::Rejeicao).
+    ///
+    /// [`ConjuntoRegras::Nfce`]: crate::base::validacao::ConjuntoRegras::Nfce
     pub fn validar(
         modelo: u8,
-        valor_total: f32,
+        valor_total: Decimal,
         qtd_itens: usize,
         tem_destinatario: bool,
         cfop: &str,
     ) -> Result<(), Vec<String>> {
-        let mut erros = Vec::new();
-
-        // Deve ser modelo 65
-        if modelo != 65 {
-            erros.push("NFC-e deve usar modelo 65".to_string());
-        }
-
-        // Limite de 990 itens
-        if qtd_itens > 990 {
-            erros.push(format!("NFC-e permite no máximo 990 itens (encontrado: {})", qtd_itens));
-        }
-
-        // Destinatário obrigatório para valores acima de R$ 10.000
-        if valor_total > 10000.0 && !tem_destinatario {
-            erros.push("Destinatário é obrigatório para NFC-e com valor acima de R$ 10.000,00".to_string());
-        }
-
-        // CFOP deve ser de saída (5xxx ou 6xxx para venda)
-        if !cfop.starts_with('5') && !cfop.starts_with('6') {
-            erros.push(format!("CFOP {} não permitido para NFC-e (use CFOP de saída)", cfop));
-        }
-
-        // NFC-e não permite CFOP de entrada (1xxx, 2xxx, 3xxx)
-        if cfop.starts_with('1') || cfop.starts_with('2') || cfop.starts_with('3') {
-            erros.push("NFC-e não permite CFOP de entrada".to_string());
-        }
+        let mut rejeicoes = validar_dados_nfce(modelo, valor_total, tem_destinatario);
+        rejeicoes.extend(validar_cfop_nfce(cfop));
+        rejeicoes.extend(validar_quantidade_itens(qtd_itens));
 
-        if erros.is_empty() {
+        if rejeicoes.is_empty() {
             Ok(())
         } else {
-            Err(erros)
+            Err(rejeicoes.iter().map(|r| r.to_string()).collect())
         }
     }
 
@@ -234,8 +221,11 @@ pub enum ModoEmissaoNfce {
     ContingenciaOffline = 9,
 }
 
-/// Formas de pagamento aceitas em NFC-e
-#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+/// Formas de pagamento aceitas em NFC-e (tag `<tPag>`)
+///
+/// Também usada no grupo `<detPag>` da NF-e modelo 55. No XML o código
+/// é sempre informado com dois dígitos (ex: `01`, `17`, `99`).
+#[derive(Debug, Clone, Copy, PartialEq)]
 #[repr(u8)]
 pub enum FormaPagamentoNfce {
     Dinheiro = 1,
@@ -257,6 +247,34 @@ pub enum FormaPagamentoNfce {
 }
 
 impl FormaPagamentoNfce {
+    /// Obtém a forma de pagamento a partir do código `tPag`
+    pub fn from_codigo(codigo: u8) -> Option<Self> {
+        Some(match codigo {
+            1 => Self::Dinheiro,
+            2 => Self::Cheque,
+            3 => Self::CartaoCredito,
+            4 => Self::CartaoDebito,
+            5 => Self::CreditoLoja,
+            10 => Self::ValeAlimentacao,
+            11 => Self::ValeRefeicao,
+            12 => Self::ValePresente,
+            13 => Self::ValeCombustivel,
+            15 => Self::BoletoBancario,
+            16 => Self::DepositoBancario,
+            17 => Self::Pix,
+            18 => Self::TransferenciaBancaria,
+            19 => Self::CashbackDebito,
+            90 => Self::SemPagamento,
+            99 => Self::Outros,
+            _ => return None,
+        })
+    }
+
+    /// Indica se a forma de pagamento exige o grupo de cartão (`<card>`)
+    pub fn cartao(&self) -> bool {
+        matches!(self, Self::CartaoCredito | Self::CartaoDebito)
+    }
+
     pub fn descricao(&self) -> &'static str {
         match self {
             Self::Dinheiro => "Dinheiro",
@@ -278,3 +296,27 @@ impl FormaPagamentoNfce {
         }
     }
 }
+
+impl Serialize for FormaPagamentoNfce {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&format!("{:02}", *self as u8))
+    }
+}
+
+impl<'de> Deserialize<'de> for FormaPagamentoNfce {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let codigo = String::deserialize(deserializer)?;
+        codigo
+            .trim()
+            .parse::<u8>()
+            .ok()
+            .and_then(Self::from_codigo)
+            .ok_or_else(|| de::Error::custom(format!("Forma de pagamento inválida: {}", codigo)))
+    }
+}
@@ -17,6 +17,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use super::decimal::{arredondar, Decimal, CASAS_VALOR};
+
 /// Nota Fiscal de Serviços Eletrônica
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Nfse {
@@ -215,37 +217,37 @@ pub struct ServicoNfse {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ValoresNfse {
     /// Valor dos serviços
-    pub valor_servicos: f32,
+    pub valor_servicos: Decimal,
     /// Valor das deduções
-    pub valor_deducoes: f32,
+    pub valor_deducoes: Decimal,
     /// Valor do PIS
-    pub valor_pis: f32,
+    pub valor_pis: Decimal,
     /// Valor da COFINS
-    pub valor_cofins: f32,
+    pub valor_cofins: Decimal,
     /// Valor do INSS
-    pub valor_inss: f32,
+    pub valor_inss: Decimal,
     /// Valor do IR
-    pub valor_ir: f32,
+    pub valor_ir: Decimal,
     /// Valor da CSLL
-    pub valor_csll: f32,
+    pub valor_csll: Decimal,
     /// Outras retenções
-    pub outras_retencoes: f32,
+    pub outras_retencoes: Decimal,
     /// Valor do ISS
-    pub valor_iss: f32,
+    pub valor_iss: Decimal,
     /// Alíquota do ISS (%)
-    pub aliquota_iss: f32,
+    pub aliquota_iss: Decimal,
     /// Desconto incondicionado
-    pub desconto_incondicionado: f32,
+    pub desconto_incondicionado: Decimal,
     /// Desconto condicionado
-    pub desconto_condicionado: f32,
+    pub desconto_condicionado: Decimal,
     /// Base de cálculo do ISS
-    pub base_calculo: f32,
+    pub base_calculo: Decimal,
     /// Valor líquido
-    pub valor_liquido: f32,
+    pub valor_liquido: Decimal,
     /// ISS retido? (1=Sim, 2=Não)
     pub iss_retido: bool,
     /// Valor do ISS retido
-    pub valor_iss_retido: f32,
+    pub valor_iss_retido: Decimal,
     /// Responsável pela retenção (1=Prestador, 2=Tomador, 3=Intermediário)
     pub responsavel_retencao: Option<u8>,
 }
@@ -253,22 +255,22 @@ pub struct ValoresNfse {
 impl Default for ValoresNfse {
     fn default() -> Self {
         Self {
-            valor_servicos: 0.0,
-            valor_deducoes: 0.0,
-            valor_pis: 0.0,
-            valor_cofins: 0.0,
-            valor_inss: 0.0,
-            valor_ir: 0.0,
-            valor_csll: 0.0,
-            outras_retencoes: 0.0,
-            valor_iss: 0.0,
-            aliquota_iss: 0.0,
-            desconto_incondicionado: 0.0,
-            desconto_condicionado: 0.0,
-            base_calculo: 0.0,
-            valor_liquido: 0.0,
+            valor_servicos: Decimal::ZERO,
+            valor_deducoes: Decimal::ZERO,
+            valor_pis: Decimal::ZERO,
+            valor_cofins: Decimal::ZERO,
+            valor_inss: Decimal::ZERO,
+            valor_ir: Decimal::ZERO,
+            valor_csll: Decimal::ZERO,
+            outras_retencoes: Decimal::ZERO,
+            valor_iss: Decimal::ZERO,
+            aliquota_iss: Decimal::ZERO,
+            desconto_incondicionado: Decimal::ZERO,
+            desconto_condicionado: Decimal::ZERO,
+            base_calculo: Decimal::ZERO,
+            valor_liquido: Decimal::ZERO,
             iss_retido: false,
-            valor_iss_retido: 0.0,
+            valor_iss_retido: Decimal::ZERO,
             responsavel_retencao: None,
         }
     }
@@ -366,27 +368,29 @@ pub struct MensagemRetorno {
 }
 
 /// Calcula os valores de uma NFS-e
+#[allow(clippy::too_many_arguments)]
 pub fn calcular_valores_nfse(
-    valor_servicos: f32,
-    aliquota_iss: f32,
-    valor_deducoes: f32,
+    valor_servicos: Decimal,
+    aliquota_iss: Decimal,
+    valor_deducoes: Decimal,
     iss_retido: bool,
-    aliquota_pis: Option<f32>,
-    aliquota_cofins: Option<f32>,
-    aliquota_inss: Option<f32>,
-    aliquota_ir: Option<f32>,
-    aliquota_csll: Option<f32>,
+    aliquota_pis: Option<Decimal>,
+    aliquota_cofins: Option<Decimal>,
+    aliquota_inss: Option<Decimal>,
+    aliquota_ir: Option<Decimal>,
+    aliquota_csll: Option<Decimal>,
 ) -> ValoresNfse {
     let base_calculo = valor_servicos - valor_deducoes;
-    let valor_iss = base_calculo * (aliquota_iss / 100.0);
+    let aplicar = |aliquota: Decimal| arredondar(base_calculo * aliquota / Decimal::ONE_HUNDRED, CASAS_VALOR);
+    let valor_iss = aplicar(aliquota_iss);
 
-    let valor_pis = aliquota_pis.map(|a| base_calculo * (a / 100.0)).unwrap_or(0.0);
-    let valor_cofins = aliquota_cofins.map(|a| base_calculo * (a / 100.0)).unwrap_or(0.0);
-    let valor_inss = aliquota_inss.map(|a| base_calculo * (a / 100.0)).unwrap_or(0.0);
-    let valor_ir = aliquota_ir.map(|a| base_calculo * (a / 100.0)).unwrap_or(0.0);
-    let valor_csll = aliquota_csll.map(|a| base_calculo * (a / 100.0)).unwrap_or(0.0);
+    let valor_pis = aliquota_pis.map(aplicar).unwrap_or_default();
+    let valor_cofins = aliquota_cofins.map(aplicar).unwrap_or_default();
+    let valor_inss = aliquota_inss.map(aplicar).unwrap_or_default();
+    let valor_ir = aliquota_ir.map(aplicar).unwrap_or_default();
+    let valor_csll = aliquota_csll.map(aplicar).unwrap_or_default();
 
-    let valor_iss_retido = if iss_retido { valor_iss } else { 0.0 };
+    let valor_iss_retido = if iss_retido { valor_iss } else { Decimal::ZERO };
     let outras_retencoes = valor_pis + valor_cofins + valor_inss + valor_ir + valor_csll;
 
     let valor_liquido = valor_servicos - valor_iss_retido - outras_retencoes;
@@ -402,8 +406,8 @@ pub fn calcular_valores_nfse(
         outras_retencoes,
         valor_iss,
         aliquota_iss,
-        desconto_incondicionado: 0.0,
-        desconto_condicionado: 0.0,
+        desconto_incondicionado: Decimal::ZERO,
+        desconto_condicionado: Decimal::ZERO,
         base_calculo,
         valor_liquido,
         iss_retido,
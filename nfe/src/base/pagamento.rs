@@ -0,0 +1,292 @@
+//! Pagamento da Nota Fiscal Eletrônica (Grupo `<pag>`)
+//!
+//! Este módulo contém as estruturas para representar as formas de
+//! pagamento da NF-e e NFC-e conforme layout 4.00.
+//!
+//! ## Estrutura do Grupo Pagamento
+//!
+//! ```text
+//! <pag>
+//!   <detPag>           <!-- 1 a 100 ocorrências -->
+//!     <indPag>         <!-- Indicador da forma de pagamento (opcional) -->
+//!     <tPag>           <!-- Meio de pagamento -->
+//!     <xPag>           <!-- Descrição, quando tPag = 99 (opcional) -->
+//!     <vPag>           <!-- Valor do pagamento -->
+//!     <card>           <!-- Grupo de cartões (opcional) -->
+//!       <tpIntegra>
+//!       <CNPJ>
+//!       <tBand>
+//!       <cAut>
+//!     </card>
+//!   </detPag>
+//!   <vTroco>           <!-- Valor do troco (opcional) -->
+//! </pag>
+//! ```
+//!
+//! ## Obrigatoriedade
+//!
+//! Desde o layout 4.00 o grupo `<pag>` é obrigatório para NF-e e NFC-e.
+//! Notas sem pagamento (ex: devolução, remessa) devem informar
+//! `tPag = 90` (Sem Pagamento) com `vPag = 0.00`; vendas faturadas em
+//! duplicatas são pagas a prazo (ver [`Pagamento::a_prazo`]).
+
+use super::decimal::Decimal;
+use super::nfce::FormaPagamentoNfce;
+use super::Error;
+use serde::de;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_repr::{Deserialize_repr, Serialize_repr};
+use std::fmt;
+use std::str::FromStr;
+
+/// Grupo de Informações de Pagamento (tag `<pag>`)
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[serde(rename = "pag")]
+pub struct Pagamento {
+    /// Detalhamento dos pagamentos (tags `<detPag>`)
+    #[serde(rename = "detPag")]
+    pub detalhes: Vec<DetalhePagamento>,
+
+    /// Valor do troco (tag `<vTroco>`)
+    #[serde(rename = "$unflatten=vTroco")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub valor_troco: Option<Decimal>,
+}
+
+/// Detalhamento de uma forma de pagamento (tag `<detPag>`)
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct DetalhePagamento {
+    /// Indicador da forma de pagamento (tag `<indPag>`)
+    #[serde(rename = "$unflatten=indPag")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub indicador: Option<IndicadorPagamento>,
+
+    /// Meio de pagamento (tag `<tPag>`)
+    #[serde(rename = "$unflatten=tPag")]
+    pub forma: FormaPagamentoNfce,
+
+    /// Descrição do meio de pagamento (tag `<xPag>`)
+    /// Obrigatória quando `tPag = 99` (Outros)
+    #[serde(rename = "$unflatten=xPag")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub descricao: Option<String>,
+
+    /// Valor do pagamento (tag `<vPag>`)
+    #[serde(rename = "$unflatten=vPag")]
+    #[serde(with = "crate::base::decimal::valor")]
+    pub valor: Decimal,
+
+    /// Grupo de cartões, PIX e boletos (tag `<card>`)
+    #[serde(rename = "card")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub cartao: Option<Cartao>,
+}
+
+/// Indicador da Forma de Pagamento (tag `<indPag>`)
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Deserialize_repr, Serialize_repr)]
+#[repr(u8)]
+pub enum IndicadorPagamento {
+    /// 0 - Pagamento à vista
+    AVista = 0,
+    /// 1 - Pagamento a prazo
+    APrazo = 1,
+}
+
+/// Grupo de Cartões (tag `<card>`)
+///
+/// Informado para pagamentos com cartão de crédito/débito e, a partir
+/// da NT 2020.006, também para PIX e boleto com integração.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct Cartao {
+    /// Tipo de integração com o sistema de automação (tag `<tpIntegra>`)
+    #[serde(rename = "$unflatten=tpIntegra")]
+    pub tipo_integracao: TipoIntegracaoPagamento,
+
+    /// CNPJ da instituição de pagamento/credenciadora (tag `<CNPJ>`)
+    #[serde(rename = "$unflatten=CNPJ")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub cnpj_credenciadora: Option<String>,
+
+    /// Bandeira da operadora de cartão (tag `<tBand>`)
+    #[serde(rename = "$unflatten=tBand")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub bandeira: Option<BandeiraCartao>,
+
+    /// Número de autorização da operação (tag `<cAut>`)
+    #[serde(rename = "$unflatten=cAut")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub autorizacao: Option<String>,
+}
+
+/// Tipo de Integração do Pagamento (tag `<tpIntegra>`)
+///
+/// | Código | Descrição |
+/// |--------|-----------|
+/// | 1 | Pagamento integrado com o sistema de automação (TEF, PIX dinâmico) |
+/// | 2 | Pagamento não integrado (POS) |
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Deserialize_repr, Serialize_repr)]
+#[repr(u8)]
+pub enum TipoIntegracaoPagamento {
+    /// 1 - Pagamento integrado
+    Integrado = 1,
+    /// 2 - Pagamento não integrado
+    NaoIntegrado = 2,
+}
+
+/// Bandeira da Operadora de Cartão (tag `<tBand>`)
+///
+/// No XML o código é informado com dois dígitos (ex: `01` = Visa).
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+#[repr(u8)]
+pub enum BandeiraCartao {
+    Visa = 1,
+    Mastercard = 2,
+    AmericanExpress = 3,
+    Sorocred = 4,
+    DinersClub = 5,
+    Elo = 6,
+    Hipercard = 7,
+    Aura = 8,
+    Cabal = 9,
+    Alelo = 10,
+    BanesCard = 11,
+    CalCard = 12,
+    Credz = 13,
+    Discover = 14,
+    GoodCard = 15,
+    GreenCard = 16,
+    Hiper = 17,
+    Jcb = 18,
+    Mais = 19,
+    MaxVan = 20,
+    Policard = 21,
+    RedeCompras = 22,
+    Sodexo = 23,
+    ValeCard = 24,
+    Verocheque = 25,
+    Vr = 26,
+    Ticket = 27,
+    Outros = 99,
+}
+
+impl BandeiraCartao {
+    /// Obtém a bandeira a partir do código `tBand`
+    pub fn from_codigo(codigo: u8) -> Option<Self> {
+        Some(match codigo {
+            1 => Self::Visa,
+            2 => Self::Mastercard,
+            3 => Self::AmericanExpress,
+            4 => Self::Sorocred,
+            5 => Self::DinersClub,
+            6 => Self::Elo,
+            7 => Self::Hipercard,
+            8 => Self::Aura,
+            9 => Self::Cabal,
+            10 => Self::Alelo,
+            11 => Self::BanesCard,
+            12 => Self::CalCard,
+            13 => Self::Credz,
+            14 => Self::Discover,
+            15 => Self::GoodCard,
+            16 => Self::GreenCard,
+            17 => Self::Hiper,
+            18 => Self::Jcb,
+            19 => Self::Mais,
+            20 => Self::MaxVan,
+            21 => Self::Policard,
+            22 => Self::RedeCompras,
+            23 => Self::Sodexo,
+            24 => Self::ValeCard,
+            25 => Self::Verocheque,
+            26 => Self::Vr,
+            27 => Self::Ticket,
+            99 => Self::Outros,
+            _ => return None,
+        })
+    }
+}
+
+impl Serialize for BandeiraCartao {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&format!("{:02}", *self as u8))
+    }
+}
+
+impl<'de> Deserialize<'de> for BandeiraCartao {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let codigo = String::deserialize(deserializer)?;
+        codigo
+            .trim()
+            .parse::<u8>()
+            .ok()
+            .and_then(Self::from_codigo)
+            .ok_or_else(|| de::Error::custom(format!("Bandeira de cartão inválida: {}", codigo)))
+    }
+}
+
+impl Pagamento {
+    /// Grupo de pagamento para notas sem pagamento (`tPag = 90`, `vPag = 0.00`)
+    pub fn sem_pagamento() -> Self {
+        Self {
+            detalhes: vec![DetalhePagamento::new(FormaPagamentoNfce::SemPagamento, Decimal::ZERO)],
+            valor_troco: None,
+        }
+    }
+
+    /// Grupo de pagamento de uma venda a prazo por boleto (`indPag = 1`, `tPag = 15`)
+    pub fn a_prazo(valor: Decimal) -> Self {
+        Self {
+            detalhes: vec![DetalhePagamento {
+                indicador: Some(IndicadorPagamento::APrazo),
+                ..DetalhePagamento::new(FormaPagamentoNfce::BoletoBancario, valor)
+            }],
+            valor_troco: None,
+        }
+    }
+
+    /// Soma dos valores de todos os `<detPag>`
+    pub fn valor_total(&self) -> Decimal {
+        self.detalhes.iter().map(|d| d.valor).sum()
+    }
+}
+
+impl DetalhePagamento {
+    /// Cria um detalhamento de pagamento sem indicador e sem cartão
+    pub fn new(forma: FormaPagamentoNfce, valor: Decimal) -> Self {
+        Self {
+            indicador: None,
+            forma,
+            descricao: None,
+            valor,
+            cartao: None,
+        }
+    }
+}
+
+impl FromStr for Pagamento {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        quick_xml::de::from_str(s).map_err(|e| e.into())
+    }
+}
+
+impl fmt::Display for Pagamento {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&quick_xml::se::to_string(self).expect("Falha ao serializar o pagamento"))
+    }
+}
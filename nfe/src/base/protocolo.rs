@@ -0,0 +1,309 @@
+//! NF-e processada e protocolo de autorização (Grupos `<nfeProc>` e `<protNFe>`)
+//!
+//! Após a autorização, a SEFAZ devolve o protocolo `<protNFe>`, que deve ser
+//! anexado à NF-e assinada para formar o XML de distribuição (`<nfeProc>`).
+//! É esse XML que deve ser arquivado e enviado ao destinatário.
+//!
+//! ## Estrutura do XML de Distribuição
+//!
+//! ```text
+//! <nfeProc versao="4.00" xmlns="http://www.portalfiscal.inf.br/nfe">
+//!   <NFe>...</NFe>
+//!   <protNFe versao="4.00">
+//!     <infProt Id="...">   <!-- Id opcional -->
+//!       <tpAmb>            <!-- Ambiente -->
+//!       <verAplic>         <!-- Versão do aplicativo da SEFAZ -->
+//!       <chNFe>            <!-- Chave de acesso -->
+//!       <dhRecbto>         <!-- Data/hora de recebimento -->
+//!       <nProt>            <!-- Número do protocolo (opcional) -->
+//!       <digVal>           <!-- Digest value da NF-e (opcional) -->
+//!       <cStat>            <!-- Código do status -->
+//!       <xMotivo>          <!-- Descrição do status -->
+//!     </infProt>
+//!   </protNFe>
+//! </nfeProc>
+//! ```
+//!
+//! ## Códigos de Status
+//!
+//! | cStat | Situação |
+//! |-------|----------|
+//! | 100 | Autorizado o uso da NF-e |
+//! | 150 | Autorizado o uso da NF-e, autorização fora de prazo |
+//! | 110, 301, 302, 303 | Uso denegado |
+
+use super::ide::TipoAmbiente;
+use super::xml::{self, ConteudoPreservado, NAMESPACE_NFE};
+use super::{Error, Nfe, VersaoLayout};
+use chrono::{DateTime, FixedOffset};
+use quick_xml::DeError;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::convert::TryFrom;
+use std::fs::File;
+use std::io::Read;
+use std::fmt;
+use std::str::FromStr;
+
+/// NF-e processada: nota assinada + protocolo de autorização (tag `<nfeProc>`)
+///
+/// ## Assinatura
+///
+/// O modelo não representa a `<Signature>` da nota. Ela só é reemitida
+/// quando a [`Nfe`] guarda o conteúdo preservado, isto é, quando foi lida
+/// com [`Nfe::parse_sem_perdas`] ou [`NfeProc::parse_sem_perdas`] e não foi
+/// alterada. Na leitura padrão, ou com uma nota montada pelo builder, a
+/// serialização gera o `<NFe>` sem assinatura, que não serve como XML de
+/// distribuição.
+#[derive(Debug, PartialEq)]
+pub struct NfeProc {
+    /// Versão do layout (atributo `@versao`)
+    pub versao: VersaoLayout,
+    /// Nota fiscal (tag `<NFe>`)
+    pub nfe: Nfe,
+    /// Protocolo de autorização (tag `<protNFe>`)
+    pub protocolo: ProtocoloAutorizacao,
+    /// Elementos fora de `<NFe>` que o modelo não representa
+    /// Preenchido apenas na leitura sem perdas ([`NfeProc::parse_sem_perdas`])
+    pub preservado: Option<ConteudoPreservado>,
+}
+
+/// Protocolo de autorização da NF-e (tag `<infProt>`)
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[serde(rename = "infProt")]
+pub struct ProtocoloAutorizacao {
+    /// Identificador do protocolo (atributo `@Id`)
+    #[serde(rename = "Id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub id: Option<String>,
+
+    /// Ambiente de processamento (tag `<tpAmb>`)
+    #[serde(rename = "$unflatten=tpAmb")]
+    pub ambiente: TipoAmbiente,
+
+    /// Versão do aplicativo que processou a NF-e (tag `<verAplic>`)
+    #[serde(rename = "$unflatten=verAplic")]
+    pub versao_aplicativo: String,
+
+    /// Chave de acesso da NF-e (tag `<chNFe>`)
+    #[serde(rename = "$unflatten=chNFe")]
+    pub chave_acesso: String,
+
+    /// Data e hora de recebimento pela SEFAZ (tag `<dhRecbto>`)
+    #[serde(rename = "$unflatten=dhRecbto")]
+    #[serde(serialize_with = "serialize_recebimento")]
+    pub recebimento: DateTime<FixedOffset>,
+
+    /// Número do protocolo (tag `<nProt>`)
+    #[serde(rename = "$unflatten=nProt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub numero: Option<String>,
+
+    /// Digest value da NF-e processada (tag `<digVal>`)
+    #[serde(rename = "$unflatten=digVal")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub digest_value: Option<String>,
+
+    /// Código do status da resposta (tag `<cStat>`)
+    #[serde(rename = "$unflatten=cStat")]
+    pub codigo_status: u16,
+
+    /// Descrição do status da resposta (tag `<xMotivo>`)
+    #[serde(rename = "$unflatten=xMotivo")]
+    pub motivo: String,
+}
+
+impl ProtocoloAutorizacao {
+    /// Indica se o uso da NF-e foi autorizado (cStat 100 ou 150)
+    pub fn autorizado(&self) -> bool {
+        matches!(self.codigo_status, 100 | 150)
+    }
+
+    /// Indica se o uso da NF-e foi denegado (cStat 110, 301, 302 ou 303)
+    pub fn denegado(&self) -> bool {
+        matches!(self.codigo_status, 110 | 301 | 302 | 303)
+    }
+}
+
+impl NfeProc {
+    /// Anexa o protocolo de autorização à nota
+    pub fn new(nfe: Nfe, protocolo: ProtocoloAutorizacao) -> Self {
+        Self {
+            versao: nfe.versao,
+            nfe,
+            protocolo,
+            preservado: None,
+        }
+    }
+
+    /// Lê o XML de distribuição a partir do conteúdo bruto de um arquivo
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        xml::decodificar(bytes).parse()
+    }
+
+    /// Lê o XML de distribuição no modo sem perdas (ver [`Nfe::parse_sem_perdas`])
+    ///
+    /// Também preserva os elementos desconhecidos de `<nfeProc>` e `<protNFe>`.
+    pub fn parse_sem_perdas(xml: &str) -> Result<Self, Error> {
+        let mut proc: NfeProc = xml.parse()?;
+        let nfe = quick_xml::se::to_string(&proc.nfe)?;
+        proc.nfe.preservado = Some(xml::preservar_nfe(xml, &nfe)?);
+        proc.preservado = Some(xml::preservar_proc(xml, &quick_xml::se::to_string(&proc)?)?);
+
+        Ok(proc)
+    }
+
+    /// Lê o XML de distribuição no modo sem perdas a partir dos bytes do arquivo
+    pub fn from_bytes_sem_perdas(bytes: &[u8]) -> Result<Self, Error> {
+        Self::parse_sem_perdas(&xml::decodificar(bytes))
+    }
+}
+
+fn serialize_recebimento<S>(date: &DateTime<FixedOffset>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(&date.format("%Y-%m-%dT%H:%M:%S%:z").to_string())
+}
+
+impl FromStr for ProtocoloAutorizacao {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        quick_xml::de::from_str(s).map_err(|e| e.into())
+    }
+}
+
+impl fmt::Display for ProtocoloAutorizacao {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&quick_xml::se::to_string(self).expect("Falha ao serializar o protocolo de autorização"))
+    }
+}
+
+impl FromStr for NfeProc {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let documento = xml::normalizar(s)?;
+
+        if documento.raiz != "nfeProc" {
+            return Err(DeError::Custom(format!(
+                "Elemento raiz <{}> não é um <nfeProc>",
+                documento.raiz
+            ))
+            .into());
+        }
+
+        quick_xml::de::from_str(&documento.xml).map_err(|e| e.into())
+    }
+}
+
+impl TryFrom<File> for NfeProc {
+    type Error = Error;
+
+    fn try_from(mut f: File) -> Result<Self, Self::Error> {
+        let mut bytes = Vec::new();
+        f.read_to_end(&mut bytes).map_err(Error::Io)?;
+
+        NfeProc::from_bytes(&bytes)
+    }
+}
+
+/// Serializa o XML de distribuição
+///
+/// Sem o conteúdo preservado da nota, o `<NFe>` sai sem `<Signature>`
+/// (ver [`NfeProc`]).
+impl fmt::Display for NfeProc {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut xml = quick_xml::se::to_string(self).expect("Falha ao serializar a nota processada");
+
+        if let Some(preservado) = &self.nfe.preservado {
+            let nfe = quick_xml::se::to_string(&self.nfe).expect("Falha ao serializar a nota");
+            xml = xml::reinserir(&xml, preservado, "NFe", preservado.inalterado(&nfe));
+        }
+        if let Some(preservado) = &self.preservado {
+            xml = xml::reinserir(&xml, preservado, "nfeProc", false);
+        }
+
+        f.write_str(&xml)
+    }
+}
+
+impl<'de> Deserialize<'de> for NfeProc {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let proc = NfeProcContainer::deserialize(deserializer)?;
+
+        Ok(Self {
+            versao: proc.versao,
+            nfe: proc.nfe,
+            protocolo: proc.protocolo.inf,
+            preservado: None,
+        })
+    }
+}
+
+impl Serialize for NfeProc {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let proc = NfeProcContainerRef {
+            versao: self.versao,
+            xmlns: NAMESPACE_NFE,
+            nfe: &self.nfe,
+            protocolo: ProtNfeContainerRef {
+                versao: self.versao,
+                inf: &self.protocolo,
+            },
+        };
+
+        proc.serialize(serializer)
+    }
+}
+
+/// Container para deserialização do elemento `<nfeProc>`
+#[derive(Deserialize)]
+#[serde(rename = "nfeProc")]
+struct NfeProcContainer {
+    #[serde(rename = "versao")]
+    pub versao: VersaoLayout,
+    #[serde(rename = "NFe")]
+    pub nfe: Nfe,
+    #[serde(rename = "protNFe")]
+    pub protocolo: ProtNfeContainer,
+}
+
+/// Container para o elemento `<protNFe>`
+#[derive(Deserialize)]
+struct ProtNfeContainer {
+    #[serde(rename = "infProt")]
+    pub inf: ProtocoloAutorizacao,
+}
+
+/// Container para serialização do elemento `<nfeProc>` sem clonar a nota
+#[derive(Serialize)]
+#[serde(rename = "nfeProc")]
+struct NfeProcContainerRef<'a> {
+    #[serde(rename = "versao")]
+    pub versao: VersaoLayout,
+    #[serde(rename = "xmlns")]
+    pub xmlns: &'static str,
+    #[serde(rename = "NFe")]
+    pub nfe: &'a Nfe,
+    #[serde(rename = "protNFe")]
+    pub protocolo: ProtNfeContainerRef<'a>,
+}
+
+/// Container para serialização do elemento `<protNFe>`
+#[derive(Serialize)]
+struct ProtNfeContainerRef<'a> {
+    #[serde(rename = "versao")]
+    pub versao: VersaoLayout,
+    #[serde(rename = "infProt")]
+    pub inf: &'a ProtocoloAutorizacao,
+}
@@ -0,0 +1,119 @@
+//! Responsável técnico pela emissão da NF-e (Grupo `<infRespTec>`)
+//!
+//! Identifica a empresa desenvolvedora do software emissor (software house).
+//! Algumas UFs exigem, além dos dados de contato, o Código de Segurança do
+//! Responsável Técnico (CSRT), fornecido pela SEFAZ.
+//!
+//! ## Estrutura do Grupo
+//!
+//! ```text
+//! <infRespTec>
+//!   <CNPJ>       <!-- CNPJ da software house -->
+//!   <xContato>   <!-- Nome da pessoa de contato -->
+//!   <email>      <!-- E-mail de contato -->
+//!   <fone>       <!-- Telefone (DDD + número) -->
+//!   <idCSRT>     <!-- Identificador do CSRT (opcional) -->
+//!   <hashCSRT>   <!-- Hash do CSRT (opcional) -->
+//! </infRespTec>
+//! ```
+//!
+//! ## Hash do CSRT
+//!
+//! ```text
+//! hashCSRT = Base64(SHA-1(CSRT + chave de acesso))
+//! ```
+//!
+//! O CSRT em si nunca é enviado; apenas o seu identificador e o hash.
+
+use super::Error;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+use sha1::{Digest, Sha1};
+use std::fmt;
+use std::str::FromStr;
+
+/// Responsável técnico (tag `<infRespTec>`)
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename = "infRespTec")]
+pub struct ResponsavelTecnico {
+    /// CNPJ da pessoa jurídica responsável pelo sistema (tag `<CNPJ>`)
+    #[serde(rename = "$unflatten=CNPJ")]
+    pub cnpj: String,
+
+    /// Nome da pessoa de contato (tag `<xContato>`)
+    #[serde(rename = "$unflatten=xContato")]
+    pub contato: String,
+
+    /// E-mail de contato (tag `<email>`)
+    #[serde(rename = "$unflatten=email")]
+    pub email: String,
+
+    /// Telefone com DDD (tag `<fone>`)
+    #[serde(rename = "$unflatten=fone")]
+    pub fone: String,
+
+    /// Identificador do CSRT (tag `<idCSRT>`)
+    #[serde(rename = "$unflatten=idCSRT")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub id_csrt: Option<String>,
+
+    /// Hash do CSRT com a chave de acesso (tag `<hashCSRT>`)
+    #[serde(rename = "$unflatten=hashCSRT")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub hash_csrt: Option<String>,
+}
+
+/// Configuração do CSRT (Código de Segurança do Responsável Técnico)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConfiguracaoCsrt {
+    /// Identificador do CSRT (2 dígitos, fornecido pela SEFAZ)
+    pub id_csrt: String,
+    /// Código CSRT
+    pub csrt: String,
+}
+
+impl ResponsavelTecnico {
+    /// Cria o grupo sem CSRT
+    pub fn new(cnpj: &str, contato: &str, email: &str, fone: &str) -> Self {
+        Self {
+            cnpj: cnpj.replace(&['.', '/', '-'][..], ""),
+            contato: contato.to_string(),
+            email: email.to_string(),
+            fone: fone.chars().filter(|c| c.is_ascii_digit()).collect(),
+            id_csrt: None,
+            hash_csrt: None,
+        }
+    }
+
+    /// Preenche `idCSRT` e `hashCSRT` para a chave de acesso informada
+    pub fn assinar_csrt(&mut self, config: &ConfiguracaoCsrt, chave_acesso: &str) {
+        self.id_csrt = Some(config.id_csrt.clone());
+        self.hash_csrt = Some(gerar_hash_csrt(&config.csrt, chave_acesso));
+    }
+}
+
+/// Calcula o `hashCSRT`: Base64(SHA-1(CSRT + chave de acesso))
+pub fn gerar_hash_csrt(csrt: &str, chave_acesso: &str) -> String {
+    let mut hasher = Sha1::new();
+    hasher.update(csrt.as_bytes());
+    hasher.update(chave_acesso.as_bytes());
+
+    BASE64.encode(hasher.finalize())
+}
+
+impl FromStr for ResponsavelTecnico {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        quick_xml::de::from_str(s).map_err(|e| e.into())
+    }
+}
+
+impl fmt::Display for ResponsavelTecnico {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&quick_xml::se::to_string(self).expect("Falha ao serializar o responsável técnico"))
+    }
+}
@@ -4,7 +4,11 @@
 //! incluindo todos os impostos e valores adicionais conforme layout 4.00.
 
 use super::Error;
+use crate::base::decimal::{formatar, Decimal, CASAS_VALOR};
+use crate::base::item::{DevolucaoTributo, Diferimento, Item};
+use chrono::NaiveDate;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
 use std::str::FromStr;
 
 /// Totalização da nota fiscal
@@ -16,56 +20,666 @@ use std::str::FromStr;
 /// - Totais de Importação
 /// - Totais de DIFAL (partilha interestadual)
 /// - Totais de serviços (ISSQN)
+/// - Totais do IS, IBS e CBS (Reforma Tributária)
 #[derive(Debug, PartialEq, Clone)]
 pub struct Totalizacao {
     /// Base de cálculo do ICMS
-    pub valor_base_calculo: f32,
+    pub valor_base_calculo: Decimal,
     /// Valor total do ICMS
-    pub valor_icms: f32,
+    pub valor_icms: Decimal,
     /// Valor total do ICMS desonerado
-    pub valor_icms_desonerado: f32,
+    pub valor_icms_desonerado: Decimal,
     /// Valor total do FCP (Fundo de Combate à Pobreza)
-    pub valor_fcp: f32,
+    pub valor_fcp: Decimal,
     /// Base de cálculo do ICMS ST
-    pub valor_base_calculo_st: f32,
+    pub valor_base_calculo_st: Decimal,
     /// Valor total do ICMS ST
-    pub valor_icms_st: f32,
+    pub valor_icms_st: Decimal,
     /// Valor total do FCP retido por ST
-    pub valor_fcp_st: f32,
+    pub valor_fcp_st: Decimal,
     /// Valor total do FCP retido anteriormente por ST
-    pub valor_fcp_st_retido: f32,
+    pub valor_fcp_st_retido: Decimal,
     /// Valor total dos produtos e serviços
-    pub valor_produtos: f32,
+    pub valor_produtos: Decimal,
     /// Valor total do frete
-    pub valor_frete: f32,
+    pub valor_frete: Decimal,
     /// Valor total do seguro
-    pub valor_seguro: f32,
+    pub valor_seguro: Decimal,
     /// Valor total do desconto
-    pub valor_desconto: f32,
+    pub valor_desconto: Decimal,
     /// Outras despesas acessórias
-    pub valor_outros: f32,
+    pub valor_outros: Decimal,
     /// Valor total do IPI
-    pub valor_ipi: f32,
+    pub valor_ipi: Decimal,
     /// Valor total do IPI devolvido
-    pub valor_ipi_devolvido: f32,
+    pub valor_ipi_devolvido: Decimal,
     /// Valor total do Imposto de Importação
-    pub valor_ii: f32,
+    pub valor_ii: Decimal,
     /// Valor total do PIS
-    pub valor_pis: f32,
+    pub valor_pis: Decimal,
     /// Valor total do COFINS
-    pub valor_cofins: f32,
+    pub valor_cofins: Decimal,
     /// Valor total da nota
-    pub valor_total: f32,
+    pub valor_total: Decimal,
     /// Valor aproximado total de tributos (Lei 12.741/2012)
-    pub valor_aproximado_tributos: f32,
+    pub valor_aproximado_tributos: Decimal,
 
     // Campos de DIFAL (partilha interestadual EC 87/2015)
     /// Valor total do FCP UF Destino
-    pub valor_fcp_uf_dest: f32,
+    pub valor_fcp_uf_dest: Decimal,
     /// Valor total do ICMS UF Destino
-    pub valor_icms_uf_dest: f32,
+    pub valor_icms_uf_dest: Decimal,
     /// Valor total do ICMS UF Remetente
-    pub valor_icms_uf_remet: f32,
+    pub valor_icms_uf_remet: Decimal,
+
+    /// Totais dos serviços sujeitos ao ISSQN (tag `<ISSQNtot>`)
+    pub servicos: Option<TotalIssqn>,
+
+    // Reforma Tributária (NT 2025.002-RTC)
+    /// Valor total do Imposto Seletivo (tag `<ISTot>`)
+    pub valor_imposto_seletivo: Option<Decimal>,
+    /// Totais do IBS e da CBS (tag `<IBSCBSTot>`)
+    pub ibs_cbs: Option<TotalIbsCbs>,
+}
+
+/// Totais dos serviços sujeitos ao ISSQN (tag `<ISSQNtot>`)
+///
+/// Os itens com o grupo `<ISSQN>` ficam fora do `vProd`, do `vPIS` e do
+/// `vCOFINS` do `<ICMSTot>`: seus valores são totalizados aqui.
+#[derive(Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
+pub struct TotalIssqn {
+    /// Valor total dos serviços (tag `<vServ>`)
+    #[serde(rename = "$unflatten=vServ")]
+    #[serde(with = "crate::base::decimal::valor")]
+    #[serde(default)]
+    pub valor_servicos: Decimal,
+
+    /// Base de cálculo do ISSQN (tag `<vBC>`)
+    #[serde(rename = "$unflatten=vBC")]
+    #[serde(with = "crate::base::decimal::valor")]
+    #[serde(default)]
+    pub valor_bc: Decimal,
+
+    /// Valor total do ISSQN (tag `<vISS>`)
+    #[serde(rename = "$unflatten=vISS")]
+    #[serde(with = "crate::base::decimal::valor")]
+    #[serde(default)]
+    pub valor_iss: Decimal,
+
+    /// Valor total do PIS sobre serviços (tag `<vPIS>`)
+    #[serde(rename = "$unflatten=vPIS")]
+    #[serde(with = "crate::base::decimal::valor")]
+    #[serde(default)]
+    pub valor_pis: Decimal,
+
+    /// Valor total da COFINS sobre serviços (tag `<vCOFINS>`)
+    #[serde(rename = "$unflatten=vCOFINS")]
+    #[serde(with = "crate::base::decimal::valor")]
+    #[serde(default)]
+    pub valor_cofins: Decimal,
+
+    /// Data da prestação do serviço (tag `<dCompet>`)
+    ///
+    /// Obrigatória no XML; [`TotalIssqn::somar`] não a conhece e deixa `None`.
+    #[serde(rename = "$unflatten=dCompet")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub data_competencia: Option<NaiveDate>,
+
+    /// Valor total das deduções da BC (tag `<vDeducao>`)
+    #[serde(rename = "$unflatten=vDeducao")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub valor_deducao: Option<Decimal>,
+
+    /// Valor total das outras retenções (tag `<vOutro>`)
+    #[serde(rename = "$unflatten=vOutro")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub valor_outras_retencoes: Option<Decimal>,
+
+    /// Valor total do desconto incondicionado (tag `<vDescIncond>`)
+    #[serde(rename = "$unflatten=vDescIncond")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub valor_desconto_incondicionado: Option<Decimal>,
+
+    /// Valor total do desconto condicionado (tag `<vDescCond>`)
+    #[serde(rename = "$unflatten=vDescCond")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub valor_desconto_condicionado: Option<Decimal>,
+
+    /// Valor total da retenção do ISS (tag `<vISSRet>`)
+    #[serde(rename = "$unflatten=vISSRet")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub valor_retencao_iss: Option<Decimal>,
+
+    /// Código do regime especial de tributação (tag `<cRegTrib>`)
+    #[serde(rename = "$unflatten=cRegTrib")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub regime_especial: Option<u8>,
+}
+
+impl TotalIssqn {
+    /// Soma os itens com o grupo `<ISSQN>`
+    ///
+    /// Retorna `None` quando a nota não tem serviços sujeitos ao ISSQN.
+    pub fn somar(itens: &[Item]) -> Option<Self> {
+        let mut servicos = itens.iter().filter(|item| item.imposto.issqn.is_some()).peekable();
+        servicos.peek()?;
+
+        let somar = |total: &mut Option<Decimal>, valor: Option<Decimal>| {
+            if let Some(valor) = valor {
+                *total.get_or_insert(Decimal::ZERO) += valor;
+            }
+        };
+
+        let mut total = Self::default();
+        for item in servicos {
+            let issqn = item.imposto.issqn.as_ref().expect("itens filtrados pelo ISSQN");
+
+            if item.produto.valor_compoe_total_nota {
+                total.valor_servicos += item.produto.valor_bruto;
+            }
+            total.valor_bc += issqn.valor_bc;
+            total.valor_iss += issqn.valor;
+            total.valor_pis += item.imposto.pis.as_ref().map(|p| p.valor()).unwrap_or_default();
+            total.valor_cofins += item.imposto.cofins.as_ref().map(|c| c.valor()).unwrap_or_default();
+            somar(&mut total.valor_deducao, issqn.valor_deducao);
+            somar(&mut total.valor_outras_retencoes, issqn.valor_outras_retencoes);
+            somar(&mut total.valor_desconto_incondicionado, issqn.valor_desconto_incondicionado);
+            somar(&mut total.valor_desconto_condicionado, issqn.valor_desconto_condicionado);
+            somar(&mut total.valor_retencao_iss, issqn.valor_retencao_iss);
+        }
+
+        Some(total)
+    }
+}
+
+/// Totais do IBS e da CBS (tag `<IBSCBSTot>`)
+#[derive(Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
+pub struct TotalIbsCbs {
+    /// Base de cálculo do IBS e da CBS (tag `<vBCIBSCBS>`)
+    #[serde(rename = "$unflatten=vBCIBSCBS")]
+    #[serde(with = "crate::base::decimal::valor")]
+    pub valor_bc: Decimal,
+
+    /// Totais do IBS (tag `<gIBS>`)
+    #[serde(rename = "gIBS")]
+    pub ibs: TotalIbs,
+
+    /// Totais da CBS (tag `<gCBS>`)
+    #[serde(rename = "gCBS")]
+    pub cbs: TotalCbs,
+}
+
+/// Totais do IBS (tag `<gIBS>`)
+#[derive(Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
+pub struct TotalIbs {
+    /// Totais do IBS estadual (tag `<gIBSUF>`)
+    #[serde(rename = "gIBSUF")]
+    pub uf: TotalIbsUf,
+
+    /// Totais do IBS municipal (tag `<gIBSMun>`)
+    #[serde(rename = "gIBSMun")]
+    pub municipal: TotalIbsMunicipal,
+
+    /// Valor total do IBS (tag `<vIBS>`)
+    #[serde(rename = "$unflatten=vIBS")]
+    #[serde(with = "crate::base::decimal::valor")]
+    pub valor: Decimal,
+
+    /// Valor total do crédito presumido (tag `<vCredPres>`)
+    #[serde(rename = "$unflatten=vCredPres")]
+    #[serde(with = "crate::base::decimal::valor")]
+    pub valor_credito_presumido: Decimal,
+
+    /// Valor total do crédito presumido em condição suspensiva (tag `<vCredPresCondSus>`)
+    #[serde(rename = "$unflatten=vCredPresCondSus")]
+    #[serde(with = "crate::base::decimal::valor")]
+    pub valor_credito_presumido_suspensivo: Decimal,
+}
+
+/// Totais do IBS estadual (tag `<gIBSUF>`)
+#[derive(Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
+pub struct TotalIbsUf {
+    /// Valor total diferido (tag `<vDif>`)
+    #[serde(rename = "$unflatten=vDif")]
+    #[serde(with = "crate::base::decimal::valor")]
+    pub valor_diferido: Decimal,
+
+    /// Valor total de devolução de tributos (tag `<vDevTrib>`)
+    #[serde(rename = "$unflatten=vDevTrib")]
+    #[serde(with = "crate::base::decimal::valor")]
+    pub valor_devolvido: Decimal,
+
+    /// Valor total do IBS estadual (tag `<vIBSUF>`)
+    #[serde(rename = "$unflatten=vIBSUF")]
+    #[serde(with = "crate::base::decimal::valor")]
+    pub valor: Decimal,
+}
+
+/// Totais do IBS municipal (tag `<gIBSMun>`)
+#[derive(Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
+pub struct TotalIbsMunicipal {
+    /// Valor total diferido (tag `<vDif>`)
+    #[serde(rename = "$unflatten=vDif")]
+    #[serde(with = "crate::base::decimal::valor")]
+    pub valor_diferido: Decimal,
+
+    /// Valor total de devolução de tributos (tag `<vDevTrib>`)
+    #[serde(rename = "$unflatten=vDevTrib")]
+    #[serde(with = "crate::base::decimal::valor")]
+    pub valor_devolvido: Decimal,
+
+    /// Valor total do IBS municipal (tag `<vIBSMun>`)
+    #[serde(rename = "$unflatten=vIBSMun")]
+    #[serde(with = "crate::base::decimal::valor")]
+    pub valor: Decimal,
+}
+
+/// Totais da CBS (tag `<gCBS>`)
+#[derive(Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
+pub struct TotalCbs {
+    /// Valor total diferido (tag `<vDif>`)
+    #[serde(rename = "$unflatten=vDif")]
+    #[serde(with = "crate::base::decimal::valor")]
+    pub valor_diferido: Decimal,
+
+    /// Valor total de devolução de tributos (tag `<vDevTrib>`)
+    #[serde(rename = "$unflatten=vDevTrib")]
+    #[serde(with = "crate::base::decimal::valor")]
+    pub valor_devolvido: Decimal,
+
+    /// Valor total da CBS (tag `<vCBS>`)
+    #[serde(rename = "$unflatten=vCBS")]
+    #[serde(with = "crate::base::decimal::valor")]
+    pub valor: Decimal,
+
+    /// Valor total do crédito presumido (tag `<vCredPres>`)
+    #[serde(rename = "$unflatten=vCredPres")]
+    #[serde(with = "crate::base::decimal::valor")]
+    pub valor_credito_presumido: Decimal,
+
+    /// Valor total do crédito presumido em condição suspensiva (tag `<vCredPresCondSus>`)
+    #[serde(rename = "$unflatten=vCredPresCondSus")]
+    #[serde(with = "crate::base::decimal::valor")]
+    pub valor_credito_presumido_suspensivo: Decimal,
+}
+
+impl TotalIbsCbs {
+    /// Soma os grupos `<IBSCBS>` dos itens
+    ///
+    /// Retorna `None` quando nenhum item informa valores de IBS/CBS.
+    pub fn somar(itens: &[Item]) -> Option<Self> {
+        let grupos: Vec<_> = itens
+            .iter()
+            .filter_map(|item| item.imposto.ibs_cbs.as_ref())
+            .filter_map(|ibs_cbs| ibs_cbs.valores.as_ref())
+            .collect();
+
+        if grupos.is_empty() {
+            return None;
+        }
+
+        let diferido = |d: &Option<Diferimento>| d.as_ref().map(|d| d.valor).unwrap_or_default();
+        let devolvido = |d: &Option<DevolucaoTributo>| d.as_ref().map(|d| d.valor).unwrap_or_default();
+
+        let mut total = Self::default();
+        for grupo in grupos {
+            total.valor_bc += grupo.valor_bc;
+
+            total.ibs.uf.valor_diferido += diferido(&grupo.ibs_uf.diferimento);
+            total.ibs.uf.valor_devolvido += devolvido(&grupo.ibs_uf.devolucao);
+            total.ibs.uf.valor += grupo.ibs_uf.valor;
+            total.ibs.municipal.valor_diferido += diferido(&grupo.ibs_municipal.diferimento);
+            total.ibs.municipal.valor_devolvido += devolvido(&grupo.ibs_municipal.devolucao);
+            total.ibs.municipal.valor += grupo.ibs_municipal.valor;
+            total.ibs.valor += grupo.valor_ibs;
+
+            total.cbs.valor_diferido += diferido(&grupo.cbs.diferimento);
+            total.cbs.valor_devolvido += devolvido(&grupo.cbs.devolucao);
+            total.cbs.valor += grupo.cbs.valor;
+
+            if let Some(credito) = &grupo.credito_presumido_ibs {
+                total.ibs.valor_credito_presumido += credito.valor.unwrap_or_default();
+                total.ibs.valor_credito_presumido_suspensivo +=
+                    credito.valor_condicao_suspensiva.unwrap_or_default();
+            }
+            if let Some(credito) = &grupo.credito_presumido_cbs {
+                total.cbs.valor_credito_presumido += credito.valor.unwrap_or_default();
+                total.cbs.valor_credito_presumido_suspensivo +=
+                    credito.valor_condicao_suspensiva.unwrap_or_default();
+            }
+        }
+
+        Some(total)
+    }
+}
+
+impl Totalizacao {
+    /// Soma os totais a partir dos itens
+    ///
+    /// Só os itens com `indTot = 1` compõem o `vProd`; os demais valores
+    /// (impostos, frete, desconto...) são somados em todos os itens. Os
+    /// itens com ISSQN vão para o `<ISSQNtot>` (ver [`TotalIssqn::somar`]).
+    /// O `vNF` segue [`Totalizacao::valor_nota`].
+    pub fn somar(itens: &[Item]) -> Self {
+        let mut total = Self::default();
+        let mut valor_imposto_seletivo = None;
+
+        for item in itens {
+            let produto = &item.produto;
+            let imposto = &item.imposto;
+
+            // Serviços sujeitos ao ISSQN são totalizados em `<ISSQNtot>`
+            let servico = imposto.issqn.is_some();
+
+            if produto.valor_compoe_total_nota && !servico {
+                total.valor_produtos += produto.valor_bruto;
+            }
+            total.valor_frete += produto.valor_frete.unwrap_or_default();
+            total.valor_seguro += produto.valor_seguro.unwrap_or_default();
+            total.valor_desconto += produto.valor_desconto.unwrap_or_default();
+            total.valor_outros += produto.valor_outros.unwrap_or_default();
+
+            if let Some(icms) = &imposto.icms {
+                total.valor_base_calculo += icms.valor_bc();
+                total.valor_icms += icms.valor_icms();
+                total.valor_icms_desonerado += icms.valor_icms_desonerado();
+                total.valor_fcp += icms.valor_fcp();
+                total.valor_base_calculo_st += icms.valor_bc_st();
+                total.valor_icms_st += icms.valor_st();
+                total.valor_fcp_st += icms.valor_fcp_st();
+                total.valor_fcp_st_retido += icms.valor_fcp_st_retido();
+            }
+            if let Some(difal) = &imposto.icms_uf_dest {
+                total.valor_fcp_uf_dest += difal.valor_fcp_uf_dest.unwrap_or_default();
+                total.valor_icms_uf_dest += difal.valor_icms_uf_dest;
+                total.valor_icms_uf_remet += difal.valor_icms_uf_remet;
+            }
+            total.valor_ipi += imposto.ipi.as_ref().map(|i| i.valor_ipi()).unwrap_or_default();
+            total.valor_ii += imposto.ii.as_ref().map(|i| i.valor).unwrap_or_default();
+            if !servico {
+                total.valor_pis += imposto.pis.as_ref().map(|p| p.valor()).unwrap_or_default();
+                total.valor_cofins += imposto.cofins.as_ref().map(|c| c.valor()).unwrap_or_default();
+            }
+            total.valor_aproximado_tributos += imposto.valor_aproximado_tributos.unwrap_or_default();
+            if let Some(devolvido) = &item.imposto_devolvido {
+                total.valor_ipi_devolvido += devolvido.ipi.valor;
+            }
+            if let Some(seletivo) = &imposto.imposto_seletivo {
+                *valor_imposto_seletivo.get_or_insert(Decimal::ZERO) += seletivo.valor;
+            }
+        }
+
+        total.servicos = TotalIssqn::somar(itens);
+        total.valor_total = total.valor_nota();
+        total.valor_imposto_seletivo = valor_imposto_seletivo;
+        total.ibs_cbs = TotalIbsCbs::somar(itens);
+        total
+    }
+
+    /// Valor da nota pelos totais informados (regra da rejeição 610)
+    ///
+    /// `vNF = vProd − vDesc + vST + vFCPST + vFrete + vSeg + vOutro + vII + vIPI + vIPIDevol + vServ`
+    ///
+    /// O ICMS desonerado não é deduzido (`indDeduzDeson` não informado).
+    pub fn valor_nota(&self) -> Decimal {
+        let valor_servicos = self.servicos.as_ref().map(|s| s.valor_servicos).unwrap_or_default();
+
+        self.valor_produtos - self.valor_desconto
+            + self.valor_icms_st
+            + self.valor_fcp_st
+            + self.valor_frete
+            + self.valor_seguro
+            + self.valor_outros
+            + self.valor_ii
+            + self.valor_ipi
+            + self.valor_ipi_devolvido
+            + valor_servicos
+    }
+
+    /// Confere os totais do `<ICMSTot>` com o somatório dos itens
+    ///
+    /// Retorna uma divergência por total, com o texto da rejeição que a
+    /// SEFAZ aplicaria na autorização.
+    pub fn divergencias(&self, itens: &[Item]) -> Vec<DivergenciaTotal> {
+        let calculado = Self::somar(itens);
+        let mut divergencias = Vec::new();
+        let mut verificar = |codigo, campo, mensagem, informado: Decimal, calculado: Decimal| {
+            if informado != calculado {
+                divergencias.push(DivergenciaTotal {
+                    codigo,
+                    campo,
+                    mensagem,
+                    informado,
+                    calculado,
+                });
+            }
+        };
+
+        verificar(
+            Some(531),
+            "vBC",
+            "Total da BC ICMS difere do somatório dos itens",
+            self.valor_base_calculo,
+            calculado.valor_base_calculo,
+        );
+        verificar(
+            Some(532),
+            "vICMS",
+            "Total do ICMS difere do somatório dos itens",
+            self.valor_icms,
+            calculado.valor_icms,
+        );
+        verificar(
+            Some(795),
+            "vICMSDeson",
+            "Total do ICMS desonerado difere do somatório dos itens",
+            self.valor_icms_desonerado,
+            calculado.valor_icms_desonerado,
+        );
+        verificar(
+            None,
+            "vFCPUFDest",
+            "Total do FCP da UF de destino difere do somatório dos itens",
+            self.valor_fcp_uf_dest,
+            calculado.valor_fcp_uf_dest,
+        );
+        verificar(
+            None,
+            "vICMSUFDest",
+            "Total do ICMS Interestadual da UF de destino difere do somatório dos itens",
+            self.valor_icms_uf_dest,
+            calculado.valor_icms_uf_dest,
+        );
+        verificar(
+            None,
+            "vICMSUFRemet",
+            "Total do ICMS Interestadual da UF do remetente difere do somatório dos itens",
+            self.valor_icms_uf_remet,
+            calculado.valor_icms_uf_remet,
+        );
+        verificar(
+            Some(861),
+            "vFCP",
+            "Total do FCP difere do somatório dos itens",
+            self.valor_fcp,
+            calculado.valor_fcp,
+        );
+        verificar(
+            Some(533),
+            "vBCST",
+            "Total da BC ICMS-ST difere do somatório dos itens",
+            self.valor_base_calculo_st,
+            calculado.valor_base_calculo_st,
+        );
+        verificar(
+            Some(534),
+            "vST",
+            "Total do ICMS-ST difere do somatório dos itens",
+            self.valor_icms_st,
+            calculado.valor_icms_st,
+        );
+        verificar(
+            Some(862),
+            "vFCPST",
+            "Total do FCP ST difere do somatório dos itens",
+            self.valor_fcp_st,
+            calculado.valor_fcp_st,
+        );
+        verificar(
+            Some(863),
+            "vFCPSTRet",
+            "Total do FCP ST retido anteriormente difere do somatório dos itens",
+            self.valor_fcp_st_retido,
+            calculado.valor_fcp_st_retido,
+        );
+        verificar(
+            Some(564),
+            "vProd",
+            "Total do Produto / Serviço difere do somatório dos itens",
+            self.valor_produtos,
+            calculado.valor_produtos,
+        );
+        verificar(
+            Some(535),
+            "vFrete",
+            "Total do Frete difere do somatório dos itens",
+            self.valor_frete,
+            calculado.valor_frete,
+        );
+        verificar(
+            Some(536),
+            "vSeg",
+            "Total do Seguro difere do somatório dos itens",
+            self.valor_seguro,
+            calculado.valor_seguro,
+        );
+        verificar(
+            Some(537),
+            "vDesc",
+            "Total do Desconto difere do somatório dos itens",
+            self.valor_desconto,
+            calculado.valor_desconto,
+        );
+        verificar(
+            Some(604),
+            "vOutro",
+            "Total do vOutro difere do somatório dos itens",
+            self.valor_outros,
+            calculado.valor_outros,
+        );
+        verificar(
+            Some(601),
+            "vII",
+            "Total do II difere do somatório dos itens",
+            self.valor_ii,
+            calculado.valor_ii,
+        );
+        verificar(
+            Some(538),
+            "vIPI",
+            "Total do IPI difere do somatório dos itens",
+            self.valor_ipi,
+            calculado.valor_ipi,
+        );
+        verificar(
+            Some(864),
+            "vIPIDevol",
+            "Total do IPI devolvido difere do somatório dos itens",
+            self.valor_ipi_devolvido,
+            calculado.valor_ipi_devolvido,
+        );
+        verificar(
+            Some(602),
+            "vPIS",
+            "Total do PIS difere do somatório dos itens",
+            self.valor_pis,
+            calculado.valor_pis,
+        );
+        verificar(
+            Some(603),
+            "vCOFINS",
+            "Total do COFINS difere do somatório dos itens",
+            self.valor_cofins,
+            calculado.valor_cofins,
+        );
+        let servicos = |total: &Self, valor: fn(&TotalIssqn) -> Decimal| {
+            total.servicos.as_ref().map(valor).unwrap_or_default()
+        };
+        verificar(
+            None,
+            "vServ",
+            "Total dos serviços difere do somatório dos itens com ISSQN",
+            servicos(self, |s| s.valor_servicos),
+            servicos(&calculado, |s| s.valor_servicos),
+        );
+        verificar(
+            None,
+            "vISS",
+            "Total do ISSQN difere do somatório dos itens",
+            servicos(self, |s| s.valor_iss),
+            servicos(&calculado, |s| s.valor_iss),
+        );
+        verificar(
+            Some(685),
+            "vTotTrib",
+            "Total do Valor Aproximado dos Tributos difere do somatório dos itens",
+            self.valor_aproximado_tributos,
+            calculado.valor_aproximado_tributos,
+        );
+        // O vNF é conferido com os próprios totais informados
+        verificar(
+            Some(610),
+            "vNF",
+            "Total da NF difere do somatório dos Valores compõe o valor Total da NF",
+            self.valor_total,
+            self.valor_nota(),
+        );
+
+        divergencias
+    }
+}
+
+/// Divergência entre um total da nota e o somatório dos itens
+#[derive(Debug, PartialEq, Clone)]
+pub struct DivergenciaTotal {
+    /// Código da rejeição da SEFAZ (`None` quando não há regra específica)
+    pub codigo: Option<u16>,
+    /// Tag do total divergente (ex: `vBC`)
+    pub campo: &'static str,
+    /// Texto da rejeição
+    pub mensagem: &'static str,
+    /// Valor informado no total
+    pub informado: Decimal,
+    /// Valor obtido a partir dos itens
+    pub calculado: Decimal,
+}
+
+impl fmt::Display for DivergenciaTotal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(codigo) = self.codigo {
+            write!(f, "Rejeição {}: ", codigo)?;
+        }
+        write!(
+            f,
+            "{} ({} informado {}, calculado {})",
+            self.mensagem,
+            self.campo,
+            formatar(self.informado, CASAS_VALOR, CASAS_VALOR),
+            formatar(self.calculado, CASAS_VALOR, CASAS_VALOR)
+        )
+    }
 }
 
 impl FromStr for Totalizacao {
@@ -76,38 +690,41 @@ impl FromStr for Totalizacao {
     }
 }
 
-impl ToString for Totalizacao {
-    fn to_string(&self) -> String {
-        quick_xml::se::to_string(self).expect("Falha ao serializar a totalização")
+impl fmt::Display for Totalizacao {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&quick_xml::se::to_string(self).expect("Falha ao serializar a totalização"))
     }
 }
 
 impl Default for Totalizacao {
     fn default() -> Self {
         Self {
-            valor_base_calculo: 0.0,
-            valor_icms: 0.0,
-            valor_icms_desonerado: 0.0,
-            valor_fcp: 0.0,
-            valor_base_calculo_st: 0.0,
-            valor_icms_st: 0.0,
-            valor_fcp_st: 0.0,
-            valor_fcp_st_retido: 0.0,
-            valor_produtos: 0.0,
-            valor_frete: 0.0,
-            valor_seguro: 0.0,
-            valor_desconto: 0.0,
-            valor_outros: 0.0,
-            valor_ipi: 0.0,
-            valor_ipi_devolvido: 0.0,
-            valor_ii: 0.0,
-            valor_pis: 0.0,
-            valor_cofins: 0.0,
-            valor_total: 0.0,
-            valor_aproximado_tributos: 0.0,
-            valor_fcp_uf_dest: 0.0,
-            valor_icms_uf_dest: 0.0,
-            valor_icms_uf_remet: 0.0,
+            valor_base_calculo: Decimal::ZERO,
+            valor_icms: Decimal::ZERO,
+            valor_icms_desonerado: Decimal::ZERO,
+            valor_fcp: Decimal::ZERO,
+            valor_base_calculo_st: Decimal::ZERO,
+            valor_icms_st: Decimal::ZERO,
+            valor_fcp_st: Decimal::ZERO,
+            valor_fcp_st_retido: Decimal::ZERO,
+            valor_produtos: Decimal::ZERO,
+            valor_frete: Decimal::ZERO,
+            valor_seguro: Decimal::ZERO,
+            valor_desconto: Decimal::ZERO,
+            valor_outros: Decimal::ZERO,
+            valor_ipi: Decimal::ZERO,
+            valor_ipi_devolvido: Decimal::ZERO,
+            valor_ii: Decimal::ZERO,
+            valor_pis: Decimal::ZERO,
+            valor_cofins: Decimal::ZERO,
+            valor_total: Decimal::ZERO,
+            valor_aproximado_tributos: Decimal::ZERO,
+            valor_fcp_uf_dest: Decimal::ZERO,
+            valor_icms_uf_dest: Decimal::ZERO,
+            valor_icms_uf_remet: Decimal::ZERO,
+            servicos: None,
+            valor_imposto_seletivo: None,
+            ibs_cbs: None,
         }
     }
 }
@@ -121,57 +738,46 @@ impl Serialize for Totalizacao {
             valor_base_calculo: self.valor_base_calculo,
             valor_icms: self.valor_icms,
             valor_icms_desonerado: self.valor_icms_desonerado,
-            valor_fcp: if self.valor_fcp > 0.0 {
-                Some(self.valor_fcp)
-            } else {
-                None
-            },
+            valor_fcp: self.valor_fcp,
             valor_base_calculo_st: self.valor_base_calculo_st,
             valor_icms_st: self.valor_icms_st,
-            valor_fcp_st: if self.valor_fcp_st > 0.0 {
-                Some(self.valor_fcp_st)
-            } else {
-                None
-            },
-            valor_fcp_st_retido: if self.valor_fcp_st_retido > 0.0 {
-                Some(self.valor_fcp_st_retido)
-            } else {
-                None
-            },
+            valor_fcp_st: self.valor_fcp_st,
+            valor_fcp_st_retido: self.valor_fcp_st_retido,
             valor_produtos: self.valor_produtos,
             valor_frete: self.valor_frete,
             valor_seguro: self.valor_seguro,
             valor_desconto: self.valor_desconto,
             valor_outros: self.valor_outros,
             valor_ipi: self.valor_ipi,
-            valor_ipi_devolvido: if self.valor_ipi_devolvido > 0.0 {
-                Some(self.valor_ipi_devolvido)
-            } else {
-                None
-            },
+            valor_ipi_devolvido: self.valor_ipi_devolvido,
             valor_ii: self.valor_ii,
             valor_pis: self.valor_pis,
             valor_cofins: self.valor_cofins,
             valor_total: self.valor_total,
             valor_aproximado_tributos: self.valor_aproximado_tributos,
-            valor_fcp_uf_dest: if self.valor_fcp_uf_dest > 0.0 {
+            valor_fcp_uf_dest: if self.valor_fcp_uf_dest > Decimal::ZERO {
                 Some(self.valor_fcp_uf_dest)
             } else {
                 None
             },
-            valor_icms_uf_dest: if self.valor_icms_uf_dest > 0.0 {
+            valor_icms_uf_dest: if self.valor_icms_uf_dest > Decimal::ZERO {
                 Some(self.valor_icms_uf_dest)
             } else {
                 None
             },
-            valor_icms_uf_remet: if self.valor_icms_uf_remet > 0.0 {
+            valor_icms_uf_remet: if self.valor_icms_uf_remet > Decimal::ZERO {
                 Some(self.valor_icms_uf_remet)
             } else {
                 None
             },
         };
 
-        let total = TotalContainer { icms };
+        let total = TotalContainer {
+            icms,
+            servicos: self.servicos.clone(),
+            imposto_seletivo: self.valor_imposto_seletivo.map(|valor| IsTot { valor }),
+            ibs_cbs: self.ibs_cbs.clone(),
+        };
 
         total.serialize(serializer)
     }
@@ -187,26 +793,29 @@ impl<'de> Deserialize<'de> for Totalizacao {
             valor_base_calculo: helper.icms.valor_base_calculo,
             valor_icms: helper.icms.valor_icms,
             valor_icms_desonerado: helper.icms.valor_icms_desonerado,
-            valor_fcp: helper.icms.valor_fcp.unwrap_or(0.0),
+            valor_fcp: helper.icms.valor_fcp,
             valor_base_calculo_st: helper.icms.valor_base_calculo_st,
             valor_icms_st: helper.icms.valor_icms_st,
-            valor_fcp_st: helper.icms.valor_fcp_st.unwrap_or(0.0),
-            valor_fcp_st_retido: helper.icms.valor_fcp_st_retido.unwrap_or(0.0),
+            valor_fcp_st: helper.icms.valor_fcp_st,
+            valor_fcp_st_retido: helper.icms.valor_fcp_st_retido,
             valor_produtos: helper.icms.valor_produtos,
             valor_frete: helper.icms.valor_frete,
             valor_seguro: helper.icms.valor_seguro,
             valor_desconto: helper.icms.valor_desconto,
             valor_outros: helper.icms.valor_outros,
             valor_ipi: helper.icms.valor_ipi,
-            valor_ipi_devolvido: helper.icms.valor_ipi_devolvido.unwrap_or(0.0),
+            valor_ipi_devolvido: helper.icms.valor_ipi_devolvido,
             valor_ii: helper.icms.valor_ii,
             valor_pis: helper.icms.valor_pis,
             valor_cofins: helper.icms.valor_cofins,
             valor_total: helper.icms.valor_total,
             valor_aproximado_tributos: helper.icms.valor_aproximado_tributos,
-            valor_fcp_uf_dest: helper.icms.valor_fcp_uf_dest.unwrap_or(0.0),
-            valor_icms_uf_dest: helper.icms.valor_icms_uf_dest.unwrap_or(0.0),
-            valor_icms_uf_remet: helper.icms.valor_icms_uf_remet.unwrap_or(0.0),
+            valor_fcp_uf_dest: helper.icms.valor_fcp_uf_dest.unwrap_or_default(),
+            valor_icms_uf_dest: helper.icms.valor_icms_uf_dest.unwrap_or_default(),
+            valor_icms_uf_remet: helper.icms.valor_icms_uf_remet.unwrap_or_default(),
+            servicos: helper.servicos,
+            valor_imposto_seletivo: helper.imposto_seletivo.map(|is| is.valor),
+            ibs_cbs: helper.ibs_cbs,
         })
     }
 }
@@ -216,89 +825,137 @@ impl<'de> Deserialize<'de> for Totalizacao {
 struct TotalContainer {
     #[serde(rename = "ICMSTot")]
     icms: IcmsTot,
+
+    #[serde(rename = "ISSQNtot")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    servicos: Option<TotalIssqn>,
+
+    #[serde(rename = "ISTot")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    imposto_seletivo: Option<IsTot>,
+
+    #[serde(rename = "IBSCBSTot")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    ibs_cbs: Option<TotalIbsCbs>,
+}
+
+#[derive(Deserialize, Serialize)]
+struct IsTot {
+    #[serde(rename = "$unflatten=vIS")]
+    #[serde(with = "crate::base::decimal::valor")]
+    valor: Decimal,
 }
 
 #[derive(Deserialize, Serialize)]
 struct IcmsTot {
     #[serde(rename = "$unflatten=vBC")]
-    valor_base_calculo: f32,
+    #[serde(with = "crate::base::decimal::valor")]
+    valor_base_calculo: Decimal,
 
     #[serde(rename = "$unflatten=vICMS")]
-    valor_icms: f32,
+    #[serde(with = "crate::base::decimal::valor")]
+    valor_icms: Decimal,
 
     #[serde(rename = "$unflatten=vICMSDeson")]
+    #[serde(with = "crate::base::decimal::valor")]
     #[serde(default)]
-    valor_icms_desonerado: f32,
+    valor_icms_desonerado: Decimal,
 
-    #[serde(rename = "$unflatten=vFCP")]
+    // Campos de DIFAL
+    #[serde(rename = "$unflatten=vFCPUFDest")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    valor_fcp_uf_dest: Option<Decimal>,
+
+    #[serde(rename = "$unflatten=vICMSUFDest")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    valor_icms_uf_dest: Option<Decimal>,
+
+    #[serde(rename = "$unflatten=vICMSUFRemet")]
+    #[serde(with = "crate::base::decimal::valor_opcional")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    valor_fcp: Option<f32>,
+    #[serde(default)]
+    valor_icms_uf_remet: Option<Decimal>,
+
+    #[serde(rename = "$unflatten=vFCP")]
+    #[serde(with = "crate::base::decimal::valor")]
+    #[serde(default)]
+    valor_fcp: Decimal,
 
     #[serde(rename = "$unflatten=vBCST")]
+    #[serde(with = "crate::base::decimal::valor")]
     #[serde(default)]
-    valor_base_calculo_st: f32,
+    valor_base_calculo_st: Decimal,
 
     #[serde(rename = "$unflatten=vST")]
+    #[serde(with = "crate::base::decimal::valor")]
     #[serde(default)]
-    valor_icms_st: f32,
+    valor_icms_st: Decimal,
 
     #[serde(rename = "$unflatten=vFCPST")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    valor_fcp_st: Option<f32>,
+    #[serde(with = "crate::base::decimal::valor")]
+    #[serde(default)]
+    valor_fcp_st: Decimal,
 
     #[serde(rename = "$unflatten=vFCPSTRet")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    valor_fcp_st_retido: Option<f32>,
+    #[serde(with = "crate::base::decimal::valor")]
+    #[serde(default)]
+    valor_fcp_st_retido: Decimal,
 
     #[serde(rename = "$unflatten=vProd")]
-    valor_produtos: f32,
+    #[serde(with = "crate::base::decimal::valor")]
+    valor_produtos: Decimal,
 
     #[serde(rename = "$unflatten=vFrete")]
-    valor_frete: f32,
+    #[serde(with = "crate::base::decimal::valor")]
+    valor_frete: Decimal,
 
     #[serde(rename = "$unflatten=vSeg")]
-    valor_seguro: f32,
+    #[serde(with = "crate::base::decimal::valor")]
+    valor_seguro: Decimal,
 
     #[serde(rename = "$unflatten=vDesc")]
-    valor_desconto: f32,
-
-    #[serde(rename = "$unflatten=vOutro")]
-    valor_outros: f32,
+    #[serde(with = "crate::base::decimal::valor")]
+    valor_desconto: Decimal,
 
     #[serde(rename = "$unflatten=vII")]
+    #[serde(with = "crate::base::decimal::valor")]
     #[serde(default)]
-    valor_ii: f32,
+    valor_ii: Decimal,
 
     #[serde(rename = "$unflatten=vIPI")]
+    #[serde(with = "crate::base::decimal::valor")]
     #[serde(default)]
-    valor_ipi: f32,
+    valor_ipi: Decimal,
 
     #[serde(rename = "$unflatten=vIPIDevol")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    valor_ipi_devolvido: Option<f32>,
+    #[serde(with = "crate::base::decimal::valor")]
+    #[serde(default)]
+    valor_ipi_devolvido: Decimal,
 
     #[serde(rename = "$unflatten=vPIS")]
-    valor_pis: f32,
+    #[serde(with = "crate::base::decimal::valor")]
+    valor_pis: Decimal,
 
     #[serde(rename = "$unflatten=vCOFINS")]
-    valor_cofins: f32,
+    #[serde(with = "crate::base::decimal::valor")]
+    valor_cofins: Decimal,
+
+    #[serde(rename = "$unflatten=vOutro")]
+    #[serde(with = "crate::base::decimal::valor")]
+    valor_outros: Decimal,
 
     #[serde(rename = "$unflatten=vNF")]
-    valor_total: f32,
+    #[serde(with = "crate::base::decimal::valor")]
+    valor_total: Decimal,
 
     #[serde(rename = "$unflatten=vTotTrib")]
-    valor_aproximado_tributos: f32,
-
-    // Campos de DIFAL
-    #[serde(rename = "$unflatten=vFCPUFDest")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    valor_fcp_uf_dest: Option<f32>,
-
-    #[serde(rename = "$unflatten=vICMSUFDest")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    valor_icms_uf_dest: Option<f32>,
-
-    #[serde(rename = "$unflatten=vICMSUFRemet")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    valor_icms_uf_remet: Option<f32>,
+    #[serde(with = "crate::base::decimal::valor")]
+    valor_aproximado_tributos: Decimal,
 }
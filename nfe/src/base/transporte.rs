@@ -5,23 +5,33 @@
 //!
 //! ## Estrutura Completa do Grupo Transporte
 //!
-//! O grupo `<transp>` pode conter:
-//! - `<modFrete>`: Modalidade do frete (obrigatório)
-//! - `<transporta>`: Dados do transportador (opcional)
-//! - `<veicTransp>`: Dados do veículo (opcional)
-//! - `<reboque>`: Dados de reboques (opcional, múltiplos)
-//! - `<vol>`: Dados dos volumes transportados (opcional, múltiplos)
+//! ```text
+//! <transp>
+//!   <modFrete>      <!-- Modalidade do frete (obrigatório) -->
+//!   <transporta>    <!-- Transportador -->
+//!   <retTransp>     <!-- Retenção do ICMS sobre o frete -->
+//!   <veicTransp>    <!-- Veículo de tração -->
+//!   <reboque>       <!-- Reboques (até 5) -->
+//!   <vagao>         <!-- Identificação do vagão (alternativo ao veículo) -->
+//!   <balsa>         <!-- Identificação da balsa (alternativo ao veículo) -->
+//!   <vol>           <!-- Volumes transportados (múltiplos) -->
+//!     <lacres>      <!-- Lacres dos volumes (múltiplos) -->
+//! </transp>
+//! ```
 //!
-//! ## Nota sobre Implementação
-//!
-//! Esta biblioteca implementa apenas o campo obrigatório `<modFrete>`.
-//! Os demais campos podem ser adicionados conforme necessidade.
+//! Veículo/reboques, vagão e balsa são mutuamente exclusivos.
 
+use super::decimal::Decimal;
+use super::documento::DocumentoNacional;
 use super::Error;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use serde_repr::{Deserialize_repr, Serialize_repr};
+use std::fmt;
 use std::str::FromStr;
 
+/// Quantidade máxima de reboques por NF-e
+pub const MAX_REBOQUES: usize = 5;
+
 /// Grupo de Informações do Transporte (tag `<transp>`)
 ///
 /// Contém as informações relacionadas ao transporte dos produtos
@@ -30,13 +40,8 @@ use std::str::FromStr;
 /// ## Campo Obrigatório
 ///
 /// O único campo obrigatório é `modFrete`, que indica quem é
-/// responsável pelo frete (CIF, FOB, etc.).
-///
-/// ## Campos Opcionais (não implementados)
-///
-/// - Dados do transportador (CNPJ, nome, endereço)
-/// - Dados do veículo (placa, UF, RNTC)
-/// - Dados dos volumes (quantidade, peso bruto/líquido)
+/// responsável pelo frete (CIF, FOB, etc.). Os demais grupos são
+/// informados conforme a operação.
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
 #[serde(rename = "transp")]
 pub struct Transporte {
@@ -44,6 +49,228 @@ pub struct Transporte {
     /// Define quem é responsável pela contratação e/ou pagamento do frete
     #[serde(rename = "$unflatten=modFrete")]
     pub modalidade: ModalidadeFrete,
+
+    /// Dados do transportador (tag `<transporta>`)
+    #[serde(rename = "transporta")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub transportador: Option<Transportador>,
+
+    /// Retenção do ICMS sobre o serviço de transporte (tag `<retTransp>`)
+    #[serde(rename = "retTransp")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub retencao_icms: Option<RetencaoIcmsTransporte>,
+
+    /// Veículo de tração (tag `<veicTransp>`)
+    #[serde(rename = "veicTransp")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub veiculo: Option<Veiculo>,
+
+    /// Reboques (tags `<reboque>`, até 5)
+    #[serde(rename = "reboque")]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
+    pub reboques: Vec<Veiculo>,
+
+    /// Identificação do vagão (tag `<vagao>`)
+    #[serde(rename = "$unflatten=vagao")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub vagao: Option<String>,
+
+    /// Identificação da balsa (tag `<balsa>`)
+    #[serde(rename = "$unflatten=balsa")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub balsa: Option<String>,
+
+    /// Volumes transportados (tags `<vol>`)
+    #[serde(rename = "vol")]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
+    pub volumes: Vec<Volume>,
+}
+
+/// Transportador (tag `<transporta>`)
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct Transportador {
+    /// CNPJ ou CPF do transportador (tags `<CNPJ>` / `<CPF>`)
+    pub documento: Option<DocumentoNacional>,
+
+    /// Razão social ou nome (tag `<xNome>`)
+    pub razao_social: Option<String>,
+
+    /// Inscrição estadual (tag `<IE>`)
+    pub ie: Option<String>,
+
+    /// Endereço completo (tag `<xEnder>`)
+    pub endereco: Option<String>,
+
+    /// Nome do município (tag `<xMun>`)
+    pub municipio: Option<String>,
+
+    /// Sigla da UF (tag `<UF>`)
+    pub uf: Option<String>,
+}
+
+/// Retenção do ICMS do Transporte (tag `<retTransp>`)
+///
+/// Informado quando o destinatário é responsável pela retenção do
+/// ICMS sobre o frete contratado de transportador autônomo.
+#[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+pub struct RetencaoIcmsTransporte {
+    /// Valor do serviço (tag `<vServ>`)
+    #[serde(rename = "$unflatten=vServ")]
+    #[serde(with = "crate::base::decimal::valor")]
+    pub valor_servico: Decimal,
+
+    /// Base de cálculo da retenção (tag `<vBCRet>`)
+    #[serde(rename = "$unflatten=vBCRet")]
+    #[serde(with = "crate::base::decimal::valor")]
+    pub valor_bc: Decimal,
+
+    /// Alíquota da retenção (tag `<pICMSRet>`)
+    #[serde(rename = "$unflatten=pICMSRet")]
+    #[serde(with = "crate::base::decimal::percentual")]
+    pub aliquota: Decimal,
+
+    /// Valor do ICMS retido (tag `<vICMSRet>`)
+    #[serde(rename = "$unflatten=vICMSRet")]
+    #[serde(with = "crate::base::decimal::valor")]
+    pub valor: Decimal,
+
+    /// CFOP do serviço de transporte (tag `<CFOP>`)
+    #[serde(rename = "$unflatten=CFOP")]
+    pub cfop: String,
+
+    /// Município de ocorrência do fato gerador (tag `<cMunFG>`)
+    #[serde(rename = "$unflatten=cMunFG")]
+    pub codigo_municipio: u32,
+}
+
+/// Veículo de tração ou reboque (tags `<veicTransp>` e `<reboque>`)
+#[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+pub struct Veiculo {
+    /// Placa do veículo (tag `<placa>`)
+    #[serde(rename = "$unflatten=placa")]
+    pub placa: String,
+
+    /// Sigla da UF de licenciamento (tag `<UF>`)
+    #[serde(rename = "$unflatten=UF")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub uf: Option<String>,
+
+    /// Registro Nacional de Transportador de Carga - ANTT (tag `<RNTC>`)
+    #[serde(rename = "$unflatten=RNTC")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub rntc: Option<String>,
+}
+
+/// Volume transportado (tag `<vol>`)
+#[derive(Debug, PartialEq, Serialize, Deserialize, Clone, Default)]
+pub struct Volume {
+    /// Quantidade de volumes (tag `<qVol>`)
+    #[serde(rename = "$unflatten=qVol")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub quantidade: Option<u32>,
+
+    /// Espécie dos volumes (tag `<esp>`)
+    #[serde(rename = "$unflatten=esp")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub especie: Option<String>,
+
+    /// Marca dos volumes (tag `<marca>`)
+    #[serde(rename = "$unflatten=marca")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub marca: Option<String>,
+
+    /// Numeração dos volumes (tag `<nVol>`)
+    #[serde(rename = "$unflatten=nVol")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub numeracao: Option<String>,
+
+    /// Peso líquido em kg (tag `<pesoL>`)
+    #[serde(rename = "$unflatten=pesoL")]
+    #[serde(with = "crate::base::decimal::peso_opcional")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub peso_liquido: Option<Decimal>,
+
+    /// Peso bruto em kg (tag `<pesoB>`)
+    #[serde(rename = "$unflatten=pesoB")]
+    #[serde(with = "crate::base::decimal::peso_opcional")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub peso_bruto: Option<Decimal>,
+
+    /// Lacres dos volumes (tags `<lacres>`)
+    #[serde(rename = "lacres")]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
+    pub lacres: Vec<Lacre>,
+}
+
+/// Lacre de um volume (tag `<lacres>`)
+#[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+pub struct Lacre {
+    /// Número do lacre (tag `<nLacre>`)
+    #[serde(rename = "$unflatten=nLacre")]
+    pub numero: String,
+}
+
+impl Transporte {
+    /// Cria o grupo de transporte apenas com a modalidade do frete
+    pub fn new(modalidade: ModalidadeFrete) -> Self {
+        Self {
+            modalidade,
+            transportador: None,
+            retencao_icms: None,
+            veiculo: None,
+            reboques: Vec::new(),
+            vagao: None,
+            balsa: None,
+            volumes: Vec::new(),
+        }
+    }
+
+    /// Valida as regras de exclusividade e quantidade do grupo
+    ///
+    /// - No máximo 5 reboques
+    /// - Veículo/reboques, vagão e balsa são mutuamente exclusivos
+    pub fn validar(&self) -> Result<(), String> {
+        if self.reboques.len() > MAX_REBOQUES {
+            return Err(format!(
+                "Máximo de {} reboques por NF-e (informados: {})",
+                MAX_REBOQUES,
+                self.reboques.len()
+            ));
+        }
+
+        let rodoviario = self.veiculo.is_some() || !self.reboques.is_empty();
+        let alternativas = [rodoviario, self.vagao.is_some(), self.balsa.is_some()];
+        if alternativas.iter().filter(|informado| **informado).count() > 1 {
+            return Err("Informe apenas um entre veículo/reboque, vagão ou balsa".to_string());
+        }
+
+        Ok(())
+    }
+}
+
+impl Lacre {
+    /// Cria um lacre a partir do número
+    pub fn new(numero: &str) -> Self {
+        Self {
+            numero: numero.to_string(),
+        }
+    }
 }
 
 impl FromStr for Transporte {
@@ -54,6 +281,87 @@ impl FromStr for Transporte {
     }
 }
 
+impl fmt::Display for Transporte {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&quick_xml::se::to_string(self).expect("Falha ao serializar o transporte"))
+    }
+}
+
+impl<'de> Deserialize<'de> for Transportador {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let transporta = TransportadorContainer::deserialize(deserializer)?;
+
+        Ok(Self {
+            documento: DocumentoNacional::from_tags(transporta.cnpj, transporta.cpf),
+            razao_social: transporta.razao_social,
+            ie: transporta.ie,
+            endereco: transporta.endereco,
+            municipio: transporta.municipio,
+            uf: transporta.uf,
+        })
+    }
+}
+
+impl Serialize for Transportador {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (cnpj, cpf) = match &self.documento {
+            Some(documento) => documento.to_tags(),
+            None => (None, None),
+        };
+
+        let transporta = TransportadorContainer {
+            cnpj,
+            cpf,
+            razao_social: self.razao_social.clone(),
+            ie: self.ie.clone(),
+            endereco: self.endereco.clone(),
+            municipio: self.municipio.clone(),
+            uf: self.uf.clone(),
+        };
+
+        transporta.serialize(serializer)
+    }
+}
+
+#[derive(Deserialize, Serialize)]
+#[serde(rename = "transporta")]
+struct TransportadorContainer {
+    #[serde(rename = "$unflatten=CNPJ")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub cnpj: Option<String>,
+    #[serde(rename = "$unflatten=CPF")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub cpf: Option<String>,
+    #[serde(rename = "$unflatten=xNome")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub razao_social: Option<String>,
+    #[serde(rename = "$unflatten=IE")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub ie: Option<String>,
+    #[serde(rename = "$unflatten=xEnder")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub endereco: Option<String>,
+    #[serde(rename = "$unflatten=xMun")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub municipio: Option<String>,
+    #[serde(rename = "$unflatten=UF")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub uf: Option<String>,
+}
+
 /// Modalidade do Frete (tag `<modFrete>`)
 ///
 /// Indica quem é responsável pela contratação e pagamento do frete.
@@ -0,0 +1,179 @@
+//! Regras da identificação: chave de acesso, datas e CFOP × destino
+
+use super::Rejeicao;
+use crate::base::ide::{DestinoOperacao, ModeloDocumentoFiscal, TipoEmissao, TipoOperacao};
+use crate::base::Nfe;
+use crate::builder::calcular_dv;
+use chrono::{DateTime, Duration, Utc};
+
+/// Tolerância para o relógio do emissor adiantado em relação à SEFAZ
+const TOLERANCIA_RELOGIO_MINUTOS: i64 = 5;
+/// Prazo máximo entre a emissão e a autorização da NF-e
+const PRAZO_EMISSAO_DIAS: i64 = 30;
+/// Prazo máximo entre a emissão e a autorização da NFC-e em emissão normal
+const PRAZO_EMISSAO_NFCE_MINUTOS: i64 = 5;
+/// Prazo máximo entre a emissão e a saída/entrada da mercadoria
+const PRAZO_SAIDA_DIAS: i64 = 30;
+
+pub(super) fn validar(nfe: &Nfe, agora: DateTime<Utc>, rejeicoes: &mut Vec<Rejeicao>) {
+    validar_chave(nfe, rejeicoes);
+    validar_datas(nfe, agora, rejeicoes);
+    validar_destino(nfe, rejeicoes);
+}
+
+/// Confere a chave de acesso com os campos do `<ide>` e do emitente
+fn validar_chave(nfe: &Nfe, rejeicoes: &mut Vec<Rejeicao>) {
+    let chave = nfe.chave_acesso.as_str();
+    let id_divergente = |detalhe: String| {
+        Rejeicao::new(
+            502,
+            "Erro na Chave de Acesso - Campo Id não corresponde à concatenação dos campos correspondentes",
+        )
+        .com_detalhe(detalhe)
+    };
+
+    if chave.len() != 44 || !chave.chars().all(|c| c.is_ascii_digit()) {
+        rejeicoes.push(id_divergente(format!("chave com formato inválido: {}", chave)));
+        return;
+    }
+    if calcular_dv(&chave[..43]).to_string() != chave[43..] {
+        rejeicoes.push(
+            Rejeicao::new(236, "Chave de Acesso com dígito verificador inválido").com_detalhe(chave),
+        );
+    }
+
+    let ide = &nfe.ide;
+    // O AAMM da chave é o mês do dhEmi no fuso informado
+    let aamm = ide.emissao.horario.format("%y%m").to_string();
+    let campos = [
+        ("cUF", 0..2, format!("{:02}", ide.codigo_uf)),
+        ("CNPJ/CPF", 6..20, format!("{:0>14}", nfe.emit.documento.numero())),
+        ("mod", 20..22, format!("{:02}", ide.modelo as u8)),
+        ("serie", 22..25, format!("{:03}", ide.serie)),
+        ("nNF", 25..34, format!("{:09}", ide.numero)),
+        ("tpEmis", 34..35, format!("{}", ide.emissao.tipo as u8)),
+        ("cNF", 35..43, format!("{:0>8}", ide.chave.codigo)),
+        ("cDV", 43..44, format!("{}", ide.chave.digito_verificador)),
+    ];
+
+    if aamm != chave[2..6] {
+        rejeicoes.push(id_divergente(format!("AAMM da chave {} difere do dhEmi", &chave[2..6])));
+    }
+    for (campo, posicao, esperado) in campos {
+        if chave[posicao.clone()] != esperado {
+            rejeicoes.push(id_divergente(format!(
+                "{} da chave {} difere do informado {}",
+                campo,
+                &chave[posicao],
+                esperado
+            )));
+        }
+    }
+
+    if ide.chave.codigo.parse::<u32>().ok() == Some(ide.numero) {
+        rejeicoes.push(
+            Rejeicao::new(897, "Código numérico em formato inválido").com_detalhe("cNF igual ao nNF"),
+        );
+    }
+}
+
+/// Confere a data de emissão com o horário de recebimento e a de saída/entrada
+fn validar_datas(nfe: &Nfe, agora: DateTime<Utc>, rejeicoes: &mut Vec<Rejeicao>) {
+    let emissao = nfe.ide.emissao.horario.with_timezone(&Utc);
+
+    if emissao > agora + Duration::minutes(TOLERANCIA_RELOGIO_MINUTOS) {
+        rejeicoes.push(Rejeicao::new(
+            703,
+            "Data-Hora de Emissão posterior ao horário de recebimento",
+        ));
+    }
+    match nfe.ide.modelo {
+        ModeloDocumentoFiscal::Nfe => {
+            if agora - emissao > Duration::days(PRAZO_EMISSAO_DIAS) {
+                rejeicoes.push(Rejeicao::new(228, "Data de Emissão muito atrasada"));
+            }
+        }
+        ModeloDocumentoFiscal::Nfce => {
+            if nfe.ide.emissao.tipo == TipoEmissao::Normal
+                && agora - emissao > Duration::minutes(PRAZO_EMISSAO_NFCE_MINUTOS)
+            {
+                rejeicoes.push(Rejeicao::new(704, "NFC-e com Data-Hora de emissão atrasada"));
+            }
+        }
+    }
+
+    if let Some(saida) = nfe.ide.operacao.horario {
+        if saida > emissao + Duration::days(PRAZO_SAIDA_DIAS) {
+            rejeicoes.push(Rejeicao::new(504, "Data de Entrada/Saída posterior ao permitido"));
+        }
+        if saida < emissao {
+            rejeicoes.push(Rejeicao::new(505, "Data de Entrada/Saída anterior ao permitido"));
+        }
+    }
+}
+
+/// Confere o destino da operação (`idDest`) com as UFs e o CFOP dos itens
+fn validar_destino(nfe: &Nfe, rejeicoes: &mut Vec<Rejeicao>) {
+    let destino = nfe.ide.operacao.destino;
+    let uf_emitente = nfe.emit.endereco.sigla_uf.as_str();
+    let uf_destino = nfe
+        .dest
+        .as_ref()
+        .and_then(|dest| dest.endereco.as_ref())
+        .map(|endereco| endereco.sigla_uf.as_str())
+        .filter(|uf| !uf.is_empty());
+
+    if let Some(uf_destino) = uf_destino {
+        if destino == DestinoOperacao::Interna && uf_destino != uf_emitente {
+            rejeicoes.push(Rejeicao::new(773, "Operação Interna e UF de destino difere da UF do emitente"));
+        }
+        if destino == DestinoOperacao::Interestadual && uf_destino == uf_emitente {
+            rejeicoes.push(Rejeicao::new(
+                772,
+                "Operação Interestadual e UF de destino igual à UF do emitente",
+            ));
+        }
+    }
+
+    for item in &nfe.itens {
+        let cfop = item.produto.tributacao.cfop.as_str();
+        let detalhe = || format!("item {}: CFOP {}", item.numero, cfop);
+
+        match (nfe.ide.operacao.tipo, cfop.chars().next()) {
+            (TipoOperacao::Saida, Some('1'..='3')) => rejeicoes
+                .push(Rejeicao::new(518, "CFOP de entrada para NF-e de saída").com_detalhe(detalhe())),
+            (TipoOperacao::Entrada, Some('5'..='7')) => rejeicoes
+                .push(Rejeicao::new(519, "CFOP de saída para NF-e de entrada").com_detalhe(detalhe())),
+            _ => {}
+        }
+
+        match (destino, cfop.chars().next()) {
+            (DestinoOperacao::Interna, Some('2' | '3' | '6' | '7')) => rejeicoes.push(
+                Rejeicao::new(
+                    523,
+                    "CFOP não é de Operação Estadual e UF emitente igual à UF destinatário",
+                )
+                .com_detalhe(detalhe()),
+            ),
+            (DestinoOperacao::Interestadual, Some('1' | '5')) => rejeicoes.push(
+                Rejeicao::new(
+                    521,
+                    "CFOP de Operação Estadual e UF do emitente difere da UF do destinatário para \
+                    destinatário contribuinte do ICMS",
+                )
+                .com_detalhe(detalhe()),
+            ),
+            (DestinoOperacao::Interestadual, Some('3' | '7')) if uf_destino != Some("EX") => {
+                rejeicoes.push(
+                    Rejeicao::new(520, "CFOP de Operação com Exterior e UF destinatário difere de EX")
+                        .com_detalhe(detalhe()),
+                )
+            }
+            (DestinoOperacao::ComExterior, Some('1' | '2' | '5' | '6')) => rejeicoes.push(
+                Rejeicao::sem_codigo("CFOP incompatível com operação com exterior (idDest = 3)")
+                    .com_detalhe(detalhe()),
+            ),
+            _ => {}
+        }
+    }
+}
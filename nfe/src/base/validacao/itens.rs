@@ -0,0 +1,66 @@
+//! Regras dos itens: quantidade, NCM e GTIN
+
+use super::Rejeicao;
+use crate::base::Nfe;
+
+/// Quantidade máxima de itens (`<det>`) por nota
+const MAXIMO_ITENS: usize = 990;
+
+pub(super) fn validar(nfe: &Nfe, rejeicoes: &mut Vec<Rejeicao>) {
+    rejeicoes.extend(validar_quantidade(nfe.itens.len()));
+
+    for item in &nfe.itens {
+        let produto = &item.produto;
+
+        if !ncm_valido(&produto.ncm) {
+            rejeicoes.push(
+                Rejeicao::new(777, "Obrigatória a informação do NCM completo")
+                    .com_detalhe(format!("item {}: NCM {}", item.numero, produto.ncm)),
+            );
+        }
+        if let Some(gtin) = produto.gtin.as_deref().filter(|gtin| !gtin_valido(gtin)) {
+            rejeicoes.push(
+                Rejeicao::new(611, "cEAN inválido").com_detalhe(format!("item {}: {}", item.numero, gtin)),
+            );
+        }
+        if let Some(gtin) = produto.tributacao.gtin.as_deref().filter(|gtin| !gtin_valido(gtin)) {
+            rejeicoes.push(
+                Rejeicao::new(612, "cEANTrib inválido")
+                    .com_detalhe(format!("item {}: {}", item.numero, gtin)),
+            );
+        }
+    }
+}
+
+/// A nota deve ter de 1 a 990 itens
+pub(crate) fn validar_quantidade(quantidade: usize) -> Option<Rejeicao> {
+    (quantidade == 0 || quantidade > MAXIMO_ITENS).then(|| {
+        Rejeicao::new(225, "Falha no Schema XML da NFe").com_detalhe(format!(
+            "a nota deve ter de 1 a {} itens (encontrados: {})",
+            MAXIMO_ITENS, quantidade
+        ))
+    })
+}
+
+/// NCM com 8 dígitos, ou `00` para serviços e itens sem classificação
+fn ncm_valido(ncm: &str) -> bool {
+    ncm == "00" || (ncm.len() == 8 && ncm.chars().all(|c| c.is_ascii_digit()))
+}
+
+/// GTIN-8, 12, 13 ou 14 com dígito verificador (módulo 10 do GS1) correto
+fn gtin_valido(gtin: &str) -> bool {
+    if !matches!(gtin.len(), 8 | 12 | 13 | 14) || !gtin.chars().all(|c| c.is_ascii_digit()) {
+        return false;
+    }
+
+    let digitos: Vec<u32> = gtin.chars().filter_map(|c| c.to_digit(10)).collect();
+    let (verificador, corpo) = digitos.split_last().expect("GTIN com ao menos 8 dígitos");
+    let soma: u32 = corpo
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(i, digito)| if i % 2 == 0 { digito * 3 } else { *digito })
+        .sum();
+
+    (10 - soma % 10) % 10 == *verificador
+}
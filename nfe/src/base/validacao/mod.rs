@@ -0,0 +1,203 @@
+//! Validação das regras de negócio da NF-e
+//!
+//! Aplica sobre uma [`Nfe`] as regras de validação do Manual de Orientação
+//! do Contribuinte (MOC 4.00), antecipando as rejeições que a SEFAZ
+//! devolveria na autorização. Cada falha traz o código (`cStat`) e o
+//! texto oficial da rejeição.
+//!
+//! As regras são agrupadas em conjuntos ([`ConjuntoRegras`]):
+//!
+//! | Conjunto | Regras |
+//! |----------|--------|
+//! | Identificação | documento do emitente, chave de acesso × `<ide>`, cNF × nNF, datas, CFOP × `idDest`/`tpNF` |
+//! | Tributação | CST × CRT |
+//! | Itens | quantidade de itens, NCM, GTIN |
+//! | Totais | totais × somatório dos itens (ver [`Totalizacao::divergencias`]) |
+//! | NFC-e | regras específicas do modelo 65 |
+//!
+//! ## Exemplo
+//!
+//! ```rust,ignore
+//! use nfe_parser::{Nfe, ValidadorNfe};
+//!
+//! let nfe: Nfe = xml.parse()?;
+//! if let Err(rejeicoes) = ValidadorNfe::new().validar(&nfe) {
+//!     for rejeicao in rejeicoes {
+//!         println!("{}", rejeicao); // Rejeição 611: cEAN inválido (item 1: 7891234567890)
+//!     }
+//! }
+//! ```
+//!
+//! [`Totalizacao::divergencias`]: crate::base::totais::Totalizacao::divergencias
+
+mod identificacao;
+mod itens;
+mod nfce;
+mod tributacao;
+
+pub(crate) use itens::validar_quantidade as validar_quantidade_itens;
+pub(crate) use nfce::{validar_cfop_nfce, validar_dados_nfce};
+
+use crate::base::decimal::{formatar, CASAS_VALOR};
+use crate::base::ide::ModeloDocumentoFiscal;
+use crate::base::Nfe;
+use chrono::{DateTime, TimeZone, Utc};
+use std::fmt;
+
+/// Rejeição apontada pela validação
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Rejeicao {
+    /// Código da rejeição da SEFAZ (`None` para regras sem código oficial)
+    pub codigo: Option<u16>,
+    /// Texto da rejeição
+    pub mensagem: String,
+    /// Ponto da nota que originou a rejeição (ex: `item 2: 7891234567890`)
+    pub detalhe: Option<String>,
+}
+
+impl Rejeicao {
+    /// Cria uma rejeição com o código e o texto oficiais
+    pub fn new(codigo: u16, mensagem: &str) -> Self {
+        Self {
+            codigo: Some(codigo),
+            mensagem: mensagem.to_string(),
+            detalhe: None,
+        }
+    }
+
+    /// Cria uma rejeição de regra do emissor, sem código oficial
+    pub fn sem_codigo(mensagem: &str) -> Self {
+        Self {
+            codigo: None,
+            mensagem: mensagem.to_string(),
+            detalhe: None,
+        }
+    }
+
+    /// Acrescenta o ponto da nota que originou a rejeição
+    pub fn com_detalhe(mut self, detalhe: impl Into<String>) -> Self {
+        self.detalhe = Some(detalhe.into());
+        self
+    }
+}
+
+impl fmt::Display for Rejeicao {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(codigo) = self.codigo {
+            write!(f, "Rejeição {}: ", codigo)?;
+        }
+        write!(f, "{}", self.mensagem)?;
+        if let Some(detalhe) = &self.detalhe {
+            write!(f, " ({})", detalhe)?;
+        }
+        Ok(())
+    }
+}
+
+/// Conjunto de regras de validação
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConjuntoRegras {
+    /// Chave de acesso, datas e CFOP × destino da operação
+    Identificacao,
+    /// CST × CRT do emitente
+    Tributacao,
+    /// Quantidade de itens, NCM e GTIN
+    Itens,
+    /// Totais × somatório dos itens
+    Totais,
+    /// Regras específicas da NFC-e (aplicadas só ao modelo 65)
+    Nfce,
+}
+
+impl ConjuntoRegras {
+    /// Todos os conjuntos, na ordem em que são aplicados
+    pub const TODOS: [ConjuntoRegras; 5] = [
+        ConjuntoRegras::Identificacao,
+        ConjuntoRegras::Tributacao,
+        ConjuntoRegras::Itens,
+        ConjuntoRegras::Totais,
+        ConjuntoRegras::Nfce,
+    ];
+}
+
+/// Validador das regras de negócio da NF-e e da NFC-e
+#[derive(Debug, Clone)]
+pub struct ValidadorNfe {
+    conjuntos: Vec<ConjuntoRegras>,
+    data_referencia: Option<DateTime<Utc>>,
+}
+
+impl Default for ValidadorNfe {
+    fn default() -> Self {
+        Self {
+            conjuntos: ConjuntoRegras::TODOS.to_vec(),
+            data_referencia: None,
+        }
+    }
+}
+
+impl ValidadorNfe {
+    /// Cria um validador com todos os conjuntos de regras
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Restringe a validação aos conjuntos informados
+    pub fn conjuntos(mut self, conjuntos: &[ConjuntoRegras]) -> Self {
+        self.conjuntos = conjuntos.to_vec();
+        self
+    }
+
+    /// Define o horário de recebimento usado nas regras de data (padrão: agora)
+    pub fn data_referencia<Tz: TimeZone>(mut self, data: DateTime<Tz>) -> Self {
+        self.data_referencia = Some(data.with_timezone(&Utc));
+        self
+    }
+
+    /// Lista todas as rejeições encontradas na nota
+    pub fn rejeicoes(&self, nfe: &Nfe) -> Vec<Rejeicao> {
+        let agora = self.data_referencia.unwrap_or_else(Utc::now);
+        let mut rejeicoes = Vec::new();
+
+        for conjunto in &self.conjuntos {
+            match conjunto {
+                ConjuntoRegras::Identificacao => {
+                    identificacao::validar(nfe, agora, &mut rejeicoes)
+                }
+                ConjuntoRegras::Tributacao => tributacao::validar(nfe, &mut rejeicoes),
+                ConjuntoRegras::Itens => itens::validar(nfe, &mut rejeicoes),
+                ConjuntoRegras::Totais => {
+                    rejeicoes.extend(nfe.verificar_totais().into_iter().map(|divergencia| {
+                        Rejeicao {
+                            codigo: divergencia.codigo,
+                            mensagem: divergencia.mensagem.to_string(),
+                            detalhe: Some(format!(
+                                "{} informado {}, calculado {}",
+                                divergencia.campo,
+                                formatar(divergencia.informado, CASAS_VALOR, CASAS_VALOR),
+                                formatar(divergencia.calculado, CASAS_VALOR, CASAS_VALOR)
+                            )),
+                        }
+                    }))
+                }
+                ConjuntoRegras::Nfce => {
+                    if nfe.ide.modelo == ModeloDocumentoFiscal::Nfce {
+                        nfce::validar(nfe, &mut rejeicoes)
+                    }
+                }
+            }
+        }
+
+        rejeicoes
+    }
+
+    /// Valida a nota, retornando as rejeições encontradas
+    pub fn validar(&self, nfe: &Nfe) -> Result<(), Vec<Rejeicao>> {
+        let rejeicoes = self.rejeicoes(nfe);
+        if rejeicoes.is_empty() {
+            Ok(())
+        } else {
+            Err(rejeicoes)
+        }
+    }
+}
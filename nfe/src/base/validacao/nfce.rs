@@ -0,0 +1,58 @@
+//! Regras específicas da NFC-e (modelo 65)
+
+use super::Rejeicao;
+use crate::base::decimal::Decimal;
+use crate::base::Nfe;
+
+pub(super) fn validar(nfe: &Nfe, rejeicoes: &mut Vec<Rejeicao>) {
+    rejeicoes.extend(validar_dados_nfce(
+        nfe.ide.modelo as u8,
+        nfe.totais.valor_total,
+        nfe.dest.is_some(),
+    ));
+
+    for item in &nfe.itens {
+        let cfop = &item.produto.tributacao.cfop;
+        if !cfop_valido(cfop) {
+            rejeicoes.push(
+                rejeicao_cfop().com_detalhe(format!("item {}: CFOP {}", item.numero, cfop)),
+            );
+        }
+    }
+}
+
+/// Regras da NFC-e sobre os dados da nota (ver [`crate::base::nfce::ValidadorNfce`])
+pub(crate) fn validar_dados_nfce(
+    modelo: u8,
+    valor_total: Decimal,
+    tem_destinatario: bool,
+) -> Vec<Rejeicao> {
+    let mut rejeicoes = Vec::new();
+
+    if modelo != 65 {
+        rejeicoes.push(Rejeicao::sem_codigo("NFC-e deve usar modelo 65"));
+    }
+
+    // Destinatário obrigatório para valores acima de R$ 10.000
+    if valor_total > Decimal::new(10000, 0) && !tem_destinatario {
+        rejeicoes.push(Rejeicao::sem_codigo(
+            "Destinatário é obrigatório para NFC-e com valor acima de R$ 10.000,00",
+        ));
+    }
+
+    rejeicoes
+}
+
+/// Regra da NFC-e sobre o CFOP de um item
+pub(crate) fn validar_cfop_nfce(cfop: &str) -> Option<Rejeicao> {
+    (!cfop_valido(cfop)).then(|| rejeicao_cfop().com_detalhe(format!("CFOP {}", cfop)))
+}
+
+/// Somente CFOP de saída (5xxx ou 6xxx)
+fn cfop_valido(cfop: &str) -> bool {
+    cfop.starts_with('5') || cfop.starts_with('6')
+}
+
+fn rejeicao_cfop() -> Rejeicao {
+    Rejeicao::new(725, "NFC-e com CFOP inválido")
+}
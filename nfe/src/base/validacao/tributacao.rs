@@ -0,0 +1,30 @@
+//! Regras de tributação: CST × CRT do emitente
+
+use super::Rejeicao;
+use crate::base::Nfe;
+
+pub(super) fn validar(nfe: &Nfe, rejeicoes: &mut Vec<Rejeicao>) {
+    let simples_nacional = nfe.emit.crt.usa_csosn();
+
+    for item in &nfe.itens {
+        let Some(icms) = &item.imposto.icms else {
+            continue;
+        };
+        let detalhe = format!("item {}", item.numero);
+
+        if simples_nacional && icms.usa_cst() {
+            rejeicoes.push(
+                Rejeicao::new(590, "Informado CST para emissor do Simples Nacional (CRT=1)")
+                    .com_detalhe(detalhe),
+            );
+        } else if !simples_nacional && icms.usa_csosn() {
+            rejeicoes.push(
+                Rejeicao::new(
+                    591,
+                    "Informado CSOSN para emissor que não é do Simples Nacional (CRT diferente de 1)",
+                )
+                .com_detalhe(detalhe),
+            );
+        }
+    }
+}
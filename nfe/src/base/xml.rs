@@ -0,0 +1,525 @@
+//! Leitura de arquivos XML da SEFAZ
+//!
+//! Os arquivos recebidos e arquivados nem sempre chegam no formato que o
+//! deserializador espera. Este módulo prepara o XML antes do parse:
+//!
+//! - **BOM**: remove a marca de ordem de bytes UTF-8 (`EF BB BF`)
+//! - **Codificação**: converte arquivos `ISO-8859-1` para UTF-8
+//! - **Declaração**: descarta `<?xml ... ?>`, já que o texto é sempre UTF-8
+//! - **Namespaces**: remove prefixos dos elementos (`<nfe:NFe>` → `<NFe>`);
+//!   declarações `xmlns` permanecem como atributos e são ignoradas
+//!
+//! Na serialização, o namespace padrão é informado em [`NAMESPACE_NFE`].
+//!
+//! ## Modo sem perdas
+//!
+//! O modelo não representa todos os grupos do leiaute. Para editar notas
+//! arquivadas sem descartar a assinatura ou grupos desconhecidos, a leitura
+//! sem perdas ([`crate::Nfe::parse_sem_perdas`]) compara a árvore do XML
+//! original com a serialização do modelo e guarda em [`ConteudoPreservado`]
+//! todo elemento, em qualquer nível, que o modelo não reproduz: `<autXML>`
+//! e `<cana>` em `<infNFe>`, `<email>` em `<dest>`, `<Signature>` e
+//! `<infNFeSupl>` em `<NFe>`, e assim por diante.
+//!
+//! Na serialização:
+//!
+//! - se a nota **não foi alterada**, o `<infNFe>` original é reemitido byte a
+//!   byte, seguido da assinatura original, que continua válida;
+//! - se a nota **foi alterada**, os elementos preservados são reinseridos no
+//!   XML gerado, logo após o irmão que os precedia no original, e a
+//!   assinatura é descartada, já que não confere mais com o conteúdo.
+
+use super::Error;
+use quick_xml::events::{BytesEnd, BytesStart, Event};
+use quick_xml::{DeError, Reader, Writer};
+use std::collections::HashMap;
+
+/// Namespace do Portal da NF-e
+pub const NAMESPACE_NFE: &str = "http://www.portalfiscal.inf.br/nfe";
+
+/// Elementos originais preservados na leitura sem perdas
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct ConteudoPreservado {
+    /// Elementos que o modelo não representa, em qualquer nível, na ordem do
+    /// documento original
+    pub elementos: Vec<ElementoPreservado>,
+    /// XML original do `<infNFe>`, reemitido enquanto a nota não for alterada
+    pub inf_nfe: Option<String>,
+    /// Serialização do modelo no momento da leitura, para detectar alterações
+    pub(crate) modelo: Option<String>,
+}
+
+/// Elemento XML copiado sem alteração do documento original
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ElementoPreservado {
+    /// Nome local do elemento (ex: `autXML`)
+    pub nome: String,
+    /// Caminho do elemento pai a partir da raiz do documento (`<NFe>` ou
+    /// `<nfeProc>`), ex: `infNFe/det[2]/prod`; vazio para filhos da raiz
+    ///
+    /// Irmãos repetidos são numerados a partir do segundo (`det[2]`). Se o
+    /// pai não existir mais na serialização, o elemento é descartado.
+    pub pai: String,
+    /// Nome do elemento irmão que o precede no documento original
+    ///
+    /// Na serialização, o elemento é reinserido logo após esse irmão; se ele
+    /// não existir mais, o elemento é inserido ao final do pai.
+    pub anterior: Option<String>,
+    /// XML original do elemento, byte a byte
+    pub xml: String,
+}
+
+impl ConteudoPreservado {
+    /// XML original da assinatura digital (tag `<Signature>`)
+    pub fn assinatura(&self) -> Option<&str> {
+        self.elementos
+            .iter()
+            .find(|e| e.pai.is_empty() && e.nome == "Signature")
+            .map(|e| e.xml.as_str())
+    }
+
+    /// Indica se nenhum elemento foi preservado
+    pub fn is_empty(&self) -> bool {
+        self.elementos.is_empty() && self.inf_nfe.is_none()
+    }
+
+    /// Indica se o modelo serializado é o mesmo da leitura
+    pub(crate) fn inalterado(&self, modelo: &str) -> bool {
+        self.modelo.as_deref() == Some(modelo)
+    }
+}
+
+/// XML pronto para deserialização
+pub(crate) struct XmlNormalizado {
+    /// Conteúdo sem BOM, declaração e prefixos de namespace
+    pub xml: String,
+    /// Nome local do elemento raiz (ex: `NFe`, `nfeProc`)
+    pub raiz: String,
+}
+
+/// Decodifica o conteúdo do arquivo conforme a declaração `encoding`
+///
+/// Arquivos declarados como ISO-8859-1 (ou que não são UTF-8 válido)
+/// são convertidos byte a byte para UTF-8.
+pub(crate) fn decodificar(bytes: &[u8]) -> String {
+    let bytes = bytes.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(bytes);
+
+    if !declara_latin1(bytes) {
+        if let Ok(texto) = std::str::from_utf8(bytes) {
+            return texto.to_string();
+        }
+    }
+
+    bytes.iter().map(|&b| b as char).collect()
+}
+
+fn declara_latin1(bytes: &[u8]) -> bool {
+    if !bytes.starts_with(b"<?xml") {
+        return false;
+    }
+
+    let fim = bytes.iter().position(|&b| b == b'>').unwrap_or(bytes.len());
+    let declaracao = String::from_utf8_lossy(&bytes[..fim]).to_ascii_lowercase();
+
+    ["iso-8859-1", "iso8859-1", "latin1"]
+        .iter()
+        .any(|encoding| declaracao.contains(encoding))
+}
+
+/// Nome local do elemento raiz (ex: `NFe`, `nfeProc`), sem prefixo de namespace
+///
+/// Permite escolher o tipo a ler antes do parse completo, distinguindo o XML
+/// de distribuição da NF-e avulsa.
+pub fn elemento_raiz(xml: &str) -> Result<String, Error> {
+    let mut reader = Reader::from_str(xml.trim_start_matches('\u{feff}'));
+
+    loop {
+        match reader.read_event_unbuffered().map_err(DeError::from)? {
+            Event::Start(e) | Event::Empty(e) => {
+                return Ok(String::from_utf8_lossy(e.local_name()).into_owned())
+            }
+            Event::Eof => return Err(DeError::Custom("XML sem elemento raiz".to_string()).into()),
+            _ => continue,
+        }
+    }
+}
+
+/// Prepara o XML para o deserializador
+pub(crate) fn normalizar(xml: &str) -> Result<XmlNormalizado, Error> {
+    let xml = xml.trim_start_matches('\u{feff}');
+
+    let mut reader = Reader::from_str(xml);
+    let mut writer = Writer::new(Vec::with_capacity(xml.len()));
+    let mut raiz = None;
+
+    loop {
+        let evento = match reader.read_event_unbuffered().map_err(DeError::from)? {
+            Event::Eof => break,
+            Event::Decl(_) => continue,
+            Event::Start(e) => {
+                let local = sem_prefixo(&e);
+                raiz.get_or_insert_with(|| String::from_utf8_lossy(local.name()).into_owned());
+                Event::Start(local)
+            }
+            Event::Empty(e) => {
+                let local = sem_prefixo(&e);
+                raiz.get_or_insert_with(|| String::from_utf8_lossy(local.name()).into_owned());
+                Event::Empty(local)
+            }
+            Event::End(e) => Event::End(BytesEnd::owned(e.local_name().to_vec())),
+            evento => evento,
+        };
+
+        writer.write_event(evento).map_err(DeError::from)?;
+    }
+
+    Ok(XmlNormalizado {
+        // O conteúdo de entrada é UTF-8 e os eventos são copiados sem recodificação
+        xml: String::from_utf8(writer.into_inner()).expect("XML normalizado em UTF-8"),
+        raiz: raiz.unwrap_or_default(),
+    })
+}
+
+fn sem_prefixo(e: &BytesStart) -> BytesStart<'static> {
+    let local = e.local_name();
+    let mut conteudo = Vec::with_capacity(local.len() + e.attributes_raw().len());
+    conteudo.extend_from_slice(local);
+    conteudo.extend_from_slice(e.attributes_raw());
+
+    BytesStart::owned(conteudo, local.len())
+}
+
+/// Elemento do documento com a sua posição no texto
+struct No {
+    nome: String,
+    prefixado: bool,
+    inicio: usize,
+    fim: usize,
+    filhos: Vec<No>,
+}
+
+impl No {
+    fn new(e: &BytesStart, inicio: usize) -> Self {
+        Self {
+            nome: String::from_utf8_lossy(e.local_name()).into_owned(),
+            prefixado: e.name() != e.local_name(),
+            inicio,
+            fim: inicio,
+            filhos: Vec::new(),
+        }
+    }
+
+    /// Primeiro elemento com o nome informado, em profundidade
+    fn localizar(&self, nome: &str) -> Option<&No> {
+        self.filhos
+            .iter()
+            .find_map(|filho| if filho.nome == nome { Some(filho) } else { filho.localizar(nome) })
+    }
+
+    /// XML original do elemento
+    ///
+    /// Elementos do namespace da NF-e perdem o prefixo, já que a serialização
+    /// usa o namespace padrão; a assinatura declara o próprio namespace e é
+    /// mantida byte a byte.
+    fn xml(&self, documento: &str) -> Result<String, Error> {
+        let original = &documento[self.inicio..self.fim];
+
+        if self.prefixado && self.nome != "Signature" {
+            Ok(normalizar(original)?.xml)
+        } else {
+            Ok(original.to_string())
+        }
+    }
+}
+
+/// Monta a árvore de elementos do documento (o nó devolvido é o documento)
+fn arvore(xml: &str) -> Result<No, Error> {
+    let mut reader = Reader::from_str(xml);
+    let mut pilha = vec![No {
+        nome: String::new(),
+        prefixado: false,
+        inicio: 0,
+        fim: xml.len(),
+        filhos: Vec::new(),
+    }];
+
+    loop {
+        let inicio = reader.buffer_position();
+
+        match reader.read_event_unbuffered().map_err(DeError::from)? {
+            Event::Eof => break,
+            Event::Start(e) => pilha.push(No::new(&e, inicio)),
+            Event::Empty(e) => {
+                let mut no = No::new(&e, inicio);
+                no.fim = reader.buffer_position();
+                pilha.last_mut().expect("documento na base da pilha").filhos.push(no);
+            }
+            Event::End(_) => {
+                if pilha.len() < 2 {
+                    return Err(DeError::Custom("Fechamento de elemento não aberto".to_string()).into());
+                }
+                let mut no = pilha.pop().expect("elemento aberto");
+                no.fim = reader.buffer_position();
+                pilha.last_mut().expect("documento na base da pilha").filhos.push(no);
+            }
+            _ => {}
+        }
+    }
+
+    if pilha.len() != 1 {
+        return Err(DeError::Custom("Elemento não fechado".to_string()).into());
+    }
+
+    Ok(pilha.pop().expect("documento na base da pilha"))
+}
+
+/// Caminho de um filho: o nome, numerado a partir da segunda ocorrência
+fn caminho_filho(pai: &str, nome: &str, ocorrencia: usize) -> String {
+    let passo = if ocorrencia > 1 {
+        format!("{}[{}]", nome, ocorrencia)
+    } else {
+        nome.to_string()
+    };
+
+    if pai.is_empty() {
+        passo
+    } else {
+        format!("{}/{}", pai, passo)
+    }
+}
+
+/// Extrai do XML original os elementos da NF-e que o modelo não reproduz
+///
+/// `modelo` é a serialização da [`crate::Nfe`] lida de `original`, que pode
+/// ser a NF-e avulsa ou o XML de distribuição.
+pub(crate) fn preservar_nfe(original: &str, modelo: &str) -> Result<ConteudoPreservado, Error> {
+    let original = original.trim_start_matches('\u{feff}');
+    let documento = arvore(original)?;
+    let serializado = arvore(modelo)?;
+
+    let (nfe, nfe_modelo) = match (documento.localizar("NFe"), serializado.localizar("NFe")) {
+        (Some(nfe), Some(nfe_modelo)) => (nfe, nfe_modelo),
+        _ => return Err(DeError::Custom("Elemento <NFe> não encontrado".to_string()).into()),
+    };
+
+    let mut elementos = Vec::new();
+    comparar(original, nfe, nfe_modelo, "", &[], &mut elementos)?;
+
+    Ok(ConteudoPreservado {
+        elementos,
+        inf_nfe: match nfe.filhos.iter().find(|filho| filho.nome == "infNFe") {
+            Some(inf_nfe) => Some(inf_nfe.xml(original)?),
+            None => None,
+        },
+        modelo: Some(modelo.to_string()),
+    })
+}
+
+/// Extrai do XML de distribuição os elementos fora de `<NFe>` que o modelo
+/// não reproduz (ex: filhos desconhecidos de `<protNFe>` e `<nfeProc>`)
+pub(crate) fn preservar_proc(original: &str, modelo: &str) -> Result<ConteudoPreservado, Error> {
+    let original = original.trim_start_matches('\u{feff}');
+    let documento = arvore(original)?;
+    let serializado = arvore(modelo)?;
+
+    let mut elementos = Vec::new();
+    if let (Some(proc), Some(proc_modelo)) = (documento.localizar("nfeProc"), serializado.localizar("nfeProc")) {
+        // O conteúdo da NF-e é preservado pela própria nota
+        comparar(original, proc, proc_modelo, "", &["NFe"], &mut elementos)?;
+    }
+
+    Ok(ConteudoPreservado {
+        elementos,
+        inf_nfe: None,
+        modelo: None,
+    })
+}
+
+/// Percorre os filhos de `original`, guardando os que não existem em `modelo`
+fn comparar(
+    documento: &str,
+    original: &No,
+    modelo: &No,
+    caminho: &str,
+    ignorar: &[&str],
+    elementos: &mut Vec<ElementoPreservado>,
+) -> Result<(), Error> {
+    let mut ocorrencias: HashMap<&str, usize> = HashMap::new();
+    let mut anterior: Option<String> = None;
+
+    for filho in &original.filhos {
+        let ocorrencia = ocorrencias.entry(filho.nome.as_str()).or_insert(0);
+        *ocorrencia += 1;
+
+        let correspondente = modelo
+            .filhos
+            .iter()
+            .filter(|m| m.nome == filho.nome)
+            .nth(*ocorrencia - 1);
+        let caminho_filho = caminho_filho(caminho, &filho.nome, *ocorrencia);
+
+        match correspondente {
+            Some(_) if ignorar.contains(&caminho_filho.as_str()) => {}
+            Some(correspondente) => {
+                comparar(documento, filho, correspondente, &caminho_filho, ignorar, elementos)?
+            }
+            None => elementos.push(ElementoPreservado {
+                nome: filho.nome.clone(),
+                pai: caminho.to_string(),
+                anterior: anterior.clone(),
+                xml: filho.xml(documento)?,
+            }),
+        }
+
+        anterior = Some(filho.nome.clone());
+    }
+
+    Ok(())
+}
+
+/// Elemento aberto durante a reinserção
+struct Nivel {
+    /// Caminho a partir da raiz (`None` fora dela)
+    caminho: Option<String>,
+    /// Filhos já vistos, por nome
+    ocorrencias: HashMap<String, usize>,
+    /// Nome do último filho visto
+    ultimo: Option<String>,
+}
+
+/// Reinsere os elementos preservados no XML serializado
+///
+/// `raiz` é o elemento a partir do qual os caminhos foram gravados (`NFe` ou
+/// `nfeProc`). Com a nota `inalterada`, o `<infNFe>` gerado é trocado pelo
+/// original; caso contrário, a assinatura preservada é descartada.
+pub(crate) fn reinserir(xml: &str, preservado: &ConteudoPreservado, raiz: &str, inalterada: bool) -> String {
+    let original = preservado.inf_nfe.as_deref().filter(|_| inalterada);
+    let descartar_assinatura = preservado.inf_nfe.is_some() && !inalterada;
+
+    let mut pendentes: Vec<&ElementoPreservado> = preservado
+        .elementos
+        .iter()
+        .filter(|e| !(original.is_some() && (e.pai == "infNFe" || e.pai.starts_with("infNFe/"))))
+        .filter(|e| !(descartar_assinatura && e.pai.is_empty() && e.nome == "Signature"))
+        .collect();
+
+    let mut reader = Reader::from_str(xml);
+    let mut saida = String::with_capacity(xml.len());
+    let mut copiado = 0;
+    let mut niveis: Vec<Nivel> = Vec::new();
+
+    loop {
+        let inicio = reader.buffer_position();
+
+        let (inserir, substituto) = match reader.read_event_unbuffered() {
+            Ok(Event::Eof) | Err(_) => break,
+            Ok(Event::Start(e)) => {
+                let nome = String::from_utf8_lossy(e.local_name()).into_owned();
+                let (caminho, inserir) = abrir(&mut niveis, &nome, raiz, &mut pendentes);
+
+                match original {
+                    Some(original) if caminho.as_deref() == Some("infNFe") => {
+                        if reader.read_to_end_unbuffered(e.name()).is_err() {
+                            break;
+                        }
+                        (inserir, Some(original))
+                    }
+                    _ => {
+                        niveis.push(Nivel {
+                            caminho,
+                            ocorrencias: HashMap::new(),
+                            ultimo: None,
+                        });
+                        (inserir, None)
+                    }
+                }
+            }
+            Ok(Event::Empty(e)) => {
+                let nome = String::from_utf8_lossy(e.local_name()).into_owned();
+                (abrir(&mut niveis, &nome, raiz, &mut pendentes).1, None)
+            }
+            Ok(Event::End(_)) => match niveis.pop() {
+                Some(Nivel {
+                    caminho: Some(caminho),
+                    ultimo,
+                    ..
+                }) => {
+                    let mut inserir = seguintes(&mut pendentes, &caminho, &ultimo);
+                    while let Some(i) = pendentes.iter().position(|e| e.pai == caminho) {
+                        inserir.push(pendentes.remove(i));
+                    }
+                    (inserir, None)
+                }
+                _ => (Vec::new(), None),
+            },
+            _ => (Vec::new(), None),
+        };
+
+        if !inserir.is_empty() || substituto.is_some() {
+            saida.push_str(&xml[copiado..inicio]);
+            copiado = inicio;
+
+            for elemento in inserir {
+                saida.push_str(&elemento.xml);
+            }
+        }
+
+        if let Some(substituto) = substituto {
+            saida.push_str(substituto);
+            copiado = reader.buffer_position();
+        }
+    }
+
+    saida.push_str(&xml[copiado..]);
+    saida
+}
+
+/// Registra um filho no elemento aberto, devolvendo o caminho do filho e os
+/// elementos preservados a inserir antes dele
+fn abrir<'a>(
+    niveis: &mut [Nivel],
+    nome: &str,
+    raiz: &str,
+    pendentes: &mut Vec<&'a ElementoPreservado>,
+) -> (Option<String>, Vec<&'a ElementoPreservado>) {
+    match niveis.last_mut() {
+        Some(Nivel {
+            caminho: Some(pai),
+            ocorrencias,
+            ultimo,
+        }) => {
+            // Irmãos repetidos (ex: vários <det>) formam um único bloco
+            let inserir = if ultimo.as_deref() != Some(nome) {
+                seguintes(pendentes, pai, ultimo)
+            } else {
+                Vec::new()
+            };
+            *ultimo = Some(nome.to_string());
+
+            let ocorrencia = ocorrencias.entry(nome.to_string()).or_insert(0);
+            *ocorrencia += 1;
+
+            (Some(caminho_filho(pai, nome, *ocorrencia)), inserir)
+        }
+        _ if nome == raiz => (Some(String::new()), Vec::new()),
+        _ => (None, Vec::new()),
+    }
+}
+
+/// Retira dos pendentes do pai a cadeia de elementos que segue `ultimo`
+fn seguintes<'a>(
+    pendentes: &mut Vec<&'a ElementoPreservado>,
+    pai: &str,
+    ultimo: &Option<String>,
+) -> Vec<&'a ElementoPreservado> {
+    let mut ultimo = ultimo.clone();
+    let mut cadeia = Vec::new();
+
+    while let Some(i) = pendentes.iter().position(|e| e.pai == pai && e.anterior == ultimo) {
+        let elemento = pendentes.remove(i);
+        ultimo = Some(elemento.nome.clone());
+        cadeia.push(elemento);
+    }
+
+    cadeia
+}
@@ -2,15 +2,41 @@
 //!
 //! Este módulo fornece uma API fluente para construir uma NF-e do zero.
 
+use crate::base::calculo::{
+    calcular_difal, calcular_icms, calcular_ipi, calcular_pis_cofins, CalculoPisCofins,
+    ParametrosDifal, ParametrosIcms, ParametrosIpi, ParametrosPisCofins,
+};
+use crate::base::cobranca::Cobranca;
+use crate::base::decimal::{arredondar, Decimal, CASAS_VALOR};
 use crate::base::dest::{Destinatario, IndicadorContribuicaoIe};
-use crate::base::emit::Emitente;
+use crate::base::documento::{Documento, DocumentoNacional};
+use crate::base::emit::{Emitente, RegimeTributario};
 use crate::base::endereco::Endereco;
+use crate::base::exportacao::{Compra, Exportacao};
 use crate::base::ide::*;
-use crate::base::item::{Item, Produto, Imposto};
+use crate::base::informacoes_adicionais::{
+    mensagem_credito_simples_nacional, InformacoesAdicionais, Observacao, ProcessoReferenciado,
+};
+use crate::base::intermediador::{validar_intermediador, Intermediador};
+use crate::base::item::{
+    Arma, CofinsContainer, Combustivel, DeclaracaoImportacao, DetalheExportacao,
+    ExportacaoIndireta, IcmsContainer, Imposto, IpiContainer, Issqn, Item, Medicamento,
+    PisContainer, Produto, ProdutoEspecifico, Rastreabilidade, VeiculoNovo,
+};
+use crate::base::nfce::FormaPagamentoNfce;
+use crate::base::pagamento::{DetalhePagamento, Pagamento};
+use crate::base::responsavel_tecnico::{ConfiguracaoCsrt, ResponsavelTecnico};
 use crate::base::totais::Totalizacao;
-use crate::base::transporte::{Transporte, ModalidadeFrete};
+use crate::base::validacao::validar_quantidade_itens;
+use crate::base::transporte::{
+    ModalidadeFrete, RetencaoIcmsTransporte, Transportador, Transporte, Veiculo, Volume,
+};
 use crate::base::{Nfe, VersaoLayout};
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, FixedOffset, NaiveDate, Timelike, Utc};
+use std::fmt;
+
+/// Tentativas de obter um cNF diferente do nNF antes de desistir
+const TENTATIVAS_CODIGO_NUMERICO: usize = 10;
 
 /// Builder para construção de NF-e
 #[derive(Debug, Default)]
@@ -19,6 +45,8 @@ pub struct NfeBuilder {
     codigo_uf: Option<u8>,
     numero: Option<u32>,
     serie: Option<u16>,
+    data_emissao: Option<DateTime<FixedOffset>>,
+    gerador_codigo_numerico: Option<GeradorCodigoNumerico>,
     modelo: Option<ModeloDocumentoFiscal>,
     natureza_operacao: Option<String>,
     tipo_operacao: Option<TipoOperacao>,
@@ -26,18 +54,27 @@ pub struct NfeBuilder {
     finalidade: Option<FinalidadeEmissao>,
     ambiente: Option<TipoAmbiente>,
     codigo_municipio: Option<u32>,
+    referencias: Vec<DocumentoReferenciado>,
+    presenca: Option<TipoPresencaComprador>,
+    consumidor: Option<TipoConsumidor>,
+    indicador_intermediador: Option<TipoIntermediador>,
+    intermediador: Option<Intermediador>,
 
     // Emitente
-    emit_cnpj: Option<String>,
+    emit_documento: Option<DocumentoNacional>,
     emit_razao_social: Option<String>,
     emit_nome_fantasia: Option<String>,
     emit_ie: Option<String>,
+    emit_im: Option<String>,
+    emit_cnae: Option<String>,
+    emit_crt: Option<RegimeTributario>,
     emit_endereco: Option<Endereco>,
 
     // Destinatário
-    dest_cnpj: Option<String>,
+    dest_documento: Option<Documento>,
     dest_razao_social: Option<String>,
     dest_indicador_ie: Option<IndicadorContribuicaoIe>,
+    dest_ie: Option<String>,
     dest_endereco: Option<Endereco>,
 
     // Itens
@@ -45,9 +82,43 @@ pub struct NfeBuilder {
 
     // Transporte
     modalidade_frete: Option<ModalidadeFrete>,
+    transportador: Option<Transportador>,
+    retencao_icms_transporte: Option<RetencaoIcmsTransporte>,
+    veiculo: Option<Veiculo>,
+    reboques: Vec<Veiculo>,
+    vagao: Option<String>,
+    balsa: Option<String>,
+    volumes: Vec<Volume>,
+
+    // Cobrança
+    cobranca: Option<Cobranca>,
+    vencimentos: Option<Vec<NaiveDate>>,
+
+    // Pagamento
+    pagamentos: Vec<DetalhePagamento>,
+    valor_troco: Option<Decimal>,
 
     // Informações adicionais
-    informacao_complementar: Option<String>,
+    informacoes_adicionais: InformacoesAdicionais,
+    textos_legais: Vec<String>,
+    aliquota_credito_simples: Option<Decimal>,
+
+    // Exportação e compra
+    exportacao: Option<Exportacao>,
+    compra: Option<Compra>,
+
+    // Responsável técnico
+    responsavel_tecnico: Option<ResponsavelTecnico>,
+    csrt: Option<ConfiguracaoCsrt>,
+}
+
+/// Fonte do código numérico (cNF) da chave de acesso
+struct GeradorCodigoNumerico(Box<dyn FnMut() -> u32 + Send>);
+
+impl fmt::Debug for GeradorCodigoNumerico {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("GeradorCodigoNumerico")
+    }
 }
 
 /// Builder para itens da NF-e
@@ -58,10 +129,30 @@ pub struct ItemBuilder {
     pub ncm: String,
     pub cfop: String,
     pub unidade: String,
-    pub quantidade: f32,
-    pub valor_unitario: f32,
+    pub quantidade: Decimal,
+    pub valor_unitario: Decimal,
     pub gtin: Option<String>,
-    pub valor_desconto: Option<f32>,
+    pub valor_desconto: Option<Decimal>,
+    pub valor_frete: Option<Decimal>,
+    pub valor_seguro: Option<Decimal>,
+    pub valor_outros: Option<Decimal>,
+    pub cest: Option<String>,
+    pub codigo_beneficio_fiscal: Option<String>,
+    pub codigo_excecao_ipi: Option<String>,
+    pub declaracoes_importacao: Vec<DeclaracaoImportacao>,
+    pub exportacoes: Vec<DetalheExportacao>,
+    pub rastreabilidade: Vec<Rastreabilidade>,
+    pub especifico: Option<ProdutoEspecifico>,
+    pub icms: Option<IcmsContainer>,
+    pub ipi: Option<IpiContainer>,
+    pub pis: Option<PisContainer>,
+    pub cofins: Option<CofinsContainer>,
+    pub issqn: Option<Issqn>,
+    // Parâmetros dos impostos calculados na construção, com os valores do item
+    pub parametros_icms: Option<ParametrosIcms>,
+    pub parametros_ipi: Option<ParametrosIpi>,
+    pub parametros_pis_cofins: Option<ParametrosPisCofins>,
+    pub parametros_difal: Option<ParametrosDifal>,
 }
 
 impl NfeBuilder {
@@ -90,6 +181,35 @@ impl NfeBuilder {
         self
     }
 
+    /// Define a data e hora de emissão, com o fuso horário (tag `<dhEmi>`)
+    ///
+    /// Sem ela, vale o horário atual em UTC. A fração de segundos é
+    /// descartada, como no leiaute; o AAMM da chave segue o fuso informado.
+    pub fn data_emissao(mut self, horario: DateTime<FixedOffset>) -> Self {
+        self.data_emissao = Some(horario);
+        self
+    }
+
+    /// Define o código numérico da chave de acesso (tag `<cNF>`, até 8 dígitos)
+    ///
+    /// Permite regerar uma nota com a mesma chave. A construção falha se o
+    /// código for igual ao número da nota.
+    pub fn codigo_numerico(self, codigo: u32) -> Self {
+        self.gerador_codigo_numerico(move || codigo)
+    }
+
+    /// Define a fonte do código numérico da chave de acesso (padrão: aleatório)
+    ///
+    /// O gerador é chamado novamente enquanto o código coincidir com o
+    /// número da nota (rejeição 897), até 10 vezes.
+    pub fn gerador_codigo_numerico<F>(mut self, gerador: F) -> Self
+    where
+        F: FnMut() -> u32 + Send + 'static,
+    {
+        self.gerador_codigo_numerico = Some(GeradorCodigoNumerico(Box::new(gerador)));
+        self
+    }
+
     /// Define o modelo (55 = NF-e, 65 = NFC-e)
     pub fn modelo(mut self, modelo: ModeloDocumentoFiscal) -> Self {
         self.modelo = Some(modelo);
@@ -120,6 +240,26 @@ impl NfeBuilder {
         self
     }
 
+    /// Referencia uma NF-e ou NFC-e pela chave de acesso (tag `<refNFe>`)
+    ///
+    /// A chave é validada (44 dígitos e dígito verificador) na construção.
+    pub fn referenciar_nfe(self, chave_acesso: &str) -> Self {
+        self.add_referencia(DocumentoReferenciado::Nfe(chave_acesso.trim().to_string()))
+    }
+
+    /// Referencia um CT-e pela chave de acesso (tag `<refCTe>`)
+    ///
+    /// A chave é validada (44 dígitos e dígito verificador) na construção.
+    pub fn referenciar_cte(self, chave_acesso: &str) -> Self {
+        self.add_referencia(DocumentoReferenciado::Cte(chave_acesso.trim().to_string()))
+    }
+
+    /// Adiciona um documento referenciado (NF modelo 1/1A, produtor rural, ECF...)
+    pub fn add_referencia(mut self, referencia: DocumentoReferenciado) -> Self {
+        self.referencias.push(referencia);
+        self
+    }
+
     /// Define o ambiente (Produção/Homologação)
     pub fn ambiente(mut self, ambiente: TipoAmbiente) -> Self {
         self.ambiente = Some(ambiente);
@@ -132,11 +272,51 @@ impl NfeBuilder {
         self
     }
 
+    /// Define o indicador de presença do comprador (padrão: presencial)
+    ///
+    /// Nas operações não presenciais (internet, teleatendimento, entrega a
+    /// domicílio e outros) o `indIntermed` é obrigatório; sem intermediador
+    /// informado, assume-se operação sem intermediador.
+    pub fn presenca(mut self, presenca: TipoPresencaComprador) -> Self {
+        self.presenca = Some(presenca);
+        self
+    }
+
+    /// Define o indicador de consumidor final (tag `<indFinal>`, padrão: normal)
+    ///
+    /// Em operação interestadual com consumidor final não contribuinte, o
+    /// DIFAL (grupo `<ICMSUFDest>`) é calculado para cada item.
+    pub fn tipo_consumidor(mut self, consumidor: TipoConsumidor) -> Self {
+        self.consumidor = Some(consumidor);
+        self
+    }
+
+    /// Define o indicador de intermediador (tag `<indIntermed>`)
+    pub fn indicador_intermediador(mut self, indicador: TipoIntermediador) -> Self {
+        self.indicador_intermediador = Some(indicador);
+        self
+    }
+
+    /// Define o intermediador da transação (tag `<infIntermed>`)
+    ///
+    /// Marca a operação como realizada em plataforma de terceiros (`indIntermed = 1`).
+    pub fn intermediador(mut self, intermediador: Intermediador) -> Self {
+        self.indicador_intermediador = Some(TipoIntermediador::EmSiteDeTerceiros);
+        self.intermediador = Some(intermediador);
+        self
+    }
+
     // === Emitente ===
 
     /// Define o CNPJ do emitente
     pub fn emit_cnpj(mut self, cnpj: &str) -> Self {
-        self.emit_cnpj = Some(cnpj.replace(&['.', '/', '-'][..], ""));
+        self.emit_documento = Some(DocumentoNacional::cnpj(cnpj));
+        self
+    }
+
+    /// Define o CPF do emitente (produtor rural pessoa física)
+    pub fn emit_cpf(mut self, cpf: &str) -> Self {
+        self.emit_documento = Some(DocumentoNacional::cpf(cpf));
         self
     }
 
@@ -158,6 +338,24 @@ impl NfeBuilder {
         self
     }
 
+    /// Define a Inscrição Municipal do emitente
+    pub fn emit_im(mut self, im: &str) -> Self {
+        self.emit_im = Some(im.to_string());
+        self
+    }
+
+    /// Define o CNAE fiscal do emitente
+    pub fn emit_cnae(mut self, cnae: &str) -> Self {
+        self.emit_cnae = Some(cnae.to_string());
+        self
+    }
+
+    /// Define o Código de Regime Tributário do emitente (padrão: Regime Normal)
+    pub fn emit_crt(mut self, crt: RegimeTributario) -> Self {
+        self.emit_crt = Some(crt);
+        self
+    }
+
     /// Define o endereço do emitente
     pub fn emit_endereco(mut self, endereco: Endereco) -> Self {
         self.emit_endereco = Some(endereco);
@@ -168,7 +366,21 @@ impl NfeBuilder {
 
     /// Define o CNPJ do destinatário
     pub fn dest_cnpj(mut self, cnpj: &str) -> Self {
-        self.dest_cnpj = Some(cnpj.replace(&['.', '/', '-'][..], ""));
+        self.dest_documento = Some(Documento::cnpj(cnpj));
+        self
+    }
+
+    /// Define o CPF do destinatário
+    pub fn dest_cpf(mut self, cpf: &str) -> Self {
+        self.dest_documento = Some(Documento::cpf(cpf));
+        self
+    }
+
+    /// Define a identificação do destinatário estrangeiro
+    ///
+    /// O documento pode ser vazio quando o estrangeiro não possui identificação.
+    pub fn dest_id_estrangeiro(mut self, id: &str) -> Self {
+        self.dest_documento = Some(Documento::id_estrangeiro(id));
         self
     }
 
@@ -184,6 +396,12 @@ impl NfeBuilder {
         self
     }
 
+    /// Define a IE do destinatário
+    pub fn dest_ie(mut self, ie: &str) -> Self {
+        self.dest_ie = Some(ie.to_string());
+        self
+    }
+
     /// Define o endereço do destinatário
     pub fn dest_endereco(mut self, endereco: Endereco) -> Self {
         self.dest_endereco = Some(endereco);
@@ -206,11 +424,177 @@ impl NfeBuilder {
         self
     }
 
+    /// Define o transportador
+    pub fn transportador(mut self, transportador: Transportador) -> Self {
+        self.transportador = Some(transportador);
+        self
+    }
+
+    /// Define a retenção do ICMS sobre o frete
+    pub fn retencao_icms_transporte(mut self, retencao: RetencaoIcmsTransporte) -> Self {
+        self.retencao_icms_transporte = Some(retencao);
+        self
+    }
+
+    /// Define o veículo de tração
+    pub fn veiculo(mut self, veiculo: Veiculo) -> Self {
+        self.veiculo = Some(veiculo);
+        self
+    }
+
+    /// Adiciona um reboque (máximo 5)
+    pub fn add_reboque(mut self, reboque: Veiculo) -> Self {
+        self.reboques.push(reboque);
+        self
+    }
+
+    /// Define a identificação do vagão (transporte ferroviário)
+    pub fn vagao(mut self, vagao: &str) -> Self {
+        self.vagao = Some(vagao.to_string());
+        self
+    }
+
+    /// Define a identificação da balsa (transporte aquaviário)
+    pub fn balsa(mut self, balsa: &str) -> Self {
+        self.balsa = Some(balsa.to_string());
+        self
+    }
+
+    /// Adiciona um volume transportado
+    pub fn add_volume(mut self, volume: Volume) -> Self {
+        self.volumes.push(volume);
+        self
+    }
+
+    // === Cobrança ===
+
+    /// Define o grupo de cobrança (fatura e duplicatas) já montado
+    ///
+    /// Sem pagamentos informados, a nota com cobrança recebe um `<detPag>`
+    /// a prazo por boleto no valor total (ver [`Pagamento::a_prazo`]).
+    pub fn cobranca(mut self, cobranca: Cobranca) -> Self {
+        self.cobranca = Some(cobranca);
+        self
+    }
+
+    /// Gera as duplicatas dividindo o valor total da nota, uma por vencimento
+    ///
+    /// A fatura recebe o número da NF-e. O parcelamento é feito por
+    /// [`Cobranca::parcelar`], sem perda de centavos; `build` falha se a
+    /// lista estiver vazia, tiver mais de 120 vencimentos ou o total não
+    /// cobrir um centavo por parcela. O pagamento segue a regra de
+    /// [`NfeBuilder::cobranca`].
+    pub fn parcelas(mut self, vencimentos: &[NaiveDate]) -> Self {
+        self.vencimentos = Some(vencimentos.to_vec());
+        self
+    }
+
+    // === Pagamento ===
+
+    /// Adiciona uma forma de pagamento (tag `<detPag>`)
+    pub fn add_pagamento(self, forma: FormaPagamentoNfce, valor: Decimal) -> Self {
+        self.add_detalhe_pagamento(DetalhePagamento::new(forma, valor))
+    }
+
+    /// Adiciona um pagamento com indicador, descrição ou dados de cartão
+    pub fn add_detalhe_pagamento(mut self, detalhe: DetalhePagamento) -> Self {
+        self.pagamentos.push(detalhe);
+        self
+    }
+
+    /// Define o valor do troco
+    ///
+    /// Quando não informado, o troco é calculado pela diferença entre
+    /// a soma dos pagamentos e o valor total da nota.
+    pub fn valor_troco(mut self, valor: Decimal) -> Self {
+        self.valor_troco = Some(valor);
+        self
+    }
+
     // === Informações Adicionais ===
 
-    /// Define informações complementares
+    /// Define informações complementares (tag `<infCpl>`)
     pub fn informacao_complementar(mut self, info: &str) -> Self {
-        self.informacao_complementar = Some(info.to_string());
+        self.informacoes_adicionais.complementar = Some(info.to_string());
+        self
+    }
+
+    /// Define informações de interesse do Fisco (tag `<infAdFisco>`)
+    pub fn informacao_fisco(mut self, info: &str) -> Self {
+        self.informacoes_adicionais.fisco = Some(info.to_string());
+        self
+    }
+
+    /// Adiciona uma observação do contribuinte (tag `<obsCont>`)
+    pub fn add_observacao_contribuinte(mut self, campo: &str, texto: &str) -> Self {
+        self.informacoes_adicionais.observacoes_contribuinte.push(Observacao {
+            campo: campo.to_string(),
+            texto: texto.to_string(),
+        });
+        self
+    }
+
+    /// Adiciona uma observação do Fisco (tag `<obsFisco>`)
+    pub fn add_observacao_fisco(mut self, campo: &str, texto: &str) -> Self {
+        self.informacoes_adicionais.observacoes_fisco.push(Observacao {
+            campo: campo.to_string(),
+            texto: texto.to_string(),
+        });
+        self
+    }
+
+    /// Adiciona um processo judicial ou administrativo referenciado (tag `<procRef>`)
+    pub fn add_processo(mut self, processo: ProcessoReferenciado) -> Self {
+        self.informacoes_adicionais.processos.push(processo);
+        self
+    }
+
+    /// Acrescenta um texto legal às informações complementares
+    ///
+    /// Os textos são incluídos após o `<infCpl>` informado, separados por `"; "`.
+    pub fn add_texto_legal(mut self, texto: &str) -> Self {
+        self.textos_legais.push(texto.to_string());
+        self
+    }
+
+    /// Acrescenta a mensagem de crédito de ICMS do Simples Nacional
+    ///
+    /// O valor do crédito é calculado sobre o valor total da nota com a
+    /// alíquota informada (LC 123/2006, art. 23). Exige emitente com CRT 1.
+    pub fn credito_simples_nacional(mut self, aliquota: Decimal) -> Self {
+        self.aliquota_credito_simples = Some(aliquota);
+        self
+    }
+
+    // === Exportação e Compra ===
+
+    /// Define o local de embarque da exportação (tag `<exporta>`)
+    ///
+    /// Obrigatório nas saídas com destino ao exterior.
+    pub fn exportacao(mut self, exportacao: Exportacao) -> Self {
+        self.exportacao = Some(exportacao);
+        self
+    }
+
+    /// Define as informações de compra: empenho, pedido e contrato (tag `<compra>`)
+    pub fn compra(mut self, compra: Compra) -> Self {
+        self.compra = Some(compra);
+        self
+    }
+
+    // === Responsável Técnico ===
+
+    /// Define o responsável técnico pelo sistema emissor (tag `<infRespTec>`)
+    pub fn responsavel_tecnico(mut self, responsavel: ResponsavelTecnico) -> Self {
+        self.responsavel_tecnico = Some(responsavel);
+        self
+    }
+
+    /// Define o CSRT da software house
+    ///
+    /// O `hashCSRT` é calculado na construção, a partir da chave de acesso gerada.
+    pub fn csrt(mut self, config: ConfiguracaoCsrt) -> Self {
+        self.csrt = Some(config);
         self
     }
 
@@ -227,30 +611,120 @@ impl NfeBuilder {
         let finalidade = self.finalidade.unwrap_or(FinalidadeEmissao::Normal);
         let ambiente = self.ambiente.unwrap_or(TipoAmbiente::Homologacao);
         let codigo_mun = self.codigo_municipio.ok_or("Código do município é obrigatório")?;
+        let emit_documento = self.emit_documento.ok_or("CNPJ ou CPF do emitente é obrigatório")?;
 
         if self.itens.is_empty() {
             return Err("Pelo menos um item é obrigatório".to_string());
         }
+        if let Some(rejeicao) = validar_quantidade_itens(self.itens.len()) {
+            return Err(rejeicao.to_string());
+        }
 
-        // Gerar código numérico aleatório (8 dígitos)
-        let codigo_numerico = format!("{:08}", rand_u32() % 100000000);
+        // Presença do comprador e intermediador
+        let presenca = self.presenca.unwrap_or(TipoPresencaComprador::Presencial);
+        let indicador_intermediador = match self.indicador_intermediador {
+            Some(indicador) => Some(indicador),
+            None => match presenca {
+                TipoPresencaComprador::ViaInternel
+                | TipoPresencaComprador::ViaTeleatendimento
+                | TipoPresencaComprador::NfceEmDomicilio
+                | TipoPresencaComprador::Outros => Some(TipoIntermediador::SemIntermediador),
+                _ => None,
+            },
+        };
+        validar_intermediador(indicador_intermediador, self.intermediador.as_ref())?;
+
+        // Documentos referenciados
+        for chave in self.referencias.iter().filter_map(|r| r.chave_acesso()) {
+            validar_chave(chave)?;
+        }
+        if self.referencias.len() > 999 {
+            return Err("Máximo de 999 documentos referenciados".to_string());
+        }
+        match finalidade {
+            FinalidadeEmissao::Complementar | FinalidadeEmissao::Devolucao
+                if self.referencias.is_empty() =>
+            {
+                return Err(
+                    "NF-e complementar ou de devolução deve referenciar o documento original"
+                        .to_string(),
+                )
+            }
+            _ => {}
+        }
+
+        // Exportação
+        if tipo_op == TipoOperacao::Saida
+            && destino == DestinoOperacao::ComExterior
+            && self.exportacao.is_none()
+        {
+            return Err("Operação de saída para o exterior exige o grupo de exportação".to_string());
+        }
+        for exportacao in self.itens.iter().flat_map(|i| &i.exportacoes) {
+            if let Some(indireta) = &exportacao.exportacao_indireta {
+                validar_chave(&indireta.chave_acesso)?;
+            }
+        }
 
-        // Data/hora atual
-        let agora: DateTime<Utc> = Utc::now();
+        // Importação
+        for di in self.itens.iter().flat_map(|i| &i.declaracoes_importacao) {
+            if di.adicoes.is_empty() {
+                return Err(format!("Declaração de importação {} sem adições", di.numero));
+            }
+        }
+
+        // Código numérico (8 dígitos), diferente do nNF
+        let mut gerar = match self.gerador_codigo_numerico {
+            Some(gerador) => gerador.0,
+            None => Box::new(|| rand_u32() % 100000000),
+        };
+        let mut codigo_numerico = None;
+        for _ in 0..TENTATIVAS_CODIGO_NUMERICO {
+            let codigo = gerar();
+            if codigo > 99999999 {
+                return Err(format!("Código numérico deve ter até 8 dígitos: {}", codigo));
+            }
+            if codigo != numero {
+                codigo_numerico = Some(format!("{:08}", codigo));
+                break;
+            }
+        }
+        let codigo_numerico = codigo_numerico
+            .ok_or("Código numérico (cNF) não pode ser igual ao número da nota (nNF)")?;
+
+        // Data/hora de emissão, sem a fração de segundos
+        let agora = self.data_emissao.unwrap_or_else(|| Utc::now().into());
+        let agora = agora.with_nanosecond(0).unwrap_or(agora);
+
+        // DIFAL: interestadual para consumidor final não contribuinte (EC 87/2015)
+        let consumidor = self.consumidor.unwrap_or(TipoConsumidor::Normal);
+        let ufs_difal = if destino == DestinoOperacao::Interestadual
+            && consumidor == TipoConsumidor::Final
+            && self.dest_indicador_ie.unwrap_or(IndicadorContribuicaoIe::NaoContribuinteIe)
+                == IndicadorContribuicaoIe::NaoContribuinteIe
+        {
+            let uf_emitente = self.emit_endereco.as_ref().map(|e| e.sigla_uf.clone());
+            let uf_destinatario = self.dest_endereco.as_ref().map(|e| e.sigla_uf.clone());
+            match (uf_emitente, uf_destinatario) {
+                (Some(origem), Some(destino)) => Some((origem, destino)),
+                _ => return Err("DIFAL exige o endereço do emitente e do destinatário".to_string()),
+            }
+        } else {
+            None
+        };
 
         // Construir itens
         let mut itens_nfe = Vec::new();
-        let mut total_produtos = 0.0f32;
-        let mut total_desconto = 0.0f32;
 
         for (idx, item) in self.itens.iter().enumerate() {
-            let valor_bruto = item.quantidade * item.valor_unitario;
-            total_produtos += valor_bruto;
-            if let Some(desc) = item.valor_desconto {
-                total_desconto += desc;
-            }
-
-            let produto = Produto::new(
+            let erro_item = |erro: String| format!("Item {}: {}", idx + 1, erro);
+            let valor_bruto = arredondar(item.quantidade * item.valor_unitario, CASAS_VALOR);
+            let valor_frete = item.valor_frete.unwrap_or_default();
+            let valor_seguro = item.valor_seguro.unwrap_or_default();
+            let valor_outros = item.valor_outros.unwrap_or_default();
+            let valor_desconto = item.valor_desconto.unwrap_or_default();
+
+            let mut produto = Produto::new(
                 item.codigo.clone(),
                 item.descricao.clone(),
                 item.ncm.clone(),
@@ -260,28 +734,221 @@ impl NfeBuilder {
                 item.valor_unitario,
                 valor_bruto,
             );
+            produto.declaracoes_importacao = item.declaracoes_importacao.clone();
+            produto.exportacoes = item.exportacoes.clone();
+            produto.rastreabilidade = item.rastreabilidade.clone();
+            produto.especifico = item.especifico.clone();
+            produto.valor_desconto = item.valor_desconto;
+            produto.valor_frete = item.valor_frete;
+            produto.valor_seguro = item.valor_seguro;
+            produto.valor_outros = item.valor_outros;
+            produto.gtin = item.gtin.clone();
+            produto.tributacao.gtin = item.gtin.clone();
+            produto.tributacao.cest = item.cest.clone();
+            produto.tributacao.codigo_beneficio_fiscal = item.codigo_beneficio_fiscal.clone();
+            produto.tributacao.codigo_excecao_ipi = item.codigo_excecao_ipi.clone();
+
+            // Impostos informados ou calculados no item (ver `crate::base::calculo`).
+            // O IPI vem primeiro: integra a BC do ICMS-ST e, a consumidor final, a do ICMS.
+            let ipi = match &item.parametros_ipi {
+                Some(parametros) => Some(
+                    calcular_ipi(&ParametrosIpi {
+                        valor_bc: valor_bruto + valor_frete + valor_seguro + valor_outros,
+                        quantidade: item.quantidade,
+                        ..parametros.clone()
+                    })
+                    .map_err(erro_item)?,
+                ),
+                None => item.ipi.clone(),
+            };
+            let valor_ipi = ipi.as_ref().map(|i| i.valor_ipi()).unwrap_or_default();
+
+            let icms = match &item.parametros_icms {
+                Some(parametros) => {
+                    let mut parametros = ParametrosIcms {
+                        valor_produto: valor_bruto,
+                        valor_frete,
+                        valor_seguro,
+                        valor_outros,
+                        valor_desconto,
+                        ..parametros.clone()
+                    };
+                    if let Some(ipi) = &ipi {
+                        parametros = parametros.com_ipi(ipi, consumidor == TipoConsumidor::Final);
+                    }
+                    Some(calcular_icms(&parametros).map_err(erro_item)?)
+                }
+                None => item.icms.clone(),
+            };
+
+            let issqn = match &item.issqn {
+                Some(_) if icms.is_some() => {
+                    return Err(erro_item("ICMS e ISSQN não podem ser informados no mesmo item".to_string()))
+                }
+                Some(issqn) => {
+                    let mut issqn = issqn.clone();
+                    if issqn.valor_desconto_incondicionado.is_none() {
+                        issqn.valor_desconto_incondicionado = item.valor_desconto;
+                    }
+                    issqn.valor_bc = valor_bruto
+                        - issqn.valor_deducao.unwrap_or_default()
+                        - issqn.valor_desconto_incondicionado.unwrap_or_default();
+                    issqn.valor =
+                        arredondar(issqn.valor_bc * issqn.aliquota / Decimal::ONE_HUNDRED, CASAS_VALOR);
+                    Some(issqn)
+                }
+                None => None,
+            };
+
+            let (pis, cofins) = match &item.parametros_pis_cofins {
+                Some(parametros) => {
+                    let calculo = calcular_pis_cofins(&ParametrosPisCofins {
+                        valor_bc: valor_bruto + valor_frete + valor_seguro + valor_outros
+                            - valor_desconto,
+                        quantidade: item.quantidade,
+                        ..parametros.clone()
+                    })
+                    .map_err(erro_item)?;
+                    (Some(calculo.pis), Some(calculo.cofins))
+                }
+                None => (item.pis.clone(), item.cofins.clone()),
+            };
+
+            // Serviços sujeitos ao ISSQN não têm DIFAL
+            let icms_uf_dest = match &ufs_difal {
+                Some((uf_origem, uf_destino)) if issqn.is_none() => Some(
+                    calcular_difal(&ParametrosDifal {
+                        valor_operacao: valor_bruto + valor_frete + valor_seguro + valor_outros
+                            - valor_desconto
+                            + valor_ipi,
+                        uf_origem: uf_origem.clone(),
+                        uf_destino: uf_destino.clone(),
+                        origem_mercadoria: icms.as_ref().and_then(|i| i.origem()).unwrap_or(0),
+                        ..item.parametros_difal.clone().unwrap_or_default()
+                    })
+                    .map_err(erro_item)?,
+                ),
+                None if item.parametros_difal.is_some() => {
+                    return Err(erro_item(
+                        "DIFAL exige operação interestadual para consumidor final não contribuinte"
+                            .to_string(),
+                    ))
+                }
+                _ => None,
+            };
+            let imposto = Imposto {
+                icms,
+                ipi,
+                issqn,
+                pis,
+                cofins,
+                icms_uf_dest,
+                ..Default::default()
+            };
 
-            // Criar imposto básico (ICMS 00, PIS e COFINS)
-            let imposto = Imposto::default();
-
+            let numero_item = u16::try_from(idx + 1)
+                .map_err(|_| erro_item("número do item fora do limite de nItem".to_string()))?;
             itens_nfe.push(Item {
-                numero: (idx + 1) as u8,
+                numero: numero_item,
                 produto,
                 imposto,
+                imposto_devolvido: None,
             });
         }
 
         // Calcular totais
-        let valor_total = total_produtos - total_desconto;
+        let mut totais = Totalizacao::somar(&itens_nfe);
+        if let Some(servicos) = &mut totais.servicos {
+            servicos.data_competencia = Some(agora.date_naive());
+        }
+        let valor_total = totais.valor_total;
+
+        // Informações adicionais (textos legais acrescentados ao infCpl)
+        let crt = self.emit_crt.unwrap_or(RegimeTributario::RegimeNormal);
+        let mut informacoes_adicionais = self.informacoes_adicionais;
+        if let Some(aliquota) = self.aliquota_credito_simples {
+            if crt != RegimeTributario::SimplesNacional {
+                return Err(
+                    "Crédito de ICMS do Simples Nacional exige emitente optante (CRT 1)".to_string(),
+                );
+            }
+            let valor_credito = arredondar(valor_total * aliquota / Decimal::ONE_HUNDRED, CASAS_VALOR);
+            informacoes_adicionais
+                .acrescentar_complementar(&mensagem_credito_simples_nacional(valor_credito, aliquota));
+        }
+        for texto in &self.textos_legais {
+            informacoes_adicionais.acrescentar_complementar(texto);
+        }
+        if informacoes_adicionais.complementar.as_ref().map_or(0, |t| t.chars().count()) > 5000 {
+            return Err("Informações complementares excedem 5000 caracteres".to_string());
+        }
+        if informacoes_adicionais.fisco.as_ref().map_or(0, |t| t.chars().count()) > 2000 {
+            return Err("Informações de interesse do Fisco excedem 2000 caracteres".to_string());
+        }
+        if informacoes_adicionais.observacoes_contribuinte.len() > 10
+            || informacoes_adicionais.observacoes_fisco.len() > 10
+        {
+            return Err("Máximo de 10 observações do contribuinte e 10 do Fisco".to_string());
+        }
+        if informacoes_adicionais.processos.len() > 100 {
+            return Err("Máximo de 100 processos referenciados".to_string());
+        }
+
+        // Transporte
+        let transporte = Transporte {
+            modalidade: self.modalidade_frete.unwrap_or(ModalidadeFrete::SemTransporte),
+            transportador: self.transportador,
+            retencao_icms: self.retencao_icms_transporte,
+            veiculo: self.veiculo,
+            reboques: self.reboques,
+            vagao: self.vagao,
+            balsa: self.balsa,
+            volumes: self.volumes,
+        };
+        transporte.validar()?;
+
+        // Cobrança
+        let cobranca = match &self.vencimentos {
+            Some(vencimentos) => Some(Cobranca::parcelar(&numero.to_string(), valor_total, vencimentos)?),
+            None => self.cobranca,
+        };
+
+        // Pagamento (sem pagamentos informados: a prazo por boleto quando há
+        // cobrança, senão tPag 90 - Sem Pagamento)
+        let pagamento = if self.pagamentos.is_empty() {
+            match cobranca {
+                Some(_) => Pagamento::a_prazo(valor_total),
+                None => Pagamento::sem_pagamento(),
+            }
+        } else {
+            let sem_pagamento = self
+                .pagamentos
+                .iter()
+                .any(|p| p.forma == FormaPagamentoNfce::SemPagamento);
+            let total_pago: Decimal = self.pagamentos.iter().map(|p| p.valor).sum();
+
+            if !sem_pagamento && total_pago < valor_total {
+                return Err(format!(
+                    "Total dos pagamentos ({}) menor que o valor total da nota ({})",
+                    total_pago, valor_total
+                ));
+            }
+
+            let troco = self.valor_troco.unwrap_or(total_pago - valor_total);
+            Pagamento {
+                detalhes: self.pagamentos,
+                valor_troco: if troco > Decimal::ZERO { Some(troco) } else { None },
+            }
+        };
 
         // Gerar chave de acesso (44 dígitos)
         let aamm = agora.format("%y%m").to_string();
-        let cnpj_emit = self.emit_cnpj.clone().unwrap_or_default();
+        // Emitente pessoa física: CPF precedido de zeros no campo CNPJ da chave
         let chave_sem_dv = format!(
-            "{:02}{}{:014}{:02}{:03}{:09}{:01}{:08}",
+            "{:02}{}{:0>14}{:02}{:03}{:09}{:01}{:08}",
             codigo_uf,
             aamm,
-            cnpj_emit,
+            emit_documento.numero(),
             modelo as u8,
             serie,
             numero,
@@ -291,8 +958,18 @@ impl NfeBuilder {
         let dv = calcular_dv(&chave_sem_dv);
         let chave_acesso = format!("{}{}", chave_sem_dv, dv);
 
+        // Responsável técnico (hash do CSRT depende da chave gerada)
+        let responsavel_tecnico = match (self.responsavel_tecnico, self.csrt) {
+            (Some(mut responsavel), Some(csrt)) => {
+                responsavel.assinar_csrt(&csrt, &chave_acesso);
+                Some(responsavel)
+            }
+            (None, Some(_)) => return Err("CSRT informado sem responsável técnico".to_string()),
+            (responsavel, None) => responsavel,
+        };
+
         // Construir endereço do emitente
-        let emit_endereco = self.emit_endereco.unwrap_or_else(|| Endereco::default());
+        let emit_endereco = self.emit_endereco.unwrap_or_default();
 
         // Construir NF-e
         Ok(Nfe {
@@ -319,44 +996,48 @@ impl NfeBuilder {
                     tipo: tipo_op,
                     destino,
                     natureza,
-                    consumidor: TipoConsumidor::Normal,
-                    presenca: TipoPresencaComprador::Presencial,
-                    intermediador: None,
+                    consumidor,
+                    presenca,
+                    intermediador: indicador_intermediador,
                 },
                 codigo_municipio: codigo_mun,
                 formato_danfe: FormatoImpressaoDanfe::NormalRetrato,
                 ambiente,
+                referencias: self.referencias,
             },
             emit: Emitente {
-                cnpj: self.emit_cnpj,
+                documento: emit_documento,
                 razao_social: self.emit_razao_social,
                 nome_fantasia: self.emit_nome_fantasia,
+                endereco: emit_endereco,
                 ie: self.emit_ie,
                 iest: None,
-                endereco: emit_endereco,
-            },
-            dest: if self.dest_cnpj.is_some() {
-                Some(Destinatario {
-                    cnpj: self.dest_cnpj.unwrap_or_default(),
-                    razao_social: self.dest_razao_social,
-                    indicador_ie: self.dest_indicador_ie.unwrap_or(IndicadorContribuicaoIe::NaoContribuinteIe),
-                    ie: None,
-                    endereco: self.dest_endereco,
-                })
-            } else {
-                None
+                im: self.emit_im,
+                cnae: self.emit_cnae,
+                crt,
             },
+            dest: self.dest_documento.map(|documento| Destinatario {
+                documento,
+                razao_social: self.dest_razao_social,
+                endereco: self.dest_endereco,
+                indicador_ie: self.dest_indicador_ie.unwrap_or(IndicadorContribuicaoIe::NaoContribuinteIe),
+                ie: self.dest_ie,
+            }),
             itens: itens_nfe,
-            totais: Totalizacao {
-                valor_produtos: total_produtos,
-                valor_desconto: total_desconto,
-                valor_total,
-                ..Default::default()
-            },
-            transporte: Transporte {
-                modalidade: self.modalidade_frete.unwrap_or(ModalidadeFrete::SemTransporte),
+            totais,
+            transporte,
+            cobranca,
+            pagamento: Some(pagamento),
+            intermediador: self.intermediador,
+            informacoes_adicionais: if informacoes_adicionais.is_empty() {
+                None
+            } else {
+                Some(informacoes_adicionais)
             },
-            informacao_complementar: self.informacao_complementar,
+            exportacao: self.exportacao,
+            compra: self.compra,
+            responsavel_tecnico,
+            preservado: None,
         })
     }
 }
@@ -370,10 +1051,29 @@ impl ItemBuilder {
             ncm: ncm.to_string(),
             cfop: cfop.to_string(),
             unidade: "UN".to_string(),
-            quantidade: 1.0,
-            valor_unitario: 0.0,
+            quantidade: Decimal::ONE,
+            valor_unitario: Decimal::ZERO,
             gtin: None,
             valor_desconto: None,
+            valor_frete: None,
+            valor_seguro: None,
+            valor_outros: None,
+            cest: None,
+            codigo_beneficio_fiscal: None,
+            codigo_excecao_ipi: None,
+            declaracoes_importacao: Vec::new(),
+            exportacoes: Vec::new(),
+            rastreabilidade: Vec::new(),
+            especifico: None,
+            icms: None,
+            ipi: None,
+            pis: None,
+            cofins: None,
+            issqn: None,
+            parametros_icms: None,
+            parametros_ipi: None,
+            parametros_pis_cofins: None,
+            parametros_difal: None,
         }
     }
 
@@ -384,13 +1084,13 @@ impl ItemBuilder {
     }
 
     /// Define a quantidade
-    pub fn quantidade(mut self, qtd: f32) -> Self {
+    pub fn quantidade(mut self, qtd: Decimal) -> Self {
         self.quantidade = qtd;
         self
     }
 
     /// Define o valor unitário
-    pub fn valor_unitario(mut self, valor: f32) -> Self {
+    pub fn valor_unitario(mut self, valor: Decimal) -> Self {
         self.valor_unitario = valor;
         self
     }
@@ -402,14 +1102,206 @@ impl ItemBuilder {
     }
 
     /// Define o valor do desconto
-    pub fn desconto(mut self, valor: f32) -> Self {
+    pub fn desconto(mut self, valor: Decimal) -> Self {
         self.valor_desconto = Some(valor);
         self
     }
+
+    /// Define o frete do item (tag `<vFrete>`)
+    pub fn frete(mut self, valor: Decimal) -> Self {
+        self.valor_frete = Some(valor);
+        self
+    }
+
+    /// Define o seguro do item (tag `<vSeg>`)
+    pub fn seguro(mut self, valor: Decimal) -> Self {
+        self.valor_seguro = Some(valor);
+        self
+    }
+
+    /// Define outras despesas acessórias do item (tag `<vOutro>`)
+    pub fn outras_despesas(mut self, valor: Decimal) -> Self {
+        self.valor_outros = Some(valor);
+        self
+    }
+
+    /// Define o CEST, para mercadorias sujeitas a ST (tag `<CEST>`)
+    pub fn cest(mut self, cest: &str) -> Self {
+        self.cest = Some(cest.to_string());
+        self
+    }
+
+    /// Define o código de benefício fiscal da UF (tag `<cBenef>`)
+    pub fn beneficio_fiscal(mut self, codigo: &str) -> Self {
+        self.codigo_beneficio_fiscal = Some(codigo.to_string());
+        self
+    }
+
+    /// Define a exceção da TIPI (tag `<EXTIPI>`)
+    pub fn ex_tipi(mut self, codigo: &str) -> Self {
+        self.codigo_excecao_ipi = Some(codigo.to_string());
+        self
+    }
+
+    /// Adiciona uma declaração de importação (tag `<DI>`)
+    pub fn add_declaracao_importacao(mut self, declaracao: DeclaracaoImportacao) -> Self {
+        self.declaracoes_importacao.push(declaracao);
+        self
+    }
+
+    /// Adiciona um detalhe de exportação (tag `<detExport>`)
+    pub fn add_exportacao(mut self, exportacao: DetalheExportacao) -> Self {
+        self.exportacoes.push(exportacao);
+        self
+    }
+
+    /// Informa o ato concessório de drawback (tag `<nDraw>`)
+    pub fn drawback(self, ato_concessorio: &str) -> Self {
+        self.add_exportacao(DetalheExportacao {
+            drawback: Some(ato_concessorio.to_string()),
+            exportacao_indireta: None,
+        })
+    }
+
+    /// Informa a exportação indireta (tag `<exportInd>`)
+    ///
+    /// A chave da NF-e recebida com fim de exportação é validada na construção.
+    pub fn exportacao_indireta(
+        self,
+        registro_exportacao: &str,
+        chave_acesso: &str,
+        quantidade: Decimal,
+    ) -> Self {
+        self.add_exportacao(DetalheExportacao {
+            drawback: None,
+            exportacao_indireta: Some(ExportacaoIndireta {
+                registro_exportacao: registro_exportacao.to_string(),
+                chave_acesso: chave_acesso.trim().to_string(),
+                quantidade,
+            }),
+        })
+    }
+
+    /// Adiciona um lote para rastreabilidade (tag `<rastro>`)
+    pub fn add_rastro(mut self, rastro: Rastreabilidade) -> Self {
+        self.rastreabilidade.push(rastro);
+        self
+    }
+
+    /// Informa o medicamento (tag `<med>`)
+    ///
+    /// Os grupos específicos são exclusivos: substitui veículo, armamento,
+    /// combustível ou RECOPI informados antes.
+    pub fn medicamento(mut self, medicamento: Medicamento) -> Self {
+        self.especifico = Some(ProdutoEspecifico::Medicamento(medicamento));
+        self
+    }
+
+    /// Informa o veículo novo (tag `<veicProd>`)
+    pub fn veiculo(mut self, veiculo: VeiculoNovo) -> Self {
+        self.especifico = Some(ProdutoEspecifico::Veiculo(veiculo));
+        self
+    }
+
+    /// Adiciona um armamento (tag `<arma>`)
+    pub fn add_arma(mut self, arma: Arma) -> Self {
+        match &mut self.especifico {
+            Some(ProdutoEspecifico::Armamentos(armas)) => armas.push(arma),
+            _ => self.especifico = Some(ProdutoEspecifico::Armamentos(vec![arma])),
+        }
+        self
+    }
+
+    /// Informa o combustível (tag `<comb>`)
+    pub fn combustivel(mut self, combustivel: Combustivel) -> Self {
+        self.especifico = Some(ProdutoEspecifico::Combustivel(combustivel));
+        self
+    }
+
+    /// Informa o número do RECOPI, para papel imune (tag `<nRECOPI>`)
+    pub fn recopi(mut self, numero: &str) -> Self {
+        self.especifico = Some(ProdutoEspecifico::Recopi(numero.to_string()));
+        self
+    }
+
+    /// Informa o grupo de ICMS do item (ver [`crate::base::calculo::calcular_icms`])
+    pub fn icms(mut self, icms: IcmsContainer) -> Self {
+        self.icms = Some(icms);
+        self.parametros_icms = None;
+        self
+    }
+
+    /// Calcula o ICMS na construção da nota
+    ///
+    /// Informe origem, CST/CSOSN e alíquotas (e o ICMS-ST, se houver); o
+    /// valor do produto, as despesas, o desconto e o IPI vêm do item. O IPI
+    /// integra a BC do ICMS próprio na venda a consumidor final.
+    pub fn calcular_icms(mut self, parametros: ParametrosIcms) -> Self {
+        self.parametros_icms = Some(parametros);
+        self.icms = None;
+        self
+    }
+
+    /// Informa o grupo de IPI do item (ver [`crate::base::calculo::calcular_ipi`])
+    pub fn ipi(mut self, ipi: IpiContainer) -> Self {
+        self.ipi = Some(ipi);
+        self.parametros_ipi = None;
+        self
+    }
+
+    /// Calcula o IPI na construção da nota
+    ///
+    /// Informe CST, enquadramento e alíquota (ou valor por unidade); a BC
+    /// (produto, frete, seguro e outras despesas) e a quantidade vêm do item.
+    pub fn calcular_ipi(mut self, parametros: ParametrosIpi) -> Self {
+        self.parametros_ipi = Some(parametros);
+        self.ipi = None;
+        self
+    }
+
+    /// Informa os grupos de PIS e COFINS do item
+    /// (ver [`crate::base::calculo::calcular_pis_cofins`])
+    pub fn pis_cofins(mut self, calculo: CalculoPisCofins) -> Self {
+        self.pis = Some(calculo.pis);
+        self.cofins = Some(calculo.cofins);
+        self.parametros_pis_cofins = None;
+        self
+    }
+
+    /// Calcula o PIS e a COFINS na construção da nota
+    ///
+    /// Informe regime, CST e, se diferentes das do regime, as alíquotas; a
+    /// BC (produto e despesas, menos o desconto) e a quantidade vêm do item.
+    pub fn calcular_pis_cofins(mut self, parametros: ParametrosPisCofins) -> Self {
+        self.parametros_pis_cofins = Some(parametros);
+        self.pis = None;
+        self.cofins = None;
+        self
+    }
+
+    /// Informa o ISSQN do item de serviço (tag `<ISSQN>`)
+    ///
+    /// Informe alíquota, município e item da lista de serviços. A BC (valor
+    /// do serviço menos deduções e desconto incondicionado) e o valor são
+    /// calculados na construção. Exclui o grupo de ICMS do item.
+    pub fn issqn(mut self, issqn: Issqn) -> Self {
+        self.issqn = Some(issqn);
+        self
+    }
+
+    /// Ajusta o cálculo do DIFAL (alíquotas do destino, FCP e método)
+    ///
+    /// O DIFAL é calculado sempre que a operação for interestadual para
+    /// consumidor final não contribuinte; valor da operação, UFs e origem da
+    /// mercadoria vêm da nota e do item.
+    pub fn difal(mut self, parametros: ParametrosDifal) -> Self {
+        self.parametros_difal = Some(parametros);
+        self
+    }
 }
 
 /// Calcula o dígito verificador da chave de acesso (módulo 11)
-fn calcular_dv(chave: &str) -> u8 {
+pub(crate) fn calcular_dv(chave: &str) -> u8 {
     let pesos = [2, 3, 4, 5, 6, 7, 8, 9];
     let mut soma = 0u32;
 
@@ -423,6 +1315,20 @@ fn calcular_dv(chave: &str) -> u8 {
     if resto < 2 { 0 } else { (11 - resto) as u8 }
 }
 
+/// Valida uma chave de acesso informada (44 dígitos e dígito verificador)
+fn validar_chave(chave: &str) -> Result<(), String> {
+    if chave.len() != 44 || !chave.chars().all(|c| c.is_ascii_digit()) {
+        return Err(format!("Chave de acesso deve ter 44 dígitos: {}", chave));
+    }
+
+    let dv = chave[43..].parse::<u8>().unwrap_or_default();
+    if calcular_dv(&chave[..43]) != dv {
+        return Err(format!("Dígito verificador inválido na chave de acesso: {}", chave));
+    }
+
+    Ok(())
+}
+
 /// Gera um número pseudo-aleatório simples
 fn rand_u32() -> u32 {
     use std::time::{SystemTime, UNIX_EPOCH};
@@ -433,6 +1339,7 @@ fn rand_u32() -> u32 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rust_decimal_macros::dec;
 
     #[test]
     fn test_builder_basico() {
@@ -447,8 +1354,8 @@ mod tests {
             .emit_ie("123456789")
             .add_item(
                 ItemBuilder::new("PROD001", "Produto Teste", "12345678", "5102")
-                    .quantidade(10.0)
-                    .valor_unitario(100.0)
+                    .quantidade(dec!(10))
+                    .valor_unitario(dec!(100))
             )
             .build();
 
@@ -456,7 +1363,7 @@ mod tests {
         let nfe = nfe.unwrap();
         assert_eq!(nfe.ide.numero, 1);
         assert_eq!(nfe.itens.len(), 1);
-        assert_eq!(nfe.totais.valor_produtos, 1000.0);
+        assert_eq!(nfe.totais.valor_produtos, dec!(1000));
     }
 
     #[test]
@@ -18,6 +18,7 @@
 //! use std::fs::File;
 //! use std::convert::TryFrom;
 //! use nfe_parser::{Nfe, NfeBuilder, ItemBuilder};
+//! use rust_decimal_macros::dec;
 //!
 //! // Lendo uma NF-e existente
 //! let file = File::open("nota.xml")?;
@@ -30,8 +31,8 @@
 //!     .emitente("12345678000195", "Empresa Ltda", "SP")
 //!     .destinatario("98765432000123", "Cliente SA")
 //!     .item(ItemBuilder::new("001", "Produto X", "12345678", "5102")
-//!         .quantidade(10.0)
-//!         .valor_unitario(99.90)
+//!         .quantidade(dec!(10))
+//!         .valor_unitario(dec!(99.90))
 //!         .build())
 //!     .build()?;
 //! ```
@@ -50,15 +51,37 @@ pub mod modelos;
 mod tests;
 
 // Re-exportação dos tipos principais para facilitar o uso
+pub use base::calculo::{
+    aliquotas_transicao, calcular_difal, calcular_ibs_cbs, calcular_icms, calcular_icms_st,
+    calcular_importacao, calcular_ipi, calcular_pis_cofins, mva_ajustada, AliquotasIbsCbs,
+    CalculoIcmsSt, CalculoImportacao, CalculoPisCofins, MetodoDifal, ParametrosDifal,
+    ParametrosIbsCbs, ParametrosIcms, ParametrosIcmsSt, ParametrosImportacao, ParametrosIpi,
+    ParametrosPisCofins, RegimePisCofins,
+};
+pub use base::cobranca::{Cobranca, Duplicata, Fatura};
+pub use base::decimal::{self, Decimal};
 pub use base::dest::{Destinatario, IndicadorContribuicaoIe};
-pub use base::emit::Emitente;
+pub use base::documento::{Documento, DocumentoNacional};
+pub use base::emit::{Emitente, RegimeTributario};
 pub use base::endereco::Endereco;
+pub use base::esquema::{EsquemaXsd, ViolacaoEsquema, ARQUIVOS_PL_009};
+pub use base::exportacao::{Compra, Exportacao};
 pub use base::ide::{
-    ComposicaoChaveAcesso, DestinoOperacao, Emissao, FinalidadeEmissao, FormatoImpressaoDanfe,
-    Identificacao, ModeloDocumentoFiscal, Operacao, TipoAmbiente, TipoConsumidor, TipoEmissao,
-    TipoIntermediador, TipoOperacao, TipoPresencaComprador, TipoProcessoEmissao,
+    ComposicaoChaveAcesso, CupomFiscalReferenciado, DestinoOperacao, DocumentoReferenciado,
+    Emissao, FinalidadeEmissao, FormatoImpressaoDanfe, Identificacao, ModeloDocumentoFiscal,
+    NotaFiscalReferenciada, NotaProdutorReferenciada, Operacao, TipoAmbiente, TipoConsumidor,
+    TipoEmissao, TipoIntermediador, TipoOperacao, TipoPresencaComprador, TipoProcessoEmissao,
+};
+pub use base::informacoes_adicionais::{
+    mensagem_credito_simples_nacional, InformacoesAdicionais, Observacao, OrigemProcesso,
+    ProcessoReferenciado,
+};
+pub use base::intermediador::{validar_intermediador, Intermediador};
+pub use base::item::{
+    Adicao, Arma, Cide, Combustivel, DeclaracaoImportacao, DetalheExportacao, Encerrante,
+    ExportacaoIndireta, FormaImportacao, Imposto, Item, Medicamento, Produto, ProdutoEspecifico,
+    Rastreabilidade, TipoArma, VeiculoNovo, ViaTransporte,
 };
-pub use base::item::{Imposto, Item, Produto};
 pub use base::item::imposto::*;
 pub use base::municipios::{
     Municipio, ConfiguracaoUf, AliquotaIss, SistemaNfse,
@@ -71,8 +94,20 @@ pub use base::nfse::{
     NaturezaOperacaoNfse, RegimeEspecialNfse, StatusNfse, Rps, LoteRps,
     calcular_valores_nfse,
 };
-pub use base::totais::Totalizacao;
-pub use base::transporte::{ModalidadeFrete, Transporte};
+pub use base::pagamento::{
+    BandeiraCartao, Cartao, DetalhePagamento, IndicadorPagamento, Pagamento, TipoIntegracaoPagamento,
+};
+pub use base::protocolo::{NfeProc, ProtocoloAutorizacao};
+pub use base::responsavel_tecnico::{gerar_hash_csrt, ConfiguracaoCsrt, ResponsavelTecnico};
+pub use base::totais::{
+    DivergenciaTotal, TotalCbs, TotalIbs, TotalIbsCbs, TotalIbsMunicipal, TotalIbsUf, TotalIssqn,
+    Totalizacao,
+};
+pub use base::transporte::{
+    Lacre, ModalidadeFrete, RetencaoIcmsTransporte, Transportador, Transporte, Veiculo, Volume,
+};
+pub use base::validacao::{ConjuntoRegras, Rejeicao, ValidadorNfe};
+pub use base::xml::{elemento_raiz, ConteudoPreservado, ElementoPreservado};
 pub use base::Error;
 pub use base::Nfe;
 pub use base::VersaoLayout;
@@ -6,8 +6,7 @@
 //! # Funcionalidades demonstradas
 //!
 //! - Leitura assíncrona de arquivo XML
-//! - Remoção do namespace para compatibilidade com o parser
-//! - Parsing da estrutura completa da NF-e
+//! - Parsing da estrutura completa da NF-e (avulsa ou nfeProc)
 //! - Exibição formatada dos dados
 
 use std::io;
@@ -15,14 +14,10 @@ use nfe_parser::Nfe;
 use tokio::fs::File;
 use tokio::io::AsyncReadExt;
 
-/// Carrega um arquivo XML para um buffer de string de forma assíncrona.
+/// Carrega um arquivo XML para um buffer de bytes de forma assíncrona.
 ///
-/// Esta função realiza duas operações importantes:
-/// 1. Lê o conteúdo completo do arquivo XML
-/// 2. Remove o namespace padrão da SEFAZ para permitir o parsing com quick-xml
-///
-/// O namespace `xmlns="http://www.portalfiscal.inf.br/nfe"` precisa ser removido
-/// porque o quick-xml não suporta namespaces diretamente na deserialização.
+/// O conteúdo é mantido como bytes para que a biblioteca trate o BOM e a
+/// codificação declarada (UTF-8 ou ISO-8859-1) via [`Nfe::from_bytes`].
 ///
 /// # Argumentos
 ///
@@ -30,15 +25,13 @@ use tokio::io::AsyncReadExt;
 ///
 /// # Retorno
 ///
-/// Retorna o conteúdo do XML como String, sem o namespace
-async fn load_xml_to_buffer(file_path: &str) -> Result<String, tokio::io::Error> {
+/// Retorna o conteúdo bruto do arquivo
+async fn load_xml_to_buffer(file_path: &str) -> Result<Vec<u8>, tokio::io::Error> {
     let mut file = File::open(file_path).await?;
-    let mut buffer = String::new();
-    file.read_to_string(&mut buffer).await?;
+    let mut buffer = Vec::new();
+    file.read_to_end(&mut buffer).await?;
 
-    // Remove o namespace padrão da SEFAZ para permitir parsing
-    // O quick-xml não lida bem com namespaces na deserialização
-    Ok(buffer.replace("xmlns=\"http://www.portalfiscal.inf.br/nfe\"", ""))
+    Ok(buffer)
 }
 
 /// Mascara dados sensíveis em uma string para exibição segura.
@@ -59,6 +52,7 @@ async fn load_xml_to_buffer(file_path: &str) -> Result<String, tokio::io::Error>
 /// mask_sensitive_data(&mut chave, "NFe", 10);
 /// // Resultado: "NFe**********22602000124550010009923461099234656"
 /// ```
+#[allow(dead_code)]
 fn mask_sensitive_data(data: &mut String, mask_start: &str, mask_len: usize) {
     if let Some(start) = data.find(mask_start) {
         let start = start + mask_start.len();
@@ -92,9 +86,7 @@ fn display_nfe(nfe: &Nfe) {
     println!("╟──────────────────────────────────────────────────────────────╢");
     println!("║ EMITENTE                                                     ║");
     println!("╟──────────────────────────────────────────────────────────────╢");
-    if let Some(ref cnpj) = nfe.emit.cnpj {
-        println!("║ CNPJ: {}", cnpj);
-    }
+    println!("║ {}: {}", nfe.emit.documento.tag(), nfe.emit.documento.numero());
     if let Some(ref razao) = nfe.emit.razao_social {
         println!("║ Razão Social: {}", razao);
     }
@@ -109,7 +101,7 @@ fn display_nfe(nfe: &Nfe) {
         println!("╟──────────────────────────────────────────────────────────────╢");
         println!("║ DESTINATÁRIO                                                 ║");
         println!("╟──────────────────────────────────────────────────────────────╢");
-        println!("║ CNPJ: {}", dest.cnpj);
+        println!("║ {}: {}", dest.documento.tag(), dest.documento.numero());
         if let Some(ref razao) = dest.razao_social {
             println!("║ Razão Social: {}", razao);
         }
@@ -147,7 +139,7 @@ fn display_nfe(nfe: &Nfe) {
     println!("║ Modalidade do Frete: {:?}", nfe.transporte.modalidade);
 
     // Informações complementares
-    if let Some(ref info) = nfe.informacao_complementar {
+    if let Some(info) = nfe.informacoes_adicionais.as_ref().and_then(|i| i.complementar.as_deref()) {
         println!("╟──────────────────────────────────────────────────────────────╢");
         println!("║ INFORMAÇÕES COMPLEMENTARES                                   ║");
         println!("╟──────────────────────────────────────────────────────────────╢");
@@ -187,29 +179,17 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         }
     };
 
-    // O XML de exemplo está no formato nfeProc (com protocolo de autorização)
-    // A biblioteca espera o formato NFe diretamente, então precisamos extrair
-    // Para este exemplo, vamos tentar fazer o parse direto
-
-    // Tenta extrair apenas a tag <NFe> do XML
-    let nfe_start = xml_data.find("<NFe");
-    let nfe_end = xml_data.find("</NFe>");
-
-    if let (Some(start), Some(end)) = (nfe_start, nfe_end) {
-        let nfe_xml = &xml_data[start..end + 6]; // +6 para incluir "</NFe>"
-
-        match nfe_xml.parse::<Nfe>() {
-            Ok(nfe) => {
-                display_nfe(&nfe);
-            }
-            Err(e) => {
-                eprintln!("Erro ao fazer parsing da NF-e: {}", e);
-                eprintln!("\nO XML pode estar em um formato diferente do esperado.");
-                eprintln!("Verifique se o arquivo está no layout 4.00 da SEFAZ.");
-            }
+    // O XML de exemplo está no formato nfeProc (com protocolo de autorização);
+    // a biblioteca aceita tanto a NF-e avulsa quanto o XML de distribuição
+    match Nfe::from_bytes(&xml_data) {
+        Ok(nfe) => {
+            display_nfe(&nfe);
+        }
+        Err(e) => {
+            eprintln!("Erro ao fazer parsing da NF-e: {}", e);
+            eprintln!("\nO XML pode estar em um formato diferente do esperado.");
+            eprintln!("Verifique se o arquivo está no layout 4.00 da SEFAZ.");
         }
-    } else {
-        eprintln!("Não foi possível encontrar a tag <NFe> no arquivo XML.");
     }
 
     println!("\nPressione Enter para sair...");
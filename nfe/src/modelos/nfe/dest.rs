@@ -2,6 +2,7 @@
 
 use super::Error;
 use crate::base::dest::Destinatario as DestinatarioBase;
+pub use crate::base::documento::Documento;
 pub use crate::base::dest::IndicadorContribuicaoIe;
 pub use crate::base::endereco::Endereco;
 use std::convert::{TryFrom, TryInto};
@@ -9,7 +10,7 @@ use std::str::FromStr;
 
 /// Destinatário da NF-e
 pub struct Destinatario {
-    pub cnpj:Option<String>,
+    pub documento: Documento,
     pub razao_social: Option<String>,
     pub endereco: Endereco,
     pub ie: Option<String>,
@@ -29,7 +30,7 @@ impl TryFrom<DestinatarioBase> for Destinatario {
             .ok_or_else(|| Error::DestinatarioInvalido("Endereço não informado".to_string()))?;
 
         Ok(Self {
-            cnpj: dest.cnpj.clone(),
+            documento: dest.documento.clone(),
             razao_social,
             endereco,
             ie: dest.ie.clone(),
@@ -41,7 +42,7 @@ impl TryFrom<DestinatarioBase> for Destinatario {
 impl From<&Destinatario> for DestinatarioBase {
     fn from(dest: &Destinatario) -> Self {
         Self {
-            cnpj: dest.cnpj.clone(),
+            documento: dest.documento.clone(),
             razao_social: Some(dest.razao_social.clone()),
             endereco: Some(dest.endereco.clone()),
             ie: dest.ie.clone(),
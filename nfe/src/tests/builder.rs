@@ -0,0 +1,114 @@
+//! Testes da emissão determinística pelo `NfeBuilder` (dhEmi e cNF injetados)
+
+use crate::base::calculo::{ParametrosIcms, ParametrosPisCofins, RegimePisCofins};
+use crate::base::esquema::EsquemaXsd;
+use crate::base::validacao::ValidadorNfe;
+use crate::base::Nfe;
+use crate::builder::{ItemBuilder, NfeBuilder};
+use chrono::{DateTime, FixedOffset};
+use rust_decimal_macros::dec;
+
+/// Snapshot do XML gerado por [`builder`], conforme o pacote PL_009
+const XML_ESPERADO: &str = concat!(
+    r#"<NFe xmlns="http://www.portalfiscal.inf.br/nfe"><infNFe versao="4.00" Id="NFe35240112345678000190550010000012341876543210">"#,
+    "<ide><cUF>35</cUF><cNF>87654321</cNF><natOp>VENDA DE MERCADORIA</natOp><mod>55</mod><serie>1</serie><nNF>1234</nNF>",
+    "<dhEmi>2024-01-31T22:30:00-03:00</dhEmi><tpNF>1</tpNF><idDest>1</idDest><cMunFG>3550308</cMunFG><tpImp>1</tpImp>",
+    "<tpEmis>1</tpEmis><cDV>0</cDV><tpAmb>2</tpAmb><finNFe>1</finNFe><indFinal>0</indFinal><indPres>1</indPres>",
+    "<procEmi>0</procEmi><verProc>1.0.0</verProc></ide>",
+    "<emit><CNPJ>12345678000190</CNPJ><xNome>EMPRESA LTDA</xNome><enderEmit><xLgr>AVENIDA PAULISTA</xLgr><nro>1000</nro>",
+    "<xBairro>BELA VISTA</xBairro><cMun>3550308</cMun><xMun>SAO PAULO</xMun><UF>SP</UF><CEP>01310100</CEP><cPais>1058</cPais>",
+    "<xPais>BRASIL</xPais></enderEmit>",
+    "<IE>123456789</IE><CRT>3</CRT></emit>",
+    r#"<det nItem="1"><prod><cProd>001</cProd><cEAN>SEM GTIN</cEAN><xProd>Camiseta</xProd><NCM>61091000</NCM>"#,
+    "<CFOP>5102</CFOP><uCom>UN</uCom><qCom>2.0000</qCom><vUnCom>50.00</vUnCom><vProd>100.00</vProd>",
+    "<cEANTrib>SEM GTIN</cEANTrib><uTrib>UN</uTrib><qTrib>2.0000</qTrib><vUnTrib>50.00</vUnTrib><indTot>1</indTot></prod>",
+    "<imposto><ICMS><ICMS00><orig>0</orig><CST>00</CST><modBC>3</modBC><vBC>100.00</vBC><pICMS>18.00</pICMS>",
+    "<vICMS>18.00</vICMS></ICMS00></ICMS><PIS><PISAliq><CST>01</CST><vBC>100.00</vBC><pPIS>0.65</pPIS><vPIS>0.65</vPIS>",
+    "</PISAliq></PIS><COFINS><COFINSAliq><CST>01</CST><vBC>100.00</vBC><pCOFINS>3.00</pCOFINS><vCOFINS>3.00</vCOFINS>",
+    "</COFINSAliq></COFINS></imposto></det>",
+    "<total><ICMSTot><vBC>100.00</vBC><vICMS>18.00</vICMS><vICMSDeson>0.00</vICMSDeson><vFCP>0.00</vFCP><vBCST>0.00</vBCST>",
+    "<vST>0.00</vST><vFCPST>0.00</vFCPST><vFCPSTRet>0.00</vFCPSTRet><vProd>100.00</vProd><vFrete>0.00</vFrete><vSeg>0.00</vSeg>",
+    "<vDesc>0.00</vDesc><vII>0.00</vII><vIPI>0.00</vIPI><vIPIDevol>0.00</vIPIDevol><vPIS>0.65</vPIS><vCOFINS>3.00</vCOFINS>",
+    "<vOutro>0.00</vOutro><vNF>100.00</vNF><vTotTrib>0.00</vTotTrib></ICMSTot></total>",
+    "<transp><modFrete>9</modFrete></transp><pag><detPag><tPag>90</tPag><vPag>0.00</vPag></detPag></pag>",
+    "</infNFe></NFe>",
+);
+
+fn emissao() -> DateTime<FixedOffset> {
+    DateTime::parse_from_rfc3339("2024-01-31T22:30:00.750-03:00").unwrap()
+}
+
+fn builder() -> NfeBuilder {
+    super::builder()
+        .numero(1234)
+        .data_emissao(emissao())
+        .codigo_numerico(87654321)
+        .natureza_operacao("VENDA DE MERCADORIA")
+        .add_item(
+            ItemBuilder::new("001", "Camiseta", "61091000", "5102")
+                .quantidade(dec!(2))
+                .valor_unitario(dec!(50))
+                .calcular_icms(ParametrosIcms {
+                    cst: "00".to_string(),
+                    aliquota: dec!(18),
+                    ..Default::default()
+                })
+                .calcular_pis_cofins(ParametrosPisCofins {
+                    regime: RegimePisCofins::Cumulativo,
+                    cst: "01".to_string(),
+                    ..Default::default()
+                }),
+        )
+}
+
+#[test]
+fn xml_gerado_confere_com_o_snapshot() {
+    let nfe = builder().build().unwrap();
+
+    assert_eq!(XML_ESPERADO, nfe.to_string());
+    assert_eq!(nfe, builder().build().unwrap());
+
+    let esquema = EsquemaXsd::pl_009_embutido().unwrap();
+    assert_eq!(Ok(()), esquema.validar_sem_assinatura(XML_ESPERADO));
+}
+
+#[test]
+fn dh_emi_mantem_o_fuso_informado() {
+    let nfe = builder().build().unwrap();
+
+    // 22:30 de 31/01 em UTC−3 já é fevereiro em UTC: o AAMM segue o fuso
+    assert_eq!("2401", &nfe.chave_acesso[2..6]);
+    assert_eq!(emissao().timestamp(), nfe.ide.emissao.horario.timestamp());
+    assert_eq!(0, nfe.ide.emissao.horario.timestamp_subsec_nanos());
+
+    let lida = nfe.to_string().parse::<Nfe>().unwrap();
+    assert_eq!(nfe.ide.emissao.horario, lida.ide.emissao.horario);
+    assert_eq!("-03:00", lida.ide.emissao.horario.offset().to_string());
+
+    let rejeicoes = ValidadorNfe::new().data_referencia(emissao()).rejeicoes(&lida);
+    assert!(rejeicoes.is_empty(), "{:?}", rejeicoes);
+}
+
+#[test]
+fn codigo_numerico_diferente_do_numero() {
+    // O gerador é chamado de novo enquanto o cNF coincidir com o nNF
+    let mut codigos = vec![1234, 1234, 4711].into_iter();
+    let nfe = builder()
+        .gerador_codigo_numerico(move || codigos.next().unwrap())
+        .build()
+        .unwrap();
+    assert_eq!("00004711", nfe.ide.chave.codigo);
+    assert_eq!("00004711", &nfe.chave_acesso[35..43]);
+
+    let erro = builder().codigo_numerico(1234).build().unwrap_err();
+    assert!(erro.contains("cNF"));
+    assert!(builder().codigo_numerico(100_000_000).build().is_err());
+
+    let outro_numero = builder().codigo_numerico(1234).numero(1235).build().unwrap();
+    assert_eq!("00001234", outro_numero.ide.chave.codigo);
+
+    let mut nfe = nfe;
+    nfe.ide.chave.codigo = "00001234".to_string();
+    let rejeicoes = ValidadorNfe::new().data_referencia(emissao()).rejeicoes(&nfe);
+    assert!(rejeicoes.iter().any(|r| r.codigo == Some(897)));
+}
@@ -0,0 +1,174 @@
+//! Testes do grupo de cobrança (cobr/fat/dup)
+
+use crate::base::cobranca::Cobranca;
+use crate::base::pagamento::Pagamento;
+use crate::builder::{ItemBuilder, NfeBuilder};
+use crate::{Error, Nfe};
+use chrono::{Duration, NaiveDate};
+use rust_decimal_macros::dec;
+
+fn data(ano: i32, mes: u32, dia: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(ano, mes, dia).unwrap()
+}
+
+#[test]
+fn parse_cobranca() -> Result<(), Error> {
+    let xml = r#"
+        <cobr>
+            <fat>
+                <nFat>1001</nFat>
+                <vOrig>300.00</vOrig>
+                <vDesc>0.00</vDesc>
+                <vLiq>300.00</vLiq>
+            </fat>
+            <dup>
+                <nDup>001</nDup>
+                <dVenc>2024-02-15</dVenc>
+                <vDup>150.00</vDup>
+            </dup>
+            <dup>
+                <nDup>002</nDup>
+                <dVenc>2024-03-15</dVenc>
+                <vDup>150.00</vDup>
+            </dup>
+        </cobr>
+    "#;
+
+    let cobr = xml.parse::<Cobranca>()?;
+    let fat = cobr.fatura.as_ref().unwrap();
+
+    assert_eq!(Some("1001".to_string()), fat.numero);
+    assert_eq!(Some(dec!(300)), fat.valor_original);
+    assert_eq!(Some(dec!(0)), fat.valor_desconto);
+    assert_eq!(Some(dec!(300)), fat.valor_liquido);
+
+    assert_eq!(2, cobr.duplicatas.len());
+    assert_eq!(Some("002".to_string()), cobr.duplicatas[1].numero);
+    assert_eq!(Some(data(2024, 3, 15)), cobr.duplicatas[1].vencimento);
+    assert_eq!(dec!(300), cobr.valor_duplicatas());
+
+    Ok(())
+}
+
+#[test]
+fn parcelar_sem_perda_de_centavos() {
+    let vencimentos: Vec<_> = (1..=7).map(|m| data(2024, m, 10)).collect();
+    let cobr = Cobranca::parcelar("55", dec!(1000.00), &vencimentos).unwrap();
+
+    assert_eq!(7, cobr.duplicatas.len());
+    assert_eq!(dec!(142.86), cobr.duplicatas[0].valor);
+    assert_eq!(dec!(142.86), cobr.duplicatas[4].valor);
+    assert_eq!(dec!(142.85), cobr.duplicatas[5].valor);
+    assert_eq!(dec!(142.85), cobr.duplicatas[6].valor);
+    assert_eq!(dec!(1000.00), cobr.valor_duplicatas());
+    assert_eq!(Some("007".to_string()), cobr.duplicatas[6].numero);
+    assert_eq!(Some(data(2024, 7, 10)), cobr.duplicatas[6].vencimento);
+}
+
+#[test]
+fn parcelar_centavos_sem_parcela_zerada() {
+    let vencimentos: Vec<_> = (1..=3).map(|m| data(2024, m, 10)).collect();
+
+    let cobr = Cobranca::parcelar("56", dec!(0.04), &vencimentos).unwrap();
+    let valores: Vec<_> = cobr.duplicatas.iter().map(|d| d.valor).collect();
+    assert_eq!(vec![dec!(0.02), dec!(0.01), dec!(0.01)], valores);
+
+    assert!(Cobranca::parcelar("57", dec!(0.02), &vencimentos).is_err());
+    assert!(Cobranca::parcelar("58", dec!(10), &[]).is_err());
+}
+
+#[test]
+fn parcelar_arredonda_meio_centavo_para_cima() {
+    let cobr = Cobranca::parcelar("59", dec!(10.125), &[data(2024, 1, 10)]).unwrap();
+
+    assert_eq!(Some(dec!(10.13)), cobr.fatura.as_ref().unwrap().valor_liquido);
+    assert_eq!(dec!(10.13), cobr.valor_duplicatas());
+}
+
+#[test]
+fn parcelar_no_maximo_120_duplicatas() {
+    let vencimentos: Vec<_> = (0..121).map(|i| data(2024, 1, 1) + Duration::days(i)).collect();
+
+    let cobr = Cobranca::parcelar("60", dec!(1200), &vencimentos[..120]).unwrap();
+    assert_eq!(120, cobr.duplicatas.len());
+    assert_eq!(
+        Err("Máximo de 120 duplicatas por NF-e (informados: 121 vencimentos)".to_string()),
+        Cobranca::parcelar("61", dec!(1210), &vencimentos)
+    );
+    let resultado = super::builder()
+        .add_item(super::produto(dec!(1210)))
+        .parcelas(&vencimentos)
+        .build();
+    assert!(resultado.is_err());
+}
+
+#[test]
+fn serializar_cobranca() -> Result<(), Error> {
+    let cobr = Cobranca::parcelar("10", dec!(50), &[data(2024, 5, 20), data(2024, 6, 20)]).unwrap();
+
+    let xml = cobr.to_string();
+    assert!(xml.contains(
+        "<fat><nFat>10</nFat><vOrig>50.00</vOrig><vDesc>0.00</vDesc><vLiq>50.00</vLiq></fat>"
+    ));
+    assert!(xml.contains("<dup><nDup>001</nDup><dVenc>2024-05-20</dVenc><vDup>25.00</vDup></dup>"));
+
+    assert_eq!(cobr, xml.parse::<Cobranca>()?);
+
+    Ok(())
+}
+
+#[test]
+fn builder_gera_duplicatas_do_total() -> Result<(), Error> {
+    let nfe = NfeBuilder::new()
+        .codigo_uf(35)
+        .numero(321)
+        .natureza_operacao("VENDA")
+        .codigo_municipio(3550308)
+        .emit_cnpj("12345678000190")
+        .emit_razao_social("EMPRESA TESTE LTDA")
+        .emit_ie("123456789")
+        .add_item(
+            ItemBuilder::new("001", "Produto", "12345678", "5102")
+                .quantidade(dec!(3))
+                .valor_unitario(dec!(33.33)),
+        )
+        .parcelas(&[data(2024, 2, 1), data(2024, 3, 1), data(2024, 4, 1)])
+        .build()
+        .unwrap();
+
+    let cobr = nfe.cobranca.as_ref().unwrap();
+    assert_eq!(Some("321".to_string()), cobr.fatura.as_ref().unwrap().numero);
+    assert_eq!(nfe.totais.valor_total, cobr.valor_duplicatas());
+
+    // Sem pagamento informado: a prazo por boleto, no valor da nota
+    assert_eq!(Some(Pagamento::a_prazo(dec!(99.99))), nfe.pagamento);
+    assert!(nfe.to_string().contains(
+        "<pag><detPag><indPag>1</indPag><tPag>15</tPag><vPag>99.99</vPag></detPag></pag>"
+    ));
+
+    let lida = nfe.to_string().parse::<Nfe>()?;
+    assert_eq!(nfe.cobranca, lida.cobranca);
+
+    Ok(())
+}
+
+#[test]
+fn builder_rejeita_parcelas_sem_vencimento() {
+    let resultado = NfeBuilder::new()
+        .codigo_uf(35)
+        .numero(322)
+        .natureza_operacao("VENDA")
+        .codigo_municipio(3550308)
+        .emit_cnpj("12345678000190")
+        .emit_razao_social("EMPRESA TESTE LTDA")
+        .emit_ie("123456789")
+        .add_item(
+            ItemBuilder::new("001", "Produto", "12345678", "5102")
+                .quantidade(dec!(1))
+                .valor_unitario(dec!(10)),
+        )
+        .parcelas(&[])
+        .build();
+
+    assert!(resultado.is_err());
+}
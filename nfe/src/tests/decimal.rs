@@ -0,0 +1,76 @@
+//! Testes dos valores decimais de precisão fixa
+
+use crate::base::decimal::{formatar, CASAS_PERCENTUAL, CASAS_VALOR, CASAS_VALOR_UNITARIO};
+use crate::base::item::Produto;
+use crate::builder::{ItemBuilder, NfeBuilder};
+use crate::Error;
+use rust_decimal_macros::dec;
+
+#[test]
+fn formata_conforme_precisao_do_layout() {
+    assert_eq!("138.31", formatar(dec!(138.305), CASAS_VALOR, CASAS_VALOR));
+    assert_eq!("10.00", formatar(dec!(10), CASAS_VALOR, CASAS_VALOR));
+    assert_eq!("0.1234567891", formatar(dec!(0.12345678905), CASAS_VALOR, CASAS_VALOR_UNITARIO));
+    assert_eq!("25.50", formatar(dec!(25.5), CASAS_VALOR, CASAS_VALOR_UNITARIO));
+    assert_eq!("1.65", formatar(dec!(1.6500), CASAS_VALOR, CASAS_PERCENTUAL));
+    assert_eq!("3.1234", formatar(dec!(3.12344), CASAS_VALOR, CASAS_PERCENTUAL));
+}
+
+#[test]
+fn produto_preserva_precisao_no_round_trip() -> Result<(), Error> {
+    let xml = r#"
+        <prod>
+            <cProd>001</cProd>
+            <cEAN>SEM GTIN</cEAN>
+            <xProd>PARAFUSO</xProd>
+            <NCM>73181500</NCM>
+            <CFOP>5102</CFOP>
+            <uCom>UN</uCom>
+            <qCom>3.0000</qCom>
+            <vUnCom>0.0333333333</vUnCom>
+            <vProd>0.10</vProd>
+            <cEANTrib>SEM GTIN</cEANTrib>
+            <uTrib>UN</uTrib>
+            <qTrib>3.0000</qTrib>
+            <vUnTrib>0.0333333333</vUnTrib>
+            <indTot>1</indTot>
+        </prod>
+    "#;
+
+    let produto = xml.parse::<Produto>()?;
+    assert_eq!(dec!(0.0333333333), produto.valor_unitario);
+
+    let xml = produto.to_string();
+    assert!(xml.contains("<qCom>3.0000</qCom>"));
+    assert!(xml.contains("<vUnCom>0.0333333333</vUnCom>"));
+    assert!(xml.contains("<vProd>0.10</vProd>"));
+
+    assert_eq!(produto, xml.parse::<Produto>()?);
+
+    Ok(())
+}
+
+#[test]
+fn totais_sem_diferenca_de_centavos() {
+    // Com f32, somatórios nessa faixa de valor perdiam centavos
+    let mut builder = NfeBuilder::new()
+        .codigo_uf(35)
+        .numero(1)
+        .natureza_operacao("VENDA")
+        .codigo_municipio(3550308)
+        .emit_cnpj("12345678000190")
+        .emit_razao_social("EMPRESA TESTE LTDA")
+        .emit_ie("123456789");
+
+    for i in 0..200 {
+        builder = builder.add_item(
+            ItemBuilder::new(&format!("{}", i), "Item", "12345678", "5102")
+                .quantidade(dec!(1))
+                .valor_unitario(dec!(41.37)),
+        );
+    }
+
+    let nfe = builder.build().unwrap();
+    assert_eq!(dec!(8274.00), nfe.totais.valor_produtos);
+    assert_eq!(dec!(8274.00), nfe.totais.valor_total);
+}
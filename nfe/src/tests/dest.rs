@@ -1,6 +1,7 @@
 //! Testes da tag <dest>
 
 use crate::base::dest::{Destinatario, IndicadorContribuicaoIe};
+use crate::base::documento::Documento;
 use crate::Error;
 
 #[test]
@@ -28,7 +29,7 @@ fn parse_destinatario() -> Result<(), Error> {
 
     let dest = xml.parse::<Destinatario>()?;
 
-    assert_eq!("58716523000119", dest.cnpj);
+    assert_eq!(Documento::Cnpj("58716523000119".to_string()), dest.documento);
     assert_eq!(IndicadorContribuicaoIe::ContribuinteIe, dest.indicador_ie);
     assert_eq!(Some("112006603110".to_string()), dest.ie);
 
@@ -56,9 +57,58 @@ fn parse_destinatario_nao_contribuinte() -> Result<(), Error> {
 
     let dest = xml.parse::<Destinatario>()?;
 
-    assert_eq!("99999999000191", dest.cnpj);
+    assert_eq!(Documento::Cnpj("99999999000191".to_string()), dest.documento);
     assert_eq!(IndicadorContribuicaoIe::NaoContribuinteIe, dest.indicador_ie);
     assert_eq!(None, dest.ie);
 
     Ok(())
 }
+
+#[test]
+fn parse_destinatario_cpf() -> Result<(), Error> {
+    let xml = "
+        <dest>
+            <CPF>12345678909</CPF>
+            <xNome>JOSE DA SILVA</xNome>
+            <indIEDest>9</indIEDest>
+        </dest>
+    ";
+
+    let dest = xml.parse::<Destinatario>()?;
+
+    assert_eq!(Documento::Cpf("12345678909".to_string()), dest.documento);
+    assert_eq!("CPF", dest.documento.tag());
+    assert_eq!(dest, dest.to_string().parse::<Destinatario>()?);
+
+    Ok(())
+}
+
+#[test]
+fn parse_destinatario_estrangeiro() -> Result<(), Error> {
+    let xml = "
+        <dest>
+            <idEstrangeiro>AB1234567</idEstrangeiro>
+            <xNome>JOHN DOE</xNome>
+            <indIEDest>9</indIEDest>
+        </dest>
+    ";
+
+    let dest = xml.parse::<Destinatario>()?;
+
+    assert_eq!(Documento::IdEstrangeiro("AB1234567".to_string()), dest.documento);
+    assert!(dest.to_string().starts_with("<dest><idEstrangeiro>AB1234567</idEstrangeiro>"));
+
+    Ok(())
+}
+
+#[test]
+fn parse_destinatario_sem_documento() {
+    let xml = "
+        <dest>
+            <xNome>SEM DOCUMENTO</xNome>
+            <indIEDest>9</indIEDest>
+        </dest>
+    ";
+
+    assert!(xml.parse::<Destinatario>().is_err());
+}
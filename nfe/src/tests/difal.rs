@@ -0,0 +1,104 @@
+//! Testes do DIFAL (grupo ICMSUFDest) por base única e base dupla
+
+use crate::base::calculo::{calcular_difal, MetodoDifal, ParametrosDifal};
+use crate::base::endereco::Endereco;
+use crate::base::ide::{DestinoOperacao, TipoConsumidor};
+use crate::builder::{ItemBuilder, NfeBuilder};
+use rust_decimal_macros::dec;
+
+fn parametros(uf_destino: &str) -> ParametrosDifal {
+    ParametrosDifal {
+        valor_operacao: dec!(1000),
+        uf_origem: "SP".to_string(),
+        uf_destino: uf_destino.to_string(),
+        ..Default::default()
+    }
+}
+
+fn endereco(uf: &str) -> Endereco {
+    Endereco {
+        sigla_uf: uf.to_string(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn base_unica() {
+    let difal = calcular_difal(&parametros("RJ")).unwrap();
+
+    assert_eq!(dec!(1000), difal.valor_bc_uf_dest);
+    assert_eq!(dec!(22), difal.aliquota_uf_dest);
+    assert_eq!(dec!(12), difal.aliquota_interestadual);
+    assert_eq!(dec!(100), difal.percentual_partilha);
+    assert_eq!(dec!(100), difal.valor_icms_uf_dest);
+    assert_eq!(Some(dec!(20)), difal.valor_fcp_uf_dest);
+    assert_eq!(dec!(0), difal.valor_icms_uf_remet);
+}
+
+#[test]
+fn base_dupla() {
+    assert_eq!(MetodoDifal::BaseDupla, MetodoDifal::da_uf("MG"));
+    let difal = calcular_difal(&parametros("MG")).unwrap();
+
+    // (1000 − 120) / (1 − 0,18 − 0,02)
+    assert_eq!(dec!(1100), difal.valor_bc_uf_dest);
+    assert_eq!(Some(dec!(1100)), difal.valor_bc_fcp_uf_dest);
+    assert_eq!(dec!(78), difal.valor_icms_uf_dest);
+    assert_eq!(Some(dec!(22)), difal.valor_fcp_uf_dest);
+
+    let unica = ParametrosDifal {
+        metodo: Some(MetodoDifal::BaseUnica),
+        ..parametros("MG")
+    };
+    assert_eq!(dec!(60), calcular_difal(&unica).unwrap().valor_icms_uf_dest);
+}
+
+#[test]
+fn mercadoria_importada_usa_quatro_por_cento() {
+    let difal = calcular_difal(&ParametrosDifal {
+        origem_mercadoria: 1,
+        ..parametros("BA")
+    })
+    .unwrap();
+
+    assert_eq!(dec!(4), difal.aliquota_interestadual);
+    assert_eq!(dec!(1238.71), difal.valor_bc_uf_dest);
+    assert_eq!(dec!(213.94), difal.valor_icms_uf_dest);
+    assert_eq!(Some(dec!(24.77)), difal.valor_fcp_uf_dest);
+}
+
+#[test]
+fn difal_rejeita_operacao_interna_e_uf_desconhecida() {
+    assert!(calcular_difal(&parametros("SP")).unwrap_err().contains("interestadual"));
+    assert!(calcular_difal(&parametros("XX")).unwrap_err().contains("desconhecida"));
+}
+
+#[test]
+fn builder_calcula_difal_para_consumidor_final() {
+    let builder = || {
+        NfeBuilder::new()
+            .codigo_uf(35)
+            .numero(50)
+            .natureza_operacao("VENDA")
+            .destino_operacao(DestinoOperacao::Interestadual)
+            .codigo_municipio(3550308)
+            .emit_cnpj("12345678000190")
+            .emit_razao_social("EMPRESA LTDA")
+            .emit_ie("123456789")
+            .emit_endereco(endereco("SP"))
+            .dest_cpf("12345678909")
+            .dest_razao_social("CONSUMIDOR")
+            .dest_endereco(endereco("RJ"))
+            .add_item(ItemBuilder::new("001", "Produto", "61091000", "6108").valor_unitario(dec!(1000)))
+    };
+
+    let nfe = builder().tipo_consumidor(TipoConsumidor::Final).build().unwrap();
+    let difal = nfe.itens[0].imposto.icms_uf_dest.as_ref().unwrap();
+    assert_eq!(dec!(100), difal.valor_icms_uf_dest);
+    assert_eq!(TipoConsumidor::Final, nfe.ide.operacao.consumidor);
+    assert_eq!(dec!(100), nfe.totais.valor_icms_uf_dest);
+    assert_eq!(dec!(20), nfe.totais.valor_fcp_uf_dest);
+
+    let normal = builder().build().unwrap();
+    assert!(normal.itens[0].imposto.icms_uf_dest.is_none());
+}
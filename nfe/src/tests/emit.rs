@@ -0,0 +1,130 @@
+//! Testes da tag <emit>
+
+use crate::base::documento::{Documento, DocumentoNacional};
+use crate::base::emit::{Emitente, RegimeTributario};
+use crate::builder::{ItemBuilder, NfeBuilder};
+use crate::{Error, Nfe};
+use rust_decimal_macros::dec;
+
+#[test]
+fn parse_emitente() -> Result<(), Error> {
+    let xml = "
+        <emit>
+            <CNPJ>12345678000190</CNPJ>
+            <xNome>EMPRESA TESTE LTDA</xNome>
+            <xFant>TESTE</xFant>
+            <enderEmit>
+                <xLgr>Rua Teste</xLgr>
+                <nro>100</nro>
+                <xBairro>Centro</xBairro>
+                <cMun>3550308</cMun>
+                <xMun>SAO PAULO</xMun>
+                <UF>SP</UF>
+                <CEP>01000000</CEP>
+            </enderEmit>
+            <IE>123456789</IE>
+            <IM>98765</IM>
+            <CNAE>4751201</CNAE>
+            <CRT>1</CRT>
+        </emit>
+    ";
+
+    let emit = xml.parse::<Emitente>()?;
+
+    assert_eq!(DocumentoNacional::Cnpj("12345678000190".to_string()), emit.documento);
+    assert_eq!(Some("98765".to_string()), emit.im);
+    assert_eq!(Some("4751201".to_string()), emit.cnae);
+    assert_eq!(RegimeTributario::SimplesNacional, emit.crt);
+    assert!(emit.crt.usa_csosn());
+
+    assert_eq!(emit, emit.to_string().parse::<Emitente>()?);
+
+    Ok(())
+}
+
+#[test]
+fn parse_emitente_cpf() -> Result<(), Error> {
+    let xml = "
+        <emit>
+            <CPF>12345678909</CPF>
+            <xNome>PRODUTOR RURAL</xNome>
+            <enderEmit>
+                <xLgr>Estrada Rural</xLgr>
+                <nro>SN</nro>
+                <xBairro>Zona Rural</xBairro>
+                <cMun>3529302</cMun>
+                <xMun>MATAO</xMun>
+                <UF>SP</UF>
+                <CEP>15990000</CEP>
+            </enderEmit>
+            <IE>123456789</IE>
+            <CRT>3</CRT>
+        </emit>
+    ";
+
+    let emit = xml.parse::<Emitente>()?;
+
+    assert_eq!(DocumentoNacional::Cpf("12345678909".to_string()), emit.documento);
+    assert!(!emit.crt.usa_csosn());
+
+    Ok(())
+}
+
+#[test]
+fn builder_emitente_cpf_destinatario_estrangeiro() -> Result<(), Error> {
+    let nfe = NfeBuilder::new()
+        .codigo_uf(35)
+        .numero(1)
+        .natureza_operacao("VENDA")
+        .codigo_municipio(3529302)
+        .emit_cpf("123.456.789-09")
+        .emit_razao_social("PRODUTOR RURAL")
+        .emit_ie("123456789")
+        .emit_crt(RegimeTributario::SimplesNacional)
+        .dest_id_estrangeiro("AB1234567")
+        .dest_razao_social("JOHN DOE")
+        .add_item(
+            ItemBuilder::new("001", "Produto", "12345678", "7102")
+                .valor_unitario(dec!(10)),
+        )
+        .build()
+        .unwrap();
+
+    assert_eq!("00012345678909", &nfe.chave_acesso[6..20]);
+    assert_eq!(RegimeTributario::SimplesNacional, nfe.emit.crt);
+
+    let lida = nfe.to_string().parse::<Nfe>()?;
+    assert_eq!(nfe.emit, lida.emit);
+    assert_eq!(nfe.dest, lida.dest);
+
+    Ok(())
+}
+
+#[test]
+fn builder_sem_documento_emitente() {
+    let resultado = NfeBuilder::new()
+        .codigo_uf(35)
+        .numero(1)
+        .natureza_operacao("VENDA")
+        .codigo_municipio(3550308)
+        .add_item(ItemBuilder::new("001", "Produto", "12345678", "5102"))
+        .build();
+
+    assert_eq!(Err("CNPJ ou CPF do emitente é obrigatório".to_string()), resultado.map(|_| ()));
+}
+
+#[test]
+fn emitente_so_aceita_documento_nacional() {
+    assert_eq!(
+        Ok(DocumentoNacional::Cpf("12345678909".to_string())),
+        DocumentoNacional::try_from(Documento::cpf("123.456.789-09"))
+    );
+    assert_eq!(
+        Err("idEstrangeiro AB1234567 não é permitido, informe CNPJ ou CPF".to_string()),
+        DocumentoNacional::try_from(Documento::id_estrangeiro("AB1234567"))
+    );
+    assert_eq!(
+        Documento::Cnpj("12345678000190".to_string()),
+        Documento::from(DocumentoNacional::cnpj("12.345.678/0001-90"))
+    );
+}
@@ -0,0 +1,224 @@
+//! Testes dos grupos específicos de produto (med, veicProd, arma, comb) e do rastro
+
+use crate::base::item::{
+    Arma, Cide, Combustivel, Encerrante, Medicamento, Produto, ProdutoEspecifico, Rastreabilidade,
+    TipoArma, VeiculoNovo,
+};
+use crate::builder::{ItemBuilder, NfeBuilder};
+use crate::{Error, Nfe};
+use chrono::NaiveDate;
+use rust_decimal_macros::dec;
+
+const INICIO: &str = "<prod><cProd>001</cProd><cEAN>SEM GTIN</cEAN><xProd>Produto</xProd>\
+    <NCM>30049099</NCM><CFOP>5102</CFOP><uCom>UN</uCom><qCom>1.0000</qCom><vUnCom>10.00</vUnCom>\
+    <vProd>10.00</vProd><cEANTrib>SEM GTIN</cEANTrib><uTrib>UN</uTrib><qTrib>1.0000</qTrib>\
+    <vUnTrib>10.00</vUnTrib><indTot>1</indTot>";
+const FIM: &str = "</prod>";
+
+fn rastro(lote: &str) -> Rastreabilidade {
+    Rastreabilidade {
+        numero_lote: lote.to_string(),
+        quantidade_lote: dec!(100),
+        data_fabricacao: NaiveDate::from_ymd_opt(2024, 1, 10).unwrap(),
+        data_validade: NaiveDate::from_ymd_opt(2026, 1, 10).unwrap(),
+        codigo_agregacao: None,
+    }
+}
+
+fn arma(serie: &str) -> Arma {
+    Arma {
+        tipo: TipoArma::UsoPermitido,
+        numero_serie: serie.to_string(),
+        numero_cano: format!("C{}", serie),
+        descricao: "PISTOLA CAL .380".to_string(),
+    }
+}
+
+fn builder(item: ItemBuilder) -> NfeBuilder {
+    super::builder().add_item(item)
+}
+
+#[test]
+fn parse_medicamento_com_rastro() -> Result<(), Error> {
+    let xml = format!(
+        "{}<rastro><nLote>L001</nLote><qLote>100.000</qLote><dFab>2024-01-10</dFab>\
+         <dVal>2026-01-10</dVal></rastro><rastro><nLote>L002</nLote><qLote>100.000</qLote>\
+         <dFab>2024-01-10</dFab><dVal>2026-01-10</dVal></rastro>\
+         <med><cProdANVISA>1234567890123</cProdANVISA><vPMC>35.90</vPMC></med>{}",
+        INICIO, FIM
+    );
+    let produto = xml.parse::<Produto>()?;
+
+    assert_eq!(vec![rastro("L001"), rastro("L002")], produto.rastreabilidade);
+    assert_eq!(
+        Some(ProdutoEspecifico::Medicamento(Medicamento {
+            codigo_anvisa: "1234567890123".to_string(),
+            motivo_isencao: None,
+            preco_maximo_consumidor: dec!(35.90),
+        })),
+        produto.especifico
+    );
+    assert_eq!(xml, produto.to_string());
+
+    Ok(())
+}
+
+#[test]
+fn parse_combustivel() -> Result<(), Error> {
+    let xml = format!(
+        "{}<comb><cProdANP>320102001</cProdANP><descANP>GASOLINA C COMUM</descANP>\
+         <CODIF>123456</CODIF><UFCons>SP</UFCons>\
+         <CIDE><qBCProd>1000.0000</qBCProd><vAliqProd>0.1000</vAliqProd><vCIDE>100.00</vCIDE></CIDE>\
+         <encerrante><nBico>3</nBico><nTanque>1</nTanque><vEncIni>1500.000</vEncIni>\
+         <vEncFin>2500.000</vEncFin></encerrante></comb>{}",
+        INICIO, FIM
+    );
+    let produto = xml.parse::<Produto>()?;
+
+    let combustivel = match &produto.especifico {
+        Some(ProdutoEspecifico::Combustivel(comb)) => comb,
+        outro => panic!("Esperado combustível, obtido {:?}", outro),
+    };
+    assert_eq!("320102001", combustivel.codigo_anp);
+    assert_eq!(Some("123456".to_string()), combustivel.codif);
+    assert_eq!(
+        Some(Cide { quantidade_bc: dec!(1000), aliquota: dec!(0.1), valor: dec!(100) }),
+        combustivel.cide
+    );
+    assert_eq!(dec!(2500), combustivel.encerrante.as_ref().unwrap().valor_final);
+    assert_eq!(xml, produto.to_string());
+
+    Ok(())
+}
+
+#[test]
+fn parse_veiculo() -> Result<(), Error> {
+    let xml = format!(
+        "{}<veicProd><tpOp>1</tpOp><chassi>9BWZZZ377VT004251</chassi><cCor>01</cCor>\
+         <xCor>BRANCO</xCor><pot>116</pot><cilin>1598</cilin><pesoL>1.1200</pesoL>\
+         <pesoB>1.5000</pesoB><nSerie>123</nSerie><tpComb>16</tpComb><nMotor>CWS123456</nMotor>\
+         <CMT>1.5000</CMT><dist>2.5600</dist><anoMod>2025</anoMod><anoFab>2024</anoFab>\
+         <tpPint>S</tpPint><tpVeic>06</tpVeic><espVeic>1</espVeic><VIN>N</VIN><condVeic>1</condVeic>\
+         <cMod>123456</cMod><cCorDENATRAN>16</cCorDENATRAN><lota>5</lota><tpRest>0</tpRest>\
+         </veicProd>{}",
+        INICIO, FIM
+    );
+    let produto = xml.parse::<Produto>()?;
+
+    match &produto.especifico {
+        Some(ProdutoEspecifico::Veiculo(veiculo)) => {
+            assert_eq!("9BWZZZ377VT004251", veiculo.chassi);
+            assert_eq!("06", veiculo.tipo_veiculo);
+            assert_eq!(2025, veiculo.ano_modelo);
+        }
+        outro => panic!("Esperado veículo, obtido {:?}", outro),
+    }
+    assert_eq!(xml, produto.to_string());
+
+    Ok(())
+}
+
+#[test]
+fn builder_armamentos() -> Result<(), Error> {
+    let nfe = builder(
+        ItemBuilder::new("001", "Pistola", "93020000", "5102")
+            .valor_unitario(dec!(5000))
+            .add_arma(arma("A1"))
+            .add_arma(arma("A2")),
+    )
+    .build()
+    .unwrap();
+
+    let lida = nfe.to_string().parse::<Nfe>()?;
+    assert_eq!(
+        Some(ProdutoEspecifico::Armamentos(vec![arma("A1"), arma("A2")])),
+        lida.itens[0].produto.especifico
+    );
+
+    Ok(())
+}
+
+#[test]
+fn builder_grupo_especifico_exclusivo() -> Result<(), Error> {
+    let combustivel = Combustivel {
+        codigo_anp: "820101034".to_string(),
+        descricao_anp: "OLEO DIESEL B S10 - COMUM".to_string(),
+        percentual_glp: None,
+        percentual_gas_nacional: None,
+        percentual_gas_importado: None,
+        valor_partida: None,
+        codif: None,
+        quantidade_temperatura: None,
+        uf_consumo: "SP".to_string(),
+        cide: None,
+        encerrante: Some(Encerrante {
+            bico: 1,
+            bomba: Some(2),
+            tanque: 3,
+            valor_inicial: dec!(100),
+            valor_final: dec!(150),
+        }),
+    };
+
+    let nfe = builder(
+        ItemBuilder::new("001", "Diesel S10", "27101921", "5656")
+            .unidade("L")
+            .quantidade(dec!(50))
+            .valor_unitario(dec!(6.19))
+            .add_arma(arma("A1"))
+            .combustivel(combustivel.clone())
+            .add_rastro(rastro("L001")),
+    )
+    .build()
+    .unwrap();
+
+    let xml = nfe.to_string();
+    assert!(!xml.contains("<arma>"));
+
+    let lida = xml.parse::<Nfe>()?;
+    let produto = &lida.itens[0].produto;
+    assert_eq!(Some(ProdutoEspecifico::Combustivel(combustivel)), produto.especifico);
+    assert_eq!(vec![rastro("L001")], produto.rastreabilidade);
+
+    Ok(())
+}
+
+#[test]
+fn veiculo_no_builder() {
+    let veiculo = VeiculoNovo {
+        tipo_operacao: 1,
+        chassi: "9BWZZZ377VT004251".to_string(),
+        codigo_cor: "01".to_string(),
+        descricao_cor: "BRANCO".to_string(),
+        potencia: "116".to_string(),
+        cilindradas: "1598".to_string(),
+        peso_liquido: "1.1200".to_string(),
+        peso_bruto: "1.5000".to_string(),
+        numero_serie: "123".to_string(),
+        tipo_combustivel: "16".to_string(),
+        numero_motor: "CWS123456".to_string(),
+        capacidade_tracao: "1.5000".to_string(),
+        distancia_eixos: "2.5600".to_string(),
+        ano_modelo: 2025,
+        ano_fabricacao: 2024,
+        tipo_pintura: "S".to_string(),
+        tipo_veiculo: "06".to_string(),
+        especie_veiculo: "1".to_string(),
+        vin: "N".to_string(),
+        condicao: 1,
+        codigo_modelo: "123456".to_string(),
+        codigo_cor_denatran: "16".to_string(),
+        lotacao: 5,
+        restricao: 0,
+    };
+
+    let item = ItemBuilder::new("001", "Automovel", "87032310", "5102")
+        .medicamento(Medicamento {
+            codigo_anvisa: "ISENTO".to_string(),
+            motivo_isencao: Some("Teste".to_string()),
+            preco_maximo_consumidor: dec!(1),
+        })
+        .veiculo(veiculo.clone());
+
+    assert_eq!(Some(ProdutoEspecifico::Veiculo(veiculo)), item.especifico);
+}
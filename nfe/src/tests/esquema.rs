@@ -0,0 +1,731 @@
+//! Testes da validação offline contra esquemas XSD
+//!
+//! Os esquemas abaixo reproduzem, em tamanho reduzido, a organização do
+//! pacote PL_009: o principal inclui o leiaute, que inclui os tipos básicos
+//! e importa o esquema da assinatura digital.
+
+use crate::base::calculo::{
+    calcular_ibs_cbs, AliquotasIbsCbs, ParametrosIbsCbs, ParametrosIcms, ParametrosIcmsSt,
+};
+use crate::base::dest::IndicadorContribuicaoIe;
+use crate::base::documento::{Documento, DocumentoNacional};
+use crate::base::esquema::{EsquemaXsd, ViolacaoEsquema};
+use crate::base::exportacao::{Compra, Exportacao};
+use crate::base::ide::{DestinoOperacao, TipoOperacao, TipoPresencaComprador};
+use crate::base::informacoes_adicionais::{OrigemProcesso, ProcessoReferenciado};
+use crate::base::intermediador::Intermediador;
+use crate::base::item::{
+    Adicao, DeclaracaoImportacao, FormaImportacao, Medicamento, Rastreabilidade, ViaTransporte,
+};
+use crate::base::nfce::FormaPagamentoNfce;
+use crate::base::pagamento::{
+    BandeiraCartao, Cartao, DetalhePagamento, IndicadorPagamento, TipoIntegracaoPagamento,
+};
+use crate::base::responsavel_tecnico::{ConfiguracaoCsrt, ResponsavelTecnico};
+use crate::base::transporte::{Lacre, ModalidadeFrete, Transportador, Veiculo, Volume};
+use crate::builder::{ItemBuilder, NfeBuilder};
+use crate::{Decimal, Error, Nfe};
+use chrono::NaiveDate;
+use rust_decimal_macros::dec;
+
+const NFE: &str = r###"<?xml version="1.0" encoding="UTF-8"?>
+<xs:schema xmlns="http://www.portalfiscal.inf.br/nfe" xmlns:xs="http://www.w3.org/2001/XMLSchema"
+    targetNamespace="http://www.portalfiscal.inf.br/nfe" elementFormDefault="qualified">
+    <xs:include schemaLocation="leiauteNFe.xsd"/>
+    <xs:element name="NFe" type="TNFe">
+        <xs:annotation><xs:documentation>Nota Fiscal Eletrônica</xs:documentation></xs:annotation>
+    </xs:element>
+</xs:schema>"###;
+
+const LEIAUTE: &str = r###"<?xml version="1.0" encoding="UTF-8"?>
+<xs:schema xmlns="http://www.portalfiscal.inf.br/nfe" xmlns:xs="http://www.w3.org/2001/XMLSchema"
+    xmlns:ds="http://www.w3.org/2000/09/xmldsig#"
+    targetNamespace="http://www.portalfiscal.inf.br/nfe" elementFormDefault="qualified">
+    <xs:import namespace="http://www.w3.org/2000/09/xmldsig#" schemaLocation="xmldsig-core-schema.xsd"/>
+    <xs:include schemaLocation="tiposBasico.xsd"/>
+    <xs:complexType name="TNFe">
+        <xs:sequence>
+            <xs:element name="infNFe">
+                <xs:complexType>
+                    <xs:sequence>
+                        <xs:element name="ide">
+                            <xs:complexType>
+                                <xs:sequence>
+                                    <xs:element name="cUF" type="TCodUfIBGE"/>
+                                    <xs:element name="cNF">
+                                        <xs:simpleType>
+                                            <xs:restriction base="xs:string">
+                                                <xs:whiteSpace value="preserve"/>
+                                                <xs:pattern value="[0-9]{8}"/>
+                                            </xs:restriction>
+                                        </xs:simpleType>
+                                    </xs:element>
+                                    <xs:any processContents="skip" minOccurs="0" maxOccurs="unbounded"/>
+                                </xs:sequence>
+                            </xs:complexType>
+                        </xs:element>
+                        <xs:element name="emit">
+                            <xs:complexType>
+                                <xs:sequence>
+                                    <xs:choice>
+                                        <xs:element name="CNPJ" type="TCnpj"/>
+                                        <xs:element name="CPF" type="TCpf"/>
+                                    </xs:choice>
+                                    <xs:element name="xNome" type="TString"/>
+                                    <xs:any processContents="skip" minOccurs="0" maxOccurs="unbounded"/>
+                                </xs:sequence>
+                            </xs:complexType>
+                        </xs:element>
+                        <xs:element name="det" maxOccurs="990">
+                            <xs:complexType>
+                                <xs:sequence>
+                                    <xs:element name="prod">
+                                        <xs:complexType>
+                                            <xs:sequence>
+                                                <xs:element name="cProd" type="TString"/>
+                                                <xs:element name="cEAN" type="TGtin"/>
+                                                <xs:element name="xProd" type="TString"/>
+                                                <xs:element name="NCM">
+                                                    <xs:simpleType>
+                                                        <xs:restriction base="xs:string">
+                                                            <xs:whiteSpace value="preserve"/>
+                                                            <xs:pattern value="[0-9]{2}|[0-9]{8}"/>
+                                                        </xs:restriction>
+                                                    </xs:simpleType>
+                                                </xs:element>
+                                                <xs:any processContents="skip" minOccurs="0" maxOccurs="unbounded"/>
+                                            </xs:sequence>
+                                        </xs:complexType>
+                                    </xs:element>
+                                    <xs:element name="imposto"/>
+                                </xs:sequence>
+                                <xs:attribute name="nItem" use="required">
+                                    <xs:simpleType>
+                                        <xs:restriction base="xs:string">
+                                            <xs:pattern value="[1-9]{1}[0-9]{0,1}|[1-8]{1}[0-9]{2}|[9]{1}[0-8]{1}[0-9]{1}|[9]{1}[9]{1}[0]{1}"/>
+                                        </xs:restriction>
+                                    </xs:simpleType>
+                                </xs:attribute>
+                            </xs:complexType>
+                        </xs:element>
+                        <xs:element name="total">
+                            <xs:complexType>
+                                <xs:sequence>
+                                    <xs:element name="ICMSTot">
+                                        <xs:complexType>
+                                            <xs:sequence>
+                                                <xs:element name="vBC" type="TDec_1302"/>
+                                                <xs:element name="vICMS" type="TDec_1302"/>
+                                                <xs:any processContents="skip" minOccurs="0" maxOccurs="unbounded"/>
+                                            </xs:sequence>
+                                        </xs:complexType>
+                                    </xs:element>
+                                </xs:sequence>
+                            </xs:complexType>
+                        </xs:element>
+                        <xs:any namespace="##targetNamespace" processContents="skip" minOccurs="0" maxOccurs="unbounded"/>
+                    </xs:sequence>
+                    <xs:attribute name="versao" type="TVerNFe" use="required"/>
+                    <xs:attribute name="Id" use="required">
+                        <xs:simpleType>
+                            <xs:restriction base="xs:ID">
+                                <xs:pattern value="NFe[0-9]{44}"/>
+                            </xs:restriction>
+                        </xs:simpleType>
+                    </xs:attribute>
+                </xs:complexType>
+            </xs:element>
+            <xs:element ref="ds:Signature" minOccurs="0"/>
+        </xs:sequence>
+    </xs:complexType>
+</xs:schema>"###;
+
+const TIPOS: &str = r###"<?xml version="1.0" encoding="UTF-8"?>
+<xs:schema xmlns="http://www.portalfiscal.inf.br/nfe" xmlns:xs="http://www.w3.org/2001/XMLSchema"
+    targetNamespace="http://www.portalfiscal.inf.br/nfe" elementFormDefault="qualified">
+    <xs:simpleType name="TCnpj">
+        <xs:restriction base="xs:string">
+            <xs:maxLength value="14"/>
+            <xs:pattern value="[0-9]{14}"/>
+        </xs:restriction>
+    </xs:simpleType>
+    <xs:simpleType name="TCpf">
+        <xs:restriction base="xs:string">
+            <xs:pattern value="[0-9]{11}"/>
+        </xs:restriction>
+    </xs:simpleType>
+    <xs:simpleType name="TCodUfIBGE">
+        <xs:restriction base="xs:string">
+            <xs:whiteSpace value="preserve"/>
+            <xs:enumeration value="35"/>
+            <xs:enumeration value="41"/>
+            <xs:enumeration value="43"/>
+        </xs:restriction>
+    </xs:simpleType>
+    <xs:simpleType name="TNF">
+        <xs:restriction base="xs:string">
+            <xs:pattern value="[1-9]{1}[0-9]{0,8}"/>
+        </xs:restriction>
+    </xs:simpleType>
+    <xs:simpleType name="TDec_1302">
+        <xs:restriction base="xs:string">
+            <xs:pattern value="0|0\.[0-9]{2}|[1-9]{1}[0-9]{0,12}(\.[0-9]{2})?"/>
+        </xs:restriction>
+    </xs:simpleType>
+    <xs:simpleType name="TString">
+        <xs:restriction base="xs:string">
+            <xs:whiteSpace value="preserve"/>
+            <xs:pattern value="[!-ÿ]{1}[ -ÿ]{0,}[!-ÿ]{1}|[!-ÿ]{1}"/>
+        </xs:restriction>
+    </xs:simpleType>
+    <xs:simpleType name="TGtin">
+        <xs:restriction base="xs:string">
+            <xs:pattern value="SEM GTIN|[0-9]{0}|[0-9]{8}|[0-9]{12,14}"/>
+        </xs:restriction>
+    </xs:simpleType>
+    <xs:simpleType name="TVerNFe">
+        <xs:restriction base="xs:token">
+            <xs:enumeration value="4.00"/>
+        </xs:restriction>
+    </xs:simpleType>
+</xs:schema>"###;
+
+const XMLDSIG: &str = r###"<?xml version="1.0" encoding="UTF-8"?>
+<schema xmlns="http://www.w3.org/2001/XMLSchema" xmlns:ds="http://www.w3.org/2000/09/xmldsig#"
+    targetNamespace="http://www.w3.org/2000/09/xmldsig#" elementFormDefault="qualified">
+    <element name="Signature" type="ds:SignatureType"/>
+    <complexType name="SignatureType">
+        <sequence>
+            <element name="SignedInfo" type="anyType"/>
+            <element name="SignatureValue" type="ds:SignatureValueType"/>
+            <element name="KeyInfo" minOccurs="0" type="anyType"/>
+        </sequence>
+        <attribute name="Id" type="ID" use="optional"/>
+    </complexType>
+    <complexType name="SignatureValueType">
+        <simpleContent>
+            <extension base="base64Binary">
+                <attribute name="Id" type="ID" use="optional"/>
+            </extension>
+        </simpleContent>
+    </complexType>
+</schema>"###;
+
+fn esquema() -> EsquemaXsd {
+    EsquemaXsd::carregar(
+        [
+            ("nfe.xsd", NFE),
+            ("leiauteNFe.xsd", LEIAUTE),
+            ("tiposBasico.xsd", TIPOS),
+            ("xmldsig-core-schema.xsd", XMLDSIG),
+        ],
+        "nfe.xsd",
+    )
+    .unwrap()
+}
+
+fn item(codigo: &str) -> ItemBuilder {
+    ItemBuilder::new(codigo, "Produto", "61091000", "5102")
+        .valor_unitario(dec!(100))
+        .gtin("7891000315507")
+}
+
+fn builder() -> NfeBuilder {
+    super::builder().add_item(item("001")).add_item(item("002"))
+}
+
+fn nfe() -> Nfe {
+    builder().build().unwrap()
+}
+
+fn mensagens(violacoes: Vec<ViolacaoEsquema>) -> Vec<String> {
+    violacoes.iter().map(ViolacaoEsquema::to_string).collect()
+}
+
+#[test]
+fn nota_do_builder_atende_ao_esquema() {
+    let esquema = EsquemaXsd::pl_009_embutido().unwrap();
+    assert_eq!(Ok(()), nfe().validar_esquema(&esquema));
+
+    let csrt = ConfiguracaoCsrt {
+        id_csrt: "01".to_string(),
+        csrt: "G8063VRTNDMO886SFNK5LDUDEI24XJ22YIPO".to_string(),
+    };
+    let nfe = builder()
+        .responsavel_tecnico(ResponsavelTecnico::new("11111111000191", "Fulano", "a@b.com", "4133334444"))
+        .csrt(csrt)
+        .build()
+        .unwrap();
+    assert_eq!(Ok(()), nfe.validar_esquema(&esquema));
+
+    let venda = venda_completa();
+    let xml = venda.to_string();
+    for grupo in [
+        "<NFref>", "<ICMS10>", "<rastro>", "<med>", "<IBSCBS>", "<IBSCBSTot>", "<transporta>",
+        "<veicTransp>", "<lacres>", "<cobr>", "<dup>", "<card>", "<infIntermed>", "<obsCont",
+        "<procRef>", "<compra>",
+    ] {
+        assert!(xml.contains(grupo), "{} ausente", grupo);
+    }
+    assert_eq!(Ok(()), venda.validar_esquema(&esquema));
+
+    let exportacao = exportacao();
+    assert!(exportacao.to_string().contains("<exportInd>"));
+    assert_eq!(Ok(()), exportacao.validar_esquema(&esquema));
+
+    let importacao = importacao();
+    assert!(importacao.to_string().contains("<adi>"));
+    assert_eq!(Ok(()), importacao.validar_esquema(&esquema));
+}
+
+/// Chave de acesso de uma NF-e anterior (exemplo da NT 2018.005)
+const CHAVE_REFERENCIADA: &str = "41180678393592000146558900000006041028190697";
+
+/// Venda pela internet com todos os grupos opcionais que o builder monta
+fn venda_completa() -> Nfe {
+    let data = |ano, mes, dia| NaiveDate::from_ymd_opt(ano, mes, dia).unwrap();
+    let icms_st = ParametrosIcms {
+        cst: "10".to_string(),
+        aliquota: dec!(18),
+        st: Some(ParametrosIcmsSt {
+            percentual_mva: dec!(40),
+            aliquota_interna: dec!(18),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let medicamento = ItemBuilder::new("003", "Dipirona 500mg", "30049099", "5102")
+        .quantidade(dec!(10))
+        .valor_unitario(dec!(12.50))
+        .medicamento(Medicamento {
+            codigo_anvisa: "1234567890123".to_string(),
+            motivo_isencao: None,
+            preco_maximo_consumidor: dec!(35.90),
+        })
+        .add_rastro(Rastreabilidade {
+            numero_lote: "L001".to_string(),
+            quantidade_lote: dec!(10),
+            data_fabricacao: data(2024, 1, 10),
+            data_validade: data(2026, 1, 10),
+            codigo_agregacao: None,
+        });
+    let cartao = DetalhePagamento {
+        indicador: Some(IndicadorPagamento::APrazo),
+        cartao: Some(Cartao {
+            tipo_integracao: TipoIntegracaoPagamento::Integrado,
+            cnpj_credenciadora: Some("01425787000104".to_string()),
+            bandeira: Some(BandeiraCartao::Visa),
+            autorizacao: Some("123456".to_string()),
+        }),
+        ..DetalhePagamento::new(FormaPagamentoNfce::CartaoCredito, dec!(1000))
+    };
+
+    let mut nfe = builder()
+        .presenca(TipoPresencaComprador::ViaInternel)
+        .intermediador(Intermediador::new("03007331000141", "LOJA_EXEMPLO"))
+        .referenciar_nfe(CHAVE_REFERENCIADA)
+        .dest_cnpj("98765432000198")
+        .dest_razao_social("CLIENTE LTDA")
+        .dest_indicador_ie(IndicadorContribuicaoIe::NaoContribuinteIe)
+        .add_item(
+            ItemBuilder::new("003", "Produto com ST", "61091000", "5401")
+                .valor_unitario(dec!(100))
+                .calcular_icms(icms_st),
+        )
+        .add_item(medicamento)
+        .modalidade_frete(ModalidadeFrete::ContratacaoPorContaDoRemetente)
+        .transportador(Transportador {
+            documento: Some(DocumentoNacional::cnpj("11222333000144")),
+            razao_social: Some("TRANSPORTES RAPIDOS LTDA".to_string()),
+            ie: Some("123456789".to_string()),
+            endereco: Some("RODOVIA ANHANGUERA KM 100".to_string()),
+            municipio: Some("CAMPINAS".to_string()),
+            uf: Some("SP".to_string()),
+        })
+        .veiculo(Veiculo {
+            placa: "ABC1D23".to_string(),
+            uf: Some("SP".to_string()),
+            rntc: Some("12345678".to_string()),
+        })
+        .add_volume(Volume {
+            quantidade: Some(2),
+            especie: Some("CAIXA".to_string()),
+            peso_liquido: Some(dec!(10.5)),
+            peso_bruto: Some(dec!(11.25)),
+            lacres: vec![Lacre::new("L001")],
+            ..Default::default()
+        })
+        .parcelas(&[data(2024, 2, 15), data(2024, 3, 15)])
+        .add_detalhe_pagamento(cartao)
+        .compra(Compra {
+            pedido: Some("PO-778".to_string()),
+            ..Default::default()
+        })
+        .informacao_complementar("Pedido 456")
+        .informacao_fisco("Regime especial 123")
+        .add_observacao_contribuinte("Vendedor", "Maria")
+        .add_processo(ProcessoReferenciado {
+            numero: "5001234-56.2024.4.03.6100".to_string(),
+            origem: OrigemProcesso::JusticaFederal,
+            tipo_ato: None,
+        })
+        .build()
+        .unwrap();
+
+    // IBS/CBS do primeiro ano de transição
+    nfe.itens[0].imposto.ibs_cbs = calcular_ibs_cbs(&ParametrosIbsCbs {
+        cst: "000".to_string(),
+        classificacao_tributaria: "000001".to_string(),
+        valor_bc: dec!(100),
+        ano: 2026,
+        aliquotas_referencia: AliquotasIbsCbs {
+            cbs: dec!(8.8),
+            ibs_uf: dec!(17.7),
+            ibs_mun: dec!(1),
+        },
+        percentual_reducao: Decimal::ZERO,
+        percentual_diferimento: Decimal::ZERO,
+    });
+    nfe.recalcular_totais();
+
+    nfe
+}
+
+/// Exportação direta e indireta, com porto de embarque
+fn exportacao() -> Nfe {
+    super::builder()
+        .natureza_operacao("EXPORTACAO")
+        .destino_operacao(DestinoOperacao::ComExterior)
+        .dest_id_estrangeiro("AB123456")
+        .dest_razao_social("FOREIGN BUYER INC")
+        .exportacao(Exportacao::new("SP", "PORTO DE SANTOS"))
+        .add_item(
+            ItemBuilder::new("001", "Produto", "61091000", "7101")
+                .valor_unitario(dec!(100))
+                .drawback("20240012345")
+                .exportacao_indireta("240012345678", CHAVE_REFERENCIADA, dec!(1)),
+        )
+        .build()
+        .unwrap()
+}
+
+/// Entrada de mercadoria importada com declaração de importação
+fn importacao() -> Nfe {
+    let data = |ano, mes, dia| NaiveDate::from_ymd_opt(ano, mes, dia).unwrap();
+    let declaracao = DeclaracaoImportacao {
+        numero: "2412345678".to_string(),
+        data_registro: data(2024, 3, 1),
+        local_desembaraco: "PORTO DE SANTOS".to_string(),
+        uf_desembaraco: "SP".to_string(),
+        data_desembaraco: data(2024, 3, 5),
+        via_transporte: ViaTransporte::Maritima,
+        valor_afrmm: Some(dec!(250)),
+        intermediacao: FormaImportacao::ContaEOrdem,
+        adquirente: Some(Documento::cnpj("98765432000198")),
+        uf_terceiro: Some("MG".to_string()),
+        codigo_exportador: "EXP001".to_string(),
+        adicoes: vec![Adicao {
+            numero: Some(1),
+            sequencial: 1,
+            codigo_fabricante: "FAB-CN-01".to_string(),
+            valor_desconto: None,
+            drawback: None,
+        }],
+    };
+
+    super::builder()
+        .natureza_operacao("IMPORTACAO")
+        .tipo_operacao(TipoOperacao::Entrada)
+        .destino_operacao(DestinoOperacao::ComExterior)
+        .dest_id_estrangeiro("")
+        .dest_razao_social("FOREIGN SUPPLIER INC")
+        .add_item(
+            ItemBuilder::new("001", "Motor eletrico", "85015210", "3102")
+                .valor_unitario(dec!(10000))
+                .add_declaracao_importacao(declaracao),
+        )
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn violacoes_com_caminho_do_elemento() {
+    let mut nfe = nfe();
+    nfe.emit.documento = DocumentoNacional::Cnpj("1234567800019".to_string());
+    nfe.itens[1].produto.ncm = "6109".to_string();
+
+    let violacoes = nfe.validar_esquema(&esquema()).unwrap_err();
+    assert_eq!(
+        vec![
+            "/NFe/infNFe/emit/CNPJ: valor '1234567800019' não atende ao padrão [0-9]{14}",
+            "/NFe/infNFe/det[2]/prod/NCM: valor '6109' não atende ao padrão [0-9]{2}|[0-9]{8}",
+        ],
+        mensagens(violacoes)
+    );
+}
+
+#[test]
+fn estrutura_do_xml_recebido() {
+    let xml = r#"<nfe:NFe xmlns:nfe="http://www.portalfiscal.inf.br/nfe">
+        <nfe:infNFe versao="3.10" Id="NFe35" tpAmb="2">
+            <nfe:ide><nfe:cUF>99</nfe:cUF></nfe:ide>
+            <nfe:emit><nfe:xNome>EMPRESA</nfe:xNome></nfe:emit>
+            <nfe:det nItem="1">
+                <nfe:prod><nfe:cProd>1</nfe:cProd><nfe:cEAN>123</nfe:cEAN><nfe:xProd>X</nfe:xProd><nfe:NCM>00</nfe:NCM></nfe:prod>
+                <nfe:imposto/>
+            </nfe:det>
+            <nfe:total><nfe:ICMSTot><nfe:vBC>10.5</nfe:vBC><nfe:vICMS>0.00</nfe:vICMS></nfe:ICMSTot></nfe:total>
+            <transp/>
+        </nfe:infNFe>
+    </nfe:NFe>"#;
+
+    let violacoes = esquema().validar(xml).unwrap_err();
+    assert_eq!(
+        vec![
+            "/NFe/infNFe: atributo 'versao': valor '3.10' não está entre os permitidos: 4.00",
+            "/NFe/infNFe: atributo 'Id': valor 'NFe35' não atende ao padrão NFe[0-9]{44}",
+            "/NFe/infNFe: atributo 'tpAmb' não declarado",
+            "/NFe/infNFe/transp: elemento 'transp' não permitido nesta posição",
+            "/NFe/infNFe/ide: elemento obrigatório ausente; esperado: 'cNF'",
+            "/NFe/infNFe/ide/cUF: valor '99' não está entre os permitidos: 35, 41, 43",
+            "/NFe/infNFe/emit/xNome: elemento 'xNome' inesperado; esperado: 'CNPJ', 'CPF'",
+            "/NFe/infNFe/det/prod/cEAN: valor '123' não atende ao padrão SEM GTIN|[0-9]{0}|[0-9]{8}|[0-9]{12,14}",
+            "/NFe/infNFe/total/ICMSTot/vBC: valor '10.5' não atende ao padrão \
+             0|0\\.[0-9]{2}|[1-9]{1}[0-9]{0,12}(\\.[0-9]{2})?",
+        ],
+        mensagens(violacoes)
+    );
+}
+
+#[test]
+fn assinatura_importada_do_xmldsig() {
+    let nfe = nfe().to_string();
+    let assinar = |valor: &str| {
+        nfe.replace(
+            "</NFe>",
+            &format!(
+                r#"<Signature xmlns="http://www.w3.org/2000/09/xmldsig#"><SignedInfo><Reference URI=""/></SignedInfo><SignatureValue>{}</SignatureValue></Signature></NFe>"#,
+                valor
+            ),
+        )
+    };
+
+    assert_eq!(Ok(()), esquema().validar(&assinar("c2VsbyBkaWdpdGFs")));
+    assert_eq!(
+        vec!["/NFe/Signature/SignatureValue: valor 'não é base64' inválido para xs:base64Binary"],
+        mensagens(esquema().validar(&assinar("não é base64")).unwrap_err())
+    );
+}
+
+#[test]
+fn xml_mal_formado() {
+    let violacoes = esquema().validar("<NFe><infNFe></NFe>").unwrap_err();
+
+    assert_eq!(1, violacoes.len());
+    assert_eq!("/", violacoes[0].caminho);
+    assert!(violacoes[0].mensagem.starts_with("XML mal formado"));
+}
+
+/// Esquema sem `targetNamespace`, incluído pelo [`PRINCIPAL`]
+const CAMALEAO: &str = r###"<?xml version="1.0" encoding="UTF-8"?>
+<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema" elementFormDefault="qualified">
+    <xs:simpleType name="THash">
+        <xs:restriction base="xs:base64Binary">
+            <xs:length value="20"/>
+        </xs:restriction>
+    </xs:simpleType>
+    <xs:complexType name="TGrupo">
+        <xs:sequence>
+            <xs:element name="hash" type="THash"/>
+            <xs:element name="codigo">
+                <xs:simpleType>
+                    <xs:restriction base="xs:hexBinary">
+                        <xs:maxLength value="2"/>
+                    </xs:restriction>
+                </xs:simpleType>
+            </xs:element>
+        </xs:sequence>
+    </xs:complexType>
+</xs:schema>"###;
+
+const PRINCIPAL: &str = r###"<?xml version="1.0" encoding="UTF-8"?>
+<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema" xmlns="urn:teste" targetNamespace="urn:teste"
+    elementFormDefault="qualified">
+    <xs:include schemaLocation="camaleao.xsd"/>
+    <xs:element name="grupo" type="TGrupo"/>
+</xs:schema>"###;
+
+#[test]
+fn include_sem_namespace_e_comprimento_em_octetos() {
+    let esquema =
+        EsquemaXsd::carregar([("principal.xsd", PRINCIPAL), ("camaleao.xsd", CAMALEAO)], "principal.xsd").unwrap();
+    let grupo = |hash: &str, codigo: &str| {
+        esquema.validar(&format!(
+            r#"<grupo xmlns="urn:teste"><hash>{}</hash><codigo>{}</codigo></grupo>"#,
+            hash, codigo
+        ))
+    };
+
+    // SHA-1: 20 octetos, 28 caracteres em base64
+    assert_eq!(Ok(()), grupo("bnsCrYNpjKvrLAv+L0FKTLQk/dc=", "0A1B"));
+    assert_eq!(
+        vec![
+            "/grupo/hash: valor 'bnsCrYNpjKvrLAv+L0FKTLQk' deve ter 20 octetos",
+            "/grupo/codigo: valor '0A1B2C' deve ter no máximo 2 octetos",
+        ],
+        mensagens(grupo("bnsCrYNpjKvrLAv+L0FKTLQk", "0A1B2C").unwrap_err())
+    );
+}
+
+/// Classes abreviadas do XSD, dentro e fora de colchetes
+const CLASSES: &str = r###"<?xml version="1.0" encoding="UTF-8"?>
+<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema" elementFormDefault="qualified">
+    <xs:element name="classes">
+        <xs:complexType>
+            <xs:sequence>
+                <xs:element name="s" maxOccurs="unbounded">
+                    <xs:simpleType>
+                        <xs:restriction base="xs:string">
+                            <xs:whiteSpace value="preserve"/>
+                            <xs:pattern value="a\sb"/>
+                        </xs:restriction>
+                    </xs:simpleType>
+                </xs:element>
+                <xs:element name="d" maxOccurs="unbounded">
+                    <xs:simpleType>
+                        <xs:restriction base="xs:string">
+                            <xs:pattern value="[\d-]+"/>
+                        </xs:restriction>
+                    </xs:simpleType>
+                </xs:element>
+                <xs:element name="w" maxOccurs="unbounded">
+                    <xs:simpleType>
+                        <xs:restriction base="xs:string">
+                            <xs:pattern value="\w+\W."/>
+                        </xs:restriction>
+                    </xs:simpleType>
+                </xs:element>
+            </xs:sequence>
+        </xs:complexType>
+    </xs:element>
+</xs:schema>"###;
+
+#[test]
+fn classes_abreviadas_do_xsd() {
+    let esquema = EsquemaXsd::carregar([("classes.xsd", CLASSES)], "classes.xsd").unwrap();
+    let xml = "<classes>\
+        <s>a\tb</s><s>a\u{a0}b</s>\
+        <d>12-\u{663}</d><d>1\u{b2}</d>\
+        <w>R$,x</w><w>a_,x</w><w>ab!\n</w>\
+        </classes>";
+
+    // NBSP não é \s, `²` não é \d, `$` é \w, `_` é pontuação (\W) e `.` não aceita
+    // quebra de linha
+    assert_eq!(
+        vec![
+            "/classes/s[2]: valor 'a\u{a0}b' não atende ao padrão a\\sb",
+            "/classes/d[2]: valor '1\u{b2}' não atende ao padrão [\\d-]+",
+            "/classes/w[2]: valor 'a_,x' não atende ao padrão \\w+\\W.",
+            "/classes/w[3]: valor 'ab!\n' não atende ao padrão \\w+\\W.",
+        ],
+        mensagens(esquema.validar(xml).unwrap_err())
+    );
+}
+
+#[test]
+fn pacote_pl_009_embutido() {
+    let esquema = EsquemaXsd::pl_009_embutido().unwrap();
+    let xml = r#"<NFe xmlns="http://www.portalfiscal.inf.br/nfe"><infNFe versao="4.00" Id="NFe1"/></NFe>"#;
+
+    assert_eq!(
+        vec![
+            "/NFe/infNFe: atributo 'Id': valor 'NFe1' não atende ao padrão NFe[0-9]{44}",
+            "/NFe/infNFe: elemento obrigatório ausente; esperado: 'ide'",
+        ],
+        mensagens(esquema.validar_sem_assinatura(xml).unwrap_err())
+    );
+    assert_eq!(
+        "/NFe: elemento obrigatório ausente; esperado: 'Signature'",
+        mensagens(esquema.validar(xml).unwrap_err())[0]
+    );
+}
+
+#[test]
+fn erros_na_leitura_dos_esquemas() {
+    let erro = |arquivos: &[(&str, &str)]| match EsquemaXsd::carregar(arquivos.iter().copied(), "nfe.xsd") {
+        Err(Error::Esquema(mensagem)) => mensagem,
+        outro => panic!("esperado erro de esquema, obtido {:?}", outro),
+    };
+
+    assert_eq!(
+        "esquema 'xmldsig-core-schema.xsd' não encontrado",
+        erro(&[("nfe.xsd", NFE), ("leiauteNFe.xsd", LEIAUTE), ("tiposBasico.xsd", TIPOS)])
+    );
+
+    let grupo = r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"><xs:group name="G"/></xs:schema>"#;
+    assert_eq!("nfe.xsd: construção XSD não suportada: xs:group", erro(&[("nfe.xsd", grupo)]));
+
+    let sem_tipo = r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"><xs:element name="NFe" type="TNFe"/></xs:schema>"#;
+    assert_eq!("tipo 'TNFe' não declarado", erro(&[("nfe.xsd", sem_tipo)]));
+
+    match EsquemaXsd::pl_009("/diretorio/inexistente") {
+        Err(Error::Esquema(mensagem)) => assert_eq!(
+            "arquivo do pacote PL_009 não encontrado: /diretorio/inexistente/nfe_v4.00.xsd",
+            mensagem
+        ),
+        outro => panic!("esperado erro de esquema, obtido {:?}", outro),
+    }
+}
+
+#[test]
+fn carregar_de_diretorio() {
+    let diretorio = std::env::temp_dir().join(format!("nfe-esquemas-{}", std::process::id()));
+    std::fs::create_dir_all(&diretorio).unwrap();
+    for (nome, conteudo) in [
+        ("nfe.xsd", NFE),
+        ("leiauteNFe.xsd", LEIAUTE),
+        ("tiposBasico.xsd", TIPOS),
+        ("xmldsig-core-schema.xsd", XMLDSIG),
+    ] {
+        std::fs::write(diretorio.join(nome), conteudo).unwrap();
+    }
+
+    let esquema = EsquemaXsd::carregar_diretorio(&diretorio, "nfe.xsd");
+    std::fs::remove_dir_all(&diretorio).unwrap();
+
+    assert_eq!(Ok(()), nfe().validar_esquema(&esquema.unwrap()));
+}
+
+#[test]
+fn assinatura_obrigatoria_no_pl_009() {
+    let esquema = EsquemaXsd::pl_009_embutido().unwrap();
+    let xml = nfe().to_string();
+
+    assert_eq!(Ok(()), esquema.validar_sem_assinatura(&xml));
+    assert_eq!(
+        vec!["/NFe: elemento obrigatório ausente; esperado: 'Signature'"],
+        mensagens(esquema.validar(&xml).unwrap_err())
+    );
+}
+
+#[test]
+fn comprimento_de_binario_em_octetos() {
+    let esquema = EsquemaXsd::pl_009_embutido().unwrap();
+    let com_hash = |hash: &str| {
+        let mut nfe = builder()
+            .responsavel_tecnico(ResponsavelTecnico::new("11111111000191", "Fulano", "a@b.com", "4133334444"))
+            .build()
+            .unwrap();
+        let resp = nfe.responsavel_tecnico.as_mut().unwrap();
+        resp.id_csrt = Some("01".to_string());
+        resp.hash_csrt = Some(hash.to_string());
+        nfe.validar_esquema(&esquema)
+    };
+
+    // hashCSRT: SHA-1 de 20 octetos, 28 caracteres em base64
+    assert_eq!(Ok(()), com_hash("bnsCrYNpjKvrLAv+L0FKTLQk/dc="));
+    assert_eq!(
+        vec!["/NFe/infNFe/infRespTec/hashCSRT: valor 'bnsCrYNpjKvrLAv+L0FKTLQk' deve ter 20 octetos"],
+        mensagens(com_hash("bnsCrYNpjKvrLAv+L0FKTLQk").unwrap_err())
+    );
+}
@@ -0,0 +1,122 @@
+//! Testes dos grupos de exportação (exporta, detExport) e de compra
+
+use crate::base::exportacao::{Compra, Exportacao};
+use crate::base::ide::{DestinoOperacao, TipoOperacao};
+use crate::base::item::{DetalheExportacao, ExportacaoIndireta};
+use crate::builder::{ItemBuilder, NfeBuilder};
+use crate::{Error, Nfe};
+use rust_decimal_macros::dec;
+
+const CHAVE_NFE: &str = "41180678393592000146558900000006041028190697";
+
+fn builder() -> NfeBuilder {
+    super::builder()
+        .natureza_operacao("EXPORTACAO")
+        .destino_operacao(DestinoOperacao::ComExterior)
+        .dest_id_estrangeiro("AB123456")
+        .dest_razao_social("FOREIGN BUYER INC")
+}
+
+fn item() -> ItemBuilder {
+    ItemBuilder::new("001", "Cafe em graos", "09011110", "7101")
+        .unidade("KG")
+        .quantidade(dec!(1000))
+        .valor_unitario(dec!(25))
+}
+
+#[test]
+fn parse_exporta() -> Result<(), Error> {
+    let xml = "<exporta><UFSaidaPais>SP</UFSaidaPais><xLocExporta>PORTO DE SANTOS</xLocExporta>\
+        <xLocDespacho>RECINTO ALFANDEGADO</xLocDespacho></exporta>";
+    let exportacao = xml.parse::<Exportacao>()?;
+
+    assert_eq!("SP", exportacao.uf_saida);
+    assert_eq!("PORTO DE SANTOS", exportacao.local_exportacao);
+    assert_eq!(Some("RECINTO ALFANDEGADO".to_string()), exportacao.local_despacho);
+    assert_eq!(xml, exportacao.to_string());
+
+    Ok(())
+}
+
+#[test]
+fn serialize_compra() -> Result<(), Error> {
+    let compra = Compra {
+        nota_empenho: Some("2024NE000123".to_string()),
+        pedido: None,
+        contrato: Some("CT-45/2024".to_string()),
+    };
+
+    let xml = compra.to_string();
+    assert_eq!(
+        "<compra><xNEmp>2024NE000123</xNEmp><xCont>CT-45/2024</xCont></compra>",
+        xml
+    );
+    assert_eq!(compra, xml.parse()?);
+
+    Ok(())
+}
+
+#[test]
+fn builder_round_trip() -> Result<(), Error> {
+    let nfe = builder()
+        .exportacao(Exportacao::new("sp", "PORTO DE SANTOS"))
+        .compra(Compra {
+            pedido: Some("PO-778".to_string()),
+            ..Default::default()
+        })
+        .add_item(
+            item()
+                .drawback("20240012345")
+                .exportacao_indireta("240012345678", CHAVE_NFE, dec!(500)),
+        )
+        .build()
+        .unwrap();
+
+    let xml = nfe.to_string();
+    assert!(xml.contains(&format!(
+        "<detExport><nDraw>20240012345</nDraw></detExport>\
+         <detExport><exportInd><nRE>240012345678</nRE><chNFe>{}</chNFe>\
+         <qExport>500.0000</qExport></exportInd></detExport>",
+        CHAVE_NFE
+    )));
+    assert!(xml.contains(
+        "<exporta><UFSaidaPais>SP</UFSaidaPais><xLocExporta>PORTO DE SANTOS</xLocExporta></exporta>\
+         <compra><xPed>PO-778</xPed></compra>"
+    ));
+
+    let lida = xml.parse::<Nfe>()?;
+    assert_eq!(2, lida.itens[0].produto.exportacoes.len());
+    assert_eq!(nfe, lida);
+
+    Ok(())
+}
+
+#[test]
+fn exterior_exige_exportacao() {
+    assert!(builder().add_item(item()).build().is_err());
+
+    // Importação (entrada do exterior) não tem grupo de exportação
+    let importacao = builder()
+        .tipo_operacao(TipoOperacao::Entrada)
+        .add_item(ItemBuilder::new("001", "Maquina", "84713012", "3102").valor_unitario(dec!(10)))
+        .build();
+    assert!(importacao.is_ok());
+}
+
+#[test]
+fn exportacao_indireta_valida_chave() {
+    let chave = format!("{}0", &CHAVE_NFE[..43]);
+    let nfe = builder()
+        .exportacao(Exportacao::new("SP", "PORTO DE SANTOS"))
+        .add_item(item().add_exportacao(DetalheExportacao {
+            drawback: None,
+            exportacao_indireta: Some(ExportacaoIndireta {
+                registro_exportacao: "240012345678".to_string(),
+                chave_acesso: chave,
+                quantidade: dec!(1),
+            }),
+        }))
+        .build();
+
+    assert!(nfe.unwrap_err().contains("Dígito verificador inválido"));
+}
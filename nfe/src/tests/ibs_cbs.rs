@@ -0,0 +1,142 @@
+//! Testes dos grupos da Reforma Tributária (IBS, CBS e IS)
+
+use crate::base::calculo::{aliquotas_transicao, calcular_ibs_cbs, AliquotasIbsCbs, ParametrosIbsCbs};
+use crate::base::item::{Imposto, Item};
+use crate::base::totais::{TotalIbsCbs, Totalizacao};
+use crate::Error;
+use rust_decimal_macros::dec;
+
+fn referencia() -> AliquotasIbsCbs {
+    AliquotasIbsCbs {
+        cbs: dec!(8.8),
+        ibs_uf: dec!(17.7),
+        ibs_mun: dec!(1),
+    }
+}
+
+#[test]
+fn parse_imposto_com_ibs_cbs_e_is() -> Result<(), Error> {
+    let xml = "<imposto>\
+        <IS><CSTIS>000</CSTIS><cClassTribIS>000001</cClassTribIS><vBCIS>100.00</vBCIS>\
+        <pIS>10.00</pIS><vIS>10.00</vIS></IS>\
+        <IBSCBS><CST>000</CST><cClassTrib>000001</cClassTrib><gIBSCBS><vBC>1000.00</vBC>\
+        <gIBSUF><pIBSUF>0.10</pIBSUF><gDif><pDif>50.00</pDif><vDif>0.50</vDif></gDif>\
+        <vIBSUF>0.50</vIBSUF></gIBSUF>\
+        <gIBSMun><pIBSMun>0.00</pIBSMun><vIBSMun>0.00</vIBSMun></gIBSMun>\
+        <vIBS>0.50</vIBS>\
+        <gCBS><pCBS>0.90</pCBS><gRed><pRedAliq>60.00</pRedAliq><pAliqEfet>0.36</pAliqEfet></gRed>\
+        <vCBS>3.60</vCBS></gCBS>\
+        <gCBSCredPres><cCredPres>01</cCredPres><pCredPres>1.00</pCredPres><vCredPres>10.00</vCredPres></gCBSCredPres>\
+        </gIBSCBS></IBSCBS></imposto>";
+    let imposto = xml.parse::<Imposto>()?;
+
+    let seletivo = imposto.imposto_seletivo.as_ref().expect("IS ausente");
+    assert_eq!(dec!(10), seletivo.valor);
+
+    let grupo = imposto.ibs_cbs.as_ref().and_then(|i| i.valores.as_ref()).expect("gIBSCBS ausente");
+    assert_eq!("000001", imposto.ibs_cbs.as_ref().unwrap().classificacao_tributaria);
+    assert_eq!(dec!(0.50), grupo.valor_diferido_ibs());
+    assert_eq!(dec!(0.36), grupo.cbs.reducao.as_ref().unwrap().aliquota_efetiva);
+    assert_eq!(Some(dec!(10)), grupo.credito_presumido_cbs.as_ref().unwrap().valor);
+    assert_eq!(xml, imposto.to_string());
+
+    Ok(())
+}
+
+#[test]
+fn totais_ibs_cbs_ida_e_volta() -> Result<(), Error> {
+    let xml = "<det nItem=\"1\"><prod><cProd>001</cProd><cEAN>SEM GTIN</cEAN><xProd>Produto</xProd>\
+        <NCM>61091000</NCM><CFOP>5102</CFOP><uCom>UN</uCom><qCom>1.0000</qCom><vUnCom>1000.00</vUnCom>\
+        <vProd>1000.00</vProd><cEANTrib>SEM GTIN</cEANTrib><uTrib>UN</uTrib><qTrib>1.0000</qTrib>\
+        <vUnTrib>1000.00</vUnTrib><indTot>1</indTot></prod>\
+        <imposto><IBSCBS><CST>000</CST><cClassTrib>000001</cClassTrib><gIBSCBS>\
+        <vBC>1000.00</vBC><gIBSUF><pIBSUF>0.1000</pIBSUF><vIBSUF>1.00</vIBSUF></gIBSUF>\
+        <gIBSMun><pIBSMun>0.0000</pIBSMun><vIBSMun>0.00</vIBSMun></gIBSMun><vIBS>1.00</vIBS>\
+        <gCBS><pCBS>0.9000</pCBS><vCBS>9.00</vCBS></gCBS></gIBSCBS></IBSCBS></imposto></det>";
+    let item = xml.parse::<Item>()?;
+    let itens = vec![item.clone(), item];
+
+    let total = TotalIbsCbs::somar(&itens).expect("totais ausentes");
+    assert_eq!(dec!(2000), total.valor_bc);
+    assert_eq!(dec!(2), total.ibs.valor);
+    assert_eq!(dec!(18), total.cbs.valor);
+
+    let totais = Totalizacao {
+        valor_imposto_seletivo: Some(dec!(10)),
+        ibs_cbs: Some(total),
+        ..Default::default()
+    };
+    let xml = totais.to_string();
+    assert!(xml.contains("</ICMSTot><ISTot><vIS>10.00</vIS></ISTot><IBSCBSTot><vBCIBSCBS>2000.00</vBCIBSCBS>"));
+    assert!(xml.contains("<gCBS><vDif>0.00</vDif><vDevTrib>0.00</vDevTrib><vCBS>18.00</vCBS>"));
+    assert_eq!(totais, xml.parse::<Totalizacao>()?);
+
+    assert_eq!(None, TotalIbsCbs::somar(&[]));
+
+    Ok(())
+}
+
+#[test]
+fn aliquotas_de_transicao_por_ano() {
+    let referencia = referencia();
+
+    assert_eq!(None, aliquotas_transicao(2025, &referencia));
+
+    let teste = aliquotas_transicao(2026, &referencia).unwrap();
+    assert_eq!((dec!(0.9), dec!(0.1), dec!(0)), (teste.cbs, teste.ibs_uf, teste.ibs_mun));
+
+    let inicio = aliquotas_transicao(2027, &referencia).unwrap();
+    assert_eq!((dec!(8.7), dec!(0.05), dec!(0.05)), (inicio.cbs, inicio.ibs_uf, inicio.ibs_mun));
+
+    let gradual = aliquotas_transicao(2030, &referencia).unwrap();
+    assert_eq!((dec!(8.8), dec!(3.54), dec!(0.2)), (gradual.cbs, gradual.ibs_uf, gradual.ibs_mun));
+
+    assert_eq!(Some(referencia), aliquotas_transicao(2033, &referencia));
+    assert_eq!(Some(referencia), aliquotas_transicao(2040, &referencia));
+}
+
+#[test]
+fn calcula_ibs_cbs_com_reducao_e_diferimento() {
+    let parametros = ParametrosIbsCbs {
+        cst: "200".to_string(),
+        classificacao_tributaria: "200003".to_string(),
+        valor_bc: dec!(1000),
+        ano: 2033,
+        aliquotas_referencia: referencia(),
+        percentual_reducao: dec!(60),
+        percentual_diferimento: dec!(10),
+    };
+    let ibs_cbs = calcular_ibs_cbs(&parametros).unwrap();
+    let grupo = ibs_cbs.valores.unwrap();
+
+    // 17,7% reduzida em 60% = 7,08% → 70,80, com 10% diferido
+    let reducao = grupo.ibs_uf.reducao.as_ref().unwrap();
+    assert_eq!(dec!(7.08), reducao.aliquota_efetiva);
+    assert_eq!(dec!(7.08), grupo.ibs_uf.diferimento.as_ref().unwrap().valor);
+    assert_eq!(dec!(63.72), grupo.ibs_uf.valor);
+    assert_eq!(dec!(3.60), grupo.ibs_municipal.valor);
+    assert_eq!(dec!(67.32), grupo.valor_ibs);
+    // 8,8% reduzida em 60% = 3,52% → 35,20 − 3,52
+    assert_eq!(dec!(31.68), grupo.cbs.valor);
+
+    let sem_vigencia = ParametrosIbsCbs { ano: 2025, ..parametros };
+    assert!(calcular_ibs_cbs(&sem_vigencia).is_none());
+}
+
+#[test]
+fn calcula_ibs_cbs_sem_reducao_omite_grupos() {
+    let parametros = ParametrosIbsCbs {
+        cst: "000".to_string(),
+        classificacao_tributaria: "000001".to_string(),
+        valor_bc: dec!(500),
+        ano: 2026,
+        aliquotas_referencia: referencia(),
+        ..Default::default()
+    };
+    let grupo = calcular_ibs_cbs(&parametros).unwrap().valores.unwrap();
+
+    assert!(grupo.cbs.reducao.is_none());
+    assert!(grupo.cbs.diferimento.is_none());
+    assert_eq!(dec!(4.50), grupo.cbs.valor);
+    assert_eq!(dec!(0.50), grupo.valor_ibs);
+}
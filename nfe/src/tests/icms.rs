@@ -0,0 +1,148 @@
+//! Testes do cálculo do ICMS próprio por CST/CSOSN
+
+use crate::base::calculo::{calcular_icms, ParametrosIcms};
+use crate::base::decimal::{arredondar, arredondar_abnt};
+use crate::builder::{ItemBuilder, NfeBuilder};
+use rust_decimal_macros::dec;
+
+fn base(cst: &str) -> ParametrosIcms {
+    ParametrosIcms {
+        cst: cst.to_string(),
+        valor_produto: dec!(1000),
+        aliquota: dec!(18),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn arredondamento_abnt() {
+    assert_eq!(dec!(0.12), arredondar_abnt(dec!(0.125), 2));
+    assert_eq!(dec!(0.14), arredondar_abnt(dec!(0.135), 2));
+    assert_eq!(dec!(0.13), arredondar_abnt(dec!(0.1251), 2));
+    assert_eq!(dec!(0.13), arredondar(dec!(0.125), 2));
+}
+
+#[test]
+fn cst_00_com_despesas_e_desconto() {
+    let parametros = ParametrosIcms {
+        valor_frete: dec!(50),
+        valor_seguro: dec!(10),
+        valor_outros: dec!(5),
+        valor_desconto: dec!(15),
+        aliquota_fcp: dec!(2),
+        ..base("00")
+    };
+    let icms = calcular_icms(&parametros).unwrap();
+    let icms00 = icms.icms00.as_ref().unwrap();
+
+    assert_eq!(3, icms00.modalidade_bc);
+    assert_eq!(dec!(1050), icms00.valor_bc);
+    assert_eq!(dec!(189), icms00.valor);
+    assert_eq!(Some(dec!(21)), icms00.valor_fcp);
+    assert_eq!((dec!(1050), dec!(189), dec!(21)), (icms.valor_bc(), icms.valor_icms(), icms.valor_fcp()));
+
+    // Centavo exatamente na metade: 2,50 × 5% = 0,125
+    let metade = ParametrosIcms {
+        valor_produto: dec!(2.50),
+        aliquota: dec!(5),
+        ..base("00")
+    };
+    assert_eq!(dec!(0.12), calcular_icms(&metade).unwrap().valor_icms());
+}
+
+#[test]
+fn cst_20_com_reducao_e_desoneracao() {
+    let parametros = ParametrosIcms {
+        aliquota: dec!(12),
+        percentual_reducao_bc: dec!(33.33),
+        motivo_desoneracao: Some(9),
+        ..base("20")
+    };
+    let icms20 = calcular_icms(&parametros).unwrap().icms20.unwrap();
+
+    assert_eq!(dec!(666.70), icms20.valor_bc);
+    assert_eq!(dec!(80.00), icms20.valor);
+    assert_eq!(Some(dec!(40.00)), icms20.valor_icms_desonerado);
+    assert_eq!(Some(9), icms20.motivo_desoneracao);
+}
+
+#[test]
+fn cst_51_com_diferimento() {
+    let parametros = ParametrosIcms {
+        percentual_diferimento: dec!(33.33),
+        ..base("51")
+    };
+    let icms51 = calcular_icms(&parametros).unwrap().icms51.unwrap();
+
+    assert_eq!(Some(dec!(180)), icms51.valor_icms_operacao);
+    assert_eq!(Some(dec!(59.99)), icms51.valor_icms_diferido);
+    assert_eq!(Some(dec!(120.01)), icms51.valor);
+}
+
+#[test]
+fn cst_41_e_50_no_grupo_icms40() {
+    for cst in ["40", "41", "50"] {
+        let icms = calcular_icms(&base(cst)).unwrap();
+        assert_eq!(cst, icms.icms40.unwrap().cst);
+        assert_eq!((None, None), (icms.icms41, icms.icms50));
+    }
+}
+
+#[test]
+fn csosn_do_simples_nacional() {
+    let credito = ParametrosIcms {
+        aliquota_credito_sn: dec!(2.56),
+        ..base("101")
+    };
+    let sn101 = calcular_icms(&credito).unwrap().icms_sn101.unwrap();
+    assert_eq!(dec!(25.60), sn101.valor_credito_icms_sn);
+
+    let sn102 = calcular_icms(&base("400")).unwrap().icms_sn102.unwrap();
+    assert_eq!("400", sn102.csosn);
+
+    let sn900 = calcular_icms(&ParametrosIcms { aliquota: dec!(0), ..base("900") }).unwrap();
+    let sn900 = sn900.icms_sn900.unwrap();
+    assert_eq!((None, None), (sn900.valor_bc, sn900.valor_credito_icms_sn));
+
+    assert!(calcular_icms(&base("201")).unwrap_err().contains("ICMS-ST"));
+    assert!(calcular_icms(&base("99")).unwrap_err().contains("inválido"));
+}
+
+#[test]
+fn aliquotas_padrao_da_uf() {
+    let interna = ParametrosIcms { cst: "00".to_string(), ..Default::default() }.com_aliquotas_uf("RJ");
+    assert_eq!((dec!(22), dec!(2)), (interna.aliquota, interna.aliquota_fcp));
+
+    let informada = base("00").com_aliquotas_uf("RJ");
+    assert_eq!(dec!(18), informada.aliquota);
+
+    let interestadual = ParametrosIcms::default().com_aliquota_interestadual("SP", "BA");
+    assert_eq!(dec!(7), interestadual.aliquota);
+
+    let importado = ParametrosIcms { origem: 1, ..Default::default() }.com_aliquota_interestadual("SP", "BA");
+    assert_eq!(dec!(4), importado.aliquota);
+}
+
+#[test]
+fn builder_soma_icms_nos_totais() {
+    let icms = calcular_icms(&base("00")).unwrap();
+    let nfe = NfeBuilder::new()
+        .codigo_uf(35)
+        .numero(40)
+        .natureza_operacao("VENDA")
+        .codigo_municipio(3550308)
+        .emit_cnpj("12345678000190")
+        .emit_razao_social("EMPRESA LTDA")
+        .emit_ie("123456789")
+        .add_item(
+            ItemBuilder::new("001", "Produto", "61091000", "5102")
+                .valor_unitario(dec!(1000))
+                .icms(icms.clone()),
+        )
+        .build()
+        .unwrap();
+
+    assert_eq!(Some(icms), nfe.itens[0].imposto.icms);
+    assert_eq!(dec!(1000), nfe.totais.valor_base_calculo);
+    assert_eq!(dec!(180), nfe.totais.valor_icms);
+}
@@ -0,0 +1,186 @@
+//! Testes do cálculo do ICMS-ST (MVA, MVA ajustada e pauta)
+
+use crate::base::calculo::{calcular_icms, mva_ajustada, ParametrosIcms, ParametrosIcmsSt};
+use crate::builder::{ItemBuilder, NfeBuilder};
+use rust_decimal_macros::dec;
+
+fn base(cst: &str, st: ParametrosIcmsSt) -> ParametrosIcms {
+    ParametrosIcms {
+        cst: cst.to_string(),
+        valor_produto: dec!(1000),
+        aliquota: dec!(18),
+        st: Some(st),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn mva_ajustada_pela_aliquota_interestadual() {
+    assert_eq!(dec!(50.2439), mva_ajustada(dec!(40), dec!(12), dec!(18)));
+    assert_eq!(dec!(40), mva_ajustada(dec!(40), dec!(18), dec!(18)));
+}
+
+#[test]
+fn cst_10_com_mva_ipi_e_fcp_st() {
+    let st = ParametrosIcmsSt {
+        percentual_mva: dec!(40),
+        aliquota_interna: dec!(18),
+        aliquota_fcp_st: dec!(2),
+        valor_ipi: dec!(100),
+        ..Default::default()
+    };
+    let icms10 = calcular_icms(&base("10", st)).unwrap().icms10.unwrap();
+
+    assert_eq!(dec!(180), icms10.valor);
+    assert_eq!(4, icms10.modalidade_bc_st);
+    assert_eq!(Some(dec!(40)), icms10.percentual_mva_st);
+    assert_eq!(dec!(1540), icms10.valor_bc_st);
+    assert_eq!(dec!(97.20), icms10.valor_st);
+    assert_eq!(Some(dec!(30.80)), icms10.valor_fcp_st);
+}
+
+#[test]
+fn cst_10_interestadual_com_mva_ajustada() {
+    let st = ParametrosIcmsSt {
+        percentual_mva: dec!(40),
+        aliquota_interestadual: Some(dec!(12)),
+        ..Default::default()
+    }
+    .com_uf_destino("RJ");
+    let parametros = ParametrosIcms {
+        aliquota: dec!(12),
+        ..base("10", st)
+    };
+    let icms10 = calcular_icms(&parametros).unwrap().icms10.unwrap();
+
+    assert_eq!(dec!(22), icms10.aliquota_st);
+    assert_eq!(Some(dec!(57.9487)), icms10.percentual_mva_st);
+    assert_eq!(dec!(1579.49), icms10.valor_bc_st);
+    assert_eq!(dec!(227.49), icms10.valor_st);
+    assert_eq!(Some(dec!(31.59)), icms10.valor_fcp_st);
+}
+
+#[test]
+fn csosn_201_ajusta_a_mva_pela_aliquota_interestadual_informada() {
+    let st = ParametrosIcmsSt {
+        percentual_mva: dec!(40),
+        aliquota_interestadual: Some(dec!(12)),
+        aliquota_interna: dec!(22),
+        ..Default::default()
+    };
+    // No Simples Nacional a alíquota do item não é a interestadual
+    let parametros = ParametrosIcms {
+        aliquota: dec!(0),
+        aliquota_credito_sn: dec!(2.5),
+        ..base("201", st)
+    };
+    let sn201 = calcular_icms(&parametros).unwrap().icms_sn201.unwrap();
+
+    assert_eq!(Some(dec!(57.9487)), sn201.percentual_mva_st);
+    assert_eq!(dec!(1579.49), sn201.valor_bc_st);
+}
+
+#[test]
+fn fcp_st_nao_deduz_o_fcp_proprio() {
+    let st = ParametrosIcmsSt {
+        percentual_mva: dec!(40),
+        aliquota_interna: dec!(18),
+        aliquota_fcp_st: dec!(2),
+        ..Default::default()
+    };
+    let parametros = ParametrosIcms {
+        aliquota_fcp: dec!(2),
+        ..base("10", st)
+    };
+    let icms10 = calcular_icms(&parametros).unwrap().icms10.unwrap();
+
+    assert_eq!((Some(dec!(1000)), Some(dec!(20))), (icms10.valor_bc_fcp, icms10.valor_fcp));
+    assert_eq!(dec!(1400), icms10.valor_bc_st);
+    assert_eq!((Some(dec!(1400)), Some(dec!(28))), (icms10.valor_bc_fcp_st, icms10.valor_fcp_st));
+
+    let xml = quick_xml::se::to_string(&icms10).unwrap();
+    assert!(xml.contains("<vICMS>180.00</vICMS><vBCFCP>1000.00</vBCFCP><pFCP>2.00</pFCP><vFCP>20.00</vFCP><modBCST>"));
+    assert!(xml.contains("<vBCFCPST>1400.00</vBCFCPST><pFCPST>2.00</pFCPST><vFCPST>28.00</vFCPST>"));
+}
+
+#[test]
+fn cst_30_com_reducao_da_bc_st() {
+    let st = ParametrosIcmsSt {
+        percentual_mva: dec!(30),
+        percentual_reducao_bc_st: dec!(10),
+        aliquota_interna: dec!(18),
+        ..Default::default()
+    };
+    let parametros = ParametrosIcms {
+        aliquota: dec!(0),
+        ..base("30", st)
+    };
+    let icms30 = calcular_icms(&parametros).unwrap().icms30.unwrap();
+
+    assert_eq!(Some(dec!(10)), icms30.percentual_reducao_bc_st);
+    assert_eq!(dec!(1170), icms30.valor_bc_st);
+    assert_eq!(dec!(210.60), icms30.valor_st);
+    assert_eq!(None, icms30.valor_fcp_st);
+}
+
+#[test]
+fn csosn_202_com_base_de_pauta() {
+    let st = ParametrosIcmsSt {
+        percentual_mva: dec!(40),
+        aliquota_interna: dec!(18),
+        base_pauta: Some(dec!(1200)),
+        ..Default::default()
+    };
+    let parametros = ParametrosIcms {
+        aliquota: dec!(12),
+        ..base("202", st)
+    };
+    let sn202 = calcular_icms(&parametros).unwrap().icms_sn202.unwrap();
+
+    assert_eq!(5, sn202.modalidade_bc_st);
+    assert_eq!(None, sn202.percentual_mva_st);
+    assert_eq!(dec!(1200), sn202.valor_bc_st);
+    assert_eq!(dec!(96), sn202.valor_st);
+}
+
+#[test]
+fn st_exige_parametros() {
+    let parametros = ParametrosIcms {
+        st: None,
+        ..base("70", ParametrosIcmsSt::default())
+    };
+    assert!(calcular_icms(&parametros).unwrap_err().contains("ICMS-ST"));
+
+    // CST 90 sem ST continua válido
+    let icms90 = calcular_icms(&ParametrosIcms { cst: "90".to_string(), ..parametros }).unwrap();
+    assert_eq!(None, icms90.icms90.unwrap().valor_bc_st);
+}
+
+#[test]
+fn builder_soma_st_nos_totais() {
+    let st = ParametrosIcmsSt {
+        percentual_mva: dec!(40),
+        aliquota_interna: dec!(18),
+        ..Default::default()
+    };
+    let icms = calcular_icms(&base("10", st)).unwrap();
+    let nfe = NfeBuilder::new()
+        .codigo_uf(35)
+        .numero(41)
+        .natureza_operacao("VENDA")
+        .codigo_municipio(3550308)
+        .emit_cnpj("12345678000190")
+        .emit_razao_social("EMPRESA LTDA")
+        .emit_ie("123456789")
+        .add_item(
+            ItemBuilder::new("001", "Produto", "61091000", "5401")
+                .valor_unitario(dec!(1000))
+                .icms(icms),
+        )
+        .build()
+        .unwrap();
+
+    assert_eq!(dec!(1400), nfe.totais.valor_base_calculo_st);
+    assert_eq!(dec!(72), nfe.totais.valor_icms_st);
+    assert_eq!(dec!(1072), nfe.totais.valor_total);
+}
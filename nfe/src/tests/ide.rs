@@ -7,12 +7,13 @@
 //! Para testar o parsing de identificação, é necessário testar
 //! a NF-e completa através do módulo infnfe.
 //!
-//! Os testes aqui focam em validar os enums e tipos auxiliares.
+//! Os testes aqui focam em validar os enums e tipos auxiliares e a ordem
+//! dos elementos na serialização.
 
 use crate::base::ide::{
     DestinoOperacao, FinalidadeEmissao, FormatoImpressaoDanfe,
     ModeloDocumentoFiscal, TipoAmbiente, TipoConsumidor,
-    TipoEmissao, TipoOperacao, TipoPresencaComprador, TipoIntermediador,
+    TipoEmissao, TipoOperacao, TipoPresencaComprador, TipoIntermediador, Identificacao,
 };
 
 /// Testa que os valores dos enums estão corretos conforme SEFAZ
@@ -102,3 +103,19 @@ fn valores_tipo_intermediador() {
     assert_eq!(0, TipoIntermediador::SemIntermediador as u8);
     assert_eq!(1, TipoIntermediador::EmSiteDeTerceiros as u8);
 }
+
+/// Testa que o `<ide>` é serializado na ordem do leiaute, com o indIntermed
+#[test]
+fn serializa_ide_na_ordem_do_leiaute() {
+    let xml = concat!(
+        "<ide><cUF>35</cUF><cNF>00000002</cNF><natOp>VENDA</natOp><mod>55</mod><serie>1</serie><nNF>1</nNF>",
+        "<dhEmi>2024-01-15T10:30:00-03:00</dhEmi><dhSaiEnt>2024-01-15T11:00:00-03:00</dhSaiEnt><tpNF>1</tpNF>",
+        "<idDest>1</idDest><cMunFG>3550308</cMunFG><tpImp>1</tpImp><tpEmis>1</tpEmis><cDV>5</cDV><tpAmb>2</tpAmb>",
+        "<finNFe>1</finNFe><indFinal>1</indFinal><indPres>2</indPres><indIntermed>1</indIntermed>",
+        "<procEmi>0</procEmi><verProc>1.0</verProc></ide>",
+    );
+
+    let ide = xml.parse::<Identificacao>().unwrap();
+    assert_eq!(Some(TipoIntermediador::EmSiteDeTerceiros), ide.operacao.intermediador);
+    assert_eq!(xml, ide.to_string());
+}
@@ -0,0 +1,165 @@
+//! Testes da declaração de importação (DI/adi) e do cálculo dos tributos
+
+use crate::base::calculo::{calcular_importacao, ParametrosImportacao};
+use crate::base::documento::Documento;
+use crate::base::item::{Adicao, DeclaracaoImportacao, FormaImportacao, Produto, ViaTransporte};
+use crate::builder::{ItemBuilder, NfeBuilder};
+use crate::{Error, Nfe};
+use chrono::NaiveDate;
+use rust_decimal_macros::dec;
+
+fn declaracao() -> DeclaracaoImportacao {
+    DeclaracaoImportacao {
+        numero: "2412345678".to_string(),
+        data_registro: NaiveDate::from_ymd_opt(2024, 3, 1).unwrap(),
+        local_desembaraco: "PORTO DE SANTOS".to_string(),
+        uf_desembaraco: "SP".to_string(),
+        data_desembaraco: NaiveDate::from_ymd_opt(2024, 3, 5).unwrap(),
+        via_transporte: ViaTransporte::Maritima,
+        valor_afrmm: Some(dec!(250)),
+        intermediacao: FormaImportacao::ContaEOrdem,
+        adquirente: Some(Documento::Cnpj("98765432000198".to_string())),
+        uf_terceiro: Some("MG".to_string()),
+        codigo_exportador: "EXP001".to_string(),
+        adicoes: vec![Adicao {
+            numero: Some(1),
+            sequencial: 1,
+            codigo_fabricante: "FAB-CN-01".to_string(),
+            valor_desconto: None,
+            drawback: None,
+        }],
+    }
+}
+
+#[test]
+fn parse_produto_com_di() -> Result<(), Error> {
+    let xml = "<prod><cProd>001</cProd><cEAN>SEM GTIN</cEAN><xProd>Motor eletrico</xProd>\
+        <NCM>85015210</NCM><CFOP>3102</CFOP><uCom>UN</uCom><qCom>1.0000</qCom>\
+        <vUnCom>10000.00</vUnCom><vProd>10000.00</vProd><cEANTrib>SEM GTIN</cEANTrib><uTrib>UN</uTrib>\
+        <qTrib>1.0000</qTrib><vUnTrib>10000.00</vUnTrib><indTot>1</indTot>\
+        <DI><nDI>2412345678</nDI><dDI>2024-03-01</dDI><xLocDesemb>PORTO DE SANTOS</xLocDesemb>\
+        <UFDesemb>SP</UFDesemb><dDesemb>2024-03-05</dDesemb><tpViaTransp>1</tpViaTransp>\
+        <vAFRMM>250.00</vAFRMM><tpIntermedio>2</tpIntermedio><CNPJ>98765432000198</CNPJ>\
+        <UFTerceiro>MG</UFTerceiro><cExportador>EXP001</cExportador>\
+        <adi><nAdicao>1</nAdicao><nSeqAdic>1</nSeqAdic><cFabricante>FAB-CN-01</cFabricante></adi></DI>\
+        </prod>";
+    let produto = xml.parse::<Produto>()?;
+
+    assert_eq!(vec![declaracao()], produto.declaracoes_importacao);
+    assert_eq!("3102", produto.tributacao.cfop);
+    assert_eq!(xml, produto.to_string());
+
+    Ok(())
+}
+
+#[test]
+fn builder_com_di() -> Result<(), Error> {
+    let nfe = NfeBuilder::new()
+        .codigo_uf(35)
+        .numero(30)
+        .natureza_operacao("IMPORTACAO")
+        .codigo_municipio(3550308)
+        .emit_cnpj("12345678000190")
+        .emit_razao_social("IMPORTADORA LTDA")
+        .emit_ie("123456789")
+        .add_item(
+            ItemBuilder::new("001", "Motor eletrico", "85015210", "3102")
+                .valor_unitario(dec!(10000))
+                .add_declaracao_importacao(declaracao()),
+        )
+        .build()
+        .unwrap();
+
+    let lida = nfe.to_string().parse::<Nfe>()?;
+    assert_eq!(vec![declaracao()], lida.itens[0].produto.declaracoes_importacao);
+
+    Ok(())
+}
+
+#[test]
+fn builder_rejeita_di_sem_adicoes() {
+    let mut di = declaracao();
+    di.adicoes.clear();
+
+    let resultado = NfeBuilder::new()
+        .codigo_uf(35)
+        .numero(31)
+        .natureza_operacao("IMPORTACAO")
+        .codigo_municipio(3550308)
+        .emit_cnpj("12345678000190")
+        .emit_razao_social("IMPORTADORA LTDA")
+        .emit_ie("123456789")
+        .add_item(
+            ItemBuilder::new("001", "Motor eletrico", "85015210", "3102")
+                .valor_unitario(dec!(10000))
+                .add_declaracao_importacao(di),
+        )
+        .build();
+
+    assert_eq!(
+        Err("Declaração de importação 2412345678 sem adições".to_string()),
+        resultado.map(|_| ())
+    );
+}
+
+#[test]
+fn calculo_tributos_importacao() {
+    let calculo = calcular_importacao(&ParametrosImportacao {
+        valor_aduaneiro: dec!(10000),
+        valor_afrmm: dec!(250),
+        taxa_siscomex: dec!(154.23),
+        aliquota_ii: dec!(14),
+        aliquota_ipi: dec!(10),
+        aliquota_pis: dec!(2.10),
+        aliquota_cofins: dec!(9.65),
+        aliquota_icms: dec!(18),
+        ..Default::default()
+    })
+    .unwrap();
+
+    assert_eq!(dec!(1400.00), calculo.valor_ii);
+    assert_eq!(dec!(11400.00), calculo.base_ipi);
+    assert_eq!(dec!(1140.00), calculo.valor_ipi);
+    assert_eq!(dec!(10000.00), calculo.base_pis_cofins);
+    assert_eq!(dec!(210.00), calculo.valor_pis);
+    assert_eq!(dec!(965.00), calculo.valor_cofins);
+    // (10000 + 1400 + 1140 + 210 + 965 + 250 + 154,23) / 0,82
+    assert_eq!(dec!(17218.57), calculo.base_icms);
+    assert_eq!(dec!(3099.34), calculo.valor_icms);
+    assert_eq!(dec!(0), calculo.valor_fcp);
+
+    let ii = calculo.imposto_importacao();
+    assert_eq!(dec!(10000.00), ii.valor_bc);
+    assert_eq!(dec!(404.23), ii.valor_despesas_aduaneiras);
+    assert_eq!(dec!(1400.00), ii.valor);
+}
+
+#[test]
+fn calculo_icms_importacao_com_fcp() {
+    let calculo = calcular_importacao(&ParametrosImportacao {
+        valor_aduaneiro: dec!(1000),
+        aliquota_icms: dec!(18),
+        aliquota_fcp: dec!(2),
+        ..Default::default()
+    })
+    .unwrap();
+
+    assert_eq!(dec!(1250.00), calculo.base_icms);
+    assert_eq!(dec!(225.00), calculo.valor_icms);
+    assert_eq!(dec!(25.00), calculo.valor_fcp);
+}
+
+#[test]
+fn calculo_importacao_rejeita_aliquota_de_cem_por_cento() {
+    let resultado = calcular_importacao(&ParametrosImportacao {
+        valor_aduaneiro: dec!(1000),
+        aliquota_icms: dec!(98),
+        aliquota_fcp: dec!(2),
+        ..Default::default()
+    });
+
+    assert_eq!(
+        Err("Alíquota de ICMS + FCP da importação deve ser menor que 100% (informada: 100%)".to_string()),
+        resultado.map(|_| ())
+    );
+}
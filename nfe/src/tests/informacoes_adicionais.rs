@@ -0,0 +1,101 @@
+//! Testes das informações adicionais (infAdic) e dos textos legais do builder
+
+use crate::base::emit::RegimeTributario;
+use crate::base::informacoes_adicionais::{
+    InformacoesAdicionais, Observacao, OrigemProcesso, ProcessoReferenciado,
+};
+use crate::builder::NfeBuilder;
+use crate::{Error, Nfe};
+use rust_decimal_macros::dec;
+
+fn builder() -> NfeBuilder {
+    super::builder().add_item(super::produto(dec!(250)).quantidade(dec!(4)))
+}
+
+#[test]
+fn parse_inf_adic_completo() -> Result<(), Error> {
+    let xml = "<infAdic><infAdFisco>Regime especial 123</infAdFisco><infCpl>Pedido 456</infCpl>\
+        <obsCont xCampo=\"Vendedor\"><xTexto>Maria</xTexto></obsCont>\
+        <obsCont xCampo=\"Loja\"><xTexto>Centro</xTexto></obsCont>\
+        <obsFisco xCampo=\"TARE\"><xTexto>789</xTexto></obsFisco>\
+        <procRef><nProc>5001234-56.2024.4.03.6100</nProc><indProc>1</indProc></procRef>\
+        <procRef><nProc>TA-2024/01</nProc><indProc>0</indProc><tpAto>08</tpAto></procRef></infAdic>";
+    let info = xml.parse::<InformacoesAdicionais>()?;
+
+    assert_eq!(Some("Regime especial 123".to_string()), info.fisco);
+    assert_eq!(Some("Pedido 456".to_string()), info.complementar);
+    assert_eq!(2, info.observacoes_contribuinte.len());
+    assert_eq!("Vendedor", info.observacoes_contribuinte[0].campo);
+    assert_eq!("789", info.observacoes_fisco[0].texto);
+    assert_eq!(OrigemProcesso::JusticaFederal, info.processos[0].origem);
+    assert_eq!(Some("08".to_string()), info.processos[1].tipo_ato);
+    assert_eq!(xml, info.to_string());
+
+    Ok(())
+}
+
+#[test]
+fn builder_inf_adic_round_trip() -> Result<(), Error> {
+    let nfe = builder()
+        .informacao_complementar("Pedido 456")
+        .informacao_fisco("Regime especial 123")
+        .add_observacao_contribuinte("Vendedor", "Maria")
+        .add_observacao_fisco("TARE", "789")
+        .add_processo(ProcessoReferenciado {
+            numero: "5001234-56.2024.4.03.6100".to_string(),
+            origem: OrigemProcesso::JusticaFederal,
+            tipo_ato: None,
+        })
+        .build()
+        .unwrap();
+
+    let lida = nfe.to_string().parse::<Nfe>()?;
+    let info = lida.informacoes_adicionais.unwrap();
+
+    assert_eq!(
+        vec![Observacao { campo: "Vendedor".to_string(), texto: "Maria".to_string() }],
+        info.observacoes_contribuinte
+    );
+    assert_eq!(nfe.informacoes_adicionais, Some(info));
+
+    Ok(())
+}
+
+#[test]
+fn builder_mensagem_credito_simples_nacional() {
+    let nfe = builder()
+        .emit_crt(RegimeTributario::SimplesNacional)
+        .informacao_complementar("Pedido 456")
+        .credito_simples_nacional(dec!(1.25))
+        .add_texto_legal("DOCUMENTO EMITIDO POR ME OU EPP OPTANTE PELO SIMPLES NACIONAL")
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        Some(
+            "Pedido 456; PERMITE O APROVEITAMENTO DO CRÉDITO DE ICMS NO VALOR DE R$ 12,50; \
+             CORRESPONDENTE À ALÍQUOTA DE 1,25%, NOS TERMOS DO ART. 23 DA LEI COMPLEMENTAR \
+             Nº 123, DE 2006; DOCUMENTO EMITIDO POR ME OU EPP OPTANTE PELO SIMPLES NACIONAL"
+                .to_string()
+        ),
+        nfe.informacoes_adicionais.unwrap().complementar
+    );
+}
+
+#[test]
+fn builder_credito_simples_exige_crt_1() {
+    let resultado = builder().credito_simples_nacional(dec!(1.25)).build();
+
+    assert_eq!(
+        Err("Crédito de ICMS do Simples Nacional exige emitente optante (CRT 1)".to_string()),
+        resultado.map(|_| ())
+    );
+}
+
+#[test]
+fn builder_sem_inf_adic() {
+    let nfe = builder().build().unwrap();
+
+    assert_eq!(None, nfe.informacoes_adicionais);
+    assert!(!nfe.to_string().contains("<infAdic>"));
+}
@@ -0,0 +1,82 @@
+//! Testes do intermediador da transação (infIntermed) e do indIntermed
+
+use crate::base::ide::{TipoIntermediador, TipoPresencaComprador};
+use crate::base::intermediador::{validar_intermediador, Intermediador};
+use crate::builder::NfeBuilder;
+use crate::{Error, Nfe};
+use rust_decimal_macros::dec;
+
+fn builder() -> NfeBuilder {
+    super::builder().add_item(super::produto(dec!(99.90)))
+}
+
+#[test]
+fn parse_inf_intermed() -> Result<(), Error> {
+    let xml = "<infIntermed><CNPJ>03007331000141</CNPJ><idCadIntTran>LOJA_EXEMPLO</idCadIntTran></infIntermed>";
+    let intermediador = xml.parse::<Intermediador>()?;
+
+    assert_eq!(Intermediador::new("03.007.331/0001-41", "LOJA_EXEMPLO"), intermediador);
+    assert_eq!(xml, intermediador.to_string());
+
+    Ok(())
+}
+
+#[test]
+fn builder_venda_em_marketplace() -> Result<(), Error> {
+    let nfe = builder()
+        .presenca(TipoPresencaComprador::ViaInternel)
+        .intermediador(Intermediador::new("03007331000141", "LOJA_EXEMPLO"))
+        .build()
+        .unwrap();
+
+    let xml = nfe.to_string();
+    assert!(xml.contains("<indPres>2</indPres><indIntermed>1</indIntermed>"));
+    assert!(xml.contains("</pag><infIntermed><CNPJ>03007331000141</CNPJ>"));
+
+    let lida = xml.parse::<Nfe>()?;
+    assert_eq!(Some(TipoIntermediador::EmSiteDeTerceiros), lida.ide.operacao.intermediador);
+    assert_eq!(nfe.intermediador, lida.intermediador);
+
+    Ok(())
+}
+
+#[test]
+fn builder_venda_internet_sem_intermediador() {
+    let nfe = builder().presenca(TipoPresencaComprador::ViaInternel).build().unwrap();
+
+    assert_eq!(Some(TipoIntermediador::SemIntermediador), nfe.ide.operacao.intermediador);
+    assert_eq!(None, nfe.intermediador);
+
+    let presencial = builder().build().unwrap();
+    assert_eq!(None, presencial.ide.operacao.intermediador);
+}
+
+#[test]
+fn builder_indicador_exige_grupo() {
+    let resultado = builder()
+        .presenca(TipoPresencaComprador::ViaInternel)
+        .indicador_intermediador(TipoIntermediador::EmSiteDeTerceiros)
+        .build();
+
+    assert_eq!(
+        Err("Operação com intermediador (indIntermed = 1) exige o grupo infIntermed".to_string()),
+        resultado.map(|_| ())
+    );
+}
+
+#[test]
+fn validacao_intermediador() {
+    let intermediador = Intermediador::new("03007331000141", "LOJA_EXEMPLO");
+
+    assert!(validar_intermediador(None, None).is_ok());
+    assert!(validar_intermediador(Some(TipoIntermediador::EmSiteDeTerceiros), Some(&intermediador)).is_ok());
+    assert!(validar_intermediador(Some(TipoIntermediador::SemIntermediador), Some(&intermediador)).is_err());
+    assert!(validar_intermediador(None, Some(&intermediador)).is_err());
+    assert_eq!(
+        Err("CNPJ do intermediador deve ter 14 dígitos: 123".to_string()),
+        validar_intermediador(
+            Some(TipoIntermediador::EmSiteDeTerceiros),
+            Some(&Intermediador::new("123", "LOJA"))
+        )
+    );
+}
@@ -2,6 +2,7 @@
 
 use crate::base::item::{Item, Produto};
 use crate::Error;
+use rust_decimal_macros::dec;
 
 #[test]
 fn parse_item() -> Result<(), Error> {
@@ -37,9 +38,9 @@ fn parse_item() -> Result<(), Error> {
     assert_eq!("UM PRODUTO TESTE QUALQUER", item.produto.descricao);
     assert_eq!("64011000", item.produto.ncm);
     assert_eq!("UN", item.produto.unidade);
-    assert_eq!(10.0, item.produto.quantidade);
-    assert_eq!(50.0, item.produto.valor_unitario);
-    assert_eq!(500.0, item.produto.valor_bruto);
+    assert_eq!(dec!(10.0), item.produto.quantidade);
+    assert_eq!(dec!(50.0), item.produto.valor_unitario);
+    assert_eq!(dec!(500.0), item.produto.valor_bruto);
     assert!(item.produto.valor_compoe_total_nota);
 
     Ok(())
@@ -73,9 +74,9 @@ fn parse_produto() -> Result<(), Error> {
     assert_eq!("Produto de Teste", produto.descricao);
     assert_eq!("12345678", produto.ncm);
     assert_eq!("PC", produto.unidade);
-    assert_eq!(5.0, produto.quantidade);
-    assert_eq!(25.50, produto.valor_unitario);
-    assert_eq!(127.50, produto.valor_bruto);
+    assert_eq!(dec!(5.0), produto.quantidade);
+    assert_eq!(dec!(25.50), produto.valor_unitario);
+    assert_eq!(dec!(127.50), produto.valor_bruto);
 
     Ok(())
 }
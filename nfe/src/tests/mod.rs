@@ -3,9 +3,67 @@
 //! Este módulo contém testes para validar o parsing e serialização
 //! das estruturas de NF-e.
 
+use crate::base::endereco::Endereco;
+use crate::builder::{ItemBuilder, NfeBuilder};
+use crate::base::decimal::Decimal;
+
+mod builder;
+mod cobranca;
+mod decimal;
 mod dest;
+mod difal;
+mod emit;
 mod endereco;
+mod esquema;
+mod especifico;
+mod exportacao;
+mod ibs_cbs;
+mod icms;
+mod icms_st;
 mod ide;
+mod importacao;
+mod informacoes_adicionais;
+mod intermediador;
 mod infnfe;
 mod itens;
+mod pagamento;
 mod parse;
+mod pis_cofins;
+mod protocolo;
+mod referencia;
+mod responsavel_tecnico;
+mod sem_perdas;
+mod totais;
+mod transporte;
+mod tributacao_item;
+mod validacao;
+mod xml;
+
+/// Nota de venda de um emitente paulista, ainda sem itens
+///
+/// Cada módulo acrescenta apenas os grupos de que trata.
+fn builder() -> NfeBuilder {
+    NfeBuilder::new()
+        .codigo_uf(35)
+        .numero(1)
+        .natureza_operacao("VENDA")
+        .codigo_municipio(3550308)
+        .emit_cnpj("12345678000190")
+        .emit_razao_social("EMPRESA LTDA")
+        .emit_ie("123456789")
+        .emit_endereco(Endereco {
+            logradouro: "AVENIDA PAULISTA".to_string(),
+            numero: "1000".to_string(),
+            bairro: "BELA VISTA".to_string(),
+            codigo_municipio: 3550308,
+            nome_municipio: "SAO PAULO".to_string(),
+            sigla_uf: "SP".to_string(),
+            cep: "01310100".to_string(),
+            ..Default::default()
+        })
+}
+
+/// Item de venda (CFOP 5102) de uma unidade pelo valor informado
+fn produto(valor: Decimal) -> ItemBuilder {
+    ItemBuilder::new("001", "Produto", "12345678", "5102").valor_unitario(valor)
+}
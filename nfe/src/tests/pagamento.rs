@@ -0,0 +1,133 @@
+//! Testes do grupo de pagamento (pag/detPag)
+
+use crate::base::nfce::FormaPagamentoNfce;
+use crate::base::pagamento::{
+    BandeiraCartao, Cartao, DetalhePagamento, IndicadorPagamento, Pagamento,
+    TipoIntegracaoPagamento,
+};
+use crate::builder::NfeBuilder;
+use crate::{Error, Nfe};
+use rust_decimal_macros::dec;
+
+fn builder() -> NfeBuilder {
+    super::builder().add_item(super::produto(dec!(45.50)).quantidade(dec!(2)))
+}
+
+#[test]
+fn parse_pagamento_com_cartao() -> Result<(), Error> {
+    let xml = r#"
+        <pag>
+            <detPag>
+                <indPag>0</indPag>
+                <tPag>03</tPag>
+                <vPag>91.00</vPag>
+                <card>
+                    <tpIntegra>1</tpIntegra>
+                    <CNPJ>01027058000191</CNPJ>
+                    <tBand>02</tBand>
+                    <cAut>123456</cAut>
+                </card>
+            </detPag>
+            <detPag>
+                <tPag>01</tPag>
+                <vPag>10.00</vPag>
+            </detPag>
+            <vTroco>10.00</vTroco>
+        </pag>
+    "#;
+
+    let pag = xml.parse::<Pagamento>()?;
+
+    assert_eq!(2, pag.detalhes.len());
+    assert_eq!(Some(IndicadorPagamento::AVista), pag.detalhes[0].indicador);
+    assert_eq!(FormaPagamentoNfce::CartaoCredito, pag.detalhes[0].forma);
+    assert_eq!(dec!(91.00), pag.detalhes[0].valor);
+
+    let cartao = pag.detalhes[0].cartao.as_ref().unwrap();
+    assert_eq!(TipoIntegracaoPagamento::Integrado, cartao.tipo_integracao);
+    assert_eq!(Some("01027058000191".to_string()), cartao.cnpj_credenciadora);
+    assert_eq!(Some(BandeiraCartao::Mastercard), cartao.bandeira);
+    assert_eq!(Some("123456".to_string()), cartao.autorizacao);
+
+    assert_eq!(FormaPagamentoNfce::Dinheiro, pag.detalhes[1].forma);
+    assert_eq!(None, pag.detalhes[1].cartao);
+    assert_eq!(Some(dec!(10)), pag.valor_troco);
+    assert_eq!(dec!(101.00), pag.valor_total());
+
+    Ok(())
+}
+
+#[test]
+fn serializa_codigos_com_dois_digitos() -> Result<(), Error> {
+    let pag = Pagamento {
+        detalhes: vec![DetalhePagamento {
+            cartao: Some(Cartao {
+                tipo_integracao: TipoIntegracaoPagamento::NaoIntegrado,
+                cnpj_credenciadora: None,
+                bandeira: Some(BandeiraCartao::Elo),
+                autorizacao: None,
+            }),
+            ..DetalhePagamento::new(FormaPagamentoNfce::CartaoDebito, dec!(50))
+        }],
+        valor_troco: None,
+    };
+
+    let xml = pag.to_string();
+    assert!(xml.contains("<tPag>04</tPag>"));
+    assert!(xml.contains("<vPag>50.00</vPag>"));
+    assert!(xml.contains("<tpIntegra>2</tpIntegra>"));
+    assert!(xml.contains("<tBand>06</tBand>"));
+    assert!(!xml.contains("vTroco"));
+
+    assert_eq!(pag, xml.parse::<Pagamento>()?);
+
+    Ok(())
+}
+
+#[test]
+fn builder_sem_pagamento_informa_tpag_90() {
+    let nfe = builder().build().unwrap();
+    let pag = nfe.pagamento.unwrap();
+
+    assert_eq!(1, pag.detalhes.len());
+    assert_eq!(FormaPagamentoNfce::SemPagamento, pag.detalhes[0].forma);
+    assert_eq!(dec!(0), pag.detalhes[0].valor);
+}
+
+#[test]
+fn builder_calcula_troco() {
+    let nfe = builder()
+        .add_pagamento(FormaPagamentoNfce::Pix, dec!(50))
+        .add_pagamento(FormaPagamentoNfce::Dinheiro, dec!(50))
+        .build()
+        .unwrap();
+    let pag = nfe.pagamento.unwrap();
+
+    assert_eq!(2, pag.detalhes.len());
+    assert_eq!(Some(dec!(9.00)), pag.valor_troco);
+}
+
+#[test]
+fn builder_rejeita_pagamento_insuficiente() {
+    let resultado = builder()
+        .add_pagamento(FormaPagamentoNfce::Dinheiro, dec!(90))
+        .build();
+
+    assert!(resultado.is_err());
+}
+
+#[test]
+fn nfe_round_trip_com_pagamento() -> Result<(), Error> {
+    let nfe = builder()
+        .add_pagamento(FormaPagamentoNfce::Pix, dec!(91))
+        .build()
+        .unwrap();
+
+    let xml = nfe.to_string();
+    assert!(xml.contains("<pag><detPag><tPag>17</tPag><vPag>91.00</vPag></detPag></pag>"));
+
+    let lida = xml.parse::<Nfe>()?;
+    assert_eq!(nfe.pagamento, lida.pagamento);
+
+    Ok(())
+}
@@ -6,6 +6,7 @@
 
 use crate::base::item::Produto;
 use crate::Error;
+use rust_decimal_macros::dec;
 
 /// Testa o parsing de produto isolado
 #[test]
@@ -40,12 +41,12 @@ fn parse_produto_completo() -> Result<(), Error> {
     assert_eq!("Produto de Teste Completo", produto.descricao);
     assert_eq!("12345678", produto.ncm);
     assert_eq!("UN", produto.unidade);
-    assert_eq!(10.5, produto.quantidade);
-    assert_eq!(25.50, produto.valor_unitario);
-    assert_eq!(267.75, produto.valor_bruto);
-    assert_eq!(Some(10.0), produto.valor_frete);
-    assert_eq!(Some(5.0), produto.valor_seguro);
-    assert_eq!(Some(7.75), produto.valor_desconto);
+    assert_eq!(dec!(10.5), produto.quantidade);
+    assert_eq!(dec!(25.50), produto.valor_unitario);
+    assert_eq!(dec!(267.75), produto.valor_bruto);
+    assert_eq!(Some(dec!(10.0)), produto.valor_frete);
+    assert_eq!(Some(dec!(5.0)), produto.valor_seguro);
+    assert_eq!(Some(dec!(7.75)), produto.valor_desconto);
     assert!(produto.valor_compoe_total_nota);
 
     // Verifica tributação
@@ -94,9 +95,9 @@ fn criar_produto_new() {
         "12345678".to_string(),
         "5102".to_string(),
         "UN".to_string(),
-        10.0,
-        50.0,
-        500.0,
+        dec!(10.0),
+        dec!(50.0),
+        dec!(500.0),
     );
 
     assert_eq!("COD001", produto.codigo);
@@ -104,9 +105,9 @@ fn criar_produto_new() {
     assert_eq!("12345678", produto.ncm);
     assert_eq!("5102", produto.tributacao.cfop);
     assert_eq!("UN", produto.unidade);
-    assert_eq!(10.0, produto.quantidade);
-    assert_eq!(50.0, produto.valor_unitario);
-    assert_eq!(500.0, produto.valor_bruto);
+    assert_eq!(dec!(10.0), produto.quantidade);
+    assert_eq!(dec!(50.0), produto.valor_unitario);
+    assert_eq!(dec!(500.0), produto.valor_bruto);
     assert!(produto.valor_compoe_total_nota);
     assert!(produto.gtin.is_none());
     assert!(produto.valor_frete.is_none());
@@ -0,0 +1,164 @@
+//! Testes do cálculo de PIS, COFINS e IPI
+
+use crate::base::calculo::{
+    calcular_icms, calcular_ipi, calcular_pis_cofins, ParametrosIcms, ParametrosIcmsSt, ParametrosIpi,
+    ParametrosPisCofins, RegimePisCofins,
+};
+use crate::base::item::Imposto;
+use crate::builder::{ItemBuilder, NfeBuilder};
+use crate::Error;
+use rust_decimal_macros::dec;
+
+fn parametros(regime: RegimePisCofins, cst: &str) -> ParametrosPisCofins {
+    ParametrosPisCofins {
+        regime,
+        cst: cst.to_string(),
+        valor_bc: dec!(1000),
+        quantidade: dec!(10),
+        ..Default::default()
+    }
+}
+
+fn ipi_tributado() -> ParametrosIpi {
+    ParametrosIpi {
+        cst: "50".to_string(),
+        valor_bc: dec!(1000),
+        aliquota: dec!(10),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn cst_01_usa_aliquotas_do_regime() {
+    let cumulativo = calcular_pis_cofins(&parametros(RegimePisCofins::Cumulativo, "01")).unwrap();
+    assert_eq!(dec!(6.50), cumulativo.pis.valor());
+    assert_eq!(dec!(30), cumulativo.cofins.valor());
+
+    let nao_cumulativo = calcular_pis_cofins(&parametros(RegimePisCofins::NaoCumulativo, "01")).unwrap();
+    let pis = nao_cumulativo.pis.pis_aliq.as_ref().unwrap();
+    assert_eq!(dec!(1.65), pis.aliquota);
+    assert_eq!(dec!(16.50), pis.valor);
+    assert_eq!(dec!(76), nao_cumulativo.cofins.valor());
+}
+
+#[test]
+fn cst_03_por_unidade() -> Result<(), Error> {
+    let calculo = calcular_pis_cofins(&ParametrosPisCofins {
+        aliquota_pis_unidade: Some(dec!(0.5)),
+        aliquota_cofins_unidade: Some(dec!(2.3)),
+        ..parametros(RegimePisCofins::NaoCumulativo, "03")
+    })
+    .unwrap();
+    assert_eq!(dec!(5), calculo.pis.valor());
+    assert_eq!(dec!(23), calculo.cofins.valor());
+
+    let xml = "<imposto>\
+        <PIS><PISQtde><CST>03</CST><qBCProd>10.0000</qBCProd><vAliqProd>0.5000</vAliqProd>\
+        <vPIS>5.00</vPIS></PISQtde></PIS>\
+        <COFINS><COFINSQtde><CST>03</CST><qBCProd>10.0000</qBCProd><vAliqProd>2.3000</vAliqProd>\
+        <vCOFINS>23.00</vCOFINS></COFINSQtde></COFINS></imposto>";
+    let imposto = xml.parse::<Imposto>()?;
+    assert_eq!(Some(&calculo.pis), imposto.pis.as_ref());
+    assert_eq!(Some(&calculo.cofins), imposto.cofins.as_ref());
+    assert_eq!(xml, imposto.to_string());
+
+    let sem_unidade = calcular_pis_cofins(&parametros(RegimePisCofins::NaoCumulativo, "03"));
+    assert!(sem_unidade.unwrap_err().contains("por unidade"));
+
+    Ok(())
+}
+
+#[test]
+fn cst_nao_tributado_e_outras_operacoes() {
+    let simples = calcular_pis_cofins(&parametros(RegimePisCofins::SimplesNacional, "06")).unwrap();
+    assert!(simples.pis.pis_nt.is_some());
+    assert_eq!(dec!(0), simples.cofins.valor());
+
+    let outras = calcular_pis_cofins(&parametros(RegimePisCofins::SimplesNacional, "99")).unwrap();
+    let pis = outras.pis.pis_outr.as_ref().unwrap();
+    assert_eq!(Some(dec!(0)), pis.aliquota);
+    assert_eq!(Some(dec!(0)), pis.valor);
+
+    let erro = calcular_pis_cofins(&parametros(RegimePisCofins::Cumulativo, "10")).unwrap_err();
+    assert!(erro.contains("inválido"));
+}
+
+#[test]
+fn ipi_tributado_e_nao_tributado() {
+    let ipi = calcular_ipi(&ipi_tributado()).unwrap();
+    assert_eq!("999", ipi.codigo_enquadramento);
+    assert_eq!(dec!(100), ipi.valor_ipi());
+
+    let por_unidade = calcular_ipi(&ParametrosIpi {
+        quantidade: dec!(12),
+        valor_unidade: Some(dec!(1.25)),
+        ..ipi_tributado()
+    })
+    .unwrap();
+    assert_eq!(None, por_unidade.ipi_trib.as_ref().unwrap().valor_bc);
+    assert_eq!(dec!(15), por_unidade.valor_ipi());
+
+    let isento = calcular_ipi(&ParametrosIpi {
+        cst: "52".to_string(),
+        ..ipi_tributado()
+    })
+    .unwrap();
+    assert!(isento.ipi_nt.is_some());
+    assert_eq!(dec!(0), isento.valor_ipi());
+
+    assert!(calcular_ipi(&ParametrosIpi { cst: "60".to_string(), ..ipi_tributado() }).is_err());
+}
+
+#[test]
+fn ipi_integra_a_base_do_icms() {
+    let ipi = calcular_ipi(&ipi_tributado()).unwrap();
+    let st = ParametrosIcmsSt {
+        percentual_mva: dec!(40),
+        aliquota_interna: dec!(18),
+        ..Default::default()
+    };
+    let parametros = ParametrosIcms {
+        cst: "10".to_string(),
+        valor_produto: dec!(1000),
+        aliquota: dec!(18),
+        st: Some(st),
+        ..Default::default()
+    };
+
+    // Revenda: o IPI só entra na BC do ICMS-ST
+    let revenda = calcular_icms(&parametros.clone().com_ipi(&ipi, false)).unwrap().icms10.unwrap();
+    assert_eq!(dec!(1000), revenda.valor_bc);
+    assert_eq!(dec!(1540), revenda.valor_bc_st);
+
+    // Consumidor final: o IPI também entra na BC do ICMS próprio
+    let final_ = calcular_icms(&parametros.com_ipi(&ipi, true)).unwrap().icms10.unwrap();
+    assert_eq!(dec!(1100), final_.valor_bc);
+    assert_eq!(dec!(198), final_.valor);
+}
+
+#[test]
+fn builder_soma_ipi_pis_e_cofins() {
+    let pis_cofins = calcular_pis_cofins(&parametros(RegimePisCofins::Cumulativo, "01")).unwrap();
+    let nfe = NfeBuilder::new()
+        .codigo_uf(35)
+        .numero(60)
+        .natureza_operacao("VENDA")
+        .codigo_municipio(3550308)
+        .emit_cnpj("12345678000190")
+        .emit_razao_social("EMPRESA LTDA")
+        .emit_ie("123456789")
+        .add_item(
+            ItemBuilder::new("001", "Produto", "61091000", "5101")
+                .valor_unitario(dec!(1000))
+                .ipi(calcular_ipi(&ipi_tributado()).unwrap())
+                .pis_cofins(pis_cofins),
+        )
+        .build()
+        .unwrap();
+
+    assert!(nfe.itens[0].imposto.pis.is_some());
+    assert_eq!(dec!(100), nfe.totais.valor_ipi);
+    assert_eq!(dec!(6.50), nfe.totais.valor_pis);
+    assert_eq!(dec!(30), nfe.totais.valor_cofins);
+    assert_eq!(dec!(1100), nfe.totais.valor_total);
+}
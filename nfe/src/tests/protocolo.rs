@@ -0,0 +1,121 @@
+//! Testes do XML de distribuição (nfeProc) e do protocolo de autorização
+
+use crate::base::ide::TipoAmbiente;
+use crate::base::protocolo::{NfeProc, ProtocoloAutorizacao};
+use crate::builder::{ItemBuilder, NfeBuilder};
+use crate::{Error, Nfe};
+use rust_decimal_macros::dec;
+
+fn nfe() -> Nfe {
+    NfeBuilder::new()
+        .codigo_uf(35)
+        .numero(1)
+        .natureza_operacao("VENDA")
+        .codigo_municipio(3550308)
+        .emit_cnpj("12345678000190")
+        .emit_razao_social("EMPRESA TESTE LTDA")
+        .emit_ie("123456789")
+        .add_item(ItemBuilder::new("001", "Produto", "12345678", "5102").valor_unitario(dec!(10)))
+        .build()
+        .unwrap()
+}
+
+fn protocolo(chave: &str) -> ProtocoloAutorizacao {
+    format!(
+        "<infProt>\
+            <tpAmb>2</tpAmb>\
+            <verAplic>SP_NFE_PL009_V4</verAplic>\
+            <chNFe>{}</chNFe>\
+            <dhRecbto>2024-01-15T10:30:45-03:00</dhRecbto>\
+            <nProt>135240000000001</nProt>\
+            <digVal>oWFauN7opm3Q6yKVaHiEBqW3DwU=</digVal>\
+            <cStat>100</cStat>\
+            <xMotivo>Autorizado o uso da NF-e</xMotivo>\
+        </infProt>",
+        chave
+    )
+    .parse()
+    .unwrap()
+}
+
+#[test]
+fn parse_protocolo() -> Result<(), Error> {
+    let xml = r#"
+        <infProt Id="ID135150001686732">
+            <tpAmb>1</tpAmb>
+            <verAplic>SP_NFE_PL_008f</verAplic>
+            <chNFe>35150300822602000124550010009923461099234656</chNFe>
+            <dhRecbto>2015-03-27T16:50:50-03:00</dhRecbto>
+            <nProt>135150001686732</nProt>
+            <digVal>oWFauN7opm3Q6yKVaHiEBqW3DwU=</digVal>
+            <cStat>100</cStat>
+            <xMotivo>Autorizado o uso da NF-e</xMotivo>
+        </infProt>
+    "#;
+
+    let prot = xml.parse::<ProtocoloAutorizacao>()?;
+
+    assert_eq!(Some("ID135150001686732".to_string()), prot.id);
+    assert_eq!(TipoAmbiente::Producao, prot.ambiente);
+    assert_eq!("35150300822602000124550010009923461099234656", prot.chave_acesso);
+    assert_eq!("2015-03-27T16:50:50-03:00", prot.recebimento.to_rfc3339());
+    assert_eq!(Some("135150001686732".to_string()), prot.numero);
+    assert_eq!(100, prot.codigo_status);
+    assert!(prot.autorizado());
+    assert!(!prot.denegado());
+
+    Ok(())
+}
+
+#[test]
+fn protocolo_rejeitado_sem_numero() -> Result<(), Error> {
+    let xml = "
+        <infProt>
+            <tpAmb>2</tpAmb>
+            <verAplic>SP_NFE_PL009_V4</verAplic>
+            <chNFe>35150300822602000124550010009923461099234656</chNFe>
+            <dhRecbto>2024-01-15T10:30:45-03:00</dhRecbto>
+            <cStat>302</cStat>
+            <xMotivo>Uso Denegado: Irregularidade fiscal do destinatario</xMotivo>
+        </infProt>
+    ";
+
+    let prot = xml.parse::<ProtocoloAutorizacao>()?;
+
+    assert_eq!(None, prot.numero);
+    assert!(!prot.autorizado());
+    assert!(prot.denegado());
+
+    Ok(())
+}
+
+#[test]
+fn serializar_nfe_proc() {
+    let nfe = nfe();
+    let prot = protocolo(&nfe.chave_acesso);
+    let proc = NfeProc::new(nfe, prot);
+
+    let xml = proc.to_string();
+
+    assert!(xml.starts_with(
+        r#"<nfeProc versao="4.00" xmlns="http://www.portalfiscal.inf.br/nfe"><NFe xmlns="http://www.portalfiscal.inf.br/nfe"><infNFe"#
+    ));
+    assert!(xml.contains(r#"</NFe><protNFe versao="4.00"><infProt><tpAmb>2</tpAmb>"#));
+    assert!(xml.contains("<dhRecbto>2024-01-15T10:30:45-03:00</dhRecbto>"));
+    assert!(xml.ends_with("</infProt></protNFe></nfeProc>"));
+}
+
+#[test]
+fn nfe_proc_round_trip() -> Result<(), Error> {
+    let nfe = nfe();
+    let prot = protocolo(&nfe.chave_acesso);
+    let proc = NfeProc::new(nfe, prot);
+
+    let xml = format!("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n{}", proc);
+    let lido = xml.parse::<NfeProc>()?;
+
+    assert_eq!(proc, lido);
+    assert_eq!(lido.nfe.chave_acesso, lido.protocolo.chave_acesso);
+
+    Ok(())
+}
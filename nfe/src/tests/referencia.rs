@@ -0,0 +1,111 @@
+//! Testes dos documentos fiscais referenciados (NFref)
+
+use crate::base::documento::Documento;
+use crate::base::ide::*;
+use crate::builder::{ItemBuilder, NfeBuilder};
+use crate::{Error, Nfe};
+use rust_decimal_macros::dec;
+
+const CHAVE_NFE: &str = "41180678393592000146558900000006041028190697";
+const CHAVE_CTE: &str = "35240112345678000190570010000000011000000017";
+
+fn builder() -> NfeBuilder {
+    super::builder()
+        .natureza_operacao("DEVOLUCAO DE COMPRA")
+        .finalidade(FinalidadeEmissao::Devolucao)
+        .add_item(ItemBuilder::new("001", "Produto", "12345678", "5202").valor_unitario(dec!(10)))
+}
+
+#[test]
+fn round_trip_todas_as_formas() -> Result<(), Error> {
+    let nfe = builder()
+        .referenciar_nfe(CHAVE_NFE)
+        .add_referencia(DocumentoReferenciado::Nf(NotaFiscalReferenciada {
+            codigo_uf: 35,
+            ano_mes: "2401".to_string(),
+            cnpj: "12345678000190".to_string(),
+            modelo: "01".to_string(),
+            serie: 1,
+            numero: 123,
+        }))
+        .add_referencia(DocumentoReferenciado::NfProdutor(NotaProdutorReferenciada {
+            codigo_uf: 41,
+            ano_mes: "2312".to_string(),
+            documento: Documento::cpf("123.456.789-09"),
+            ie: "ISENTO".to_string(),
+            modelo: "04".to_string(),
+            serie: 0,
+            numero: 45,
+        }))
+        .referenciar_cte(CHAVE_CTE)
+        .add_referencia(DocumentoReferenciado::Ecf(CupomFiscalReferenciado {
+            modelo: "2D".to_string(),
+            numero_ecf: "001".to_string(),
+            numero_coo: "000123".to_string(),
+        }))
+        .build()
+        .unwrap();
+
+    let xml = nfe.to_string();
+    assert!(xml.contains(&format!(
+        "</verProc><NFref><refNFe>{}</refNFe></NFref>\
+         <NFref><refNF><cUF>35</cUF><AAMM>2401</AAMM><CNPJ>12345678000190</CNPJ><mod>01</mod>\
+         <serie>1</serie><nNF>123</nNF></refNF></NFref>\
+         <NFref><refNFP><cUF>41</cUF><AAMM>2312</AAMM><CPF>12345678909</CPF><IE>ISENTO</IE>\
+         <mod>04</mod><serie>0</serie><nNF>45</nNF></refNFP></NFref>\
+         <NFref><refCTe>{}</refCTe></NFref>\
+         <NFref><refECF><mod>2D</mod><nECF>001</nECF><nCOO>000123</nCOO></refECF></NFref></ide>",
+        CHAVE_NFE, CHAVE_CTE
+    )));
+
+    let lida = xml.parse::<Nfe>()?;
+    assert_eq!(5, lida.ide.referencias.len());
+    assert_eq!(Some(CHAVE_NFE), lida.ide.referencias[0].chave_acesso());
+    assert_eq!(nfe, lida);
+
+    Ok(())
+}
+
+#[test]
+fn parse_ref_nfp_cnpj() -> Result<(), Error> {
+    let xml = "<NFref><refNFP><cUF>41</cUF><AAMM>2312</AAMM><CNPJ>12345678000190</CNPJ>\
+        <IE>1234567890</IE><mod>04</mod><serie>2</serie><nNF>7</nNF></refNFP></NFref>";
+    let referencia: DocumentoReferenciado = quick_xml::de::from_str(xml)?;
+
+    match referencia {
+        DocumentoReferenciado::NfProdutor(nfp) => {
+            assert_eq!(Documento::Cnpj("12345678000190".to_string()), nfp.documento);
+            assert_eq!("1234567890", nfp.ie);
+            assert_eq!(2, nfp.serie);
+        }
+        outra => panic!("Referência inesperada: {:?}", outra),
+    }
+
+    Ok(())
+}
+
+#[test]
+fn builder_rejeita_digito_verificador_invalido() {
+    let chave = format!("{}8", &CHAVE_NFE[..43]);
+
+    let erro = builder().referenciar_nfe(&chave).build().unwrap_err();
+    assert!(erro.contains("Dígito verificador inválido"));
+
+    assert!(builder().referenciar_cte("123").build().is_err());
+}
+
+#[test]
+fn devolucao_exige_referencia() {
+    assert!(builder().build().is_err());
+    assert!(builder().finalidade(FinalidadeEmissao::Normal).build().is_ok());
+}
+
+#[test]
+fn no_maximo_999_referencias() {
+    let referenciar = |quantidade: usize| {
+        (0..quantidade).fold(builder(), |b, _| b.referenciar_nfe(CHAVE_NFE)).build()
+    };
+
+    assert_eq!(999, referenciar(999).unwrap().ide.referencias.len());
+    assert_eq!(Err("Máximo de 999 documentos referenciados".to_string()), referenciar(1000).map(|_| ()));
+}
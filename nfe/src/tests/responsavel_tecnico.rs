@@ -0,0 +1,82 @@
+//! Testes do grupo do responsável técnico (infRespTec) e do hash do CSRT
+
+use crate::base::responsavel_tecnico::*;
+use crate::builder::NfeBuilder;
+use crate::Error;
+use rust_decimal_macros::dec;
+
+fn builder() -> NfeBuilder {
+    super::builder().add_item(super::produto(dec!(10)))
+}
+
+fn csrt() -> ConfiguracaoCsrt {
+    ConfiguracaoCsrt {
+        id_csrt: "01".to_string(),
+        csrt: "G8063VRTNDMO886SFNK5LDUDEI24XJ22YIPO".to_string(),
+    }
+}
+
+#[test]
+fn hash_csrt() {
+    // Exemplo da NT 2018.005
+    let hash = gerar_hash_csrt(
+        "G8063VRTNDMO886SFNK5LDUDEI24XJ22YIPO",
+        "41180678393592000146558900000006041028190697",
+    );
+
+    assert_eq!("aWv6LeEM4X6u4+qBI2OYZ8grigw=", hash);
+}
+
+#[test]
+fn parse_resp_tec() -> Result<(), Error> {
+    let xml = "<infRespTec><CNPJ>11111111000191</CNPJ><xContato>Fulano de Tal</xContato>\
+        <email>suporte@exemplo.com.br</email><fone>4133334444</fone><idCSRT>01</idCSRT>\
+        <hashCSRT>aWv6LeEM4X6u4+qBI2OYZ8grigw=</hashCSRT></infRespTec>";
+    let resp = xml.parse::<ResponsavelTecnico>()?;
+
+    assert_eq!("11111111000191", resp.cnpj);
+    assert_eq!("Fulano de Tal", resp.contato);
+    assert_eq!("suporte@exemplo.com.br", resp.email);
+    assert_eq!("4133334444", resp.fone);
+    assert_eq!(Some("01".to_string()), resp.id_csrt);
+    assert_eq!(Some("aWv6LeEM4X6u4+qBI2OYZ8grigw=".to_string()), resp.hash_csrt);
+    assert_eq!(xml, resp.to_string());
+
+    Ok(())
+}
+
+#[test]
+fn serialize_resp_tec_sem_csrt() {
+    let resp = ResponsavelTecnico::new("11.111.111/0001-91", "Fulano", "a@b.com", "(41) 3333-4444");
+
+    assert_eq!(
+        "<infRespTec><CNPJ>11111111000191</CNPJ><xContato>Fulano</xContato>\
+         <email>a@b.com</email><fone>4133334444</fone></infRespTec>",
+        resp.to_string()
+    );
+}
+
+#[test]
+fn builder_calcula_hash_csrt() -> Result<(), Error> {
+    let resp = ResponsavelTecnico::new("11111111000191", "Fulano", "a@b.com", "4133334444");
+    let nfe = builder().responsavel_tecnico(resp).csrt(csrt()).build().unwrap();
+
+    let resp = nfe.responsavel_tecnico.as_ref().unwrap();
+    assert_eq!(Some("01".to_string()), resp.id_csrt);
+    assert_eq!(
+        Some(gerar_hash_csrt("G8063VRTNDMO886SFNK5LDUDEI24XJ22YIPO", &nfe.chave_acesso)),
+        resp.hash_csrt
+    );
+
+    let xml = nfe.to_string();
+    assert!(xml.contains("<idCSRT>01</idCSRT><hashCSRT>"));
+    assert!(xml.ends_with("</infRespTec></infNFe></NFe>"));
+    assert_eq!(nfe, xml.parse()?);
+
+    Ok(())
+}
+
+#[test]
+fn builder_csrt_sem_responsavel() {
+    assert!(builder().csrt(csrt()).build().is_err());
+}
@@ -0,0 +1,213 @@
+//! Testes da leitura sem perdas (assinatura e grupos não modelados)
+
+use crate::base::protocolo::NfeProc;
+use crate::builder::{ItemBuilder, NfeBuilder};
+use crate::{Error, Nfe};
+use rust_decimal_macros::dec;
+
+const AUT_XML: &str = "<autXML><CNPJ>99999999000191</CNPJ></autXML>";
+const EMAIL: &str = "<email>compras@cliente.com.br</email>";
+const NVE: &str = "<NVE>AA0001</NVE>";
+const RESP_TEC: &str = "<infRespTec><CNPJ>11111111000191</CNPJ><xContato>Suporte</xContato>\
+    <email>suporte@exemplo.com.br</email><fone>1133334444</fone></infRespTec>";
+const AGROPECUARIO: &str = "<agropecuario><guiaTransito><tpGuia>1</tpGuia></guiaTransito></agropecuario>";
+const SUPL: &str = "<infNFeSupl><qrCode><![CDATA[https://exemplo?p=1|2|3]]></qrCode>\
+    <urlChave>https://exemplo/consulta</urlChave></infNFeSupl>";
+const ASSINATURA: &str = "<Signature xmlns=\"http://www.w3.org/2000/09/xmldsig#\">\
+    <SignedInfo><CanonicalizationMethod Algorithm=\"http://www.w3.org/TR/2001/REC-xml-c14n-20010315\"/>\
+    <Reference URI=\"#NFe1\"><DigestValue>abc+/=</DigestValue></Reference></SignedInfo>\
+    <SignatureValue>\n  QUJD\nREVG  \n</SignatureValue></Signature>";
+
+fn nfe() -> Nfe {
+    NfeBuilder::new()
+        .codigo_uf(35)
+        .numero(1)
+        .natureza_operacao("VENDA")
+        .codigo_municipio(3550308)
+        .emit_cnpj("12345678000190")
+        .emit_razao_social("EMPRESA LTDA")
+        .emit_ie("123456789")
+        .dest_cnpj("98765432000198")
+        .dest_razao_social("CLIENTE LTDA")
+        .add_item(ItemBuilder::new("001", "Produto A", "12345678", "5102").valor_unitario(dec!(10)))
+        .add_item(ItemBuilder::new("002", "Produto B", "12345678", "5102").valor_unitario(dec!(5)))
+        .informacao_complementar("Obs")
+        .build()
+        .unwrap()
+}
+
+/// XML assinado com grupos que o modelo não representa, em vários níveis
+///
+/// As quebras de linha entre os itens não são reproduzidas pelo serializador:
+/// só aparecem na saída se o `<infNFe>` original for reemitido.
+fn xml_assinado() -> String {
+    let xml = nfe().to_string();
+    let segundo_ncm = xml.rfind("</NCM>").unwrap() + "</NCM>".len();
+
+    format!("{}{}{}", &xml[..segundo_ncm], NVE, &xml[segundo_ncm..])
+        .replacen("</emit>", &format!("</emit>{}", AUT_XML), 1)
+        .replacen("</dest>", &format!("{}</dest>", EMAIL), 1)
+        .replace("<det ", "\n  <det ")
+        .replacen("</infAdic>", &format!("</infAdic>{}{}", RESP_TEC, AGROPECUARIO), 1)
+        .replacen("</infNFe>", &format!("</infNFe>{}{}", SUPL, ASSINATURA), 1)
+}
+
+fn nomes(nfe: &Nfe) -> Vec<(&str, &str)> {
+    let preservado = nfe.preservado.as_ref().unwrap();
+    preservado.elementos.iter().map(|e| (e.pai.as_str(), e.nome.as_str())).collect()
+}
+
+#[test]
+fn round_trip_sem_perdas() -> Result<(), Error> {
+    let xml = xml_assinado();
+    let nfe = Nfe::parse_sem_perdas(&xml)?;
+
+    assert_eq!(xml, nfe.to_string());
+
+    assert_eq!(
+        vec![
+            ("infNFe", "autXML"),
+            ("infNFe/dest", "email"),
+            ("infNFe/det[2]/prod", "NVE"),
+            ("infNFe", "agropecuario"),
+            ("", "infNFeSupl"),
+            ("", "Signature"),
+        ],
+        nomes(&nfe)
+    );
+
+    let preservado = nfe.preservado.as_ref().unwrap();
+    assert_eq!(Some(ASSINATURA), preservado.assinatura());
+    assert_eq!(Some("emit".to_string()), preservado.elementos[0].anterior);
+    assert_eq!(Some("NCM".to_string()), preservado.elementos[2].anterior);
+
+    Ok(())
+}
+
+#[test]
+fn assinatura_confere_com_o_inf_nfe_original() -> Result<(), Error> {
+    let xml = xml_assinado();
+    let inicio = xml.find("<infNFe").unwrap();
+    let fim = xml.find("</infNFe>").unwrap() + "</infNFe>".len();
+
+    let reserializado = Nfe::parse_sem_perdas(&xml)?.to_string();
+
+    // O conteúdo assinado sai byte a byte igual, seguido da mesma assinatura
+    assert!(reserializado.contains(&format!("{}{}{}</NFe>", &xml[inicio..fim], SUPL, ASSINATURA)));
+
+    Ok(())
+}
+
+#[test]
+fn leitura_padrao_descarta_nao_modelados() -> Result<(), Error> {
+    let xml = xml_assinado();
+    let nfe = xml.parse::<Nfe>()?;
+
+    assert!(nfe.preservado.is_none());
+
+    let reserializado = nfe.to_string();
+    for tag in ["<autXML>", EMAIL, NVE, "<agropecuario>", "<infNFeSupl>", "<Signature"] {
+        assert!(!reserializado.contains(tag));
+    }
+
+    Ok(())
+}
+
+#[test]
+fn edicao_mantem_preservados_e_descarta_assinatura() -> Result<(), Error> {
+    let mut nfe = Nfe::parse_sem_perdas(&xml_assinado())?;
+    nfe.informacoes_adicionais = None;
+    nfe.itens[1].produto.descricao = "Produto B revisado".to_string();
+
+    let xml = nfe.to_string();
+
+    assert!(xml.contains(&format!("</emit>{}<dest>", AUT_XML)));
+    assert!(xml.contains(&format!("{}</dest>", EMAIL)));
+    assert!(xml.contains(&format!("<NCM>12345678</NCM>{}", NVE)));
+    assert_eq!(1, xml.matches(NVE).count());
+
+    // Sem <infAdic>, os elementos que o seguiam vão para o final de <infNFe>
+    assert!(xml.contains(&format!("{}{}</infNFe>{}</NFe>", RESP_TEC, AGROPECUARIO, SUPL)));
+    assert!(!xml.contains("<infAdic>"));
+
+    // A assinatura original não confere mais com o conteúdo alterado
+    assert!(!xml.contains("<Signature"));
+    assert!(nfe.preservado.as_ref().unwrap().assinatura().is_some());
+
+    Ok(())
+}
+
+#[test]
+fn sem_perdas_namespace_prefixado() -> Result<(), Error> {
+    let xml = xml_assinado().replace(ASSINATURA, "");
+    let prefixado = xml
+        .replace("</", "\u{0}")
+        .replace('<', "<nfe:")
+        .replace('\u{0}', "</nfe:")
+        .replace("xmlns=", "xmlns:nfe=")
+        .replace("<nfe:![CDATA[", "<![CDATA[");
+
+    let nfe = Nfe::parse_sem_perdas(&prefixado)?;
+
+    assert_eq!(xml, nfe.to_string());
+
+    Ok(())
+}
+
+#[test]
+fn nfe_proc_sem_preservado_nao_reemite_assinatura() -> Result<(), Error> {
+    let xml = xml_assinado();
+    let nfe = Nfe::parse_sem_perdas(&xml)?;
+    let protocolo = format!(
+        "<infProt><tpAmb>2</tpAmb><verAplic>SP_NFE_PL009_V4</verAplic><chNFe>{}</chNFe>\
+         <dhRecbto>2024-01-15T10:30:45-03:00</dhRecbto><nProt>135240000000001</nProt>\
+         <cStat>100</cStat><xMotivo>Autorizado o uso da NF-e</xMotivo></infProt>",
+        nfe.chave_acesso
+    )
+    .parse()?;
+    let distribuicao = NfeProc::new(nfe, protocolo).to_string();
+    assert!(distribuicao.contains(ASSINATURA));
+
+    // A leitura padrão descarta a assinatura, que não volta na serialização
+    let proc = distribuicao.parse::<NfeProc>()?;
+    assert!(proc.nfe.preservado.is_none());
+    assert!(!proc.to_string().contains("<Signature"));
+
+    let proc = NfeProc::parse_sem_perdas(&distribuicao)?;
+    assert_eq!(distribuicao, proc.to_string());
+
+    Ok(())
+}
+
+#[test]
+fn sem_perdas_nfe_proc() -> Result<(), Error> {
+    let xml = xml_assinado();
+    let nfe = Nfe::parse_sem_perdas(&xml)?;
+    let protocolo = format!(
+        "<infProt><tpAmb>2</tpAmb><verAplic>SP_NFE_PL009_V4</verAplic><chNFe>{}</chNFe>\
+         <dhRecbto>2024-01-15T10:30:45-03:00</dhRecbto><nProt>135240000000001</nProt>\
+         <cStat>100</cStat><xMotivo>Autorizado o uso da NF-e</xMotivo></infProt>",
+        nfe.chave_acesso
+    )
+    .parse()?;
+    let distribuicao = NfeProc::new(nfe, protocolo).to_string();
+
+    assert!(distribuicao.contains(&xml));
+
+    // Grupos do protocolo que o modelo não representa
+    let mensagem = "<cMsg>200</cMsg><xMsg>Mensagem da SEFAZ</xMsg>";
+    let assinatura_sefaz = ASSINATURA.replace("#NFe1", "#ID135240000000001");
+    let distribuicao = distribuicao
+        .replacen("</xMotivo>", &format!("</xMotivo>{}", mensagem), 1)
+        .replacen("</infProt>", &format!("</infProt>{}", assinatura_sefaz), 1);
+
+    let proc = NfeProc::parse_sem_perdas(&distribuicao)?;
+    assert_eq!(distribuicao, proc.to_string());
+    assert_eq!(
+        vec!["protNFe/infProt", "protNFe/infProt", "protNFe"],
+        proc.preservado.as_ref().unwrap().elementos.iter().map(|e| e.pai.as_str()).collect::<Vec<_>>()
+    );
+    assert_eq!(xml, Nfe::parse_sem_perdas(&distribuicao)?.to_string());
+
+    Ok(())
+}
@@ -0,0 +1,145 @@
+//! Testes do recálculo e da conferência dos totais
+
+use crate::base::calculo::{
+    calcular_difal, calcular_icms, calcular_ipi, ParametrosDifal, ParametrosIcms, ParametrosIcmsSt,
+    ParametrosIpi,
+};
+use crate::base::item::{ImpostoDevolvido, IpiDevolvido, Item};
+use crate::base::Nfe;
+use crate::builder::{ItemBuilder, NfeBuilder};
+use crate::Error;
+use rust_decimal_macros::dec;
+
+fn nfe_com_st() -> Nfe {
+    let icms = calcular_icms(&ParametrosIcms {
+        cst: "10".to_string(),
+        valor_produto: dec!(1000),
+        aliquota: dec!(18),
+        aliquota_fcp: dec!(2),
+        st: Some(ParametrosIcmsSt {
+            percentual_mva: dec!(40),
+            aliquota_interna: dec!(18),
+            aliquota_fcp_st: dec!(2),
+            ..Default::default()
+        }),
+        ..Default::default()
+    })
+    .unwrap();
+    let ipi = calcular_ipi(&ParametrosIpi {
+        cst: "50".to_string(),
+        valor_bc: dec!(1000),
+        aliquota: dec!(5),
+        ..Default::default()
+    })
+    .unwrap();
+
+    NfeBuilder::new()
+        .codigo_uf(35)
+        .numero(70)
+        .natureza_operacao("VENDA")
+        .codigo_municipio(3550308)
+        .emit_cnpj("12345678000190")
+        .emit_razao_social("EMPRESA LTDA")
+        .emit_ie("123456789")
+        .add_item(
+            ItemBuilder::new("001", "Produto", "61091000", "5401")
+                .valor_unitario(dec!(1000))
+                .desconto(dec!(50))
+                .icms(icms)
+                .ipi(ipi),
+        )
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn builder_preenche_todos_os_totais() {
+    let nfe = nfe_com_st();
+
+    assert_eq!(dec!(1000), nfe.totais.valor_base_calculo);
+    assert_eq!(dec!(180), nfe.totais.valor_icms);
+    assert_eq!(dec!(20), nfe.totais.valor_fcp);
+    assert_eq!(dec!(1400), nfe.totais.valor_base_calculo_st);
+    assert_eq!(dec!(72), nfe.totais.valor_icms_st);
+    assert_eq!(dec!(28), nfe.totais.valor_fcp_st);
+    assert_eq!(dec!(50), nfe.totais.valor_ipi);
+    assert_eq!(dec!(50), nfe.totais.valor_desconto);
+    // 1000 − 50 + 72 + 28 + 50
+    assert_eq!(dec!(1100), nfe.totais.valor_total);
+    assert!(nfe.verificar_totais().is_empty());
+}
+
+#[test]
+fn recalcula_respeitando_ind_tot_devolucao_e_difal() {
+    let mut nfe = nfe_com_st();
+
+    let mut brinde = nfe.itens[0].clone();
+    brinde.numero = 2;
+    brinde.produto.valor_compoe_total_nota = false;
+    brinde.produto.valor_frete = Some(dec!(10));
+    brinde.produto.valor_desconto = None;
+    brinde.imposto.icms = None;
+    brinde.imposto.ipi = None;
+    brinde.imposto.icms_uf_dest = Some(
+        calcular_difal(&ParametrosDifal {
+            valor_operacao: dec!(1000),
+            uf_origem: "SP".to_string(),
+            uf_destino: "RJ".to_string(),
+            ..Default::default()
+        })
+        .unwrap(),
+    );
+    brinde.imposto_devolvido = Some(ImpostoDevolvido {
+        percentual_devolucao: dec!(100),
+        ipi: IpiDevolvido { valor: dec!(5) },
+    });
+    nfe.itens.push(brinde);
+    nfe.recalcular_totais();
+
+    assert_eq!(dec!(1000), nfe.totais.valor_produtos);
+    assert_eq!(dec!(10), nfe.totais.valor_frete);
+    assert_eq!(dec!(5), nfe.totais.valor_ipi_devolvido);
+    assert_eq!(dec!(100), nfe.totais.valor_icms_uf_dest);
+    assert_eq!(dec!(20), nfe.totais.valor_fcp_uf_dest);
+    assert_eq!(dec!(1115), nfe.totais.valor_total);
+    assert!(nfe.verificar_totais().is_empty());
+}
+
+#[test]
+fn aponta_divergencias_com_texto_da_rejeicao() {
+    let mut nfe = nfe_com_st();
+    nfe.totais.valor_base_calculo = dec!(900);
+    nfe.totais.valor_total = dec!(1000);
+
+    let divergencias = nfe.verificar_totais();
+    assert_eq!(2, divergencias.len());
+    assert_eq!(Some(531), divergencias[0].codigo);
+    assert_eq!(
+        "Rejeição 531: Total da BC ICMS difere do somatório dos itens \
+        (vBC informado 900.00, calculado 1000.00)",
+        divergencias[0].to_string()
+    );
+    assert_eq!(Some(610), divergencias[1].codigo);
+    assert_eq!(dec!(1100), divergencias[1].calculado);
+}
+
+#[test]
+fn imposto_devolvido_ida_e_volta() -> Result<(), Error> {
+    let xml = "<det nItem=\"1\"><prod><cProd>001</cProd><cEAN>SEM GTIN</cEAN><xProd>Produto</xProd>\
+        <NCM>61091000</NCM><CFOP>5202</CFOP><uCom>UN</uCom><qCom>1.0000</qCom><vUnCom>100.00</vUnCom>\
+        <vProd>100.00</vProd><cEANTrib>SEM GTIN</cEANTrib><uTrib>UN</uTrib><qTrib>1.0000</qTrib>\
+        <vUnTrib>100.00</vUnTrib><indTot>1</indTot></prod><imposto></imposto>\
+        <impostoDevol><pDevol>50.00</pDevol><IPI><vIPIDevol>2.50</vIPIDevol></IPI></impostoDevol></det>";
+    let item = xml.parse::<Item>()?;
+
+    let devolvido = item.imposto_devolvido.as_ref().expect("impostoDevol ausente");
+    assert_eq!(dec!(50), devolvido.percentual_devolucao);
+    assert_eq!(dec!(2.50), devolvido.ipi.valor);
+    let serializado = item.to_string();
+    assert!(serializado.ends_with(
+        "<impostoDevol><pDevol>50.00</pDevol><IPI><vIPIDevol>2.50</vIPIDevol></IPI></impostoDevol></det>"
+    ));
+    assert_eq!(item, serializado.parse::<Item>()?);
+
+    Ok(())
+}
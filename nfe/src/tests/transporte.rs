@@ -0,0 +1,184 @@
+//! Testes do grupo de transporte (transp)
+
+use crate::base::documento::DocumentoNacional;
+use crate::base::transporte::{
+    Lacre, ModalidadeFrete, RetencaoIcmsTransporte, Transportador, Transporte, Veiculo, Volume,
+};
+use crate::builder::NfeBuilder;
+use crate::{Error, Nfe};
+use rust_decimal_macros::dec;
+
+fn veiculo(placa: &str) -> Veiculo {
+    Veiculo {
+        placa: placa.to_string(),
+        uf: Some("SP".to_string()),
+        rntc: None,
+    }
+}
+
+fn builder() -> NfeBuilder {
+    super::builder().add_item(super::produto(dec!(100)))
+}
+
+#[test]
+fn parse_transporte_completo() -> Result<(), Error> {
+    let xml = r#"
+        <transp>
+            <modFrete>0</modFrete>
+            <transporta>
+                <CNPJ>11222333000144</CNPJ>
+                <xNome>TRANSPORTADORA TESTE LTDA</xNome>
+                <IE>111222333444</IE>
+                <xEnder>RUA DAS CARGAS, 100</xEnder>
+                <xMun>CAMPINAS</xMun>
+                <UF>SP</UF>
+            </transporta>
+            <retTransp>
+                <vServ>500.00</vServ>
+                <vBCRet>500.00</vBCRet>
+                <pICMSRet>12.00</pICMSRet>
+                <vICMSRet>60.00</vICMSRet>
+                <CFOP>5352</CFOP>
+                <cMunFG>3509502</cMunFG>
+            </retTransp>
+            <veicTransp>
+                <placa>ABC1D23</placa>
+                <UF>SP</UF>
+                <RNTC>12345678</RNTC>
+            </veicTransp>
+            <reboque>
+                <placa>XYZ9K87</placa>
+                <UF>MG</UF>
+            </reboque>
+            <reboque>
+                <placa>XYZ9K88</placa>
+            </reboque>
+            <vol>
+                <qVol>10</qVol>
+                <esp>CAIXA</esp>
+                <marca>ACME</marca>
+                <nVol>1-10</nVol>
+                <pesoL>120.500</pesoL>
+                <pesoB>130.750</pesoB>
+                <lacres>
+                    <nLacre>L001</nLacre>
+                </lacres>
+                <lacres>
+                    <nLacre>L002</nLacre>
+                </lacres>
+            </vol>
+            <vol>
+                <qVol>1</qVol>
+                <esp>PALETE</esp>
+            </vol>
+        </transp>
+    "#;
+
+    let transp = xml.parse::<Transporte>()?;
+
+    assert_eq!(ModalidadeFrete::ContratacaoPorContaDoRemetente, transp.modalidade);
+
+    let transporta = transp.transportador.as_ref().unwrap();
+    assert_eq!(Some(DocumentoNacional::Cnpj("11222333000144".to_string())), transporta.documento);
+    assert_eq!(Some("CAMPINAS".to_string()), transporta.municipio);
+
+    let ret = transp.retencao_icms.as_ref().unwrap();
+    assert_eq!(dec!(60), ret.valor);
+    assert_eq!(3509502, ret.codigo_municipio);
+
+    assert_eq!("ABC1D23", transp.veiculo.as_ref().unwrap().placa);
+    assert_eq!(2, transp.reboques.len());
+    assert_eq!(None, transp.reboques[1].uf);
+
+    assert_eq!(2, transp.volumes.len());
+    assert_eq!(Some(10), transp.volumes[0].quantidade);
+    assert_eq!(Some(dec!(130.75)), transp.volumes[0].peso_bruto);
+    assert_eq!(vec![Lacre::new("L001"), Lacre::new("L002")], transp.volumes[0].lacres);
+    assert!(transp.volumes[1].lacres.is_empty());
+
+    assert_eq!(transp, transp.to_string().parse::<Transporte>()?);
+
+    Ok(())
+}
+
+#[test]
+fn serializar_somente_modalidade() {
+    let transp = Transporte::new(ModalidadeFrete::SemTransporte);
+    assert_eq!("<transp><modFrete>9</modFrete></transp>", transp.to_string());
+}
+
+#[test]
+fn serializar_volume_com_peso() {
+    let mut transp = Transporte::new(ModalidadeFrete::ContratacaoPorContaDoDestinatario);
+    transp.volumes.push(Volume {
+        quantidade: Some(2),
+        peso_liquido: Some(dec!(10.5)),
+        lacres: vec![Lacre::new("999")],
+        ..Default::default()
+    });
+
+    let xml = transp.to_string();
+    assert!(xml.contains("<vol><qVol>2</qVol><pesoL>10.500</pesoL><lacres><nLacre>999</nLacre></lacres></vol>"));
+}
+
+#[test]
+fn validar_exclusividade_e_reboques() {
+    let mut transp = Transporte::new(ModalidadeFrete::ContratacaoPorContaDoRemetente);
+    transp.veiculo = Some(veiculo("ABC1234"));
+    assert!(transp.validar().is_ok());
+
+    transp.vagao = Some("VAGAO-1".to_string());
+    assert!(transp.validar().is_err());
+
+    transp.vagao = None;
+    transp.reboques = (0..6).map(|i| veiculo(&format!("REB000{}", i))).collect();
+    assert!(transp.validar().is_err());
+}
+
+#[test]
+fn builder_com_transporte_round_trip() -> Result<(), Error> {
+    let nfe = builder()
+        .modalidade_frete(ModalidadeFrete::ContratacaoPorContaDoRemetente)
+        .transportador(Transportador {
+            documento: Some(DocumentoNacional::cpf("123.456.789-09")),
+            razao_social: Some("JOSE MOTORISTA".to_string()),
+            uf: Some("SP".to_string()),
+            ..Default::default()
+        })
+        .retencao_icms_transporte(RetencaoIcmsTransporte {
+            valor_servico: dec!(200),
+            valor_bc: dec!(200),
+            aliquota: dec!(12),
+            valor: dec!(24),
+            cfop: "5352".to_string(),
+            codigo_municipio: 3550308,
+        })
+        .veiculo(veiculo("ABC1D23"))
+        .add_reboque(veiculo("REB1D23"))
+        .add_volume(Volume {
+            quantidade: Some(3),
+            especie: Some("CAIXA".to_string()),
+            ..Default::default()
+        })
+        .build()
+        .unwrap();
+
+    let xml = nfe.to_string();
+    assert!(xml.contains("<transporta><CPF>12345678909</CPF><xNome>JOSE MOTORISTA</xNome><UF>SP</UF></transporta>"));
+
+    let lida = xml.parse::<Nfe>()?;
+    assert_eq!(nfe.transporte, lida.transporte);
+    assert_eq!(1, lida.transporte.reboques.len());
+
+    Ok(())
+}
+
+#[test]
+fn builder_rejeita_veiculo_e_balsa() {
+    let resultado = builder()
+        .veiculo(veiculo("ABC1D23"))
+        .balsa("BALSA-7")
+        .build();
+
+    assert!(resultado.is_err());
+}
@@ -0,0 +1,176 @@
+//! Testes da tributação configurada no `ItemBuilder`
+
+use crate::base::calculo::{
+    MetodoDifal, ParametrosDifal, ParametrosIcms, ParametrosIpi, ParametrosPisCofins,
+    RegimePisCofins,
+};
+use crate::base::endereco::Endereco;
+use crate::base::ide::{DestinoOperacao, TipoConsumidor};
+use crate::base::item::Issqn;
+use super::builder;
+use crate::builder::ItemBuilder;
+use rust_decimal_macros::dec;
+
+fn mercadoria() -> ItemBuilder {
+    ItemBuilder::new("001", "Produto", "61091000", "5102")
+        .valor_unitario(dec!(1000))
+        .frete(dec!(100))
+        .seguro(dec!(20))
+        .outras_despesas(dec!(30))
+        .desconto(dec!(50))
+        .cest("2806300")
+        .beneficio_fiscal("SP000001")
+        .ex_tipi("01")
+        .calcular_ipi(ParametrosIpi {
+            cst: "50".to_string(),
+            aliquota: dec!(10),
+            ..Default::default()
+        })
+        .calcular_icms(ParametrosIcms {
+            cst: "00".to_string(),
+            aliquota: dec!(18),
+            ..Default::default()
+        })
+        .calcular_pis_cofins(ParametrosPisCofins {
+            regime: RegimePisCofins::Cumulativo,
+            cst: "01".to_string(),
+            ..Default::default()
+        })
+}
+
+fn servico() -> ItemBuilder {
+    ItemBuilder::new("S01", "Instalação", "00", "5933")
+        .valor_unitario(dec!(500))
+        .issqn(Issqn {
+            aliquota: dec!(5),
+            codigo_municipio_fato_gerador: "3550308".to_string(),
+            codigo_lista_servico: "14.01".to_string(),
+            ..Default::default()
+        })
+        .calcular_pis_cofins(ParametrosPisCofins {
+            regime: RegimePisCofins::Cumulativo,
+            cst: "01".to_string(),
+            ..Default::default()
+        })
+}
+
+#[test]
+fn calcula_impostos_com_despesas_do_item() {
+    let nfe = builder().add_item(mercadoria()).build().unwrap();
+    let item = &nfe.itens[0];
+
+    assert_eq!(Some(dec!(100)), item.produto.valor_frete);
+    assert_eq!(Some("2806300".to_string()), item.produto.tributacao.cest);
+    assert_eq!(Some("SP000001".to_string()), item.produto.tributacao.codigo_beneficio_fiscal);
+    assert_eq!(Some("01".to_string()), item.produto.tributacao.codigo_excecao_ipi);
+
+    // IPI: 1000 + 100 + 20 + 30 = 1150 × 10%
+    assert_eq!(dec!(115), item.imposto.ipi.as_ref().unwrap().valor_ipi());
+    // ICMS: 1150 − 50 = 1100 × 18% (IPI fora da BC entre contribuintes)
+    let icms = item.imposto.icms.as_ref().unwrap();
+    assert_eq!(dec!(1100), icms.valor_bc());
+    assert_eq!(dec!(198), icms.valor_icms());
+    // PIS/COFINS cumulativos sobre 1100
+    assert_eq!(dec!(7.15), item.imposto.pis.as_ref().unwrap().valor());
+    assert_eq!(dec!(33), item.imposto.cofins.as_ref().unwrap().valor());
+
+    assert_eq!(dec!(150), nfe.totais.valor_frete + nfe.totais.valor_seguro + nfe.totais.valor_outros);
+    // 1000 − 50 + 100 + 20 + 30 + 115
+    assert_eq!(dec!(1215), nfe.totais.valor_total);
+    assert!(nfe.verificar_totais().is_empty());
+
+    let xml = nfe.to_string();
+    assert!(xml.contains(
+        "<NCM>61091000</NCM><CEST>2806300</CEST><cBenef>SP000001</cBenef><EXTIPI>01</EXTIPI><CFOP>5102</CFOP>"
+    ));
+    assert!(xml.contains("<vFrete>100.00</vFrete><vSeg>20.00</vSeg><vDesc>50.00</vDesc><vOutro>30.00</vOutro>"));
+
+    // A consumidor final, o IPI integra a BC do ICMS
+    let consumidor = builder()
+        .tipo_consumidor(TipoConsumidor::Final)
+        .add_item(mercadoria())
+        .build()
+        .unwrap();
+    let icms = consumidor.itens[0].imposto.icms.as_ref().unwrap();
+    assert_eq!(dec!(1215), icms.valor_bc());
+    assert_eq!(dec!(218.70), icms.valor_icms());
+}
+
+#[test]
+fn totaliza_servicos_no_issqntot() {
+    let nfe = builder().add_item(mercadoria()).add_item(servico()).build().unwrap();
+
+    let issqn = nfe.itens[1].imposto.issqn.as_ref().unwrap();
+    assert_eq!(dec!(500), issqn.valor_bc);
+    assert_eq!(dec!(25), issqn.valor);
+
+    // O serviço fica fora do vProd, vPIS e vCOFINS do ICMSTot
+    assert_eq!(dec!(1000), nfe.totais.valor_produtos);
+    assert_eq!(dec!(7.15), nfe.totais.valor_pis);
+    let servicos = nfe.totais.servicos.as_ref().unwrap();
+    assert_eq!(dec!(500), servicos.valor_servicos);
+    assert_eq!(dec!(25), servicos.valor_iss);
+    assert_eq!(dec!(3.25), servicos.valor_pis);
+    assert_eq!(dec!(15), servicos.valor_cofins);
+    assert_eq!(Some(nfe.ide.emissao.horario.date_naive()), servicos.data_competencia);
+    // vNF inclui o vServ
+    assert_eq!(dec!(1715), nfe.totais.valor_total);
+    assert!(nfe.verificar_totais().is_empty());
+
+    // ISSQN no lugar do ICMS, antes do PIS
+    let xml = nfe.to_string();
+    assert!(xml.contains("<imposto><ISSQN><vBC>500.00</vBC>"));
+    assert!(xml.contains("</ISSQN><PIS>"));
+
+    let xml = nfe.totais.to_string();
+    assert!(xml.contains("</ICMSTot><ISSQNtot><vServ>500.00</vServ><vBC>500.00</vBC><vISS>25.00</vISS>"));
+    assert_eq!(nfe.totais, xml.parse().unwrap());
+
+    let erro = builder()
+        .add_item(servico().calcular_icms(ParametrosIcms {
+            cst: "00".to_string(),
+            ..Default::default()
+        }))
+        .build()
+        .unwrap_err();
+    assert!(erro.starts_with("Item 1:"));
+    assert!(erro.contains("ISSQN"));
+}
+
+#[test]
+fn ajusta_difal_pelo_item() {
+    let endereco = |uf: &str| Endereco {
+        sigla_uf: uf.to_string(),
+        ..Default::default()
+    };
+    let item = || {
+        ItemBuilder::new("001", "Produto", "61091000", "6108")
+            .valor_unitario(dec!(1000))
+            .frete(dec!(100))
+            .difal(ParametrosDifal {
+                aliquota_interna: Some(dec!(18)),
+                aliquota_fcp: Some(dec!(0)),
+                metodo: Some(MetodoDifal::BaseUnica),
+                ..Default::default()
+            })
+    };
+
+    let nfe = builder()
+        .destino_operacao(DestinoOperacao::Interestadual)
+        .tipo_consumidor(TipoConsumidor::Final)
+        .emit_endereco(endereco("SP"))
+        .dest_cpf("12345678909")
+        .dest_endereco(endereco("RJ"))
+        .add_item(item())
+        .build()
+        .unwrap();
+
+    // (1000 + 100) × (18% − 12%)
+    let difal = nfe.itens[0].imposto.icms_uf_dest.as_ref().unwrap();
+    assert_eq!(dec!(1100), difal.valor_bc_uf_dest);
+    assert_eq!(dec!(66), difal.valor_icms_uf_dest);
+    assert_eq!(None, difal.valor_fcp_uf_dest);
+
+    let erro = builder().add_item(item()).build().unwrap_err();
+    assert!(erro.contains("DIFAL exige operação interestadual"));
+}
@@ -0,0 +1,188 @@
+//! Testes do validador de regras de negócio (códigos de rejeição da SEFAZ)
+
+use crate::base::calculo::{calcular_icms, ParametrosIcms};
+use crate::base::emit::RegimeTributario;
+use crate::base::ide::ModeloDocumentoFiscal;
+use crate::base::nfce::ValidadorNfce;
+use crate::base::validacao::{ConjuntoRegras, ValidadorNfe};
+use crate::base::Nfe;
+use super::builder;
+use crate::builder::ItemBuilder;
+use chrono::Duration;
+use rust_decimal_macros::dec;
+
+fn item() -> ItemBuilder {
+    ItemBuilder::new("001", "Produto", "61091000", "5102")
+        .valor_unitario(dec!(100))
+        .gtin("7891000315507")
+}
+
+fn codigos(validador: &ValidadorNfe, nfe: &Nfe) -> Vec<Option<u16>> {
+    validador.rejeicoes(nfe).iter().map(|r| r.codigo).collect()
+}
+
+fn validador(nfe: &Nfe) -> ValidadorNfe {
+    ValidadorNfe::new().data_referencia(nfe.ide.emissao.horario)
+}
+
+#[test]
+fn nota_do_builder_e_valida() {
+    let nfe = builder().add_item(item()).build().unwrap();
+    assert_eq!(Ok(()), validador(&nfe).validar(&nfe));
+}
+
+#[test]
+fn chave_divergente_do_ide() {
+    let mut nfe = builder().add_item(item()).build().unwrap();
+    nfe.ide.numero = 2;
+
+    let rejeicoes = validador(&nfe).rejeicoes(&nfe);
+    assert_eq!(1, rejeicoes.len());
+    assert_eq!(Some(502), rejeicoes[0].codigo);
+    assert_eq!(
+        Some("nNF da chave 000000001 difere do informado 000000002".to_string()),
+        rejeicoes[0].detalhe
+    );
+
+    nfe.ide.numero = 1;
+    let digito = (nfe.ide.chave.digito_verificador + 1) % 10;
+    nfe.chave_acesso.replace_range(43.., &digito.to_string());
+    nfe.ide.chave.digito_verificador = digito;
+    assert_eq!(vec![Some(236)], codigos(&validador(&nfe), &nfe));
+}
+
+#[test]
+fn datas_de_emissao_e_saida() {
+    let mut nfe = builder().add_item(item()).build().unwrap();
+    let emissao = nfe.ide.emissao.horario;
+
+    let atrasada = ValidadorNfe::new().data_referencia(emissao + Duration::days(31));
+    assert_eq!(vec![Some(228)], codigos(&atrasada, &nfe));
+    let futura = ValidadorNfe::new().data_referencia(emissao - Duration::hours(1));
+    assert_eq!(vec![Some(703)], codigos(&futura, &nfe));
+
+    nfe.ide.operacao.horario = Some(emissao - Duration::days(1));
+    assert_eq!(vec![Some(505)], codigos(&validador(&nfe), &nfe));
+}
+
+#[test]
+fn cfop_incompativel_com_tipo_e_destino() {
+    let nfe = builder()
+        .add_item(ItemBuilder::new("001", "Produto", "61091000", "1102").valor_unitario(dec!(100)))
+        .add_item(ItemBuilder::new("002", "Produto", "61091000", "6102").valor_unitario(dec!(100)))
+        .build()
+        .unwrap();
+
+    let rejeicoes = validador(&nfe).rejeicoes(&nfe);
+    let codigos: Vec<_> = rejeicoes.iter().map(|r| r.codigo).collect();
+    assert_eq!(vec![Some(518), Some(523)], codigos);
+    assert_eq!(Some("item 2: CFOP 6102".to_string()), rejeicoes[1].detalhe);
+}
+
+#[test]
+fn cst_incompativel_com_crt() {
+    let csosn = calcular_icms(&ParametrosIcms {
+        cst: "102".to_string(),
+        valor_produto: dec!(100),
+        ..Default::default()
+    })
+    .unwrap();
+    let nfe = builder().add_item(item().icms(csosn.clone())).build().unwrap();
+    assert_eq!(vec![Some(591)], codigos(&validador(&nfe), &nfe));
+
+    let simples = builder()
+        .emit_crt(RegimeTributario::SimplesNacional)
+        .add_item(item().icms(csosn))
+        .build()
+        .unwrap();
+    assert_eq!(Ok(()), validador(&simples).validar(&simples));
+
+    let cst = calcular_icms(&ParametrosIcms {
+        cst: "41".to_string(),
+        ..Default::default()
+    })
+    .unwrap();
+    let nfe = builder()
+        .emit_crt(RegimeTributario::SimplesNacional)
+        .add_item(item().icms(cst))
+        .build()
+        .unwrap();
+    assert_eq!(vec![Some(590)], codigos(&validador(&nfe), &nfe));
+}
+
+#[test]
+fn ncm_e_gtin_invalidos() {
+    let nfe = builder()
+        .add_item(ItemBuilder::new("001", "Produto", "6109", "5102").valor_unitario(dec!(100)))
+        .add_item(item().gtin("7891000315508"))
+        .build()
+        .unwrap();
+
+    let rejeicoes = ValidadorNfe::new().conjuntos(&[ConjuntoRegras::Itens]).rejeicoes(&nfe);
+    let codigos: Vec<_> = rejeicoes.iter().map(|r| r.codigo).collect();
+    assert_eq!(vec![Some(777), Some(611), Some(612)], codigos);
+    assert_eq!("Rejeição 611: cEAN inválido (item 2: 7891000315508)", rejeicoes[1].to_string());
+}
+
+#[test]
+fn totais_divergentes_entram_na_validacao() {
+    let mut nfe = builder().add_item(item()).build().unwrap();
+    nfe.totais.valor_produtos = dec!(90);
+
+    let rejeicoes = ValidadorNfe::new().conjuntos(&[ConjuntoRegras::Totais]).rejeicoes(&nfe);
+    assert_eq!(vec![Some(564), Some(610)], rejeicoes.iter().map(|r| r.codigo).collect::<Vec<_>>());
+    assert_eq!(Some("vProd informado 90.00, calculado 100.00".to_string()), rejeicoes[0].detalhe);
+}
+
+#[test]
+fn regras_da_nfce() {
+    let nfce = builder()
+        .modelo(ModeloDocumentoFiscal::Nfce)
+        .add_item(ItemBuilder::new("001", "Produto", "61091000", "1102").valor_unitario(dec!(100)))
+        .build()
+        .unwrap();
+    let rejeicoes = ValidadorNfe::new().conjuntos(&[ConjuntoRegras::Nfce]).rejeicoes(&nfce);
+    assert_eq!(vec![Some(725)], rejeicoes.iter().map(|r| r.codigo).collect::<Vec<_>>());
+
+    // Regras da nota uma única vez; o CFOP, por item
+    let nfce = builder()
+        .modelo(ModeloDocumentoFiscal::Nfce)
+        .add_item(item().valor_unitario(dec!(6000)))
+        .add_item(ItemBuilder::new("002", "Produto", "61091000", "1102").valor_unitario(dec!(6000)))
+        .add_item(ItemBuilder::new("003", "Produto", "61091000", "2102").valor_unitario(dec!(1)))
+        .build()
+        .unwrap();
+    let rejeicoes = ValidadorNfe::new().conjuntos(&[ConjuntoRegras::Nfce]).rejeicoes(&nfce);
+    assert_eq!(
+        vec![
+            "Destinatário é obrigatório para NFC-e com valor acima de R$ 10.000,00".to_string(),
+            "Rejeição 725: NFC-e com CFOP inválido (item 2: CFOP 1102)".to_string(),
+            "Rejeição 725: NFC-e com CFOP inválido (item 3: CFOP 2102)".to_string(),
+        ],
+        rejeicoes.iter().map(|r| r.to_string()).collect::<Vec<_>>()
+    );
+
+    // O validador legado aplica o mesmo conjunto sobre os dados informados
+    let erros = ValidadorNfce::validar(55, dec!(20000), 1000, false, "5102").unwrap_err();
+    assert_eq!(
+        vec![
+            "NFC-e deve usar modelo 65".to_string(),
+            "Destinatário é obrigatório para NFC-e com valor acima de R$ 10.000,00".to_string(),
+            "Rejeição 225: Falha no Schema XML da NFe (a nota deve ter de 1 a 990 itens (encontrados: 1000))"
+                .to_string(),
+        ],
+        erros
+    );
+    assert!(ValidadorNfce::validar(65, dec!(100), 1, false, "5102").is_ok());
+}
+
+#[test]
+fn builder_numera_acima_de_255_itens_e_respeita_o_limite() {
+    let nfe = (0..300).fold(builder(), |b, _| b.add_item(item())).build().unwrap();
+    assert_eq!(300, nfe.itens.last().unwrap().numero);
+    assert!(nfe.to_string().contains("<det nItem=\"300\">"));
+
+    let erro = (0..991).fold(builder(), |b, _| b.add_item(item())).build().unwrap_err();
+    assert!(erro.contains("Rejeição 225"));
+    assert!(erro.contains("encontrados: 991"));
+}
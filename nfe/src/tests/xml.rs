@@ -0,0 +1,115 @@
+//! Testes de leitura de XML real da SEFAZ (namespaces, BOM, codificação)
+
+use crate::base::protocolo::NfeProc;
+use crate::base::xml::elemento_raiz;
+use crate::builder::{ItemBuilder, NfeBuilder};
+use crate::{Error, Nfe};
+use rust_decimal_macros::dec;
+
+fn nfe() -> Nfe {
+    NfeBuilder::new()
+        .codigo_uf(35)
+        .numero(1)
+        .natureza_operacao("VENDA DE PRODUÇÃO DO ESTABELECIMENTO")
+        .codigo_municipio(3550308)
+        .emit_cnpj("12345678000190")
+        .emit_razao_social("INDÚSTRIA AÇÃO LTDA")
+        .emit_ie("123456789")
+        .add_item(ItemBuilder::new("001", "Produto", "12345678", "5101").valor_unitario(dec!(10)))
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn serializar_com_namespace() {
+    let xml = nfe().to_string();
+
+    assert!(xml.starts_with(r#"<NFe xmlns="http://www.portalfiscal.inf.br/nfe"><infNFe"#));
+}
+
+#[test]
+fn parse_namespace_padrao() -> Result<(), Error> {
+    let nfe = nfe();
+    let xml = format!("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n{}", nfe);
+
+    assert_eq!(nfe, xml.parse::<Nfe>()?);
+
+    Ok(())
+}
+
+#[test]
+fn parse_namespace_prefixado() -> Result<(), Error> {
+    let nfe = nfe();
+    let xml = nfe
+        .to_string()
+        .replace("</", "\u{0}")
+        .replace('<', "<nfe:")
+        .replace('\u{0}', "</nfe:")
+        .replace("xmlns=", "xmlns:nfe=");
+
+    assert!(xml.starts_with(r#"<nfe:NFe xmlns:nfe="http://www.portalfiscal.inf.br/nfe"><nfe:infNFe"#));
+    assert_eq!(nfe, xml.parse::<Nfe>()?);
+
+    Ok(())
+}
+
+#[test]
+fn parse_com_bom() -> Result<(), Error> {
+    let nfe = nfe();
+    let xml = format!("\u{feff}<?xml version=\"1.0\" encoding=\"UTF-8\"?>{}", nfe);
+
+    assert_eq!(nfe, xml.parse::<Nfe>()?);
+    assert_eq!(nfe, Nfe::from_bytes(xml.as_bytes())?);
+
+    Ok(())
+}
+
+#[test]
+fn parse_iso_8859_1() -> Result<(), Error> {
+    let nfe = nfe();
+    let xml = format!("<?xml version=\"1.0\" encoding=\"ISO-8859-1\"?>{}", nfe);
+    let bytes: Vec<u8> = xml.chars().map(|c| c as u8).collect();
+
+    assert!(std::str::from_utf8(&bytes).is_err());
+
+    let lida = Nfe::from_bytes(&bytes)?;
+    assert_eq!("VENDA DE PRODUÇÃO DO ESTABELECIMENTO", lida.ide.operacao.natureza);
+    assert_eq!(nfe, lida);
+
+    Ok(())
+}
+
+#[test]
+fn parse_nfe_proc_como_nfe() -> Result<(), Error> {
+    let nfe = nfe();
+    let protocolo = format!(
+        "<infProt><tpAmb>2</tpAmb><verAplic>SP_NFE_PL009_V4</verAplic><chNFe>{}</chNFe>\
+         <dhRecbto>2024-01-15T10:30:45-03:00</dhRecbto><nProt>135240000000001</nProt>\
+         <cStat>100</cStat><xMotivo>Autorizado o uso da NF-e</xMotivo></infProt>",
+        nfe.chave_acesso
+    )
+    .parse()?;
+    let xml = NfeProc::new(nfe, protocolo).to_string();
+
+    let proc = xml.parse::<NfeProc>()?;
+    assert_eq!(proc.nfe, xml.parse::<Nfe>()?);
+
+    Ok(())
+}
+
+#[test]
+fn parse_nfe_proc_raiz_errada() {
+    let xml = nfe().to_string();
+
+    assert!(xml.parse::<NfeProc>().is_err());
+}
+
+#[test]
+fn elemento_raiz_sem_prefixo() -> Result<(), Error> {
+    let xml = format!("\u{feff}<?xml version=\"1.0\"?><!-- nota -->{}", nfe());
+    assert_eq!("NFe", elemento_raiz(&xml)?);
+    assert_eq!("nfeProc", elemento_raiz("<nfe:nfeProc xmlns:nfe=\"x\"><nfe:NFe/></nfe:nfeProc>")?);
+    assert!(elemento_raiz("<?xml version=\"1.0\"?>").is_err());
+
+    Ok(())
+}
@@ -1,6 +1,7 @@
 //! Modelos para banco de dados
 
 use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
 /// NF-e armazenada no banco de dados
@@ -15,7 +16,7 @@ pub struct NfeRecord {
     pub emit_razao_social: String,
     pub dest_cnpj: Option<String>,
     pub dest_razao_social: Option<String>,
-    pub valor_total: f64,
+    pub valor_total: Decimal,
     pub xml: String,
     pub json_data: String,
     pub created_at: DateTime<Utc>,
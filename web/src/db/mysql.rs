@@ -49,7 +49,7 @@ impl MysqlClient {
 
     /// Busca NF-e por chave de acesso
     pub async fn find_by_chave(&self, chave: &str) -> Result<Option<NfeRecord>, sqlx::Error> {
-        let row = sqlx::query_as::<_, (String, String, i32, i16, chrono::DateTime<chrono::Utc>, String, String, Option<String>, Option<String>, f64, String, String, chrono::DateTime<chrono::Utc>)>(
+        let row = sqlx::query_as::<_, (String, String, i32, i16, chrono::DateTime<chrono::Utc>, String, String, Option<String>, Option<String>, rust_decimal::Decimal, String, String, chrono::DateTime<chrono::Utc>)>(
             "SELECT id, chave_acesso, numero, serie, data_emissao, emit_cnpj, emit_razao_social, dest_cnpj, dest_razao_social, valor_total, xml, json_data, created_at FROM nfe WHERE chave_acesso = ?"
         )
             .bind(chave)
@@ -75,7 +75,7 @@ impl MysqlClient {
 
     /// Lista NF-e com paginação
     pub async fn list(&self, limit: i64, offset: i64) -> Result<Vec<NfeRecord>, sqlx::Error> {
-        let rows = sqlx::query_as::<_, (String, String, i32, i16, chrono::DateTime<chrono::Utc>, String, String, Option<String>, Option<String>, f64, String, String, chrono::DateTime<chrono::Utc>)>(
+        let rows = sqlx::query_as::<_, (String, String, i32, i16, chrono::DateTime<chrono::Utc>, String, String, Option<String>, Option<String>, rust_decimal::Decimal, String, String, chrono::DateTime<chrono::Utc>)>(
             "SELECT id, chave_acesso, numero, serie, data_emissao, emit_cnpj, emit_razao_social, dest_cnpj, dest_razao_social, valor_total, xml, json_data, created_at FROM nfe ORDER BY created_at DESC LIMIT ? OFFSET ?"
         )
             .bind(limit)
@@ -49,8 +49,8 @@ impl PostgresClient {
 
     /// Busca NF-e por chave de acesso
     pub async fn find_by_chave(&self, chave: &str) -> Result<Option<NfeRecord>, sqlx::Error> {
-        let row = sqlx::query_as::<_, (String, String, i32, i16, chrono::DateTime<chrono::Utc>, String, String, Option<String>, Option<String>, f64, String, String, chrono::DateTime<chrono::Utc>)>(
-            "SELECT id, chave_acesso, numero, serie, data_emissao, emit_cnpj, emit_razao_social, dest_cnpj, dest_razao_social, valor_total::float8, xml, json_data::text, created_at FROM nfe WHERE chave_acesso = $1"
+        let row = sqlx::query_as::<_, (String, String, i32, i16, chrono::DateTime<chrono::Utc>, String, String, Option<String>, Option<String>, rust_decimal::Decimal, String, String, chrono::DateTime<chrono::Utc>)>(
+            "SELECT id, chave_acesso, numero, serie, data_emissao, emit_cnpj, emit_razao_social, dest_cnpj, dest_razao_social, valor_total, xml, json_data::text, created_at FROM nfe WHERE chave_acesso = $1"
         )
             .bind(chave)
             .fetch_optional(&self.pool)
@@ -75,8 +75,8 @@ impl PostgresClient {
 
     /// Lista NF-e com paginação
     pub async fn list(&self, limit: i64, offset: i64) -> Result<Vec<NfeRecord>, sqlx::Error> {
-        let rows = sqlx::query_as::<_, (String, String, i32, i16, chrono::DateTime<chrono::Utc>, String, String, Option<String>, Option<String>, f64, String, String, chrono::DateTime<chrono::Utc>)>(
-            "SELECT id, chave_acesso, numero, serie, data_emissao, emit_cnpj, emit_razao_social, dest_cnpj, dest_razao_social, valor_total::float8, xml, json_data::text, created_at FROM nfe ORDER BY created_at DESC LIMIT $1 OFFSET $2"
+        let rows = sqlx::query_as::<_, (String, String, i32, i16, chrono::DateTime<chrono::Utc>, String, String, Option<String>, Option<String>, rust_decimal::Decimal, String, String, chrono::DateTime<chrono::Utc>)>(
+            "SELECT id, chave_acesso, numero, serie, data_emissao, emit_cnpj, emit_razao_social, dest_cnpj, dest_razao_social, valor_total, xml, json_data::text, created_at FROM nfe ORDER BY created_at DESC LIMIT $1 OFFSET $2"
         )
             .bind(limit)
             .bind(offset)
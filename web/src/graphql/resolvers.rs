@@ -5,6 +5,8 @@ use crate::sefaz;
 use crate::sefaz::webservice::{SefazClient, AmbienteNfe};
 use crate::certificado::{CertificadoA1, AssinadorXml};
 use async_graphql::{Context, Object, Result as GqlResult};
+use nfe_parser::decimal::{arredondar, formatar};
+use nfe_parser::Decimal;
 use std::sync::Arc;
 use tokio::sync::RwLock;
 
@@ -65,12 +67,12 @@ impl QueryRoot {
             destinatario: None,
             itens: vec![],
             totais: TotaisType {
-                base_calculo_icms: 0.0,
-                valor_icms: 0.0,
-                valor_produtos: 0.0,
-                valor_frete: 0.0,
-                valor_desconto: 0.0,
-                valor_total: 0.0,
+                base_calculo_icms: Decimal::ZERO,
+                valor_icms: Decimal::ZERO,
+                valor_produtos: Decimal::ZERO,
+                valor_frete: Decimal::ZERO,
+                valor_desconto: Decimal::ZERO,
+                valor_total: Decimal::ZERO,
             },
             xml: None,
         }))
@@ -402,10 +404,8 @@ impl MutationRoot {
 
     /// Parse XML de NF-e
     async fn parse_xml(&self, xml: String) -> GqlResult<NfeType> {
-        // Usar parser existente
-        let xml_clean = xml.replace("xmlns=\"http://www.portalfiscal.inf.br/nfe\"", "");
-
-        let nfe: nfe_parser::Nfe = xml_clean.parse()
+        // Usar parser existente (aceita NFe avulsa ou nfeProc, com namespace)
+        let nfe: nfe_parser::Nfe = xml.parse()
             .map_err(|e| async_graphql::Error::new(format!("Erro ao parsear XML: {}", e)))?;
 
         Ok(NfeType {
@@ -424,7 +424,7 @@ impl MutationRoot {
             data_autorizacao: None,
             protocolo: None,
             emitente: EmitenteType {
-                cnpj: nfe.emit.cnpj.clone().unwrap_or_default(),
+                cnpj: nfe.emit.documento.numero().to_string(),
                 razao_social: nfe.emit.razao_social.clone().unwrap_or_default(),
                 nome_fantasia: nfe.emit.nome_fantasia.clone(),
                 inscricao_estadual: nfe.emit.ie.clone(),
@@ -439,21 +439,25 @@ impl MutationRoot {
                     pais: Some("Brasil".to_string()),
                 },
             },
-            destinatario: nfe.dest.as_ref().map(|d| DestinatarioType {
-                cnpj: Some(d.cnpj.clone()),
-                cpf: None,
-                razao_social: d.razao_social.clone().unwrap_or_default(),
-                inscricao_estadual: None,
-                endereco: d.endereco.as_ref().map(|e| EnderecoType {
-                    logradouro: e.logradouro.clone(),
-                    numero: e.numero.clone(),
-                    complemento: e.complemento.clone(),
-                    bairro: e.bairro.clone(),
-                    municipio: e.nome_municipio.clone(),
-                    uf: e.sigla_uf.clone(),
-                    cep: e.cep.clone(),
-                    pais: Some("Brasil".to_string()),
-                }),
+            destinatario: nfe.dest.as_ref().map(|d| {
+                let (cnpj, cpf, id_estrangeiro) = d.documento.to_tags();
+                DestinatarioType {
+                    cnpj,
+                    cpf,
+                    id_estrangeiro,
+                    razao_social: d.razao_social.clone().unwrap_or_default(),
+                    inscricao_estadual: d.ie.clone(),
+                    endereco: d.endereco.as_ref().map(|e| EnderecoType {
+                        logradouro: e.logradouro.clone(),
+                        numero: e.numero.clone(),
+                        complemento: e.complemento.clone(),
+                        bairro: e.bairro.clone(),
+                        municipio: e.nome_municipio.clone(),
+                        uf: e.sigla_uf.clone(),
+                        cep: e.cep.clone(),
+                        pais: Some("Brasil".to_string()),
+                    }),
+                }
             }),
             itens: nfe.itens.iter().map(|item| ItemType {
                 numero: item.numero as i32,
@@ -462,17 +466,17 @@ impl MutationRoot {
                 ncm: item.produto.ncm.clone(),
                 cfop: item.produto.tributacao.cfop.clone(),
                 unidade: item.produto.unidade.clone(),
-                quantidade: item.produto.quantidade as f64,
-                valor_unitario: item.produto.valor_unitario as f64,
-                valor_total: item.produto.valor_bruto as f64,
+                quantidade: item.produto.quantidade,
+                valor_unitario: item.produto.valor_unitario,
+                valor_total: item.produto.valor_bruto,
             }).collect(),
             totais: TotaisType {
-                base_calculo_icms: nfe.totais.valor_base_calculo as f64,
-                valor_icms: nfe.totais.valor_icms as f64,
-                valor_produtos: nfe.totais.valor_produtos as f64,
-                valor_frete: nfe.totais.valor_frete as f64,
-                valor_desconto: nfe.totais.valor_desconto as f64,
-                valor_total: nfe.totais.valor_total as f64,
+                base_calculo_icms: nfe.totais.valor_base_calculo,
+                valor_icms: nfe.totais.valor_icms,
+                valor_produtos: nfe.totais.valor_produtos,
+                valor_frete: nfe.totais.valor_frete,
+                valor_desconto: nfe.totais.valor_desconto,
+                valor_total: nfe.totais.valor_total,
             },
             xml: Some(xml),
         })
@@ -545,6 +549,8 @@ fn gerar_xml_nfe(input: &NfeInput) -> Result<String, async_graphql::Error> {
             xml.push_str(&format!("<CNPJ>{}</CNPJ>", cnpj));
         } else if let Some(ref cpf) = dest.cpf {
             xml.push_str(&format!("<CPF>{}</CPF>", cpf));
+        } else if let Some(ref id) = dest.id_estrangeiro {
+            xml.push_str(&format!("<idEstrangeiro>{}</idEstrangeiro>", id));
         }
         xml.push_str(&format!("<xNome>{}</xNome>", dest.razao_social));
         if let Some(ref end) = dest.endereco {
@@ -574,13 +580,13 @@ fn gerar_xml_nfe(input: &NfeInput) -> Result<String, async_graphql::Error> {
         xml.push_str(&format!("<NCM>{}</NCM>", item.ncm));
         xml.push_str(&format!("<CFOP>{}</CFOP>", item.cfop));
         xml.push_str(&format!("<uCom>{}</uCom>", item.unidade));
-        xml.push_str(&format!("<qCom>{:.4}</qCom>", item.quantidade));
-        xml.push_str(&format!("<vUnCom>{:.4}</vUnCom>", item.valor_unitario));
-        xml.push_str(&format!("<vProd>{:.2}</vProd>", item.quantidade * item.valor_unitario));
+        xml.push_str(&format!("<qCom>{}</qCom>", formatar(item.quantidade, 4, 4)));
+        xml.push_str(&format!("<vUnCom>{}</vUnCom>", formatar(item.valor_unitario, 4, 4)));
+        xml.push_str(&format!("<vProd>{}</vProd>", formatar(item.quantidade * item.valor_unitario, 2, 2)));
         xml.push_str("<cEANTrib>SEM GTIN</cEANTrib>");
         xml.push_str(&format!("<uTrib>{}</uTrib>", item.unidade));
-        xml.push_str(&format!("<qTrib>{:.4}</qTrib>", item.quantidade));
-        xml.push_str(&format!("<vUnTrib>{:.4}</vUnTrib>", item.valor_unitario));
+        xml.push_str(&format!("<qTrib>{}</qTrib>", formatar(item.quantidade, 4, 4)));
+        xml.push_str(&format!("<vUnTrib>{}</vUnTrib>", formatar(item.valor_unitario, 4, 4)));
         xml.push_str("<indTot>1</indTot>");
         xml.push_str("</prod>");
         xml.push_str("<imposto>");
@@ -592,7 +598,11 @@ fn gerar_xml_nfe(input: &NfeInput) -> Result<String, async_graphql::Error> {
     }
 
     // total
-    let total_produtos: f64 = input.itens.iter().map(|i| i.quantidade * i.valor_unitario).sum();
+    let total_produtos = formatar(
+        input.itens.iter().map(|i| arredondar(i.quantidade * i.valor_unitario, 2)).sum(),
+        2,
+        2,
+    );
     xml.push_str("<total>");
     xml.push_str("<ICMSTot>");
     xml.push_str("<vBC>0.00</vBC>");
@@ -603,7 +613,7 @@ fn gerar_xml_nfe(input: &NfeInput) -> Result<String, async_graphql::Error> {
     xml.push_str("<vST>0.00</vST>");
     xml.push_str("<vFCPST>0.00</vFCPST>");
     xml.push_str("<vFCPSTRet>0.00</vFCPSTRet>");
-    xml.push_str(&format!("<vProd>{:.2}</vProd>", total_produtos));
+    xml.push_str(&format!("<vProd>{}</vProd>", total_produtos));
     xml.push_str("<vFrete>0.00</vFrete>");
     xml.push_str("<vSeg>0.00</vSeg>");
     xml.push_str("<vDesc>0.00</vDesc>");
@@ -613,7 +623,7 @@ fn gerar_xml_nfe(input: &NfeInput) -> Result<String, async_graphql::Error> {
     xml.push_str("<vPIS>0.00</vPIS>");
     xml.push_str("<vCOFINS>0.00</vCOFINS>");
     xml.push_str("<vOutro>0.00</vOutro>");
-    xml.push_str(&format!("<vNF>{:.2}</vNF>", total_produtos));
+    xml.push_str(&format!("<vNF>{}</vNF>", total_produtos));
     xml.push_str("</ICMSTot>");
     xml.push_str("</total>");
 
@@ -626,7 +636,7 @@ fn gerar_xml_nfe(input: &NfeInput) -> Result<String, async_graphql::Error> {
     xml.push_str("<pag>");
     xml.push_str("<detPag>");
     xml.push_str("<tPag>01</tPag>");
-    xml.push_str(&format!("<vPag>{:.2}</vPag>", total_produtos));
+    xml.push_str(&format!("<vPag>{}</vPag>", total_produtos));
     xml.push_str("</detPag>");
     xml.push_str("</pag>");
 
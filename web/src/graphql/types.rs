@@ -1,6 +1,7 @@
 //! Tipos GraphQL para NF-e
 
 use async_graphql::{SimpleObject, InputObject, Enum};
+use nfe_parser::Decimal;
 use serde::{Deserialize, Serialize};
 
 /// Tipo de documento fiscal
@@ -77,6 +78,7 @@ pub struct EmitenteType {
 pub struct DestinatarioType {
     pub cnpj: Option<String>,
     pub cpf: Option<String>,
+    pub id_estrangeiro: Option<String>,
     pub razao_social: String,
     pub inscricao_estadual: Option<String>,
     pub endereco: Option<EnderecoType>,
@@ -104,20 +106,20 @@ pub struct ItemType {
     pub ncm: String,
     pub cfop: String,
     pub unidade: String,
-    pub quantidade: f64,
-    pub valor_unitario: f64,
-    pub valor_total: f64,
+    pub quantidade: Decimal,
+    pub valor_unitario: Decimal,
+    pub valor_total: Decimal,
 }
 
 /// Totais da NF-e
 #[derive(Debug, Clone, SimpleObject, Serialize, Deserialize)]
 pub struct TotaisType {
-    pub base_calculo_icms: f64,
-    pub valor_icms: f64,
-    pub valor_produtos: f64,
-    pub valor_frete: f64,
-    pub valor_desconto: f64,
-    pub valor_total: f64,
+    pub base_calculo_icms: Decimal,
+    pub valor_icms: Decimal,
+    pub valor_produtos: Decimal,
+    pub valor_frete: Decimal,
+    pub valor_desconto: Decimal,
+    pub valor_total: Decimal,
 }
 
 /// Resultado de consulta SEFAZ
@@ -194,6 +196,8 @@ pub struct EmitenteInput {
 pub struct DestinatarioInput {
     pub cnpj: Option<String>,
     pub cpf: Option<String>,
+    /// Documento do destinatário estrangeiro (passaporte ou equivalente)
+    pub id_estrangeiro: Option<String>,
     pub razao_social: String,
     pub inscricao_estadual: Option<String>,
     pub endereco: Option<EnderecoInput>,
@@ -220,8 +224,8 @@ pub struct ItemInput {
     pub ncm: String,
     pub cfop: String,
     pub unidade: String,
-    pub quantidade: f64,
-    pub valor_unitario: f64,
+    pub quantidade: Decimal,
+    pub valor_unitario: Decimal,
 }
 
 /// Input para cancelamento
@@ -5,6 +5,7 @@
 //! - Geração de XML
 //! - Exportação JSON e PDF
 //! - Leitura de DANFE em PDF
+//! - Validação de regras de negócio da SEFAZ (dry-run)
 //! - Armazenamento em PostgreSQL/MySQL
 //! - Auto-save
 
@@ -20,12 +21,13 @@ use actix_web::{web, App, HttpRequest, HttpResponse, HttpServer, middleware};
 use actix_multipart::Multipart;
 use async_graphql_actix_web::{GraphQLRequest, GraphQLResponse};
 use futures_util::StreamExt;
-use nfe_parser::{Nfe, NfeBuilder, ItemBuilder};
+use nfe_parser::{Decimal, Nfe, NfeBuilder, NfeProc, ItemBuilder, ProtocoloAutorizacao};
 use pdf::{extract_danfe_data, DanfeData};
 use sefaz::{validar_chave_acesso, consultar_portal_publico, gerar_url_consulta_portal, ChaveAcessoInfo};
 use nfe_parser::base::endereco::Endereco;
 use nfe_parser::base::transporte::ModalidadeFrete;
-use nfe_parser::{TipoAmbiente, TipoOperacao, DestinoOperacao};
+use nfe_parser::{TipoAmbiente, TipoOperacao, DestinoOperacao, RegimeTributario};
+use rust_decimal::prelude::ToPrimitive;
 use serde::{Deserialize, Serialize};
 use std::env;
 use std::sync::Arc;
@@ -76,6 +78,18 @@ struct NfeData {
     totais: TotaisData,
     transporte: TransporteData,
     informacao_complementar: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    protocolo: Option<ProtocoloData>,
+}
+
+#[derive(Serialize, Clone)]
+struct ProtocoloData {
+    numero: Option<String>,
+    data_recebimento: String,
+    codigo_status: u16,
+    motivo: String,
+    digest_value: Option<String>,
+    autorizado: bool,
 }
 
 #[derive(Serialize, Clone)]
@@ -94,15 +108,21 @@ struct IdentificacaoData {
 #[derive(Serialize, Clone)]
 struct EmitenteData {
     cnpj: Option<String>,
+    cpf: Option<String>,
     razao_social: Option<String>,
     nome_fantasia: Option<String>,
     inscricao_estadual: Option<String>,
+    inscricao_municipal: Option<String>,
+    cnae: Option<String>,
+    crt: String,
     endereco: EnderecoData,
 }
 
 #[derive(Serialize, Clone)]
 struct DestinatarioData {
-    cnpj: String,
+    cnpj: Option<String>,
+    cpf: Option<String>,
+    id_estrangeiro: Option<String>,
     razao_social: Option<String>,
     indicador_ie: String,
     endereco: Option<EnderecoData>,
@@ -121,32 +141,32 @@ struct EnderecoData {
 
 #[derive(Serialize, Clone)]
 struct ItemData {
-    numero: u8,
+    numero: u16,
     codigo: String,
     descricao: String,
     ncm: String,
     cfop: String,
     unidade: String,
-    quantidade: f32,
-    valor_unitario: f32,
-    valor_bruto: f32,
-    valor_desconto: Option<f32>,
+    quantidade: Decimal,
+    valor_unitario: Decimal,
+    valor_bruto: Decimal,
+    valor_desconto: Option<Decimal>,
     gtin: Option<String>,
 }
 
 #[derive(Serialize, Clone)]
 struct TotaisData {
-    valor_produtos: f32,
-    valor_frete: f32,
-    valor_seguro: f32,
-    valor_desconto: f32,
-    valor_outros: f32,
-    valor_total: f32,
-    base_calculo_icms: f32,
-    valor_icms: f32,
-    valor_pis: f32,
-    valor_cofins: f32,
-    valor_aproximado_tributos: f32,
+    valor_produtos: Decimal,
+    valor_frete: Decimal,
+    valor_seguro: Decimal,
+    valor_desconto: Decimal,
+    valor_outros: Decimal,
+    valor_total: Decimal,
+    base_calculo_icms: Decimal,
+    valor_icms: Decimal,
+    valor_pis: Decimal,
+    valor_cofins: Decimal,
+    valor_aproximado_tributos: Decimal,
 }
 
 #[derive(Serialize, Clone)]
@@ -162,7 +182,8 @@ struct GenerateRequest {
     natureza_operacao: String,
     ambiente: String,
     codigo_municipio: u32,
-    emit_cnpj: String,
+    emit_cnpj: Option<String>,
+    emit_cpf: Option<String>,
     emit_razao_social: String,
     emit_nome_fantasia: Option<String>,
     emit_ie: String,
@@ -172,8 +193,14 @@ struct GenerateRequest {
     emit_municipio: String,
     emit_uf: String,
     emit_cep: String,
+    emit_im: Option<String>,
+    emit_cnae: Option<String>,
+    emit_crt: Option<u8>,
     dest_cnpj: Option<String>,
+    dest_cpf: Option<String>,
+    dest_id_estrangeiro: Option<String>,
     dest_razao_social: Option<String>,
+    dest_ie: Option<String>,
     dest_logradouro: Option<String>,
     dest_numero: Option<String>,
     dest_bairro: Option<String>,
@@ -194,8 +221,8 @@ struct ItemRequest {
     ncm: String,
     cfop: String,
     unidade: String,
-    quantidade: f32,
-    valor_unitario: f32,
+    quantidade: Decimal,
+    valor_unitario: Decimal,
 }
 
 #[derive(Deserialize)]
@@ -229,7 +256,9 @@ struct PdfBase64Request {
 // Funções auxiliares
 // ============================================================================
 
-fn nfe_to_data(nfe: &Nfe) -> NfeData {
+fn nfe_to_data(nfe: &Nfe, protocolo: Option<&ProtocoloAutorizacao>) -> NfeData {
+    let (emit_cnpj, emit_cpf) = nfe.emit.documento.to_tags();
+
     NfeData {
         chave_acesso: nfe.chave_acesso.clone(),
         versao: format!("{:?}", nfe.versao),
@@ -245,10 +274,14 @@ fn nfe_to_data(nfe: &Nfe) -> NfeData {
             data_emissao: nfe.ide.emissao.horario.format("%d/%m/%Y %H:%M").to_string(),
         },
         emitente: EmitenteData {
-            cnpj: nfe.emit.cnpj.clone(),
+            cnpj: emit_cnpj,
+            cpf: emit_cpf,
             razao_social: nfe.emit.razao_social.clone(),
             nome_fantasia: nfe.emit.nome_fantasia.clone(),
             inscricao_estadual: nfe.emit.ie.clone(),
+            inscricao_municipal: nfe.emit.im.clone(),
+            cnae: nfe.emit.cnae.clone(),
+            crt: format!("{:?}", nfe.emit.crt),
             endereco: EnderecoData {
                 logradouro: nfe.emit.endereco.logradouro.clone(),
                 numero: nfe.emit.endereco.numero.clone(),
@@ -259,19 +292,24 @@ fn nfe_to_data(nfe: &Nfe) -> NfeData {
                 cep: Some(nfe.emit.endereco.cep.clone()),
             },
         },
-        destinatario: nfe.dest.as_ref().map(|dest| DestinatarioData {
-            cnpj: dest.cnpj.clone(),
-            razao_social: dest.razao_social.clone(),
-            indicador_ie: format!("{:?}", dest.indicador_ie),
-            endereco: dest.endereco.as_ref().map(|end| EnderecoData {
-                logradouro: end.logradouro.clone(),
-                numero: end.numero.clone(),
-                complemento: end.complemento.clone(),
-                bairro: end.bairro.clone(),
-                municipio: end.nome_municipio.clone(),
-                uf: end.sigla_uf.clone(),
-                cep: Some(end.cep.clone()),
-            }),
+        destinatario: nfe.dest.as_ref().map(|dest| {
+            let (cnpj, cpf, id_estrangeiro) = dest.documento.to_tags();
+            DestinatarioData {
+                cnpj,
+                cpf,
+                id_estrangeiro,
+                razao_social: dest.razao_social.clone(),
+                indicador_ie: format!("{:?}", dest.indicador_ie),
+                endereco: dest.endereco.as_ref().map(|end| EnderecoData {
+                    logradouro: end.logradouro.clone(),
+                    numero: end.numero.clone(),
+                    complemento: end.complemento.clone(),
+                    bairro: end.bairro.clone(),
+                    municipio: end.nome_municipio.clone(),
+                    uf: end.sigla_uf.clone(),
+                    cep: Some(end.cep.clone()),
+                }),
+            }
         }),
         itens: nfe.itens.iter().map(|item| ItemData {
             numero: item.numero,
@@ -302,7 +340,31 @@ fn nfe_to_data(nfe: &Nfe) -> NfeData {
         transporte: TransporteData {
             modalidade: format!("{:?}", nfe.transporte.modalidade),
         },
-        informacao_complementar: nfe.informacao_complementar.clone(),
+        informacao_complementar: nfe
+            .informacoes_adicionais
+            .as_ref()
+            .and_then(|i| i.complementar.clone()),
+        protocolo: protocolo.map(|prot| ProtocoloData {
+            numero: prot.numero.clone(),
+            data_recebimento: prot.recebimento.format("%d/%m/%Y %H:%M:%S").to_string(),
+            codigo_status: prot.codigo_status,
+            motivo: prot.motivo.clone(),
+            digest_value: prot.digest_value.clone(),
+            autorizado: prot.autorizado(),
+        }),
+    }
+}
+
+/// Lê o XML recebido, aceitando tanto o XML de distribuição (`<nfeProc>`)
+/// quanto a NF-e avulsa (`<NFe>`)
+fn ler_nfe(xml: &str) -> Result<(Nfe, Option<ProtocoloAutorizacao>), nfe_parser::Error> {
+    // O parser trata namespaces (inclusive prefixados), BOM e a declaração XML;
+    // um <nfeProc> inválido devolve o próprio erro, sem nova tentativa como <NFe>
+    if nfe_parser::elemento_raiz(xml)? == "nfeProc" {
+        let proc = xml.parse::<NfeProc>()?;
+        Ok((proc.nfe, Some(proc.protocolo)))
+    } else {
+        Ok((xml.parse::<Nfe>()?, None))
     }
 }
 
@@ -312,12 +374,12 @@ async fn save_to_db(state: &AppState, nfe: &Nfe, xml: &str, data: &NfeData) -> b
         chave_acesso: nfe.chave_acesso.clone(),
         numero: nfe.ide.numero as i32,
         serie: nfe.ide.serie as i16,
-        data_emissao: nfe.ide.emissao.horario,
-        emit_cnpj: nfe.emit.cnpj.clone().unwrap_or_default(),
+        data_emissao: nfe.ide.emissao.horario.with_timezone(&Utc),
+        emit_cnpj: nfe.emit.documento.numero().to_string(),
         emit_razao_social: nfe.emit.razao_social.clone().unwrap_or_default(),
-        dest_cnpj: nfe.dest.as_ref().map(|d| d.cnpj.clone()),
+        dest_cnpj: nfe.dest.as_ref().map(|d| d.documento.numero().to_string()),
         dest_razao_social: nfe.dest.as_ref().and_then(|d| d.razao_social.clone()),
-        valor_total: nfe.totais.valor_total as f64,
+        valor_total: nfe.totais.valor_total,
         xml: xml.to_string(),
         json_data: serde_json::to_string(data).unwrap_or_default(),
         created_at: Utc::now(),
@@ -349,21 +411,14 @@ async fn save_to_db(state: &AppState, nfe: &Nfe, xml: &str, data: &NfeData) -> b
 /// Parse de XML
 async fn parse_nfe(body: web::Json<ParseRequest>, state: web::Data<AppState>) -> HttpResponse {
     let xml = &body.xml;
-    let xml_clean = xml.replace("xmlns=\"http://www.portalfiscal.inf.br/nfe\"", "");
 
-    let xml_to_parse = if let (Some(start), Some(end)) = (xml_clean.find("<NFe"), xml_clean.find("</NFe>")) {
-        &xml_clean[start..end + 6]
-    } else {
-        &xml_clean
-    };
+    match ler_nfe(xml) {
+        Ok((nfe, protocolo)) => {
+            let data = nfe_to_data(&nfe, protocolo.as_ref());
 
-    match xml_to_parse.parse::<Nfe>() {
-        Ok(nfe) => {
-            let data = nfe_to_data(&nfe);
-
-            // Auto-save se habilitado
+            // Auto-save se habilitado (arquiva o XML recebido, com o protocolo)
             let saved = if body.auto_save || state.auto_save {
-                save_to_db(&state, &nfe, xml_to_parse, &data).await
+                save_to_db(&state, &nfe, xml, &data).await
             } else {
                 false
             };
@@ -429,21 +484,59 @@ async fn generate_nfe(body: web::Json<GenerateRequest>, state: web::Data<AppStat
         .destino_operacao(DestinoOperacao::Interna)
         .ambiente(ambiente)
         .codigo_municipio(req.codigo_municipio)
-        .emit_cnpj(&req.emit_cnpj)
         .emit_razao_social(&req.emit_razao_social)
         .emit_ie(&req.emit_ie)
         .emit_endereco(emit_endereco)
         .modalidade_frete(modalidade);
 
+    if let Some(cnpj) = req.emit_cnpj {
+        builder = builder.emit_cnpj(&cnpj);
+    } else if let Some(cpf) = req.emit_cpf {
+        builder = builder.emit_cpf(&cpf);
+    }
     if let Some(fantasia) = req.emit_nome_fantasia {
         builder = builder.emit_nome_fantasia(&fantasia);
     }
+    if let Some(im) = req.emit_im {
+        builder = builder.emit_im(&im);
+    }
+    if let Some(cnae) = req.emit_cnae {
+        builder = builder.emit_cnae(&cnae);
+    }
+    if let Some(crt) = req.emit_crt {
+        let crt = match crt {
+            1 => RegimeTributario::SimplesNacional,
+            2 => RegimeTributario::SimplesNacionalExcessoSublimite,
+            3 => RegimeTributario::RegimeNormal,
+            4 => RegimeTributario::SimplesNacionalMei,
+            outro => {
+                return HttpResponse::BadRequest().json(NfeResponse {
+                    success: false,
+                    data: None,
+                    xml: None,
+                    error: Some(format!("CRT {} inválido: informe 1, 2, 3 ou 4", outro)),
+                    saved: None,
+                });
+            }
+        };
+        builder = builder.emit_crt(crt);
+    }
 
-    if let Some(dest_cnpj) = req.dest_cnpj {
-        builder = builder.dest_cnpj(&dest_cnpj);
+    let dest_informado = req.dest_cnpj.is_some() || req.dest_cpf.is_some() || req.dest_id_estrangeiro.is_some();
+    if dest_informado {
+        if let Some(cnpj) = req.dest_cnpj {
+            builder = builder.dest_cnpj(&cnpj);
+        } else if let Some(cpf) = req.dest_cpf {
+            builder = builder.dest_cpf(&cpf);
+        } else if let Some(id) = req.dest_id_estrangeiro {
+            builder = builder.dest_id_estrangeiro(&id);
+        }
         if let Some(razao) = req.dest_razao_social {
             builder = builder.dest_razao_social(&razao);
         }
+        if let Some(ie) = req.dest_ie {
+            builder = builder.dest_ie(&ie);
+        }
         if let (Some(log), Some(num), Some(bairro), Some(mun), Some(uf), Some(cep)) = (
             req.dest_logradouro, req.dest_numero, req.dest_bairro,
             req.dest_municipio, req.dest_uf, req.dest_cep
@@ -479,8 +572,8 @@ async fn generate_nfe(body: web::Json<GenerateRequest>, state: web::Data<AppStat
 
     match builder.build() {
         Ok(nfe) => {
-            let data = nfe_to_data(&nfe);
-            let xml = format!("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n{}", nfe.to_string());
+            let data = nfe_to_data(&nfe, None);
+            let xml = format!("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n{}", nfe);
 
             // Auto-save se habilitado
             let saved = if req.auto_save || state.auto_save {
@@ -511,18 +604,9 @@ async fn generate_nfe(body: web::Json<GenerateRequest>, state: web::Data<AppStat
 
 /// Exportar para JSON
 async fn export_json(body: web::Json<ParseRequest>) -> HttpResponse {
-    let xml = &body.xml;
-    let xml_clean = xml.replace("xmlns=\"http://www.portalfiscal.inf.br/nfe\"", "");
-
-    let xml_to_parse = if let (Some(start), Some(end)) = (xml_clean.find("<NFe"), xml_clean.find("</NFe>")) {
-        &xml_clean[start..end + 6]
-    } else {
-        &xml_clean
-    };
-
-    match xml_to_parse.parse::<Nfe>() {
-        Ok(nfe) => {
-            let data = nfe_to_data(&nfe);
+    match ler_nfe(&body.xml) {
+        Ok((nfe, protocolo)) => {
+            let data = nfe_to_data(&nfe, protocolo.as_ref());
             let json = serde_json::to_string_pretty(&data).unwrap();
 
             HttpResponse::Ok()
@@ -893,20 +977,42 @@ async fn validar_chave(path: web::Path<String>) -> HttpResponse {
     }
 }
 
-/// Exportar NF-e para PDF (DANFE)
-async fn export_pdf(body: web::Json<ParseRequest>) -> HttpResponse {
-    let xml = &body.xml;
-    let xml_clean = xml.replace("xmlns=\"http://www.portalfiscal.inf.br/nfe\"", "");
+/// Valida as regras de negócio da NF-e sem transmiti-la (dry-run)
+async fn validar_nfe(body: web::Json<ParseRequest>) -> HttpResponse {
+    match ler_nfe(&body.xml) {
+        Ok((nfe, _)) => {
+            let rejeicoes: Vec<_> = nfe_parser::ValidadorNfe::new()
+                .rejeicoes(&nfe)
+                .into_iter()
+                .map(|r| serde_json::json!({
+                    "codigo": r.codigo,
+                    "mensagem": r.mensagem,
+                    "detalhe": r.detalhe
+                }))
+                .collect();
 
-    let xml_to_parse = if let (Some(start), Some(end)) = (xml_clean.find("<NFe"), xml_clean.find("</NFe>")) {
-        &xml_clean[start..end + 6]
-    } else {
-        &xml_clean
-    };
+            HttpResponse::Ok().json(serde_json::json!({
+                "success": true,
+                "valida": rejeicoes.is_empty(),
+                "chave_acesso": nfe.chave_acesso,
+                "rejeicoes": rejeicoes
+            }))
+        }
+        Err(e) => {
+            HttpResponse::BadRequest().json(serde_json::json!({
+                "success": false,
+                "valida": false,
+                "error": format!("Erro ao processar XML: {}", e)
+            }))
+        }
+    }
+}
 
+/// Exportar NF-e para PDF (DANFE)
+async fn export_pdf(body: web::Json<ParseRequest>) -> HttpResponse {
     // Parser para o formato interno (nfe-parser)
-    match xml_to_parse.parse::<Nfe>() {
-        Ok(nfe) => {
+    match ler_nfe(&body.xml) {
+        Ok((nfe, _)) => {
             // Converter para o formato nfe_parser::Nfe que o generate_danfe espera
             // Nota: precisamos do formato da crate original
             match generate_danfe_from_parsed(&nfe) {
@@ -985,7 +1091,7 @@ fn generate_danfe_from_parsed(nfe: &Nfe) -> Result<Vec<u8>, String> {
     draw_box_pdf(&current_layer, 10.0, y, 190.0, 25.0);
     current_layer.use_text("EMITENTE", 6.0, Mm(12.0), Mm(y + 22.0), &font);
     current_layer.use_text(nfe.emit.razao_social.as_deref().unwrap_or(""), 10.0, Mm(12.0), Mm(y + 15.0), &font_bold);
-    current_layer.use_text(&format!("CNPJ: {}", format_cnpj_pdf(nfe.emit.cnpj.as_deref().unwrap_or(""))), 8.0, Mm(12.0), Mm(y + 8.0), &font);
+    current_layer.use_text(&format!("{}: {}", nfe.emit.documento.tag(), format_cnpj_pdf(nfe.emit.documento.numero())), 8.0, Mm(12.0), Mm(y + 8.0), &font);
     let endereco_emit = format!(
         "{}, {} - {} - {}/{}",
         nfe.emit.endereco.logradouro,
@@ -1002,7 +1108,7 @@ fn generate_danfe_from_parsed(nfe: &Nfe) -> Result<Vec<u8>, String> {
     current_layer.use_text("DESTINATÁRIO/REMETENTE", 6.0, Mm(12.0), Mm(y + 22.0), &font);
     if let Some(dest) = &nfe.dest {
         current_layer.use_text(dest.razao_social.as_deref().unwrap_or(""), 10.0, Mm(12.0), Mm(y + 15.0), &font_bold);
-        current_layer.use_text(&format!("CNPJ/CPF: {}", &dest.cnpj), 8.0, Mm(12.0), Mm(y + 8.0), &font);
+        current_layer.use_text(&format!("CNPJ/CPF: {}", dest.documento.numero()), 8.0, Mm(12.0), Mm(y + 8.0), &font);
         if let Some(end) = &dest.endereco {
             let endereco_dest = format!(
                 "{}, {} - {} - {}/{}",
@@ -1158,16 +1264,8 @@ struct DanfeRequest {
 async fn gerar_danfe_pdf(body: web::Json<DanfeRequest>) -> HttpResponse {
     // Se recebeu XML, parsear primeiro
     if let Some(ref xml) = body.xml {
-        let xml_clean = xml.replace("xmlns=\"http://www.portalfiscal.inf.br/nfe\"", "");
-
-        let xml_to_parse = if let (Some(start), Some(end)) = (xml_clean.find("<NFe"), xml_clean.find("</NFe>")) {
-            &xml_clean[start..end + 6]
-        } else {
-            &xml_clean
-        };
-
-        match xml_to_parse.parse::<Nfe>() {
-            Ok(nfe) => {
+        match ler_nfe(xml) {
+            Ok((nfe, protocolo)) => {
                 // Converter para DanfeInput
                 let input = pdf::DanfeInput {
                     chave_acesso: nfe.chave_acesso.clone(),
@@ -1175,10 +1273,10 @@ async fn gerar_danfe_pdf(body: web::Json<DanfeRequest>) -> HttpResponse {
                     serie: nfe.ide.serie,
                     data_emissao: nfe.ide.emissao.horario.format("%d/%m/%Y %H:%M").to_string(),
                     natureza_operacao: nfe.ide.operacao.natureza.clone(),
-                    protocolo: None,
-                    data_autorizacao: None,
+                    protocolo: protocolo.as_ref().and_then(|p| p.numero.clone()),
+                    data_autorizacao: protocolo.as_ref().map(|p| p.recebimento.format("%d/%m/%Y %H:%M:%S").to_string()),
                     emitente: pdf::DanfeEmitente {
-                        cnpj: nfe.emit.cnpj.clone().unwrap_or_default(),
+                        cnpj: nfe.emit.documento.numero().to_string(),
                         razao_social: nfe.emit.razao_social.clone().unwrap_or_default(),
                         nome_fantasia: nfe.emit.nome_fantasia.clone(),
                         inscricao_estadual: nfe.emit.ie.clone(),
@@ -1189,9 +1287,9 @@ async fn gerar_danfe_pdf(body: web::Json<DanfeRequest>) -> HttpResponse {
                         telefone: nfe.emit.endereco.telefone.clone(),
                     },
                     destinatario: nfe.dest.as_ref().map(|d| pdf::DanfeDestinatario {
-                        cnpj_cpf: d.cnpj.clone(),
+                        cnpj_cpf: d.documento.numero().to_string(),
                         razao_social: d.razao_social.clone().unwrap_or_default(),
-                        inscricao_estadual: None,
+                        inscricao_estadual: d.ie.clone(),
                         endereco: d.endereco.as_ref().map(|e| format!("{}, {}", e.logradouro, e.numero)).unwrap_or_default(),
                         municipio: d.endereco.as_ref().map(|e| e.nome_municipio.clone()).unwrap_or_default(),
                         uf: d.endereco.as_ref().map(|e| e.sigla_uf.clone()).unwrap_or_default(),
@@ -1204,29 +1302,50 @@ async fn gerar_danfe_pdf(body: web::Json<DanfeRequest>) -> HttpResponse {
                         ncm: item.produto.ncm.clone(),
                         cfop: item.produto.tributacao.cfop.clone(),
                         unidade: item.produto.unidade.clone(),
-                        quantidade: item.produto.quantidade as f64,
-                        valor_unitario: item.produto.valor_unitario as f64,
-                        valor_total: item.produto.valor_bruto as f64,
+                        quantidade: item.produto.quantidade.to_f64().unwrap_or_default(),
+                        valor_unitario: item.produto.valor_unitario.to_f64().unwrap_or_default(),
+                        valor_total: item.produto.valor_bruto.to_f64().unwrap_or_default(),
                     }).collect(),
                     totais: pdf::DanfeTotais {
-                        base_calculo_icms: nfe.totais.valor_base_calculo as f64,
-                        valor_icms: nfe.totais.valor_icms as f64,
+                        base_calculo_icms: nfe.totais.valor_base_calculo.to_f64().unwrap_or_default(),
+                        valor_icms: nfe.totais.valor_icms.to_f64().unwrap_or_default(),
                         base_calculo_st: 0.0,
                         valor_st: 0.0,
-                        valor_produtos: nfe.totais.valor_produtos as f64,
-                        valor_frete: nfe.totais.valor_frete as f64,
-                        valor_seguro: nfe.totais.valor_seguro as f64,
-                        valor_desconto: nfe.totais.valor_desconto as f64,
+                        valor_produtos: nfe.totais.valor_produtos.to_f64().unwrap_or_default(),
+                        valor_frete: nfe.totais.valor_frete.to_f64().unwrap_or_default(),
+                        valor_seguro: nfe.totais.valor_seguro.to_f64().unwrap_or_default(),
+                        valor_desconto: nfe.totais.valor_desconto.to_f64().unwrap_or_default(),
                         valor_ipi: 0.0,
-                        valor_total: nfe.totais.valor_total as f64,
+                        valor_total: nfe.totais.valor_total.to_f64().unwrap_or_default(),
                     },
-                    transporte: Some(pdf::DanfeTransporte {
-                        modalidade: format!("{:?}", nfe.transporte.modalidade),
-                        transportadora: None,
-                        placa: None,
-                        uf: None,
+                    transporte: Some({
+                        let transporta = nfe.transporte.transportador.as_ref();
+                        let veiculo = nfe.transporte.veiculo.as_ref();
+                        pdf::DanfeTransporte {
+                            modalidade: format!("{:?}", nfe.transporte.modalidade),
+                            transportadora: transporta.and_then(|t| t.razao_social.clone()),
+                            placa: veiculo.map(|v| v.placa.clone()),
+                            uf: veiculo.and_then(|v| v.uf.clone()),
+                            cnpj_cpf: transporta.and_then(|t| t.documento.as_ref().map(|d| d.numero().to_string())),
+                            inscricao_estadual: transporta.and_then(|t| t.ie.clone()),
+                            endereco: transporta.and_then(|t| t.endereco.clone()),
+                            municipio: transporta.and_then(|t| t.municipio.clone()),
+                            uf_transportadora: transporta.and_then(|t| t.uf.clone()),
+                            rntc: veiculo.and_then(|v| v.rntc.clone()),
+                            volumes: nfe.transporte.volumes.iter().map(|v| pdf::DanfeVolume {
+                                quantidade: v.quantidade,
+                                especie: v.especie.clone(),
+                                marca: v.marca.clone(),
+                                numeracao: v.numeracao.clone(),
+                                peso_liquido: v.peso_liquido,
+                                peso_bruto: v.peso_bruto,
+                            }).collect(),
+                        }
                     }),
-                    informacoes_complementares: nfe.informacao_complementar.clone(),
+                    informacoes_complementares: nfe
+                        .informacoes_adicionais
+                        .as_ref()
+                        .and_then(|i| i.complementar.clone()),
                 };
 
                 match pdf::gerar_danfe(&input) {
@@ -1366,6 +1485,7 @@ async fn main() -> std::io::Result<()> {
             .route("/api/consultar/chave/{chave}", web::get().to(consultar_por_chave))
             .route("/api/consultar", web::post().to(consultar_nfe))
             .route("/api/validar-chave/{chave}", web::get().to(validar_chave))
+            .route("/api/validar", web::post().to(validar_nfe))
             .route("/api/nfe", web::get().to(list_nfe))
             .route("/api/nfe/{chave}", web::get().to(get_nfe))
             // GraphQL endpoints
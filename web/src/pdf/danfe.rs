@@ -2,6 +2,8 @@
 //!
 //! Layout moderno e elegante para NF-e usando printpdf
 
+use nfe_parser::decimal::{formatar, CASAS_PESO};
+use nfe_parser::Decimal;
 use printpdf::*;
 use serde::{Deserialize, Serialize};
 use std::io::BufWriter;
@@ -81,6 +83,30 @@ pub struct DanfeTransporte {
     pub transportadora: Option<String>,
     pub placa: Option<String>,
     pub uf: Option<String>,
+    #[serde(default)]
+    pub cnpj_cpf: Option<String>,
+    #[serde(default)]
+    pub inscricao_estadual: Option<String>,
+    #[serde(default)]
+    pub endereco: Option<String>,
+    #[serde(default)]
+    pub municipio: Option<String>,
+    #[serde(default)]
+    pub uf_transportadora: Option<String>,
+    #[serde(default)]
+    pub rntc: Option<String>,
+    #[serde(default)]
+    pub volumes: Vec<DanfeVolume>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DanfeVolume {
+    pub quantidade: Option<u32>,
+    pub especie: Option<String>,
+    pub marca: Option<String>,
+    pub numeracao: Option<String>,
+    pub peso_liquido: Option<Decimal>,
+    pub peso_bruto: Option<Decimal>,
 }
 
 /// Gera DANFE em PDF
@@ -114,6 +140,11 @@ pub fn gerar_danfe(input: &DanfeInput) -> Result<Vec<u8>, String> {
         y = draw_destinatario(&current_layer, &font, &font_bold, dest, y)?;
     }
 
+    // === TRANSPORTADOR / VOLUMES ===
+    if let Some(ref transp) = input.transporte {
+        y = draw_transporte(&current_layer, &font, &font_bold, transp, y)?;
+    }
+
     // === ITENS ===
     y = draw_itens(&current_layer, &font, &font_bold, &input.itens, y)?;
 
@@ -321,6 +352,103 @@ fn draw_destinatario(
     Ok(y)
 }
 
+fn draw_transporte(
+    layer: &PdfLayerReference,
+    font: &IndirectFontRef,
+    font_bold: &IndirectFontRef,
+    transp: &DanfeTransporte,
+    mut y: f32,
+) -> Result<f32, String> {
+    draw_section_title(layer, font_bold, "TRANSPORTADOR / VOLUMES TRANSPORTADOS", y);
+    y -= 5.0;
+
+    let linhas_volumes = transp.volumes.len().max(1) as f32;
+    let altura = 20.0 + linhas_volumes * 5.0;
+    draw_box(layer, 10.0, y - altura + 5.0, 190.0, altura);
+
+    // Razão Social / Frete / Veículo
+    layer.use_text(
+        transp.transportadora.as_deref().unwrap_or("-"),
+        9.0,
+        Mm(15.0),
+        Mm(y - 5.0),
+        font_bold,
+    );
+    layer.use_text(format!("Frete: {}", transp.modalidade), 8.0, Mm(95.0), Mm(y - 5.0), font);
+    layer.use_text(
+        format!(
+            "Placa: {} {}  RNTC: {}",
+            transp.placa.as_deref().unwrap_or("-"),
+            transp.uf.as_deref().unwrap_or(""),
+            transp.rntc.as_deref().unwrap_or("-")
+        ),
+        8.0,
+        Mm(140.0),
+        Mm(y - 5.0),
+        font,
+    );
+    y -= 6.0;
+
+    // Endereço / CNPJ / IE
+    layer.use_text(
+        format!(
+            "{} - {} - {}",
+            transp.endereco.as_deref().unwrap_or("-"),
+            transp.municipio.as_deref().unwrap_or("-"),
+            transp.uf_transportadora.as_deref().unwrap_or("-")
+        ),
+        8.0,
+        Mm(15.0),
+        Mm(y - 5.0),
+        font,
+    );
+    layer.use_text(
+        format!(
+            "CNPJ/CPF: {}",
+            transp.cnpj_cpf.as_deref().map(format_cnpj_cpf).unwrap_or_else(|| "-".to_string())
+        ),
+        8.0,
+        Mm(95.0),
+        Mm(y - 5.0),
+        font,
+    );
+    layer.use_text(
+        format!("IE: {}", transp.inscricao_estadual.as_deref().unwrap_or("-")),
+        8.0,
+        Mm(160.0),
+        Mm(y - 5.0),
+        font,
+    );
+    y -= 6.0;
+
+    // Volumes
+    layer.use_text("Qtd.", 7.0, Mm(15.0), Mm(y - 5.0), font_bold);
+    layer.use_text("Espécie", 7.0, Mm(30.0), Mm(y - 5.0), font_bold);
+    layer.use_text("Marca", 7.0, Mm(65.0), Mm(y - 5.0), font_bold);
+    layer.use_text("Numeração", 7.0, Mm(100.0), Mm(y - 5.0), font_bold);
+    layer.use_text("Peso Bruto", 7.0, Mm(135.0), Mm(y - 5.0), font_bold);
+    layer.use_text("Peso Líquido", 7.0, Mm(165.0), Mm(y - 5.0), font_bold);
+    y -= 5.0;
+
+    for vol in &transp.volumes {
+        let quantidade = vol.quantidade.map(|q| q.to_string()).unwrap_or_default();
+        let peso_bruto = vol.peso_bruto.map(|p| formatar(p, CASAS_PESO, CASAS_PESO)).unwrap_or_default();
+        let peso_liquido = vol.peso_liquido.map(|p| formatar(p, CASAS_PESO, CASAS_PESO)).unwrap_or_default();
+
+        layer.use_text(&quantidade, 7.0, Mm(15.0), Mm(y - 5.0), font);
+        layer.use_text(vol.especie.as_deref().unwrap_or(""), 7.0, Mm(30.0), Mm(y - 5.0), font);
+        layer.use_text(vol.marca.as_deref().unwrap_or(""), 7.0, Mm(65.0), Mm(y - 5.0), font);
+        layer.use_text(vol.numeracao.as_deref().unwrap_or(""), 7.0, Mm(100.0), Mm(y - 5.0), font);
+        layer.use_text(&peso_bruto, 7.0, Mm(135.0), Mm(y - 5.0), font);
+        layer.use_text(&peso_liquido, 7.0, Mm(165.0), Mm(y - 5.0), font);
+        y -= 5.0;
+    }
+
+    y -= 8.0;
+
+    Ok(y)
+}
+
 fn draw_itens(
     layer: &PdfLayerReference,
     font: &IndirectFontRef,